@@ -2,7 +2,7 @@ var e=Object.defineProperty,t=t=>{let n={};for(var r in t)e(n,r,{get:t[r],enumer
 `&&(e.tagName===`PRE`||e.tagName===`TEXTAREA`)&&(n=n.slice(1));let{textContent:r}=e;r!==n&&r!==n.replace(/\r\n|\r/g,`
 `)&&(ci(e,0)||ei(),e.textContent=t.children)}if(u){if(g||!o||d&48){let t=e.tagName.includes(`-`);for(let i in u)(g&&(i.endsWith(`value`)||i===`indeterminate`)||s(i)&&!te(i)||i[0]===`.`||t)&&r(e,i,null,u[i],void 0,n)}else if(u.onClick)r(e,`onClick`,null,u.onClick,void 0,n);else if(d&4&&nn(u.style))for(let e in u.style)u.style[e]}let _;(_=u&&u.onVnodeBeforeMount)&&bs(_,n,t),p&&vr(t,null,n,`beforeMount`),((_=u&&u.onVnodeMounted)||p||l)&&Jo(()=>{_&&bs(_,n,t),l&&h.enter(e),p&&vr(t,null,n,`mounted`)},i)}return e.nextSibling},m=(e,t,r,o,s,c,u)=>{u||=!!t.dynamicChildren;let d=t.children,p=d.length;for(let t=0;t<p;t++){let m=u?d[t]:d[t]=_s(d[t]),h=m.type===Zo;e?(h&&!u&&t+1<p&&_s(d[t+1]).type===Zo&&(l(i(e.data.slice(m.children.length)),r,a(e)),e.data=m.children),e=f(e,m,o,s,c,u)):h&&!m.children?l(m.el=i(``),r):(ci(r,1)||ei(),n(null,m,r,null,o,s,ri(r),c))}return e},h=(e,t,n,r,i,s)=>{let{slotScopeIds:c}=t;c&&(i=i?i.concat(c):c);let d=o(e),f=m(a(e),t,d,n,r,i,s);return f&&ii(f)&&f.data===`]`?a(t.anchor=f):(ei(),l(t.anchor=u(`]`),d,f),f)},g=(e,t,r,i,s,l)=>{if(ci(e.parentElement,1)||ei(),t.el=null,l){let t=_(e);for(;;){let n=a(e);if(n&&n!==t)c(n);else break}}let u=a(e),d=o(e);return c(e),n(null,t,d,u,r,i,ri(d),s),r&&(r.vnode.el=t.el,so(r,t.el)),u},_=(e,t=`[`,n=`]`)=>{let r=0;for(;e;)if(e=a(e),e&&ii(e)&&(e.data===t&&r++,e.data===n)){if(r===0)return a(e);r--}return e},v=(e,t,n)=>{let r=t.parentNode;r&&r.replaceChild(e,t);let i=n;for(;i;)i.vnode.el===t&&(i.vnode.el=i.subTree.el=e),i=i.parent},y=e=>e.nodeType===1&&e.tagName===`TEMPLATE`;return[d,f]}var oi=`data-allow-mismatch`,si={0:`text`,1:`children`,2:`class`,3:`style`,4:`attribute`};function ci(e,t){if(t===0||t===1)for(;e&&!e.hasAttribute(oi);)e=e.parentElement;let n=e&&e.getAttribute(oi);if(n==null)return!1;if(n===``)return!0;{let e=n.split(`,`);return t===0&&e.includes(`children`)?!0:e.includes(si[t])}}var li=fe().requestIdleCallback||(e=>setTimeout(e,1)),ui=fe().cancelIdleCallback||(e=>clearTimeout(e)),di=(e=1e4)=>t=>{let n=li(t,{timeout:e});return()=>ui(n)};function fi(e){let{top:t,left:n,bottom:r,right:i}=e.getBoundingClientRect(),{innerHeight:a,innerWidth:o}=window;return(t>0&&t<a||r>0&&r<a)&&(n>0&&n<o||i>0&&i<o)}var pi=e=>(t,n)=>{let r=new IntersectionObserver(e=>{for(let n of e)if(n.isIntersecting){r.disconnect(),t();break}},e);return n(e=>{if(e instanceof Element){if(fi(e))return t(),r.disconnect(),!1;r.observe(e)}}),()=>r.disconnect()},mi=e=>t=>{if(e){let n=matchMedia(e);if(n.matches)t();else return n.addEventListener(`change`,t,{once:!0}),()=>n.removeEventListener(`change`,t)}},hi=(e=[])=>(t,n)=>{y(e)&&(e=[e]);let r=!1,i=e=>{r||(r=!0,a(),t(),e.target.dispatchEvent(new e.constructor(e.type,e)))},a=()=>{n(t=>{for(let n of e)t.removeEventListener(n,i)})};return n(t=>{for(let n of e)t.addEventListener(n,i,{once:!0})}),a};function gi(e,t){if(ii(e)&&e.data===`[`){let n=1,r=e.nextSibling;for(;r;){if(r.nodeType===1){if(t(r)===!1)break}else if(ii(r))if(r.data===`]`){if(--n===0)break}else r.data===`[`&&n++;r=r.nextSibling}}else t(e)}var _i=e=>!!e.type.__asyncLoader;function vi(e){v(e)&&(e={loader:e});let{loader:t,loadingComponent:n,errorComponent:r,delay:i=200,hydrate:a,timeout:o,suspensible:s=!0,onError:c}=e,l=null,u,d=0,f=()=>(d++,l=null,p()),p=()=>{let e;return l||(e=l=t().catch(e=>{if(e=e instanceof Error?e:Error(String(e)),c)return new Promise((t,n)=>{c(e,()=>t(f()),()=>n(e),d+1)});throw e}).then(t=>e!==l&&l?l:(t&&(t.__esModule||t[Symbol.toStringTag]===`Module`)&&(t=t.default),u=t,t)))};return F({name:`AsyncComponentWrapper`,__asyncLoader:p,__asyncHydrate(e,t,n){let r=!1;(t.bu||=[]).push(()=>r=!0);let i=()=>{r||n()},o=a?()=>{let n=a(i,t=>gi(e,t));n&&(t.bum||=[]).push(n)}:i;u?o():p().then(()=>!t.isUnmounted&&o())},get __asyncResolved(){return u},setup(){let e=ws;if(Jr(e),u)return()=>yi(u,e);let t=t=>{l=null,Wn(t,e,13,!r)};if(s&&e.suspense||js)return p().then(t=>()=>yi(t,e)).catch(e=>(t(e),()=>r?U(r,{error:e}):null));let a=M(!1),c=M(),d=M(!!i);return i&&setTimeout(()=>{d.value=!1},i),o!=null&&setTimeout(()=>{if(!a.value&&!c.value){let e=Error(`Async component timed out after ${o}ms.`);t(e),c.value=e}},o),p().then(()=>{a.value=!0,e.parent&&bi(e.parent.vnode)&&e.parent.update()}).catch(e=>{t(e),c.value=e}),()=>{if(a.value&&u)return yi(u,e);if(c.value&&r)return U(r,{error:c.value});if(n&&!d.value)return yi(n,e)}}})}function yi(e,t){let{ref:n,props:r,children:i,ce:a}=t.vnode,o=U(e,r,i);return o.ref=n,o.ce=a,delete t.vnode.ce,o}var bi=e=>e.type.__isKeepAlive,xi={name:`KeepAlive`,__isKeepAlive:!0,props:{include:[String,RegExp,Array],exclude:[String,RegExp,Array],max:[String,Number]},setup(e,{slots:t}){let n=Ts(),r=n.ctx;if(!r.renderer)return()=>{let e=t.default&&t.default();return e&&e.length===1?e[0]:e};let i=new Map,a=new Set,o=null,s=n.suspense,{renderer:{p:c,m:l,um:u,o:{createElement:d}}}=r,f=d(`div`);r.activate=(e,t,n,r,i)=>{let a=e.component;l(e,t,n,0,s),c(a.vnode,e,t,n,a,s,r,e.slotScopeIds,i),Do(()=>{a.isDeactivated=!1,a.a&&se(a.a);let t=e.props&&e.props.onVnodeMounted;t&&bs(t,a.parent,e)},s)},r.deactivate=e=>{let t=e.component;Lo(t.m),Lo(t.a),l(e,f,null,1,s),Do(()=>{t.da&&se(t.da);let n=e.props&&e.props.onVnodeUnmounted;n&&bs(n,t.parent,e),t.isDeactivated=!0},s)};function p(e){Di(e),u(e,n,s,!0)}function m(e){i.forEach((t,n)=>{let r=Us(t.type);r&&!e(r)&&h(n)})}function h(e){let t=i.get(e);t&&(!o||!ss(t,o))?p(t):o&&Di(o),i.delete(e),a.delete(e)}L(()=>[e.include,e.exclude],([e,t])=>{e&&m(t=>Si(e,t)),t&&m(e=>!Si(t,e))},{flush:`post`,deep:!0});let g=null,_=()=>{g!=null&&(Ro(n.subTree.type)?Do(()=>{i.set(g,Oi(n.subTree))},n.subTree.suspense):i.set(g,Oi(n.subTree)))};return Mi(_),Pi(_),Fi(()=>{i.forEach(e=>{let{subTree:t,suspense:r}=n,i=Oi(t);if(e.type===i.type&&e.key===i.key){Di(i);let e=i.component.da;e&&Do(e,r);return}p(e)})}),()=>{if(g=null,!t.default)return o=null;let n=t.default(),r=n[0];if(n.length>1)return o=null,n;if(!os(r)||!(r.shapeFlag&4)&&!(r.shapeFlag&128))return o=null,r;let s=Oi(r);if(s.type===Qo)return o=null,s;let c=s.type,l=Us(_i(s)?s.type.__asyncResolved||{}:c),{include:u,exclude:d,max:f}=e;if(u&&(!l||!Si(u,l))||d&&l&&Si(d,l))return s.shapeFlag&=-257,o=s,r;let p=s.key==null?c:s.key,m=i.get(p);return s.el&&(s=ps(s),r.shapeFlag&128&&(r.ssContent=s)),g=p,m?(s.el=m.el,s.component=m.component,s.transition&&Gr(s,s.transition),s.shapeFlag|=512,a.delete(p),a.add(p)):(a.add(p),f&&a.size>parseInt(f,10)&&h(a.values().next().value)),s.shapeFlag|=256,o=s,Ro(r.type)?r:s}}};function Si(e,t){return p(e)?e.some(e=>Si(e,t)):y(e)?e.split(`,`).includes(t):_(e)?(e.lastIndex=0,e.test(t)):!1}function Ci(e,t){Ti(e,`a`,t)}function wi(e,t){Ti(e,`da`,t)}function Ti(e,t,n=ws){let r=e.__wdc||=()=>{let t=n;for(;t;){if(t.isDeactivated)return;t=t.parent}return e()};if(ki(t,r,n),n){let e=n.parent;for(;e&&e.parent;)bi(e.parent.vnode)&&Ei(r,t,n,e),e=e.parent}}function Ei(e,t,n,r){let i=ki(t,e,r,!0);Ii(()=>{u(r[t],i)},n)}function Di(e){e.shapeFlag&=-257,e.shapeFlag&=-513}function Oi(e){return e.shapeFlag&128?e.ssContent:e}function ki(e,t,n=ws,r=!1){if(n){let i=n[e]||(n[e]=[]),a=t.__weh||=(...r)=>{tt();let i=Os(n),a=Un(t,n,e,r);return i(),nt(),a};return r?i.unshift(a):i.push(a),a}}var Ai=e=>(t,n=ws)=>{(!js||e===`sp`)&&ki(e,(...e)=>t(...e),n)},ji=Ai(`bm`),Mi=Ai(`m`),Ni=Ai(`bu`),Pi=Ai(`u`),Fi=Ai(`bum`),Ii=Ai(`um`),Li=Ai(`sp`),Ri=Ai(`rtg`),zi=Ai(`rtc`);function Bi(e,t=ws){ki(`ec`,e,t)}var Vi=`components`,Hi=`directives`;function Ui(e,t){return qi(Vi,e,!0,t)||e}var Wi=Symbol.for(`v-ndc`);function Gi(e){return y(e)?qi(Vi,e,!1)||e:e||Wi}function Ki(e){return qi(Hi,e)}function qi(e,t,n=!0,r=!1){let i=dr||ws;if(i){let n=i.type;if(e===Vi){let e=Us(n,!1);if(e&&(e===t||e===D(t)||e===k(D(t))))return n}let a=Ji(i[e]||n[e],t)||Ji(i.appContext[e],t);return!a&&r?n:a}}function Ji(e,t){return e&&(e[t]||e[D(t)]||e[k(D(t))])}function Yi(e,t,n,r){let i,a=n&&n[r],o=p(e);if(o||y(e)){let n=o&&nn(e),r=!1,s=!1;n&&(r=!an(e),s=rn(e),e=gt(e)),i=Array(e.length);for(let n=0,o=e.length;n<o;n++)i[n]=t(r?s?ln(cn(e[n])):cn(e[n]):e[n],n,void 0,a&&a[n])}else if(typeof e==`number`){i=Array(e);for(let n=0;n<e;n++)i[n]=t(n+1,n,void 0,a&&a[n])}else if(x(e))if(e[Symbol.iterator])i=Array.from(e,(e,n)=>t(e,n,void 0,a&&a[n]));else{let n=Object.keys(e);i=Array(n.length);for(let r=0,o=n.length;r<o;r++){let o=n[r];i[r]=t(e[o],o,r,a&&a[r])}}else i=[];return n&&(n[r]=i),i}function Xi(e,t){for(let n=0;n<t.length;n++){let r=t[n];if(p(r))for(let t=0;t<r.length;t++)e[r[t].name]=r[t].fn;else r&&(e[r.name]=r.key?(...e)=>{let t=r.fn(...e);return t&&(t.key=r.key),t}:r.fn)}return e}function I(e,t,n={},r,i){if(dr.ce||dr.parent&&_i(dr.parent)&&dr.parent.ce){let e=Object.keys(n).length>0;return t!==`default`&&(n.name=t),z(),V(R,null,[U(`slot`,n,r&&r())],e?-2:64)}let a=e[t];a&&a._c&&(a._d=!1),z();let o=a&&Zi(a(n)),s=n.key||o&&o.key,c=V(R,{key:(s&&!b(s)?s:`_${t}`)+(!o&&r?`_fb`:``)},o||(r?r():[]),o&&e._===1?64:-2);return!i&&c.scopeId&&(c.slotScopeIds=[c.scopeId+`-s`]),a&&a._c&&(a._d=!0),c}function Zi(e){return e.some(e=>os(e)?!(e.type===Qo||e.type===R&&!Zi(e.children)):!0)?e:null}function Qi(e,t){let n={};for(let r in e)n[t&&/[A-Z]/.test(r)?`on:${r}`:ae(r)]=e[r];return n}var $i=e=>e?As(e)?Hs(e):$i(e.parent):null,ea=l(Object.create(null),{$:e=>e,$el:e=>e.vnode.el,$data:e=>e.data,$props:e=>e.props,$attrs:e=>e.attrs,$slots:e=>e.slots,$refs:e=>e.refs,$parent:e=>$i(e.parent),$root:e=>$i(e.root),$host:e=>e.ce,$emit:e=>e.emit,$options:e=>wa(e),$forceUpdate:e=>e.f||=()=>{tr(e.update)},$nextTick:e=>e.n||=$n.bind(e.proxy),$watch:e=>qa.bind(e)}),ta=(e,t)=>e!==r&&!e.__isScriptSetup&&f(e,t),na={get({_:e},t){if(t===`__v_skip`)return!0;let{ctx:n,setupState:i,data:a,props:o,accessCache:s,type:c,appContext:l}=e;if(t[0]!==`$`){let e=s[t];if(e!==void 0)switch(e){case 1:return i[t];case 2:return a[t];case 4:return n[t];case 3:return o[t]}else if(ta(i,t))return s[t]=1,i[t];else if(a!==r&&f(a,t))return s[t]=2,a[t];else if(f(o,t))return s[t]=3,o[t];else if(n!==r&&f(n,t))return s[t]=4,n[t];else ya&&(s[t]=0)}let u=ea[t],d,p;if(u)return t===`$attrs`&&ft(e.attrs,`get`,``),u(e);if((d=c.__cssModules)&&(d=d[t]))return d;if(n!==r&&f(n,t))return s[t]=4,n[t];if(p=l.config.globalProperties,f(p,t))return p[t]},set({_:e},t,n){let{data:i,setupState:a,ctx:o}=e;return ta(a,t)?(a[t]=n,!0):i!==r&&f(i,t)?(i[t]=n,!0):f(e.props,t)||t[0]===`$`&&t.slice(1)in e?!1:(o[t]=n,!0)},has({_:{data:e,setupState:t,accessCache:n,ctx:i,appContext:a,props:o,type:s}},c){let l;return!!(n[c]||e!==r&&c[0]!==`$`&&f(e,c)||ta(t,c)||f(o,c)||f(i,c)||f(ea,c)||f(a.config.globalProperties,c)||(l=s.__cssModules)&&l[c])},defineProperty(e,t,n){return n.get==null?f(n,`value`)&&this.set(e,t,n.value,null):e._.accessCache[t]=0,Reflect.defineProperty(e,t,n)}},ra=l({},na,{get(e,t){if(t!==Symbol.unscopables)return na.get(e,t,e)},has(e,t){return t[0]!==`_`&&!pe(t)}});function ia(){return null}function aa(){return null}function oa(e){}function sa(e){}function ca(){return null}function la(){}function ua(e,t){return null}function da(){return pa(`useSlots`).slots}function fa(){return pa(`useAttrs`).attrs}function pa(e){let t=Ts();return t.setupContext||=Vs(t)}function ma(e){return p(e)?e.reduce((e,t)=>(e[t]=null,e),{}):e}function ha(e,t){let n=ma(e);for(let e in t){if(e.startsWith(`__skip`))continue;let r=n[e];r?p(r)||v(r)?r=n[e]={type:r,default:t[e]}:r.default=t[e]:r===null&&(r=n[e]={default:t[e]}),r&&t[`__skip_${e}`]&&(r.skipFactory=!0)}return n}function ga(e,t){return!e||!t?e||t:p(e)&&p(t)?e.concat(t):l({},ma(e),ma(t))}function _a(e,t){let n={};for(let r in e)t.includes(r)||Object.defineProperty(n,r,{enumerable:!0,get:()=>e[r]});return n}function va(e){let t=Ts(),n=e();return ks(),S(n)&&(n=n.catch(e=>{throw Os(t),e})),[n,()=>Os(t)]}var ya=!0;function ba(e){let t=wa(e),n=e.proxy,r=e.ctx;ya=!1,t.beforeCreate&&Sa(t.beforeCreate,e,`bc`);let{data:i,computed:o,methods:s,watch:c,provide:l,inject:u,created:d,beforeMount:f,mounted:m,beforeUpdate:h,updated:g,activated:_,deactivated:y,beforeDestroy:b,beforeUnmount:S,destroyed:C,unmounted:w,render:T,renderTracked:ee,renderTriggered:E,errorCaptured:te,serverPrefetch:ne,expose:re,inheritAttrs:D,components:ie,directives:O,filters:k}=t;if(u&&xa(u,r,null),s)for(let e in s){let t=s[e];v(t)&&(r[e]=t.bind(n))}if(i){let t=i.call(n,n);x(t)&&(e.data=Zt(t))}if(ya=!0,o)for(let e in o){let t=o[e],i=G({get:v(t)?t.bind(n,n):v(t.get)?t.get.bind(n,n):a,set:!v(t)&&v(t.set)?t.set.bind(n):a});Object.defineProperty(r,e,{enumerable:!0,configurable:!0,get:()=>i.value,set:e=>i.value=e})}if(c)for(let e in c)Ca(c[e],r,n,e);if(l){let e=v(l)?l.call(n):l;Reflect.ownKeys(e).forEach(t=>{Ra(t,e[t])})}d&&Sa(d,e,`c`);function ae(e,t){p(t)?t.forEach(t=>e(t.bind(n))):t&&e(t.bind(n))}if(ae(ji,f),ae(Mi,m),ae(Ni,h),ae(Pi,g),ae(Ci,_),ae(wi,y),ae(Bi,te),ae(zi,ee),ae(Ri,E),ae(Fi,S),ae(Ii,w),ae(Li,ne),p(re))if(re.length){let t=e.exposed||={};re.forEach(e=>{Object.defineProperty(t,e,{get:()=>n[e],set:t=>n[e]=t,enumerable:!0})})}else e.exposed||={};T&&e.render===a&&(e.render=T),D!=null&&(e.inheritAttrs=D),ie&&(e.components=ie),O&&(e.directives=O),ne&&Jr(e)}function xa(e,t,n=a){for(let n in p(e)&&(e=ka(e)),e){let r=e[n],i;i=x(r)?`default`in r?za(r.from||n,r.default,!0):za(r.from||n):za(r),un(i)?Object.defineProperty(t,n,{enumerable:!0,configurable:!0,get:()=>i.value,set:e=>i.value=e}):t[n]=i}}function Sa(e,t,n){Un(p(e)?e.map(e=>e.bind(t.proxy)):e.bind(t.proxy),t,n)}function Ca(e,t,n,r){let i=r.includes(`.`)?Ja(n,r):()=>n[r];if(y(e)){let n=t[e];v(n)&&L(i,n)}else if(v(e))L(i,e.bind(n));else if(x(e))if(p(e))e.forEach(e=>Ca(e,t,n,r));else{let r=v(e.handler)?e.handler.bind(n):t[e.handler];v(r)&&L(i,r,e)}}function wa(e){let t=e.type,{mixins:n,extends:r}=t,{mixins:i,optionsCache:a,config:{optionMergeStrategies:o}}=e.appContext,s=a.get(t),c;return s?c=s:!i.length&&!n&&!r?c=t:(c={},i.length&&i.forEach(e=>Ta(c,e,o,!0)),Ta(c,t,o)),x(t)&&a.set(t,c),c}function Ta(e,t,n,r=!1){let{mixins:i,extends:a}=t;for(let o in a&&Ta(e,a,n,!0),i&&i.forEach(t=>Ta(e,t,n,!0)),t)if(!(r&&o===`expose`)){let r=Ea[o]||n&&n[o];e[o]=r?r(e[o],t[o]):t[o]}return e}var Ea={data:Da,props:Ma,emits:Ma,methods:ja,computed:ja,beforeCreate:Aa,created:Aa,beforeMount:Aa,mounted:Aa,beforeUpdate:Aa,updated:Aa,beforeDestroy:Aa,beforeUnmount:Aa,destroyed:Aa,unmounted:Aa,activated:Aa,deactivated:Aa,errorCaptured:Aa,serverPrefetch:Aa,components:ja,directives:ja,watch:Na,provide:Da,inject:Oa};function Da(e,t){return t?e?function(){return l(v(e)?e.call(this,this):e,v(t)?t.call(this,this):t)}:t:e}function Oa(e,t){return ja(ka(e),ka(t))}function ka(e){if(p(e)){let t={};for(let n=0;n<e.length;n++)t[e[n]]=e[n];return t}return e}function Aa(e,t){return e?[...new Set([].concat(e,t))]:t}function ja(e,t){return e?l(Object.create(null),e,t):t}function Ma(e,t){return e?p(e)&&p(t)?[...new Set([...e,...t])]:l(Object.create(null),ma(e),ma(t??{})):t}function Na(e,t){if(!e)return t;if(!t)return e;let n=l(Object.create(null),e);for(let r in t)n[r]=Aa(e[r],t[r]);return n}function Pa(){return{app:null,config:{isNativeTag:o,performance:!1,globalProperties:{},optionMergeStrategies:{},errorHandler:void 0,warnHandler:void 0,compilerOptions:{}},mixins:[],components:{},directives:{},provides:Object.create(null),optionsCache:new WeakMap,propsCache:new WeakMap,emitsCache:new WeakMap}}var Fa=0;function Ia(e,t){return function(n,r=null){v(n)||(n=l({},n)),r!=null&&!x(r)&&(r=null);let i=Pa(),a=new WeakSet,o=[],s=!1,c=i.app={_uid:Fa++,_component:n,_props:r,_container:null,_context:i,_instance:null,version:Js,get config(){return i.config},set config(e){},use(e,...t){return a.has(e)||(e&&v(e.install)?(a.add(e),e.install(c,...t)):v(e)&&(a.add(e),e(c,...t))),c},mixin(e){return i.mixins.includes(e)||i.mixins.push(e),c},component(e,t){return t?(i.components[e]=t,c):i.components[e]},directive(e,t){return t?(i.directives[e]=t,c):i.directives[e]},mount(a,o,l){if(!s){let u=c._ceVNode||U(n,r);return u.appContext=i,l===!0?l=`svg`:l===!1&&(l=void 0),o&&t?t(u,a):e(u,a,l),s=!0,c._container=a,a.__vue_app__=c,Hs(u.component)}},onUnmount(e){o.push(e)},unmount(){s&&(Un(o,c._instance,16),e(null,c._container),delete c._container.__vue_app__)},provide(e,t){return i.provides[e]=t,c},runWithContext(e){let t=La;La=c;try{return e()}finally{La=t}}};return c}}var La=null;function Ra(e,t){if(ws){let n=ws.provides,r=ws.parent&&ws.parent.provides;r===n&&(n=ws.provides=Object.create(r)),n[e]=t}}function za(e,t,n=!1){let r=Ts();if(r||La){let i=La?La._context.provides:r?r.parent==null||r.ce?r.vnode.appContext&&r.vnode.appContext.provides:r.parent.provides:void 0;if(i&&e in i)return i[e];if(arguments.length>1)return n&&v(t)?t.call(r&&r.proxy):t}}function Ba(){return!!(Ts()||La)}var Va=Symbol.for(`v-scx`),Ha=()=>za(Va);function Ua(e,t){return Ka(e,null,t)}function Wa(e,t){return Ka(e,null,{flush:`post`})}function Ga(e,t){return Ka(e,null,{flush:`sync`})}function L(e,t,n){return Ka(e,t,n)}function Ka(e,t,n=r){let{immediate:i,deep:o,flush:s,once:c}=n,u=l({},n),d=t&&i||!t&&s!==`post`,f;if(js){if(s===`sync`){let e=Ha();f=e.__watcherHandles||=[]}else if(!d){let e=()=>{};return e.stop=a,e.resume=a,e.pause=a,e}}let p=ws;u.call=(e,t,n)=>Un(e,p,t,n);let m=!1;s===`post`?u.scheduler=e=>{Do(e,p&&p.suspense)}:s!==`sync`&&(m=!0,u.scheduler=(e,t)=>{t?e():tr(e)}),u.augmentJob=e=>{t&&(e.flags|=4),m&&(e.flags|=2,p&&(e.id=p.uid,e.i=p))};let h=Pn(e,t,u);return js&&(f?f.push(h):d&&h()),h}function qa(e,t,n){let r=this.proxy,i=y(e)?e.includes(`.`)?Ja(r,e):()=>r[e]:e.bind(r,r),a;v(t)?a=t:(a=t.handler,n=t);let o=Os(this),s=Ka(i,a.bind(r),n);return o(),s}function Ja(e,t){let n=t.split(`.`);return()=>{let t=e;for(let e=0;e<n.length&&t;e++)t=t[n[e]];return t}}function Ya(e,t,n=r){let i=Ts(),a=D(t),o=O(t),s=Xa(e,a),c=yn((s,c)=>{let l,u=r,d;return Ga(()=>{let t=e[a];oe(l,t)&&(l=t,c())}),{get(){return s(),n.get?n.get(l):l},set(e){let s=n.set?n.set(e):e;if(!oe(s,l)&&!(u!==r&&oe(e,u)))return;let f=i.vnode.props;f&&(t in f||a in f||o in f)&&(`onUpdate:${t}`in f||`onUpdate:${a}`in f||`onUpdate:${o}`in f)||(l=e,c()),i.emit(`update:${t}`,s),oe(e,s)&&oe(e,u)&&!oe(s,d)&&c(),u=e,d=s}}});return c[Symbol.iterator]=()=>{let e=0;return{next(){return e<2?{value:e++?s||r:c,done:!1}:{done:!0}}}},c}var Xa=(e,t)=>t===`modelValue`||t===`model-value`?e.modelModifiers:e[`${t}Modifiers`]||e[`${D(t)}Modifiers`]||e[`${O(t)}Modifiers`];function Za(e,t,...n){if(e.isUnmounted)return;let i=e.vnode.props||r,a=n,o=t.startsWith(`update:`),s=o&&Xa(i,t.slice(7));s&&(s.trim&&(a=n.map(e=>y(e)?e.trim():e)),s.number&&(a=n.map(le)));let c,l=i[c=ae(t)]||i[c=ae(D(t))];!l&&o&&(l=i[c=ae(O(t))]),l&&Un(l,e,6,a);let u=i[c+`Once`];if(u){if(!e.emitted)e.emitted={};else if(e.emitted[c])return;e.emitted[c]=!0,Un(u,e,6,a)}}var Qa=new WeakMap;function $a(e,t,n=!1){let r=n?Qa:t.emitsCache,i=r.get(e);if(i!==void 0)return i;let a=e.emits,o={},s=!1;if(!v(e)){let r=e=>{let n=$a(e,t,!0);n&&(s=!0,l(o,n))};!n&&t.mixins.length&&t.mixins.forEach(r),e.extends&&r(e.extends),e.mixins&&e.mixins.forEach(r)}return!a&&!s?(x(e)&&r.set(e,null),null):(p(a)?a.forEach(e=>o[e]=null):l(o,a),x(e)&&r.set(e,o),o)}function eo(e,t){return!e||!s(t)?!1:(t=t.slice(2).replace(/Once$/,``),f(e,t[0].toLowerCase()+t.slice(1))||f(e,O(t))||f(e,t))}function to(e){let{type:t,vnode:n,proxy:r,withProxy:i,propsOptions:[a],slots:o,attrs:s,emit:l,render:u,renderCache:d,props:f,data:p,setupState:m,ctx:h,inheritAttrs:g}=e,_=pr(e),v,y;try{if(n.shapeFlag&4){let e=i||r,t=e;v=_s(u.call(t,e,d,f,m,p,h)),y=s}else{let e=t;v=_s(e.length>1?e(f,{attrs:s,slots:o,emit:l}):e(f,null)),y=t.props?s:ro(s)}}catch(t){es.length=0,Wn(t,e,1),v=U(Qo)}let b=v;if(y&&g!==!1){let e=Object.keys(y),{shapeFlag:t}=b;e.length&&t&7&&(a&&e.some(c)&&(y=io(y,a)),b=ps(b,y,!1,!0))}return n.dirs&&(b=ps(b,null,!1,!0),b.dirs=b.dirs?b.dirs.concat(n.dirs):n.dirs),n.transition&&Gr(b,n.transition),v=b,pr(_),v}function no(e,t=!0){let n;for(let t=0;t<e.length;t++){let r=e[t];if(os(r)){if(r.type!==Qo||r.children===`v-if`){if(n)return;n=r}}else return}return n}var ro=e=>{let t;for(let n in e)(n===`class`||n===`style`||s(n))&&((t||={})[n]=e[n]);return t},io=(e,t)=>{let n={};for(let r in e)(!c(r)||!(r.slice(9)in t))&&(n[r]=e[r]);return n};function ao(e,t,n){let{props:r,children:i,component:a}=e,{props:o,children:s,patchFlag:c}=t,l=a.emitsOptions;if(t.dirs||t.transition)return!0;if(n&&c>=0){if(c&1024)return!0;if(c&16)return r?oo(r,o,l):!!o;if(c&8){let e=t.dynamicProps;for(let t=0;t<e.length;t++){let n=e[t];if(o[n]!==r[n]&&!eo(l,n))return!0}}}else return(i||s)&&(!s||!s.$stable)?!0:r===o?!1:r?o?oo(r,o,l):!0:!!o;return!1}function oo(e,t,n){let r=Object.keys(t);if(r.length!==Object.keys(e).length)return!0;for(let i=0;i<r.length;i++){let a=r[i];if(t[a]!==e[a]&&!eo(n,a))return!0}return!1}function so({vnode:e,parent:t},n){for(;t;){let r=t.subTree;if(r.suspense&&r.suspense.activeBranch===e&&(r.el=e.el),r===e)(e=t.vnode).el=n,t=t.parent;else break}}var co={},lo=()=>Object.create(co),uo=e=>Object.getPrototypeOf(e)===co;function fo(e,t,n,r=!1){let i={},a=lo();for(let n in e.propsDefaults=Object.create(null),mo(e,t,i,a),e.propsOptions[0])n in i||(i[n]=void 0);n?e.props=r?i:Qt(i):e.type.props?e.props=i:e.props=a,e.attrs=a}function po(e,t,n,r){let{props:i,attrs:a,vnode:{patchFlag:o}}=e,s=j(i),[c]=e.propsOptions,l=!1;if((r||o>0)&&!(o&16)){if(o&8){let n=e.vnode.dynamicProps;for(let r=0;r<n.length;r++){let o=n[r];if(eo(e.emitsOptions,o))continue;let u=t[o];if(c)if(f(a,o))u!==a[o]&&(a[o]=u,l=!0);else{let t=D(o);i[t]=ho(c,s,t,u,e,!1)}else u!==a[o]&&(a[o]=u,l=!0)}}}else{mo(e,t,i,a)&&(l=!0);let r;for(let a in s)(!t||!f(t,a)&&((r=O(a))===a||!f(t,r)))&&(c?n&&(n[a]!==void 0||n[r]!==void 0)&&(i[a]=ho(c,s,a,void 0,e,!0)):delete i[a]);if(a!==s)for(let e in a)(!t||!f(t,e))&&(delete a[e],l=!0)}l&&pt(e.attrs,`set`,``)}function mo(e,t,n,i){let[a,o]=e.propsOptions,s=!1,c;if(t)for(let r in t){if(te(r))continue;let l=t[r],u;a&&f(a,u=D(r))?!o||!o.includes(u)?n[u]=l:(c||={})[u]=l:eo(e.emitsOptions,r)||(!(r in i)||l!==i[r])&&(i[r]=l,s=!0)}if(o){let t=j(n),i=c||r;for(let r=0;r<o.length;r++){let s=o[r];n[s]=ho(a,t,s,i[s],e,!f(i,s))}}return s}function ho(e,t,n,r,i,a){let o=e[n];if(o!=null){let e=f(o,`default`);if(e&&r===void 0){let e=o.default;if(o.type!==Function&&!o.skipFactory&&v(e)){let{propsDefaults:a}=i;if(n in a)r=a[n];else{let o=Os(i);r=a[n]=e.call(null,t),o()}}else r=e;i.ce&&i.ce._setProp(n,r)}o[0]&&(a&&!e?r=!1:o[1]&&(r===``||r===O(n))&&(r=!0))}return r}var go=new WeakMap;function _o(e,t,n=!1){let a=n?go:t.propsCache,o=a.get(e);if(o)return o;let s=e.props,c={},u=[],d=!1;if(!v(e)){let r=e=>{d=!0;let[n,r]=_o(e,t,!0);l(c,n),r&&u.push(...r)};!n&&t.mixins.length&&t.mixins.forEach(r),e.extends&&r(e.extends),e.mixins&&e.mixins.forEach(r)}if(!s&&!d)return x(e)&&a.set(e,i),i;if(p(s))for(let e=0;e<s.length;e++){let t=D(s[e]);vo(t)&&(c[t]=r)}else if(s)for(let e in s){let t=D(e);if(vo(t)){let n=s[e],r=c[t]=p(n)||v(n)?{type:n}:l({},n),i=r.type,a=!1,o=!0;if(p(i))for(let e=0;e<i.length;++e){let t=i[e],n=v(t)&&t.name;if(n===`Boolean`){a=!0;break}else n===`String`&&(o=!1)}else a=v(i)&&i.name===`Boolean`;r[0]=a,r[1]=o,(a||f(r,`default`))&&u.push(t)}}let m=[c,u];return x(e)&&a.set(e,m),m}function vo(e){return e[0]!==`$`&&!te(e)}var yo=e=>e===`_`||e===`_ctx`||e===`$stable`,bo=e=>p(e)?e.map(_s):[_s(e)],xo=(e,t,n)=>{if(t._n)return t;let r=P((...e)=>bo(t(...e)),n);return r._c=!1,r},So=(e,t,n)=>{let r=e._ctx;for(let n in e){if(yo(n))continue;let i=e[n];if(v(i))t[n]=xo(n,i,r);else if(i!=null){let e=bo(i);t[n]=()=>e}}},Co=(e,t)=>{let n=bo(t);e.slots.default=()=>n},wo=(e,t,n)=>{for(let r in t)(n||!yo(r))&&(e[r]=t[r])},To=(e,t,n)=>{let r=e.slots=lo();if(e.vnode.shapeFlag&32){let e=t._;e?(wo(r,t,n),n&&ce(r,`_`,e,!0)):So(t,r)}else t&&Co(e,t)},Eo=(e,t,n)=>{let{vnode:i,slots:a}=e,o=!0,s=r;if(i.shapeFlag&32){let e=t._;e?n&&e===1?o=!1:wo(a,t,n):(o=!t.$stable,So(t,a)),s=t}else t&&(Co(e,t),s={default:1});if(o)for(let e in a)!yo(e)&&s[e]==null&&delete a[e]},Do=Jo;function Oo(e){return Ao(e)}function ko(e){return Ao(e,ai)}function Ao(e,t){let n=fe();n.__VUE__=!0;let{insert:o,remove:s,patchProp:c,createElement:l,createText:u,createComment:d,setText:f,setElementText:p,parentNode:m,nextSibling:h,setScopeId:g=a,insertStaticContent:_}=e,v=(e,t,n,r=null,i=null,a=null,o=void 0,s=null,c=!!t.dynamicChildren)=>{if(e===t)return;e&&!ss(e,t)&&(r=A(e),me(e,i,a,!0),e=null),t.patchFlag===-2&&(c=!1,t.dynamicChildren=null);let{type:l,ref:u,shapeFlag:d}=t;switch(l){case Zo:y(e,t,n,r);break;case Qo:b(e,t,n,r);break;case $o:e??x(t,n,r,o);break;case R:ie(e,t,n,r,i,a,o,s,c);break;default:d&1?w(e,t,n,r,i,a,o,s,c):d&6?O(e,t,n,r,i,a,o,s,c):(d&64||d&128)&&l.process(e,t,n,r,i,a,o,s,c,xe)}u!=null&&i?Zr(u,e&&e.ref,a,t||e,!t):u==null&&e&&e.ref!=null&&Zr(e.ref,null,a,e,!0)},y=(e,t,n,r)=>{if(e==null)o(t.el=u(t.children),n,r);else{let n=t.el=e.el;t.children!==e.children&&f(n,t.children)}},b=(e,t,n,r)=>{e==null?o(t.el=d(t.children||``),n,r):t.el=e.el},x=(e,t,n,r)=>{[e.el,e.anchor]=_(e.children,t,n,r,e.el,e.anchor)},S=({el:e,anchor:t},n,r)=>{let i;for(;e&&e!==t;)i=h(e),o(e,n,r),e=i;o(t,n,r)},C=({el:e,anchor:t})=>{let n;for(;e&&e!==t;)n=h(e),s(e),e=n;s(t)},w=(e,t,n,r,i,a,o,s,c)=>{if(t.type===`svg`?o=`svg`:t.type===`math`&&(o=`mathml`),e==null)T(t,n,r,i,a,o,s,c);else{let n=e.el&&e.el._isVueCE?e.el:null;try{n&&n._beginPatch(),ne(e,t,i,a,o,s,c)}finally{n&&n._endPatch()}}},T=(e,t,n,r,i,a,s,u)=>{let d,f,{props:m,shapeFlag:h,transition:g,dirs:_}=e;if(d=e.el=l(e.type,a,m&&m.is,m),h&8?p(d,e.children):h&16&&E(e.children,d,null,r,i,jo(e,a),s,u),_&&vr(e,null,r,`created`),ee(d,e,e.scopeId,s,r),m){for(let e in m)e!==`value`&&!te(e)&&c(d,e,null,m[e],a,r);`value`in m&&c(d,`value`,null,m.value,a),(f=m.onVnodeBeforeMount)&&bs(f,r,e)}_&&vr(e,null,r,`beforeMount`);let v=No(i,g);v&&g.beforeEnter(d),o(d,t,n),((f=m&&m.onVnodeMounted)||v||_)&&Do(()=>{f&&bs(f,r,e),v&&g.enter(d),_&&vr(e,null,r,`mounted`)},i)},ee=(e,t,n,r,i)=>{if(n&&g(e,n),r)for(let t=0;t<r.length;t++)g(e,r[t]);if(i){let n=i.subTree;if(t===n||Ro(n.type)&&(n.ssContent===t||n.ssFallback===t)){let t=i.vnode;ee(e,t,t.scopeId,t.slotScopeIds,i.parent)}}},E=(e,t,n,r,i,a,o,s,c=0)=>{for(let l=c;l<e.length;l++)v(null,e[l]=s?vs(e[l]):_s(e[l]),t,n,r,i,a,o,s)},ne=(e,t,n,i,a,o,s)=>{let l=t.el=e.el,{patchFlag:u,dynamicChildren:d,dirs:f}=t;u|=e.patchFlag&16;let m=e.props||r,h=t.props||r,g;if(n&&Mo(n,!1),(g=h.onVnodeBeforeUpdate)&&bs(g,n,t,e),f&&vr(t,e,n,`beforeUpdate`),n&&Mo(n,!0),(m.innerHTML&&h.innerHTML==null||m.textContent&&h.textContent==null)&&p(l,``),d?re(e.dynamicChildren,d,l,n,i,jo(t,a),o):s||le(e,t,l,null,n,i,jo(t,a),o,!1),u>0){if(u&16)D(l,m,h,n,a);else if(u&2&&m.class!==h.class&&c(l,`class`,null,h.class,a),u&4&&c(l,`style`,m.style,h.style,a),u&8){let e=t.dynamicProps;for(let t=0;t<e.length;t++){let r=e[t],i=m[r],o=h[r];(o!==i||r===`value`)&&c(l,r,i,o,a,n)}}u&1&&e.children!==t.children&&p(l,t.children)}else !s&&d==null&&D(l,m,h,n,a);((g=h.onVnodeUpdated)||f)&&Do(()=>{g&&bs(g,n,t,e),f&&vr(t,e,n,`updated`)},i)},re=(e,t,n,r,i,a,o)=>{for(let s=0;s<t.length;s++){let c=e[s],l=t[s];v(c,l,c.el&&(c.type===R||!ss(c,l)||c.shapeFlag&198)?m(c.el):n,null,r,i,a,o,!0)}},D=(e,t,n,i,a)=>{if(t!==n){if(t!==r)for(let r in t)!te(r)&&!(r in n)&&c(e,r,t[r],null,a,i);for(let r in n){if(te(r))continue;let o=n[r],s=t[r];o!==s&&r!==`value`&&c(e,r,s,o,a,i)}`value`in n&&c(e,`value`,t.value,n.value,a)}},ie=(e,t,n,r,i,a,s,c,l)=>{let d=t.el=e?e.el:u(``),f=t.anchor=e?e.anchor:u(``),{patchFlag:p,dynamicChildren:m,slotScopeIds:h}=t;h&&(c=c?c.concat(h):h),e==null?(o(d,n,r),o(f,n,r),E(t.children||[],n,f,i,a,s,c,l)):p>0&&p&64&&m&&e.dynamicChildren?(re(e.dynamicChildren,m,n,i,a,s,c),(t.key!=null||i&&t===i.subTree)&&Po(e,t,!0)):le(e,t,n,f,i,a,s,c,l)},O=(e,t,n,r,i,a,o,s,c)=>{t.slotScopeIds=s,e==null?t.shapeFlag&512?i.ctx.activate(t,n,r,o,c):k(t,n,r,i,a,o,c):ae(e,t,c)},k=(e,t,n,r,i,a,o)=>{let s=e.component=Cs(e,r,i);if(bi(e)&&(s.ctx.renderer=xe),Ms(s,!1,o),s.asyncDep){if(i&&i.registerDep(s,oe,o),!e.el){let r=s.subTree=U(Qo);b(null,r,t,n),e.placeholder=r.el}}else oe(s,e,t,n,i,a,o)},ae=(e,t,n)=>{let r=t.component=e.component;if(ao(e,t,n))if(r.asyncDep&&!r.asyncResolved){ce(r,t,n);return}else r.next=t,r.update();else t.el=e.el,r.vnode=t},oe=(e,t,n,r,i,a,o)=>{let s=()=>{if(e.isMounted){let{next:t,bu:n,u:r,parent:c,vnode:l}=e;{let n=Io(e);if(n){t&&(t.el=l.el,ce(e,t,o)),n.asyncDep.then(()=>{e.isUnmounted||s()});return}}let u=t,d;Mo(e,!1),t?(t.el=l.el,ce(e,t,o)):t=l,n&&se(n),(d=t.props&&t.props.onVnodeBeforeUpdate)&&bs(d,c,t,l),Mo(e,!0);let f=to(e),p=e.subTree;e.subTree=f,v(p,f,m(p.el),A(p),e,i,a),t.el=f.el,u===null&&so(e,f.el),r&&Do(r,i),(d=t.props&&t.props.onVnodeUpdated)&&Do(()=>bs(d,c,t,l),i)}else{let o,{el:s,props:c}=t,{bm:l,m:u,parent:d,root:f,type:p}=e,m=_i(t);if(Mo(e,!1),l&&se(l),!m&&(o=c&&c.onVnodeBeforeMount)&&bs(o,d,t),Mo(e,!0),s&&Ce){let t=()=>{e.subTree=to(e),Ce(s,e.subTree,e,i,null)};m&&p.__asyncHydrate?p.__asyncHydrate(s,e,t):t()}else{f.ce&&f.ce._def.shadowRoot!==!1&&f.ce._injectChildStyle(p);let o=e.subTree=to(e);v(null,o,n,r,e,i,a),t.el=o.el}if(u&&Do(u,i),!m&&(o=c&&c.onVnodeMounted)){let e=t;Do(()=>bs(o,d,e),i)}(t.shapeFlag&256||d&&_i(d.vnode)&&d.vnode.shapeFlag&256)&&e.a&&Do(e.a,i),e.isMounted=!0,t=n=r=null}};e.scope.on();let c=e.effect=new Re(s);e.scope.off();let l=e.update=c.run.bind(c),u=e.job=c.runIfDirty.bind(c);u.i=e,u.id=e.uid,c.scheduler=()=>tr(u),Mo(e,!0),l()},ce=(e,t,n)=>{t.component=e;let r=e.vnode.props;e.vnode=t,e.next=null,po(e,t.props,r,n),Eo(e,t.children,n),tt(),ir(e),nt()},le=(e,t,n,r,i,a,o,s,c=!1)=>{let l=e&&e.children,u=e?e.shapeFlag:0,d=t.children,{patchFlag:f,shapeFlag:m}=t;if(f>0){if(f&128){de(l,d,n,r,i,a,o,s,c);return}else if(f&256){ue(l,d,n,r,i,a,o,s,c);return}}m&8?(u&16&&ve(l,i,a),d!==l&&p(n,d)):u&16?m&16?de(l,d,n,r,i,a,o,s,c):ve(l,i,a,!0):(u&8&&p(n,``),m&16&&E(d,n,r,i,a,o,s,c))},ue=(e,t,n,r,a,o,s,c,l)=>{e||=i,t||=i;let u=e.length,d=t.length,f=Math.min(u,d),p;for(p=0;p<f;p++){let r=t[p]=l?vs(t[p]):_s(t[p]);v(e[p],r,n,null,a,o,s,c,l)}u>d?ve(e,a,o,!0,!1,f):E(t,n,r,a,o,s,c,l,f)},de=(e,t,n,r,a,o,s,c,l)=>{let u=0,d=t.length,f=e.length-1,p=d-1;for(;u<=f&&u<=p;){let r=e[u],i=t[u]=l?vs(t[u]):_s(t[u]);if(ss(r,i))v(r,i,n,null,a,o,s,c,l);else break;u++}for(;u<=f&&u<=p;){let r=e[f],i=t[p]=l?vs(t[p]):_s(t[p]);if(ss(r,i))v(r,i,n,null,a,o,s,c,l);else break;f--,p--}if(u>f){if(u<=p){let e=p+1,i=e<d?t[e].el:r;for(;u<=p;)v(null,t[u]=l?vs(t[u]):_s(t[u]),n,i,a,o,s,c,l),u++}}else if(u>p)for(;u<=f;)me(e[u],a,o,!0),u++;else{let m=u,h=u,g=new Map;for(u=h;u<=p;u++){let e=t[u]=l?vs(t[u]):_s(t[u]);e.key!=null&&g.set(e.key,u)}let _,y=0,b=p-h+1,x=!1,S=0,C=Array(b);for(u=0;u<b;u++)C[u]=0;for(u=m;u<=f;u++){let r=e[u];if(y>=b){me(r,a,o,!0);continue}let i;if(r.key!=null)i=g.get(r.key);else for(_=h;_<=p;_++)if(C[_-h]===0&&ss(r,t[_])){i=_;break}i===void 0?me(r,a,o,!0):(C[i-h]=u+1,i>=S?S=i:x=!0,v(r,t[i],n,null,a,o,s,c,l),y++)}let w=x?Fo(C):i;for(_=w.length-1,u=b-1;u>=0;u--){let e=h+u,i=t[e],f=t[e+1],p=e+1<d?f.el||f.placeholder:r;C[u]===0?v(null,i,n,p,a,o,s,c,l):x&&(_<0||u!==w[_]?pe(i,n,p,2):_--)}}},pe=(e,t,n,r,i=null)=>{let{el:a,type:c,transition:l,children:u,shapeFlag:d}=e;if(d&6){pe(e.component.subTree,t,n,r);return}if(d&128){e.suspense.move(t,n,r);return}if(d&64){c.move(e,t,n,xe);return}if(c===R){o(a,t,n);for(let e=0;e<u.length;e++)pe(u[e],t,n,r);o(e.anchor,t,n);return}if(c===$o){S(e,t,n);return}if(r!==2&&d&1&&l)if(r===0)l.beforeEnter(a),o(a,t,n),Do(()=>l.enter(a),i);else{let{leave:r,delayLeave:i,afterLeave:c}=l,u=()=>{e.ctx.isUnmounted?s(a):o(a,t,n)},d=()=>{a._isLeaving&&a[Mr](!0),r(a,()=>{u(),c&&c()})};i?i(a,u,d):d()}else o(a,t,n)},me=(e,t,n,r=!1,i=!1)=>{let{type:a,props:o,ref:s,children:c,dynamicChildren:l,shapeFlag:u,patchFlag:d,dirs:f,cacheIndex:p}=e;if(d===-2&&(i=!1),s!=null&&(tt(),Zr(s,null,n,e,!0),nt()),p!=null&&(t.renderCache[p]=void 0),u&256){t.ctx.deactivate(e);return}let m=u&1&&f,h=!_i(e),g;if(h&&(g=o&&o.onVnodeBeforeUnmount)&&bs(g,t,e),u&6)_e(e.component,n,r);else{if(u&128){e.suspense.unmount(n,r);return}m&&vr(e,null,t,`beforeUnmount`),u&64?e.type.remove(e,t,n,xe,r):l&&!l.hasOnce&&(a!==R||d>0&&d&64)?ve(l,t,n,!1,!0):(a===R&&d&384||!i&&u&16)&&ve(c,t,n),r&&he(e)}(h&&(g=o&&o.onVnodeUnmounted)||m)&&Do(()=>{g&&bs(g,t,e),m&&vr(e,null,t,`unmounted`)},n)},he=e=>{let{type:t,el:n,anchor:r,transition:i}=e;if(t===R){ge(n,r);return}if(t===$o){C(e);return}let a=()=>{s(n),i&&!i.persisted&&i.afterLeave&&i.afterLeave()};if(e.shapeFlag&1&&i&&!i.persisted){let{leave:t,delayLeave:r}=i,o=()=>t(n,a);r?r(e.el,a,o):o()}else a()},ge=(e,t)=>{let n;for(;e!==t;)n=h(e),s(e),e=n;s(t)},_e=(e,t,n)=>{let{bum:r,scope:i,job:a,subTree:o,um:s,m:c,a:l}=e;Lo(c),Lo(l),r&&se(r),i.stop(),a&&(a.flags|=8,me(o,e,t,n)),s&&Do(s,t),Do(()=>{e.isUnmounted=!0},t)},ve=(e,t,n,r=!1,i=!1,a=0)=>{for(let o=a;o<e.length;o++)me(e[o],t,n,r,i)},A=e=>{if(e.shapeFlag&6)return A(e.component.subTree);if(e.shapeFlag&128)return e.suspense.next();let t=h(e.anchor||e.el),n=t&&t[yr];return n?h(n):t},ye=!1,be=(e,t,n)=>{e==null?t._vnode&&me(t._vnode,null,null,!0):v(t._vnode||null,e,t,null,null,null,n),t._vnode=e,ye||=(ye=!0,ir(),ar(),!1)},xe={p:v,um:me,m:pe,r:he,mt:k,mc:E,pc:le,pbc:re,n:A,o:e},Se,Ce;return t&&([Se,Ce]=t(xe)),{render:be,hydrate:Se,createApp:Ia(be,Se)}}function jo({type:e,props:t},n){return n===`svg`&&e===`foreignObject`||n===`mathml`&&e===`annotation-xml`&&t&&t.encoding&&t.encoding.includes(`html`)?void 0:n}function Mo({effect:e,job:t},n){n?(e.flags|=32,t.flags|=4):(e.flags&=-33,t.flags&=-5)}function No(e,t){return(!e||e&&!e.pendingBranch)&&t&&!t.persisted}function Po(e,t,n=!1){let r=e.children,i=t.children;if(p(r)&&p(i))for(let e=0;e<r.length;e++){let t=r[e],a=i[e];a.shapeFlag&1&&!a.dynamicChildren&&((a.patchFlag<=0||a.patchFlag===32)&&(a=i[e]=vs(i[e]),a.el=t.el),!n&&a.patchFlag!==-2&&Po(t,a)),a.type===Zo&&a.patchFlag!==-1&&(a.el=t.el),a.type===Qo&&!a.el&&(a.el=t.el)}}function Fo(e){let t=e.slice(),n=[0],r,i,a,o,s,c=e.length;for(r=0;r<c;r++){let c=e[r];if(c!==0){if(i=n[n.length-1],e[i]<c){t[r]=i,n.push(r);continue}for(a=0,o=n.length-1;a<o;)s=a+o>>1,e[n[s]]<c?a=s+1:o=s;c<e[n[a]]&&(a>0&&(t[r]=n[a-1]),n[a]=r)}}for(a=n.length,o=n[a-1];a-- >0;)n[a]=o,o=t[o];return n}function Io(e){let t=e.subTree.component;if(t)return t.asyncDep&&!t.asyncResolved?t:Io(t)}function Lo(e){if(e)for(let t=0;t<e.length;t++)e[t].flags|=8}var Ro=e=>e.__isSuspense,zo=0,Bo={name:`Suspense`,__isSuspense:!0,process(e,t,n,r,i,a,o,s,c,l){if(e==null)Ho(t,n,r,i,a,o,s,c,l);else{if(a&&a.deps>0&&!e.suspense.isInFallback){t.suspense=e.suspense,t.suspense.vnode=t,t.el=e.el;return}Uo(e,t,n,r,i,o,s,c,l)}},hydrate:Go,normalize:Ko};function Vo(e,t){let n=e.props&&e.props[t];v(n)&&n()}function Ho(e,t,n,r,i,a,o,s,c){let{p:l,o:{createElement:u}}=c,d=u(`div`),f=e.suspense=Wo(e,i,r,t,d,n,a,o,s,c);l(null,f.pendingBranch=e.ssContent,d,null,r,f,a,o),f.deps>0?(Vo(e,`onPending`),Vo(e,`onFallback`),l(null,e.ssFallback,t,n,r,null,a,o),Yo(f,e.ssFallback)):f.resolve(!1,!0)}function Uo(e,t,n,r,i,a,o,s,{p:c,um:l,o:{createElement:u}}){let d=t.suspense=e.suspense;d.vnode=t,t.el=e.el;let f=t.ssContent,p=t.ssFallback,{activeBranch:m,pendingBranch:h,isInFallback:g,isHydrating:_}=d;if(h)d.pendingBranch=f,ss(h,f)?(c(h,f,d.hiddenContainer,null,i,d,a,o,s),d.deps<=0?d.resolve():g&&(_||(c(m,p,n,r,i,null,a,o,s),Yo(d,p)))):(d.pendingId=zo++,_?(d.isHydrating=!1,d.activeBranch=h):l(h,i,d),d.deps=0,d.effects.length=0,d.hiddenContainer=u(`div`),g?(c(null,f,d.hiddenContainer,null,i,d,a,o,s),d.deps<=0?d.resolve():(c(m,p,n,r,i,null,a,o,s),Yo(d,p))):m&&ss(m,f)?(c(m,f,n,r,i,d,a,o,s),d.resolve(!0)):(c(null,f,d.hiddenContainer,null,i,d,a,o,s),d.deps<=0&&d.resolve()));else if(m&&ss(m,f))c(m,f,n,r,i,d,a,o,s),Yo(d,f);else if(Vo(t,`onPending`),d.pendingBranch=f,f.shapeFlag&512?d.pendingId=f.component.suspenseId:d.pendingId=zo++,c(null,f,d.hiddenContainer,null,i,d,a,o,s),d.deps<=0)d.resolve();else{let{timeout:e,pendingId:t}=d;e>0?setTimeout(()=>{d.pendingId===t&&d.fallback(p)},e):e===0&&d.fallback(p)}}function Wo(e,t,n,r,i,a,o,s,c,l,u=!1){let{p:d,m:f,um:p,n:m,o:{parentNode:h,remove:g}}=l,_,v=Xo(e);v&&t&&t.pendingBranch&&(_=t.pendingId,t.deps++);let y=e.props?ue(e.props.timeout):void 0,b=a,x={vnode:e,parent:t,parentComponent:n,namespace:o,container:r,hiddenContainer:i,deps:0,pendingId:zo++,timeout:typeof y==`number`?y:-1,activeBranch:null,pendingBranch:null,isInFallback:!u,isHydrating:u,isUnmounted:!1,effects:[],resolve(e=!1,n=!1){let{vnode:r,activeBranch:i,pendingBranch:o,pendingId:s,effects:c,parentComponent:l,container:u,isInFallback:d}=x,g=!1;x.isHydrating?x.isHydrating=!1:e||(g=i&&o.transition&&o.transition.mode===`out-in`,g&&(i.transition.afterLeave=()=>{s===x.pendingId&&(f(o,u,a===b?m(i):a,0),rr(c),d&&r.ssFallback&&(r.ssFallback.el=null))}),i&&(h(i.el)===u&&(a=m(i)),p(i,l,x,!0),!g&&d&&r.ssFallback&&Do(()=>r.ssFallback.el=null,x)),g||f(o,u,a,0)),Yo(x,o),x.pendingBranch=null,x.isInFallback=!1;let y=x.parent,S=!1;for(;y;){if(y.pendingBranch){y.effects.push(...c),S=!0;break}y=y.parent}!S&&!g&&rr(c),x.effects=[],v&&t&&t.pendingBranch&&_===t.pendingId&&(t.deps--,t.deps===0&&!n&&t.resolve()),Vo(r,`onResolve`)},fallback(e){if(!x.pendingBranch)return;let{vnode:t,activeBranch:n,parentComponent:r,container:i,namespace:a}=x;Vo(t,`onFallback`);let o=m(n),l=()=>{x.isInFallback&&(d(null,e,i,o,r,null,a,s,c),Yo(x,e))},u=e.transition&&e.transition.mode===`out-in`;u&&(n.transition.afterLeave=l),x.isInFallback=!0,p(n,r,null,!0),u||l()},move(e,t,n){x.activeBranch&&f(x.activeBranch,e,t,n),x.container=e},next(){return x.activeBranch&&m(x.activeBranch)},registerDep(e,t,n){let r=!!x.pendingBranch;r&&x.deps++;let i=e.vnode.el;e.asyncDep.catch(t=>{Wn(t,e,0)}).then(a=>{if(e.isUnmounted||x.isUnmounted||x.pendingId!==e.suspenseId)return;e.asyncResolved=!0;let{vnode:s}=e;Ps(e,a,!1),i&&(s.el=i);let c=!i&&e.subTree.el;t(e,s,h(i||e.subTree.el),i?null:m(e.subTree),x,o,n),c&&(s.placeholder=null,g(c)),so(e,s.el),r&&--x.deps===0&&x.resolve()})},unmount(e,t){x.isUnmounted=!0,x.activeBranch&&p(x.activeBranch,n,e,t),x.pendingBranch&&p(x.pendingBranch,n,e,t)}};return x}function Go(e,t,n,r,i,a,o,s,c){let l=t.suspense=Wo(t,r,n,e.parentNode,document.createElement(`div`),null,i,a,o,s,!0),u=c(e,l.pendingBranch=t.ssContent,n,l,a,o);return l.deps===0&&l.resolve(!1,!0),u}function Ko(e){let{shapeFlag:t,children:n}=e,r=t&32;e.ssContent=qo(r?n.default:n),e.ssFallback=r?qo(n.fallback):U(Qo)}function qo(e){let t;if(v(e)){let n=rs&&e._c;n&&(e._d=!1,z()),e=e(),n&&(e._d=!0,t=ts,ns())}return p(e)&&(e=no(e)),e=_s(e),t&&!e.dynamicChildren&&(e.dynamicChildren=t.filter(t=>t!==e)),e}function Jo(e,t){t&&t.pendingBranch?p(e)?t.effects.push(...e):t.effects.push(e):rr(e)}function Yo(e,t){e.activeBranch=t;let{vnode:n,parentComponent:r}=e,i=t.el;for(;!i&&t.component;)t=t.component.subTree,i=t.el;n.el=i,r&&r.subTree===n&&(r.vnode.el=i,so(r,i))}function Xo(e){let t=e.props&&e.props.suspensible;return t!=null&&t!==!1}var R=Symbol.for(`v-fgt`),Zo=Symbol.for(`v-txt`),Qo=Symbol.for(`v-cmt`),$o=Symbol.for(`v-stc`),es=[],ts=null;function z(e=!1){es.push(ts=e?null:[])}function ns(){es.pop(),ts=es[es.length-1]||null}var rs=1;function is(e,t=!1){rs+=e,e<0&&ts&&t&&(ts.hasOnce=!0)}function as(e){return e.dynamicChildren=rs>0?ts||i:null,ns(),rs>0&&ts&&ts.push(e),e}function B(e,t,n,r,i,a){return as(H(e,t,n,r,i,a,!0))}function V(e,t,n,r,i){return as(U(e,t,n,r,i,!0))}function os(e){return e?e.__v_isVNode===!0:!1}function ss(e,t){return e.type===t.type&&e.key===t.key}function cs(e){}var ls=({key:e})=>e??null,us=({ref:e,ref_key:t,ref_for:n})=>(typeof e==`number`&&(e=``+e),e==null?null:y(e)||un(e)||v(e)?{i:dr,r:e,k:t,f:!!n}:e);function H(e,t=null,n=null,r=0,i=null,a=e===R?0:1,o=!1,s=!1){let c={__v_isVNode:!0,__v_skip:!0,type:e,props:t,key:t&&ls(t),ref:t&&us(t),scopeId:fr,slotScopeIds:null,children:n,component:null,suspense:null,ssContent:null,ssFallback:null,dirs:null,transition:null,el:null,anchor:null,target:null,targetStart:null,targetAnchor:null,staticCount:0,shapeFlag:a,patchFlag:r,dynamicProps:i,dynamicChildren:null,appContext:null,ctx:dr};return s?(ys(c,n),a&128&&e.normalize(c)):n&&(c.shapeFlag|=y(n)?8:16),rs>0&&!o&&ts&&(c.patchFlag>0||a&6)&&c.patchFlag!==32&&ts.push(c),c}var U=ds;function ds(e,t=null,n=null,r=0,i=null,a=!1){if((!e||e===Wi)&&(e=Qo),os(e)){let r=ps(e,t,!0);return n&&ys(r,n),rs>0&&!a&&ts&&(r.shapeFlag&6?ts[ts.indexOf(e)]=r:ts.push(r)),r.patchFlag=-2,r}if(Ws(e)&&(e=e.__vccOpts),t){t=fs(t);let{class:e,style:n}=t;e&&!y(e)&&(t.class=A(e)),x(n)&&(on(n)&&!p(n)&&(n=l({},n)),t.style=me(n))}let o=y(e)?1:Ro(e)?128:br(e)?64:x(e)?4:v(e)?2:0;return H(e,t,n,r,i,o,a,!0)}function fs(e){return e?on(e)||uo(e)?l({},e):e:null}function ps(e,t,n=!1,r=!1){let{props:i,ref:a,patchFlag:o,children:s,transition:c}=e,l=t?W(i||{},t):i,u={__v_isVNode:!0,__v_skip:!0,type:e.type,props:l,key:l&&ls(l),ref:t&&t.ref?n&&a?p(a)?a.concat(us(t)):[a,us(t)]:us(t):a,scopeId:e.scopeId,slotScopeIds:e.slotScopeIds,children:s,target:e.target,targetStart:e.targetStart,targetAnchor:e.targetAnchor,staticCount:e.staticCount,shapeFlag:e.shapeFlag,patchFlag:t&&e.type!==R?o===-1?16:o|16:o,dynamicProps:e.dynamicProps,dynamicChildren:e.dynamicChildren,appContext:e.appContext,dirs:e.dirs,transition:c,component:e.component,suspense:e.suspense,ssContent:e.ssContent&&ps(e.ssContent),ssFallback:e.ssFallback&&ps(e.ssFallback),placeholder:e.placeholder,el:e.el,anchor:e.anchor,ctx:e.ctx,ce:e.ce};return c&&r&&Gr(u,c.clone(u)),u}function ms(e=` `,t=0){return U(Zo,null,e,t)}function hs(e,t){let n=U($o,null,e);return n.staticCount=t,n}function gs(e=``,t=!1){return t?(z(),V(Qo,null,e)):U(Qo,null,e)}function _s(e){return e==null||typeof e==`boolean`?U(Qo):p(e)?U(R,null,e.slice()):os(e)?vs(e):U(Zo,null,String(e))}function vs(e){return e.el===null&&e.patchFlag!==-1||e.memo?e:ps(e)}function ys(e,t){let n=0,{shapeFlag:r}=e;if(t==null)t=null;else if(p(t))n=16;else if(typeof t==`object`)if(r&65){let n=t.default;n&&(n._c&&(n._d=!1),ys(e,n()),n._c&&(n._d=!0));return}else{n=32;let r=t._;!r&&!uo(t)?t._ctx=dr:r===3&&dr&&(dr.slots._===1?t._=1:(t._=2,e.patchFlag|=1024))}else v(t)?(t={default:t,_ctx:dr},n=32):(t=String(t),r&64?(n=16,t=[ms(t)]):n=8);e.children=t,e.shapeFlag|=n}function W(...e){let t={};for(let n=0;n<e.length;n++){let r=e[n];for(let e in r)if(e===`class`)t.class!==r.class&&(t.class=A([t.class,r.class]));else if(e===`style`)t.style=me([t.style,r.style]);else if(s(e)){let n=t[e],i=r[e];i&&n!==i&&!(p(n)&&n.includes(i))&&(t[e]=n?[].concat(n,i):i)}else e!==``&&(t[e]=r[e])}return t}function bs(e,t,n,r=null){Un(e,t,7,[n,r])}var xs=Pa(),Ss=0;function Cs(e,t,n){let i=e.type,a=(t?t.appContext:e.appContext)||xs,o={uid:Ss++,vnode:e,type:i,parent:t,appContext:a,root:null,next:null,subTree:null,effect:null,update:null,job:null,scope:new Me(!0),render:null,proxy:null,exposed:null,exposeProxy:null,withProxy:null,provides:t?t.provides:Object.create(a.provides),ids:t?t.ids:[``,0,0],accessCache:null,renderCache:[],components:null,directives:null,propsOptions:_o(i,a),emitsOptions:$a(i,a),emit:null,emitted:null,propsDefaults:r,inheritAttrs:i.inheritAttrs,ctx:r,data:r,props:r,attrs:r,slots:r,refs:r,setupState:r,setupContext:null,suspense:n,suspenseId:n?n.pendingId:0,asyncDep:null,asyncResolved:!1,isMounted:!1,isUnmounted:!1,isDeactivated:!1,bc:null,c:null,bm:null,m:null,bu:null,u:null,um:null,bum:null,da:null,a:null,rtg:null,rtc:null,ec:null,sp:null};return o.ctx={_:o},o.root=t?t.root:o,o.emit=Za.bind(null,o),e.ce&&e.ce(o),o}var ws=null,Ts=()=>ws||dr,Es,Ds;{let e=fe(),t=(t,n)=>{let r;return(r=e[t])||(r=e[t]=[]),r.push(n),e=>{r.length>1?r.forEach(t=>t(e)):r[0](e)}};Es=t(`__VUE_INSTANCE_SETTERS__`,e=>ws=e),Ds=t(`__VUE_SSR_SETTERS__`,e=>js=e)}var Os=e=>{let t=ws;return Es(e),e.scope.on(),()=>{e.scope.off(),Es(t)}},ks=()=>{ws&&ws.scope.off(),Es(null)};function As(e){return e.vnode.shapeFlag&4}var js=!1;function Ms(e,t=!1,n=!1){t&&Ds(t);let{props:r,children:i}=e.vnode,a=As(e);fo(e,r,a,t),To(e,i,n||t);let o=a?Ns(e,t):void 0;return t&&Ds(!1),o}function Ns(e,t){let n=e.type;e.accessCache=Object.create(null),e.proxy=new Proxy(e.ctx,na);let{setup:r}=n;if(r){tt();let n=e.setupContext=r.length>1?Vs(e):null,i=Os(e),a=Hn(r,e,0,[e.props,n]),o=S(a);if(nt(),i(),(o||e.sp)&&!_i(e)&&Jr(e),o){if(a.then(ks,ks),t)return a.then(n=>{Ps(e,n,t)}).catch(t=>{Wn(t,e,0)});e.asyncDep=a}else Ps(e,a,t)}else zs(e,t)}function Ps(e,t,n){v(t)?e.type.__ssrInlineRender?e.ssrRender=t:e.render=t:x(t)&&(e.setupState=_n(t)),zs(e,n)}var Fs,Is;function Ls(e){Fs=e,Is=e=>{e.render._rc&&(e.withProxy=new Proxy(e.ctx,ra))}}var Rs=()=>!Fs;function zs(e,t,n){let r=e.type;if(!e.render){if(!t&&Fs&&!r.render){let t=r.template||wa(e).template;if(t){let{isCustomElement:n,compilerOptions:i}=e.appContext.config,{delimiters:a,compilerOptions:o}=r,s=l(l({isCustomElement:n,delimiters:a},i),o);r.render=Fs(t,s)}}e.render=r.render||a,Is&&Is(e)}{let t=Os(e);tt();try{ba(e)}finally{nt(),t()}}}var Bs={get(e,t){return ft(e,`get`,``),e[t]}};function Vs(e){return{attrs:new Proxy(e.attrs,Bs),slots:e.slots,emit:e.emit,expose:t=>{e.exposed=t||{}}}}function Hs(e){return e.exposed?e.exposeProxy||=new Proxy(_n(sn(e.exposed)),{get(t,n){if(n in t)return t[n];if(n in ea)return ea[n](e)},has(e,t){return t in e||t in ea}}):e.proxy}function Us(e,t=!0){return v(e)?e.displayName||e.name:e.name||t&&e.__name}function Ws(e){return v(e)&&`__vccOpts`in e}var G=(e,t)=>En(e,t,js);function K(e,t,n){try{is(-1);let r=arguments.length;return r===2?x(t)&&!p(t)?os(t)?U(e,null,[t]):U(e,t):U(e,null,t):(r>3?n=Array.prototype.slice.call(arguments,2):r===3&&os(n)&&(n=[n]),U(e,t,n))}finally{is(1)}}function Gs(){return;function e(t,n,r){let i=t[r];if(p(i)&&i.includes(n)||x(i)&&n in i||t.extends&&e(t.extends,n,r)||t.mixins&&t.mixins.some(t=>e(t,n,r)))return!0}}function Ks(e,t,n,r){let i=n[r];if(i&&qs(i,e))return i;let a=t();return a.memo=e.slice(),a.cacheIndex=r,n[r]=a}function qs(e,t){let n=e.memo;if(n.length!=t.length)return!1;for(let e=0;e<n.length;e++)if(oe(n[e],t[e]))return!1;return rs>0&&ts&&ts.push(e),!0}var Js=`3.5.25`,Ys=a,Xs=Vn,Zs=cr,Qs=ur,$s={createComponentInstance:Cs,setupComponent:Ms,renderComponentRoot:to,setCurrentRenderingInstance:pr,isVNode:os,normalizeVNode:_s,getComponentPublicInstance:Hs,ensureValidVNode:Zi,pushWarningContext:Ln,popWarningContext:Rn},ec=void 0,tc=typeof window<`u`&&window.trustedTypes;if(tc)try{ec=tc.createPolicy(`vue`,{createHTML:e=>e})}catch{}var nc=ec?e=>ec.createHTML(e):e=>e,rc=`http://www.w3.org/2000/svg`,ic=`http://www.w3.org/1998/Math/MathML`,ac=typeof document<`u`?document:null,oc=ac&&ac.createElement(`template`),sc={insert:(e,t,n)=>{t.insertBefore(e,n||null)},remove:e=>{let t=e.parentNode;t&&t.removeChild(e)},createElement:(e,t,n,r)=>{let i=t===`svg`?ac.createElementNS(rc,e):t===`mathml`?ac.createElementNS(ic,e):n?ac.createElement(e,{is:n}):ac.createElement(e);return e===`select`&&r&&r.multiple!=null&&i.setAttribute(`multiple`,r.multiple),i},createText:e=>ac.createTextNode(e),createComment:e=>ac.createComment(e),setText:(e,t)=>{e.nodeValue=t},setElementText:(e,t)=>{e.textContent=t},parentNode:e=>e.parentNode,nextSibling:e=>e.nextSibling,querySelector:e=>ac.querySelector(e),setScopeId(e,t){e.setAttribute(t,``)},insertStaticContent(e,t,n,r,i,a){let o=n?n.previousSibling:t.lastChild;if(i&&(i===a||i.nextSibling))for(;t.insertBefore(i.cloneNode(!0),n),!(i===a||!(i=i.nextSibling)););else{oc.innerHTML=nc(r===`svg`?`<svg>${e}</svg>`:r===`mathml`?`<math>${e}</math>`:e);let i=oc.content;if(r===`svg`||r===`mathml`){let e=i.firstChild;for(;e.firstChild;)i.appendChild(e.firstChild);i.removeChild(e)}t.insertBefore(i,n)}return[o?o.nextSibling:t.firstChild,n?n.previousSibling:t.lastChild]}},cc=`transition`,lc=`animation`,uc=Symbol(`_vtc`),dc={name:String,type:String,css:{type:Boolean,default:!0},duration:[String,Number,Object],enterFromClass:String,enterActiveClass:String,enterToClass:String,appearFromClass:String,appearActiveClass:String,appearToClass:String,leaveFromClass:String,leaveActiveClass:String,leaveToClass:String},fc=l({},Ir,dc),pc=(e=>(e.displayName=`Transition`,e.props=fc,e))((e,{slots:t})=>K(Br,gc(e),t)),mc=(e,t=[])=>{p(e)?e.forEach(e=>e(...t)):e&&e(...t)},hc=e=>e?p(e)?e.some(e=>e.length>1):e.length>1:!1;function gc(e){let t={};for(let n in e)n in dc||(t[n]=e[n]);if(e.css===!1)return t;let{name:n=`v`,type:r,duration:i,enterFromClass:a=`${n}-enter-from`,enterActiveClass:o=`${n}-enter-active`,enterToClass:s=`${n}-enter-to`,appearFromClass:c=a,appearActiveClass:u=o,appearToClass:d=s,leaveFromClass:f=`${n}-leave-from`,leaveActiveClass:p=`${n}-leave-active`,leaveToClass:m=`${n}-leave-to`}=e,h=_c(i),g=h&&h[0],_=h&&h[1],{onBeforeEnter:v,onEnter:y,onEnterCancelled:b,onLeave:x,onLeaveCancelled:S,onBeforeAppear:C=v,onAppear:w=y,onAppearCancelled:T=b}=t,ee=(e,t,n,r)=>{e._enterCancelled=r,bc(e,t?d:s),bc(e,t?u:o),n&&n()},E=(e,t)=>{e._isLeaving=!1,bc(e,f),bc(e,m),bc(e,p),t&&t()},te=e=>(t,n)=>{let i=e?w:y,o=()=>ee(t,e,n);mc(i,[t,o]),xc(()=>{bc(t,e?c:a),yc(t,e?d:s),hc(i)||Cc(t,r,g,o)})};return l(t,{onBeforeEnter(e){mc(v,[e]),yc(e,a),yc(e,o)},onBeforeAppear(e){mc(C,[e]),yc(e,c),yc(e,u)},onEnter:te(!1),onAppear:te(!0),onLeave(e,t){e._isLeaving=!0;let n=()=>E(e,t);yc(e,f),e._enterCancelled?(yc(e,p),Dc(e)):(Dc(e),yc(e,p)),xc(()=>{e._isLeaving&&(bc(e,f),yc(e,m),hc(x)||Cc(e,r,_,n))}),mc(x,[e,n])},onEnterCancelled(e){ee(e,!1,void 0,!0),mc(b,[e])},onAppearCancelled(e){ee(e,!0,void 0,!0),mc(T,[e])},onLeaveCancelled(e){E(e),mc(S,[e])}})}function _c(e){if(e==null)return null;if(x(e))return[vc(e.enter),vc(e.leave)];{let t=vc(e);return[t,t]}}function vc(e){return ue(e)}function yc(e,t){t.split(/\s+/).forEach(t=>t&&e.classList.add(t)),(e[uc]||(e[uc]=new Set)).add(t)}function bc(e,t){t.split(/\s+/).forEach(t=>t&&e.classList.remove(t));let n=e[uc];n&&(n.delete(t),n.size||(e[uc]=void 0))}function xc(e){requestAnimationFrame(()=>{requestAnimationFrame(e)})}var Sc=0;function Cc(e,t,n,r){let i=e._endId=++Sc,a=()=>{i===e._endId&&r()};if(n!=null)return setTimeout(a,n);let{type:o,timeout:s,propCount:c}=wc(e,t);if(!o)return r();let l=o+`end`,u=0,d=()=>{e.removeEventListener(l,f),a()},f=t=>{t.target===e&&++u>=c&&d()};setTimeout(()=>{u<c&&d()},s+1),e.addEventListener(l,f)}function wc(e,t){let n=window.getComputedStyle(e),r=e=>(n[e]||``).split(`, `),i=r(`${cc}Delay`),a=r(`${cc}Duration`),o=Tc(i,a),s=r(`${lc}Delay`),c=r(`${lc}Duration`),l=Tc(s,c),u=null,d=0,f=0;t===cc?o>0&&(u=cc,d=o,f=a.length):t===lc?l>0&&(u=lc,d=l,f=c.length):(d=Math.max(o,l),u=d>0?o>l?cc:lc:null,f=u?u===cc?a.length:c.length:0);let p=u===cc&&/\b(?:transform|all)(?:,|$)/.test(r(`${cc}Property`).toString());return{type:u,timeout:d,propCount:f,hasTransform:p}}function Tc(e,t){for(;e.length<t.length;)e=e.concat(e);return Math.max(...t.map((t,n)=>Ec(t)+Ec(e[n])))}function Ec(e){return e===`auto`?0:Number(e.slice(0,-1).replace(`,`,`.`))*1e3}function Dc(e){return(e?e.ownerDocument:document).body.offsetHeight}function Oc(e,t,n){let r=e[uc];r&&(t=(t?[t,...r]:[...r]).join(` `)),t==null?e.removeAttribute(`class`):n?e.setAttribute(`class`,t):e.className=t}var kc=Symbol(`_vod`),Ac=Symbol(`_vsh`),jc={name:`show`,beforeMount(e,{value:t},{transition:n}){e[kc]=e.style.display===`none`?``:e.style.display,n&&t?n.beforeEnter(e):Mc(e,t)},mounted(e,{value:t},{transition:n}){n&&t&&n.enter(e)},updated(e,{value:t,oldValue:n},{transition:r}){!t!=!n&&(r?t?(r.beforeEnter(e),Mc(e,!0),r.enter(e)):r.leave(e,()=>{Mc(e,!1)}):Mc(e,t))},beforeUnmount(e,{value:t}){Mc(e,t)}};function Mc(e,t){e.style.display=t?e[kc]:`none`,e[Ac]=!t}function Nc(){jc.getSSRProps=({value:e})=>{if(!e)return{style:{display:`none`}}}}var Pc=Symbol(``);function Fc(e){let t=Ts();if(!t)return;let n=t.ut=(n=e(t.proxy))=>{Array.from(document.querySelectorAll(`[data-v-owner="${t.uid}"]`)).forEach(e=>Lc(e,n))},r=()=>{let r=e(t.proxy);t.ce?Lc(t.ce,r):Ic(t.subTree,r),n(r)};Ni(()=>{rr(r)}),Mi(()=>{L(r,a,{flush:`post`});let e=new MutationObserver(r);e.observe(t.subTree.el.parentNode,{childList:!0}),Ii(()=>e.disconnect())})}function Ic(e,t){if(e.shapeFlag&128){let n=e.suspense;e=n.activeBranch,n.pendingBranch&&!n.isHydrating&&n.effects.push(()=>{Ic(n.activeBranch,t)})}for(;e.component;)e=e.component.subTree;if(e.shapeFlag&1&&e.el)Lc(e.el,t);else if(e.type===R)e.children.forEach(e=>Ic(e,t));else if(e.type===$o){let{el:n,anchor:r}=e;for(;n&&(Lc(n,t),n!==r);)n=n.nextSibling}}function Lc(e,t){if(e.nodeType===1){let n=e.style,r=``;for(let e in t){let i=Ae(t[e]);n.setProperty(`--${e}`,i),r+=`--${e}: ${i};`}n[Pc]=r}}var Rc=/(?:^|;)\s*display\s*:/;function zc(e,t,n){let r=e.style,i=y(n),a=!1;if(n&&!i){if(t)if(y(t))for(let e of t.split(`;`)){let t=e.slice(0,e.indexOf(`:`)).trim();n[t]??Vc(r,t,``)}else for(let e in t)n[e]??Vc(r,e,``);for(let e in n)e===`display`&&(a=!0),Vc(r,e,n[e])}else if(i){if(t!==n){let e=r[Pc];e&&(n+=`;`+e),r.cssText=n,a=Rc.test(n)}}else t&&e.removeAttribute(`style`);kc in e&&(e[kc]=a?r.display:``,e[Ac]&&(r.display=`none`))}var Bc=/\s*!important$/;function Vc(e,t,n){if(p(n))n.forEach(n=>Vc(e,t,n));else if(n??=``,t.startsWith(`--`))e.setProperty(t,n);else{let r=Wc(e,t);Bc.test(n)?e.setProperty(O(r),n.replace(Bc,``),`important`):e[r]=n}}var Hc=[`Webkit`,`Moz`,`ms`],Uc={};function Wc(e,t){let n=Uc[t];if(n)return n;let r=D(t);if(r!==`filter`&&r in e)return Uc[t]=r;r=k(r);for(let n=0;n<Hc.length;n++){let i=Hc[n]+r;if(i in e)return Uc[t]=i}return t}var Gc=`http://www.w3.org/1999/xlink`;function Kc(e,t,n,r,i,a=xe(t)){r&&t.startsWith(`xlink:`)?n==null?e.removeAttributeNS(Gc,t.slice(6,t.length)):e.setAttributeNS(Gc,t,n):n==null||a&&!Se(n)?e.removeAttribute(t):e.setAttribute(t,a?``:b(n)?String(n):n)}function qc(e,t,n,r,i){if(t===`innerHTML`||t===`textContent`){n!=null&&(e[t]=t===`innerHTML`?nc(n):n);return}let a=e.tagName;if(t===`value`&&a!==`PROGRESS`&&!a.includes(`-`)){let r=a===`OPTION`?e.getAttribute(`value`)||``:e.value,i=n==null?e.type===`checkbox`?`on`:``:String(n);(r!==i||!(`_value`in e))&&(e.value=i),n??e.removeAttribute(t),e._value=n;return}let o=!1;if(n===``||n==null){let r=typeof e[t];r===`boolean`?n=Se(n):n==null&&r===`string`?(n=``,o=!0):r===`number`&&(n=0,o=!0)}try{e[t]=n}catch{}o&&e.removeAttribute(i||t)}function Jc(e,t,n,r){e.addEventListener(t,n,r)}function Yc(e,t,n,r){e.removeEventListener(t,n,r)}var Xc=Symbol(`_vei`);function Zc(e,t,n,r,i=null){let a=e[Xc]||(e[Xc]={}),o=a[t];if(r&&o)o.value=r;else{let[n,s]=$c(t);r?Jc(e,n,a[t]=rl(r,i),s):o&&(Yc(e,n,o,s),a[t]=void 0)}}var Qc=/(?:Once|Passive|Capture)$/;function $c(e){let t;if(Qc.test(e)){t={};let n;for(;n=e.match(Qc);)e=e.slice(0,e.length-n[0].length),t[n[0].toLowerCase()]=!0}return[e[2]===`:`?e.slice(3):O(e.slice(2)),t]}var el=0,tl=Promise.resolve(),nl=()=>el||=(tl.then(()=>el=0),Date.now());function rl(e,t){let n=e=>{if(!e._vts)e._vts=Date.now();else if(e._vts<=n.attached)return;Un(il(e,n.value),t,5,[e])};return n.value=e,n.attached=nl(),n}function il(e,t){if(p(t)){let n=e.stopImmediatePropagation;return e.stopImmediatePropagation=()=>{n.call(e),e._stopped=!0},t.map(e=>t=>!t._stopped&&e&&e(t))}else return t}var al=e=>e.charCodeAt(0)===111&&e.charCodeAt(1)===110&&e.charCodeAt(2)>96&&e.charCodeAt(2)<123,ol=(e,t,n,r,i,a)=>{let o=i===`svg`;t===`class`?Oc(e,r,o):t===`style`?zc(e,n,r):s(t)?c(t)||Zc(e,t,n,r,a):(t[0]===`.`?(t=t.slice(1),!0):t[0]===`^`?(t=t.slice(1),!1):sl(e,t,r,o))?(qc(e,t,r),!e.tagName.includes(`-`)&&(t===`value`||t===`checked`||t===`selected`)&&Kc(e,t,r,o,a,t!==`value`)):e._isVueCE&&(/[A-Z]/.test(t)||!y(r))?qc(e,D(t),r,a,t):(t===`true-value`?e._trueValue=r:t===`false-value`&&(e._falseValue=r),Kc(e,t,r,o))};function sl(e,t,n,r){if(r)return!!(t===`innerHTML`||t===`textContent`||t in e&&al(t)&&v(n));if(t===`spellcheck`||t===`draggable`||t===`translate`||t===`autocorrect`||t===`sandbox`&&e.tagName===`IFRAME`||t===`form`||t===`list`&&e.tagName===`INPUT`||t===`type`&&e.tagName===`TEXTAREA`)return!1;if(t===`width`||t===`height`){let t=e.tagName;if(t===`IMG`||t===`VIDEO`||t===`CANVAS`||t===`SOURCE`)return!1}return al(t)&&y(n)?!1:t in e}var cl={};function ll(e,t,n){let r=F(e,t);ee(r)&&(r=l({},r,t));class i extends fl{constructor(e){super(r,e,n)}}return i.def=r,i}var ul=((e,t)=>ll(e,t,tu)),dl=typeof HTMLElement<`u`?HTMLElement:class{},fl=class e extends dl{constructor(e,t={},n=eu){super(),this._def=e,this._props=t,this._createApp=n,this._isVueCE=!0,this._instance=null,this._app=null,this._nonce=this._def.nonce,this._connected=!1,this._resolved=!1,this._patching=!1,this._dirty=!1,this._numberProps=null,this._styleChildren=new WeakSet,this._ob=null,this.shadowRoot&&n!==eu?this._root=this.shadowRoot:e.shadowRoot===!1?this._root=this:(this.attachShadow(l({},e.shadowRootOptions,{mode:`open`})),this._root=this.shadowRoot)}connectedCallback(){if(!this.isConnected)return;!this.shadowRoot&&!this._resolved&&this._parseSlots(),this._connected=!0;let t=this;for(;t&&=t.parentNode||t.host;)if(t instanceof e){this._parent=t;break}this._instance||(this._resolved?this._mount(this._def):t&&t._pendingResolve?this._pendingResolve=t._pendingResolve.then(()=>{this._pendingResolve=void 0,this._resolveDef()}):this._resolveDef())}_setParent(e=this._parent){e&&(this._instance.parent=e._instance,this._inheritParentContext(e))}_inheritParentContext(e=this._parent){e&&this._app&&Object.setPrototypeOf(this._app._context.provides,e._instance.provides)}disconnectedCallback(){this._connected=!1,$n(()=>{this._connected||(this._ob&&=(this._ob.disconnect(),null),this._app&&this._app.unmount(),this._instance&&(this._instance.ce=void 0),this._app=this._instance=null,this._teleportTargets&&=(this._teleportTargets.clear(),void 0))})}_processMutations(e){for(let t of e)this._setAttr(t.attributeName)}_resolveDef(){if(this._pendingResolve)return;for(let e=0;e<this.attributes.length;e++)this._setAttr(this.attributes[e].name);this._ob=new MutationObserver(this._processMutations.bind(this)),this._ob.observe(this,{attributes:!0});let e=(e,t=!1)=>{this._resolved=!0,this._pendingResolve=void 0;let{props:n,styles:r}=e,i;if(n&&!p(n))for(let e in n){let t=n[e];(t===Number||t&&t.type===Number)&&(e in this._props&&(this._props[e]=ue(this._props[e])),(i||=Object.create(null))[D(e)]=!0)}this._numberProps=i,this._resolveProps(e),this.shadowRoot&&this._applyStyles(r),this._mount(e)},t=this._def.__asyncLoader;t?this._pendingResolve=t().then(t=>{t.configureApp=this._def.configureApp,e(this._def=t,!0)}):e(this._def)}_mount(e){this._app=this._createApp(e),this._inheritParentContext(),e.configureApp&&e.configureApp(this._app),this._app._ceVNode=this._createVNode(),this._app.mount(this._root);let t=this._instance&&this._instance.exposed;if(t)for(let e in t)f(this,e)||Object.defineProperty(this,e,{get:()=>N(t[e])})}_resolveProps(e){let{props:t}=e,n=p(t)?t:Object.keys(t||{});for(let e of Object.keys(this))e[0]!==`_`&&n.includes(e)&&this._setProp(e,this[e]);for(let e of n.map(D))Object.defineProperty(this,e,{get(){return this._getProp(e)},set(t){this._setProp(e,t,!0,!this._patching)}})}_setAttr(e){if(e.startsWith(`data-v-`))return;let t=this.hasAttribute(e),n=t?this.getAttribute(e):cl,r=D(e);t&&this._numberProps&&this._numberProps[r]&&(n=ue(n)),this._setProp(r,n,!1,!0)}_getProp(e){return this._props[e]}_setProp(e,t,n=!0,r=!1){if(t!==this._props[e]&&(this._dirty=!0,t===cl?delete this._props[e]:(this._props[e]=t,e===`key`&&this._app&&(this._app._ceVNode.key=t)),r&&this._instance&&this._update(),n)){let n=this._ob;n&&(this._processMutations(n.takeRecords()),n.disconnect()),t===!0?this.setAttribute(O(e),``):typeof t==`string`||typeof t==`number`?this.setAttribute(O(e),t+``):t||this.removeAttribute(O(e)),n&&n.observe(this,{attributes:!0})}}_update(){let e=this._createVNode();this._app&&(e.appContext=this._app._context),Ql(e,this._root)}_createVNode(){let e={};this.shadowRoot||(e.onVnodeMounted=e.onVnodeUpdated=this._renderSlots.bind(this));let t=U(this._def,l(e,this._props));return this._instance||(t.ce=e=>{this._instance=e,e.ce=this,e.isCE=!0;let t=(e,t)=>{this.dispatchEvent(new CustomEvent(e,ee(t[0])?l({detail:t},t[0]):{detail:t}))};e.emit=(e,...n)=>{t(e,n),O(e)!==e&&t(O(e),n)},this._setParent()}),t}_applyStyles(e,t){if(!e)return;if(t){if(t===this._def||this._styleChildren.has(t))return;this._styleChildren.add(t)}let n=this._nonce;for(let t=e.length-1;t>=0;t--){let r=document.createElement(`style`);n&&r.setAttribute(`nonce`,n),r.textContent=e[t],this.shadowRoot.prepend(r)}}_parseSlots(){let e=this._slots={},t;for(;t=this.firstChild;){let n=t.nodeType===1&&t.getAttribute(`slot`)||`default`;(e[n]||(e[n]=[])).push(t),this.removeChild(t)}}_renderSlots(){let e=this._getSlots(),t=this._instance.type.__scopeId;for(let n=0;n<e.length;n++){let r=e[n],i=r.getAttribute(`name`)||`default`,a=this._slots[i],o=r.parentNode;if(a)for(let e of a){if(t&&e.nodeType===1){let n=t+`-s`,r=document.createTreeWalker(e,1);e.setAttribute(n,``);let i;for(;i=r.nextNode();)i.setAttribute(n,``)}o.insertBefore(e,r)}else for(;r.firstChild;)o.insertBefore(r.firstChild,r);o.removeChild(r)}}_getSlots(){let e=[this];this._teleportTargets&&e.push(...this._teleportTargets);let t=new Set;for(let n of e){let e=n.querySelectorAll(`slot`);for(let n=0;n<e.length;n++)t.add(e[n])}return Array.from(t)}_injectChildStyle(e){this._applyStyles(e.styles,e)}_beginPatch(){this._patching=!0,this._dirty=!1}_endPatch(){this._patching=!1,this._dirty&&this._instance&&this._update()}_removeChildStyle(e){}};function pl(e){let t=Ts();return t&&t.ce||null}function ml(){let e=pl();return e&&e.shadowRoot}function hl(e=`$style`){{let t=Ts();if(!t)return r;let n=t.type.__cssModules;return n&&n[e]||r}}var gl=new WeakMap,_l=new WeakMap,vl=Symbol(`_moveCb`),yl=Symbol(`_enterCb`),bl=(e=>(delete e.props.mode,e))({name:`TransitionGroup`,props:l({},fc,{tag:String,moveClass:String}),setup(e,{slots:t}){let n=Ts(),r=Pr(),i,a;return Pi(()=>{if(!i.length)return;let t=e.moveClass||`${e.name||`v`}-move`;if(!wl(i[0].el,n.vnode.el,t)){i=[];return}i.forEach(xl),i.forEach(Sl);let r=i.filter(Cl);Dc(n.vnode.el),r.forEach(e=>{let n=e.el,r=n.style;yc(n,t),r.transform=r.webkitTransform=r.transitionDuration=``;let i=n[vl]=e=>{e&&e.target!==n||(!e||e.propertyName.endsWith(`transform`))&&(n.removeEventListener(`transitionend`,i),n[vl]=null,bc(n,t))};n.addEventListener(`transitionend`,i)}),i=[]}),()=>{let o=j(e),s=gc(o),c=o.tag||R;if(i=[],a)for(let e=0;e<a.length;e++){let t=a[e];t.el&&t.el instanceof Element&&(i.push(t),Gr(t,Hr(t,s,r,n)),gl.set(t,{left:t.el.offsetLeft,top:t.el.offsetTop}))}a=t.default?Kr(t.default()):[];for(let e=0;e<a.length;e++){let t=a[e];t.key!=null&&Gr(t,Hr(t,s,r,n))}return U(c,null,a)}}});function xl(e){let t=e.el;t[vl]&&t[vl](),t[yl]&&t[yl]()}function Sl(e){_l.set(e,{left:e.el.offsetLeft,top:e.el.offsetTop})}function Cl(e){let t=gl.get(e),n=_l.get(e),r=t.left-n.left,i=t.top-n.top;if(r||i){let t=e.el.style;return t.transform=t.webkitTransform=`translate(${r}px,${i}px)`,t.transitionDuration=`0s`,e}}function wl(e,t,n){let r=e.cloneNode(),i=e[uc];i&&i.forEach(e=>{e.split(/\s+/).forEach(e=>e&&r.classList.remove(e))}),n.split(/\s+/).forEach(e=>e&&r.classList.add(e)),r.style.display=`none`;let a=t.nodeType===1?t:t.parentNode;a.appendChild(r);let{hasTransform:o}=wc(r);return a.removeChild(r),o}var Tl=e=>{let t=e.props[`onUpdate:modelValue`]||!1;return p(t)?e=>se(t,e):t};function El(e){e.target.composing=!0}function Dl(e){let t=e.target;t.composing&&(t.composing=!1,t.dispatchEvent(new Event(`input`)))}var Ol=Symbol(`_assign`);function kl(e,t,n){return t&&(e=e.trim()),n&&(e=le(e)),e}var Al={created(e,{modifiers:{lazy:t,trim:n,number:r}},i){e[Ol]=Tl(i);let a=r||i.props&&i.props.type===`number`;Jc(e,t?`change`:`input`,t=>{t.target.composing||e[Ol](kl(e.value,n,a))}),(n||a)&&Jc(e,`change`,()=>{e.value=kl(e.value,n,a)}),t||(Jc(e,`compositionstart`,El),Jc(e,`compositionend`,Dl),Jc(e,`change`,Dl))},mounted(e,{value:t}){e.value=t??``},beforeUpdate(e,{value:t,oldValue:n,modifiers:{lazy:r,trim:i,number:a}},o){if(e[Ol]=Tl(o),e.composing)return;let s=(a||e.type===`number`)&&!/^0\d/.test(e.value)?le(e.value):e.value,c=t??``;s!==c&&(document.activeElement===e&&e.type!==`range`&&(r&&t===n||i&&e.value.trim()===c)||(e.value=c))}},jl={deep:!0,created(e,t,n){e[Ol]=Tl(n),Jc(e,`change`,()=>{let t=e._modelValue,n=Il(e),r=e.checked,i=e[Ol];if(p(t)){let e=Te(t,n),a=e!==-1;if(r&&!a)i(t.concat(n));else if(!r&&a){let n=[...t];n.splice(e,1),i(n)}}else if(h(t)){let e=new Set(t);r?e.add(n):e.delete(n),i(e)}else i(Ll(e,r))})},mounted:Ml,beforeUpdate(e,t,n){e[Ol]=Tl(n),Ml(e,t,n)}};function Ml(e,{value:t,oldValue:n},r){e._modelValue=t;let i;if(p(t))i=Te(t,r.props.value)>-1;else if(h(t))i=t.has(r.props.value);else{if(t===n)return;i=we(t,Ll(e,!0))}e.checked!==i&&(e.checked=i)}var Nl={created(e,{value:t},n){e.checked=we(t,n.props.value),e[Ol]=Tl(n),Jc(e,`change`,()=>{e[Ol](Il(e))})},beforeUpdate(e,{value:t,oldValue:n},r){e[Ol]=Tl(r),t!==n&&(e.checked=we(t,r.props.value))}},Pl={deep:!0,created(e,{value:t,modifiers:{number:n}},r){let i=h(t);Jc(e,`change`,()=>{let t=Array.prototype.filter.call(e.options,e=>e.selected).map(e=>n?le(Il(e)):Il(e));e[Ol](e.multiple?i?new Set(t):t:t[0]),e._assigning=!0,$n(()=>{e._assigning=!1})}),e[Ol]=Tl(r)},mounted(e,{value:t}){Fl(e,t)},beforeUpdate(e,t,n){e[Ol]=Tl(n)},updated(e,{value:t}){e._assigning||Fl(e,t)}};function Fl(e,t){let n=e.multiple,r=p(t);if(!(n&&!r&&!h(t))){for(let i=0,a=e.options.length;i<a;i++){let a=e.options[i],o=Il(a);if(n)if(r){let e=typeof o;e===`string`||e===`number`?a.selected=t.some(e=>String(e)===String(o)):a.selected=Te(t,o)>-1}else a.selected=t.has(o);else if(we(Il(a),t)){e.selectedIndex!==i&&(e.selectedIndex=i);return}}!n&&e.selectedIndex!==-1&&(e.selectedIndex=-1)}}function Il(e){return`_value`in e?e._value:e.value}function Ll(e,t){let n=t?`_trueValue`:`_falseValue`;return n in e?e[n]:t}var Rl={created(e,t,n){Bl(e,t,n,null,`created`)},mounted(e,t,n){Bl(e,t,n,null,`mounted`)},beforeUpdate(e,t,n,r){Bl(e,t,n,r,`beforeUpdate`)},updated(e,t,n,r){Bl(e,t,n,r,`updated`)}};function zl(e,t){switch(e){case`SELECT`:return Pl;case`TEXTAREA`:return Al;default:switch(t){case`checkbox`:return jl;case`radio`:return Nl;default:return Al}}}function Bl(e,t,n,r,i){let a=zl(e.tagName,n.props&&n.props.type)[i];a&&a(e,t,n,r)}function Vl(){Al.getSSRProps=({value:e})=>({value:e}),Nl.getSSRProps=({value:e},t)=>{if(t.props&&we(t.props.value,e))return{checked:!0}},jl.getSSRProps=({value:e},t)=>{if(p(e)){if(t.props&&Te(e,t.props.value)>-1)return{checked:!0}}else if(h(e)){if(t.props&&e.has(t.props.value))return{checked:!0}}else if(e)return{checked:!0}},Rl.getSSRProps=(e,t)=>{if(typeof t.type!=`string`)return;let n=zl(t.type.toUpperCase(),t.props&&t.props.type);if(n.getSSRProps)return n.getSSRProps(e,t)}}var Hl=[`ctrl`,`shift`,`alt`,`meta`],Ul={stop:e=>e.stopPropagation(),prevent:e=>e.preventDefault(),self:e=>e.target!==e.currentTarget,ctrl:e=>!e.ctrlKey,shift:e=>!e.shiftKey,alt:e=>!e.altKey,meta:e=>!e.metaKey,left:e=>`button`in e&&e.button!==0,middle:e=>`button`in e&&e.button!==1,right:e=>`button`in e&&e.button!==2,exact:(e,t)=>Hl.some(n=>e[`${n}Key`]&&!t.includes(n))},Wl=(e,t)=>{let n=e._withMods||={},r=t.join(`.`);return n[r]||(n[r]=((n,...r)=>{for(let e=0;e<t.length;e++){let r=Ul[t[e]];if(r&&r(n,t))return}return e(n,...r)}))},Gl={esc:`escape`,space:` `,up:`arrow-up`,left:`arrow-left`,right:`arrow-right`,down:`arrow-down`,delete:`backspace`},Kl=(e,t)=>{let n=e._withKeys||={},r=t.join(`.`);return n[r]||(n[r]=(n=>{if(!(`key`in n))return;let r=O(n.key);if(t.some(e=>e===r||Gl[e]===r))return e(n)}))},ql=l({patchProp:ol},sc),Jl,Yl=!1;function Xl(){return Jl||=Oo(ql)}function Zl(){return Jl=Yl?Jl:ko(ql),Yl=!0,Jl}var Ql=((...e)=>{Xl().render(...e)}),$l=((...e)=>{Zl().hydrate(...e)}),eu=((...e)=>{let t=Xl().createApp(...e),{mount:n}=t;return t.mount=e=>{let r=ru(e);if(!r)return;let i=t._component;!v(i)&&!i.render&&!i.template&&(i.template=r.innerHTML),r.nodeType===1&&(r.textContent=``);let a=n(r,!1,nu(r));return r instanceof Element&&(r.removeAttribute(`v-cloak`),r.setAttribute(`data-v-app`,``)),a},t}),tu=((...e)=>{let t=Zl().createApp(...e),{mount:n}=t;return t.mount=e=>{let t=ru(e);if(t)return n(t,!0,nu(t))},t});function nu(e){if(e instanceof SVGElement)return`svg`;if(typeof MathMLElement==`function`&&e instanceof MathMLElement)return`mathml`}function ru(e){return y(e)?document.querySelector(e):e}var iu=!1,au=()=>{iu||(iu=!0,Vl(),Nc())},ou=t({BaseTransition:()=>Br,BaseTransitionPropsValidators:()=>Ir,Comment:()=>Qo,DeprecationTypes:()=>null,EffectScope:()=>Me,ErrorCodes:()=>Bn,ErrorTypeStrings:()=>Xs,Fragment:()=>R,KeepAlive:()=>xi,ReactiveEffect:()=>Re,Static:()=>$o,Suspense:()=>Bo,Teleport:()=>kr,Text:()=>Zo,TrackOpTypes:()=>Dn,Transition:()=>pc,TransitionGroup:()=>bl,TriggerOpTypes:()=>On,VueElement:()=>fl,assertNumber:()=>zn,callWithAsyncErrorHandling:()=>Un,callWithErrorHandling:()=>Hn,camelize:()=>D,capitalize:()=>k,cloneVNode:()=>ps,compatUtils:()=>null,compile:()=>su,computed:()=>G,createApp:()=>eu,createBlock:()=>V,createCommentVNode:()=>gs,createElementBlock:()=>B,createElementVNode:()=>H,createHydrationRenderer:()=>ko,createPropsRestProxy:()=>_a,createRenderer:()=>Oo,createSSRApp:()=>tu,createSlots:()=>Xi,createStaticVNode:()=>hs,createTextVNode:()=>ms,createVNode:()=>U,customRef:()=>yn,defineAsyncComponent:()=>vi,defineComponent:()=>F,defineCustomElement:()=>ll,defineEmits:()=>aa,defineExpose:()=>oa,defineModel:()=>la,defineOptions:()=>sa,defineProps:()=>ia,defineSSRCustomElement:()=>ul,defineSlots:()=>ca,devtools:()=>Zs,effect:()=>Ze,effectScope:()=>Ne,getCurrentInstance:()=>Ts,getCurrentScope:()=>Pe,getCurrentWatcher:()=>Mn,getTransitionRawChildren:()=>Kr,guardReactiveProps:()=>fs,h:()=>K,handleError:()=>Wn,hasInjectionContext:()=>Ba,hydrate:()=>$l,hydrateOnIdle:()=>di,hydrateOnInteraction:()=>hi,hydrateOnMediaQuery:()=>mi,hydrateOnVisible:()=>pi,initCustomFormatter:()=>Gs,initDirectivesForSSR:()=>au,inject:()=>za,isMemoSame:()=>qs,isProxy:()=>on,isReactive:()=>nn,isReadonly:()=>rn,isRef:()=>un,isRuntimeOnly:()=>Rs,isShallow:()=>an,isVNode:()=>os,markRaw:()=>sn,mergeDefaults:()=>ha,mergeModels:()=>ga,mergeProps:()=>W,nextTick:()=>$n,nodeOps:()=>sc,normalizeClass:()=>A,normalizeProps:()=>ye,normalizeStyle:()=>me,onActivated:()=>Ci,onBeforeMount:()=>ji,onBeforeUnmount:()=>Fi,onBeforeUpdate:()=>Ni,onDeactivated:()=>wi,onErrorCaptured:()=>Bi,onMounted:()=>Mi,onRenderTracked:()=>zi,onRenderTriggered:()=>Ri,onScopeDispose:()=>Fe,onServerPrefetch:()=>Li,onUnmounted:()=>Ii,onUpdated:()=>Pi,onWatcherCleanup:()=>Nn,openBlock:()=>z,patchProp:()=>ol,popScopeId:()=>hr,provide:()=>Ra,proxyRefs:()=>_n,pushScopeId:()=>mr,queuePostFlushCb:()=>rr,reactive:()=>Zt,readonly:()=>$t,ref:()=>M,registerRuntimeCompiler:()=>Ls,render:()=>Ql,renderList:()=>Yi,renderSlot:()=>I,resolveComponent:()=>Ui,resolveDirective:()=>Ki,resolveDynamicComponent:()=>Gi,resolveFilter:()=>null,resolveTransitionHooks:()=>Hr,setBlockTracking:()=>is,setDevtoolsHook:()=>Qs,setTransitionHooks:()=>Gr,shallowReactive:()=>Qt,shallowReadonly:()=>en,shallowRef:()=>dn,ssrContextKey:()=>Va,ssrUtils:()=>$s,stop:()=>Qe,toDisplayString:()=>De,toHandlerKey:()=>ae,toHandlers:()=>Qi,toRaw:()=>j,toRef:()=>Cn,toRefs:()=>bn,toValue:()=>hn,transformVNodeArgs:()=>cs,triggerRef:()=>mn,unref:()=>N,useAttrs:()=>fa,useCssModule:()=>hl,useCssVars:()=>Fc,useHost:()=>pl,useId:()=>qr,useModel:()=>Ya,useSSRContext:()=>Ha,useShadowRoot:()=>ml,useSlots:()=>da,useTemplateRef:()=>Yr,useTransitionState:()=>Pr,vModelCheckbox:()=>jl,vModelDynamic:()=>Rl,vModelRadio:()=>Nl,vModelSelect:()=>Pl,vModelText:()=>Al,vShow:()=>jc,version:()=>Js,warn:()=>Ys,watch:()=>L,watchEffect:()=>Ua,watchPostEffect:()=>Wa,watchSyncEffect:()=>Ga,withAsyncContext:()=>va,withCtx:()=>P,withDefaults:()=>ua,withDirectives:()=>_r,withKeys:()=>Kl,withMemo:()=>Ks,withModifiers:()=>Wl,withScopeId:()=>gr}),su=()=>{};function cu(e){var t,n,r=``;if(typeof e==`string`||typeof e==`number`)r+=e;else if(typeof e==`object`)if(Array.isArray(e)){var i=e.length;for(t=0;t<i;t++)e[t]&&(n=cu(e[t]))&&(r&&(r+=` `),r+=n)}else for(n in e)e[n]&&(r&&(r+=` `),r+=n);return r}function lu(){for(var e,t,n=0,r=``,i=arguments.length;n<i;n++)(e=arguments[n])&&(t=cu(e))&&(r&&(r+=` `),r+=t);return r}var uu=e=>typeof e==`boolean`?`${e}`:e===0?`0`:e;const du=lu,fu=(e,t)=>n=>{if(t?.variants==null)return du(e,n?.class,n?.className);let{variants:r,defaultVariants:i}=t,a=Object.keys(r).map(e=>{let t=n?.[e],a=i?.[e];if(t===null)return null;let o=uu(t)||uu(a);return r[e][o]}),o=n&&Object.entries(n).reduce((e,t)=>{let[n,r]=t;return r===void 0||(e[n]=r),e},{});return du(e,a,t?.compoundVariants?.reduce((e,t)=>{let{class:n,className:r,...a}=t;return Object.entries(a).every(e=>{let[t,n]=e;return Array.isArray(n)?n.includes({...i,...o}[t]):{...i,...o}[t]===n})?[...e,n,r]:e},[]),n?.class,n?.className)};var pu=typeof window<`u`&&typeof document<`u`;typeof WorkerGlobalScope<`u`&&globalThis instanceof WorkerGlobalScope;var mu=e=>e!==void 0;function hu(e){return un(e)?Zt(new Proxy({},{get(t,n,r){return N(Reflect.get(e.value,n,r))},set(t,n,r){return un(e.value[n])&&!un(r)?e.value[n].value=r:e.value[n]=r,!0},deleteProperty(t,n){return Reflect.deleteProperty(e.value,n)},has(t,n){return Reflect.has(e.value,n)},ownKeys(){return Object.keys(e.value)},getOwnPropertyDescriptor(){return{enumerable:!0,configurable:!0}}})):Zt(e)}function gu(e){return hu(G(e))}function _u(e,...t){let n=t.flat(),r=n[0];return gu(()=>typeof r==`function`?Object.fromEntries(Object.entries(bn(e)).filter(([e,t])=>!r(hn(t),e))):Object.fromEntries(Object.entries(bn(e)).filter(e=>!n.includes(e[0]))))}pu&&window.document,pu&&window.navigator,pu&&window.location;function vu(e){return JSON.parse(JSON.stringify(e))}function yu(e,t,n,r={}){var i,a;let{clone:o=!1,passive:s=!1,eventName:c,deep:l=!1,defaultValue:u,shouldEmit:d}=r,f=Ts(),p=n||f?.emit||(f==null||(i=f.$emit)==null?void 0:i.bind(f))||(f==null||(a=f.proxy)==null||(a=a.$emit)==null?void 0:a.bind(f?.proxy)),m=c;t||=`modelValue`,m||=`update:${t.toString()}`;let h=e=>o?typeof o==`function`?o(e):vu(e):e,g=()=>mu(e[t])?h(e[t]):u,_=e=>{d?d(e)&&p(m,e):p(m,e)};if(s){let n=M(g()),r=!1;return L(()=>e[t],e=>{r||(r=!0,n.value=h(e),$n(()=>r=!1))}),L(n,n=>{!r&&(n!==e[t]||l)&&_(n)},{deep:l}),n}else return G({get(){return g()},set(e){_(e)}})}function bu(e,t){let n=typeof e==`string`&&!t?`${e}Context`:t,r=Symbol(n);return[t=>{let n=za(r,t);if(n||n===null)return n;throw Error(`Injection \`${r.toString()}\` not found. Component must be used within ${Array.isArray(e)?`one of the following components: ${e.join(`, `)}`:`\`${e}\``}`)},e=>(Ra(r,e),e)]}function xu(){let e=document.activeElement;if(e==null)return null;for(;e!=null&&e.shadowRoot!=null&&e.shadowRoot.activeElement!=null;)e=e.shadowRoot.activeElement;return e}function Su(e,t,n){let r=n.originalEvent.target,i=new CustomEvent(e,{bubbles:!1,cancelable:!0,detail:n});t&&r.addEventListener(e,t,{once:!0}),r.dispatchEvent(i)}function Cu(e){return e?e.flatMap(e=>e.type===R?Cu(e.children):[e]):[]}var wu=[`INPUT`,`TEXTAREA`];function Tu(e,t,n,r={}){if(!t||r.enableIgnoredElement&&wu.includes(t.nodeName))return null;let{arrowKeyOptions:i=`both`,attributeName:a=`[data-reka-collection-item]`,itemsArray:o=[],loop:s=!0,dir:c=`ltr`,preventScroll:l=!0,focus:u=!1}=r,[d,f,p,m,h,g]=[e.key===`ArrowRight`,e.key===`ArrowLeft`,e.key===`ArrowUp`,e.key===`ArrowDown`,e.key===`Home`,e.key===`End`],_=p||m,v=d||f;if(!h&&!g&&(!_&&!v||i===`vertical`&&v||i===`horizontal`&&_))return null;let y=n?Array.from(n.querySelectorAll(a)):o;if(!y.length)return null;l&&e.preventDefault();let b=null;return v||_?b=Eu(y,t,{goForward:_?m:c===`ltr`?d:f,loop:s}):h?b=y.at(0)||null:g&&(b=y.at(-1)||null),u&&b?.focus(),b}function Eu(e,t,n,r=e.length){if(--r===0)return null;let i=e.indexOf(t),a=n.goForward?i+1:i-1;if(!n.loop&&(a<0||a>=e.length))return null;let o=e[(a+e.length)%e.length];return o?o.hasAttribute(`disabled`)&&o.getAttribute(`disabled`)!==`false`?Eu(e,o,n,r):o:null}var[Du,Ou]=bu(`ConfigProvider`);function ku(e,t){let n=dn();return Ua(()=>{n.value=e()},{...t,flush:t?.flush??`sync`}),$t(n)}function Au(e){return Pe()?(Fe(e),!0):!1}function ju(e){let t=!1,n,r=Ne(!0);return(...i)=>(t||=(n=r.run(()=>e(...i)),!0),n)}function Mu(e){let t=0,n,r,i=()=>{--t,r&&t<=0&&(r.stop(),n=void 0,r=void 0)};return(...a)=>(t+=1,r||(r=Ne(!0),n=r.run(()=>e(...a))),Au(i),n)}var Nu=typeof window<`u`&&typeof document<`u`;typeof WorkerGlobalScope<`u`&&globalThis instanceof WorkerGlobalScope;var Pu=e=>e!==void 0,Fu=Object.prototype.toString,Iu=e=>Fu.call(e)===`[object Object]`,Lu=()=>{},Ru=zu();function zu(){return Nu&&(window==null?void 0:window.navigator)?.userAgent&&(/iP(?:ad|hone|od)/.test(window.navigator.userAgent)||(window==null?void 0:window.navigator)?.maxTouchPoints>2&&/iPad|Macintosh/.test(window==null?void 0:window.navigator.userAgent))}function Bu(e,t){function n(...n){return new Promise((r,i)=>{Promise.resolve(e(()=>t.apply(this,n),{fn:t,thisArg:this,args:n})).then(r).catch(i)})}return n}function Vu(e,t={}){let n,r,i=Lu,a=e=>{clearTimeout(e),i(),i=Lu},o;return s=>{let c=hn(e),l=hn(t.maxWait);return n&&a(n),c<=0||l!==void 0&&l<=0?(r&&=(a(r),null),Promise.resolve(s())):new Promise((e,u)=>{i=t.rejectOnCancel?u:e,o=s,l&&!r&&(r=setTimeout(()=>{n&&a(n),r=null,e(o())},l)),n=setTimeout(()=>{r&&a(r),r=null,e(s())},c)})}}function Hu(e){return e||Ts()}function Uu(e){return Array.isArray(e)?e:[e]}function Wu(e,t=1e4){return yn((n,r)=>{let i=hn(e),a,o=()=>setTimeout(()=>{i=hn(e),r()},hn(t));return Au(()=>{clearTimeout(a)}),{get(){return n(),i},set(e){i=e,r(),clearTimeout(a),a=o()}}})}function Gu(e,t=200,n={}){return Bu(Vu(t,n),e)}function Ku(e,t){Hu(t)&&Fi(e,t)}function qu(e,t,n){return L(e,t,{...n,immediate:!0})}var Ju=Nu?window:void 0;Nu&&window.document,Nu&&window.navigator,Nu&&window.location;function Yu(e){let t=hn(e);return t?.$el??t}function Xu(...e){let t=[],n=()=>{t.forEach(e=>e()),t.length=0},r=(e,t,n,r)=>(e.addEventListener(t,n,r),()=>e.removeEventListener(t,n,r)),i=G(()=>{let t=Uu(hn(e[0])).filter(e=>e!=null);return t.every(e=>typeof e!=`string`)?t:void 0}),a=qu(()=>[i.value?.map(e=>Yu(e))??[Ju].filter(e=>e!=null),Uu(hn(i.value?e[1]:e[0])),Uu(N(i.value?e[2]:e[1])),hn(i.value?e[3]:e[2])],([e,i,a,o])=>{if(n(),!e?.length||!i?.length||!a?.length)return;let s=Iu(o)?{...o}:o;t.push(...e.flatMap(e=>i.flatMap(t=>a.map(n=>r(e,t,n,s)))))},{flush:`post`});return Au(n),()=>{a(),n()}}function Zu(){let e=dn(!1),t=Ts();return t&&Mi(()=>{e.value=!0},t),e}function Qu(e){let t=Zu();return G(()=>(t.value,!!e()))}function $u(e){return typeof e==`function`?e:typeof e==`string`?t=>t.key===e:Array.isArray(e)?t=>e.includes(t.key):()=>!0}function ed(...e){let t,n,r={};e.length===3?(t=e[0],n=e[1],r=e[2]):e.length===2?typeof e[1]==`object`?(t=!0,n=e[0],r=e[1]):(t=e[0],n=e[1]):(t=!0,n=e[0]);let{target:i=Ju,eventName:a=`keydown`,passive:o=!1,dedupe:s=!1}=r,c=$u(t);return Xu(i,a,e=>{e.repeat&&hn(s)||c(e)&&n(e)},o)}function td(e){return JSON.parse(JSON.stringify(e))}function nd(e,t,n={}){let{window:r=Ju,...i}=n,a,o=Qu(()=>r&&`ResizeObserver`in r),s=()=>{a&&=(a.disconnect(),void 0)},c=L(G(()=>{let t=hn(e);return Array.isArray(t)?t.map(e=>Yu(e)):[Yu(t)]}),e=>{if(s(),o.value&&r){a=new ResizeObserver(t);for(let t of e)t&&a.observe(t,i)}},{immediate:!0,flush:`post`}),l=()=>{s(),c()};return Au(l),{isSupported:o,stop:l}}function rd(e,t,n,r={}){let{clone:i=!1,passive:a=!1,eventName:o,deep:s=!1,defaultValue:c,shouldEmit:l}=r,u=Ts(),d=n||u?.emit||(u?.$emit)?.bind(u)||(u?.proxy?.$emit)?.bind(u?.proxy),f=o;t||=`modelValue`,f||=`update:${t.toString()}`;let p=e=>i?typeof i==`function`?i(e):td(e):e,m=()=>Pu(e[t])?p(e[t]):c,h=e=>{l?l(e)&&d(f,e):d(f,e)};if(a){let n=M(m()),r=!1;return L(()=>e[t],e=>{r||(r=!0,n.value=p(e),$n(()=>r=!1))}),L(n,n=>{!r&&(n!==e[t]||s)&&h(n)},{deep:s}),n}else return G({get(){return m()},set(e){h(e)}})}function id(e){if(typeof e!=`object`||!e)return!1;let t=Object.getPrototypeOf(e);return t!==null&&t!==Object.prototype&&Object.getPrototypeOf(t)!==null||Symbol.iterator in e?!1:Symbol.toStringTag in e?Object.prototype.toString.call(e)===`[object Module]`:!0}function ad(e,t,n=`.`,r){if(!id(t))return ad(e,{},n,r);let i=Object.assign({},t);for(let t in e){if(t===`__proto__`||t===`constructor`)continue;let a=e[t];a!=null&&(r&&r(i,t,a,n)||(Array.isArray(a)&&Array.isArray(i[t])?i[t]=[...a,...i[t]]:id(a)&&id(i[t])?i[t]=ad(a,i[t],(n?`${n}.`:``)+t.toString(),r):i[t]=a))}return i}function od(e){return(...t)=>t.reduce((t,n)=>ad(t,n,``,e),{})}var sd=od(),cd=Mu(()=>{let e=M(new Map),t=M(),n=G(()=>{for(let t of e.value.values())if(t)return!0;return!1}),r=Du({scrollBody:M(!0)}),i=null,a=()=>{document.body.style.paddingRight=``,document.body.style.marginRight=``,document.body.style.pointerEvents=``,document.documentElement.style.removeProperty(`--scrollbar-width`),document.body.style.overflow=t.value??``,Ru&&i?.(),t.value=void 0};return L(n,(e,n)=>{if(!Nu)return;if(!e){n&&a();return}t.value===void 0&&(t.value=document.body.style.overflow);let o=window.innerWidth-document.documentElement.clientWidth,s={padding:o,margin:0},c=r.scrollBody?.value?typeof r.scrollBody.value==`object`?sd({padding:r.scrollBody.value.padding===!0?o:r.scrollBody.value.padding,margin:r.scrollBody.value.margin===!0?o:r.scrollBody.value.margin},s):s:{padding:0,margin:0};o>0&&(document.body.style.paddingRight=typeof c.padding==`number`?`${c.padding}px`:String(c.padding),document.body.style.marginRight=typeof c.margin==`number`?`${c.margin}px`:String(c.margin),document.documentElement.style.setProperty(`--scrollbar-width`,`${o}px`),document.body.style.overflow=`hidden`),Ru&&(i=Xu(document,`touchmove`,e=>dd(e),{passive:!1})),$n(()=>{document.body.style.pointerEvents=`none`,document.body.style.overflow=`hidden`})},{immediate:!0,flush:`sync`}),e});function ld(e){let t=Math.random().toString(36).substring(2,7),n=cd();n.value.set(t,e??!1);let r=G({get:()=>n.value.get(t)??!1,set:e=>n.value.set(t,e)});return Ku(()=>{n.value.delete(t)}),r}function ud(e){let t=window.getComputedStyle(e);if(t.overflowX===`scroll`||t.overflowY===`scroll`||t.overflowX===`auto`&&e.clientWidth<e.scrollWidth||t.overflowY===`auto`&&e.clientHeight<e.scrollHeight)return!0;{let t=e.parentNode;return!(t instanceof Element)||t.tagName===`BODY`?!1:ud(t)}}function dd(e){let t=e||window.event,n=t.target;return n instanceof Element&&ud(n)?!1:t.touches.length>1?!0:(t.preventDefault&&t.cancelable&&t.preventDefault(),!1)}function fd(e){let t=Du({dir:M(`ltr`)});return G(()=>e?.value||t.dir?.value||`ltr`)}function pd(e){let t=Ts(),n=t?.type.emits,r={};return n?.length||console.warn(`No emitted event found. Please check component: ${t?.type.__name}`),n?.forEach(t=>{r[ae(D(t))]=(...n)=>e(t,...n)}),r}var md=0;function hd(){Ua(e=>{if(!Nu)return;let t=document.querySelectorAll(`[data-reka-focus-guard]`);document.body.insertAdjacentElement(`afterbegin`,t[0]??gd()),document.body.insertAdjacentElement(`beforeend`,t[1]??gd()),md++,e(()=>{md===1&&document.querySelectorAll(`[data-reka-focus-guard]`).forEach(e=>e.remove()),md--})})}function gd(){let e=document.createElement(`span`);return e.setAttribute(`data-reka-focus-guard`,``),e.tabIndex=0,e.style.outline=`none`,e.style.opacity=`0`,e.style.position=`fixed`,e.style.pointerEvents=`none`,e}function q(){let e=Ts(),t=M(),n=G(()=>[`#text`,`#comment`].includes(t.value?.$el.nodeName)?t.value?.$el.nextElementSibling:Yu(t)),r=Object.assign({},e.exposed),i={};for(let t in e.props)Object.defineProperty(i,t,{enumerable:!0,configurable:!0,get:()=>e.props[t]});if(Object.keys(r).length>0)for(let e in r)Object.defineProperty(i,e,{enumerable:!0,configurable:!0,get:()=>r[e]});Object.defineProperty(i,`$el`,{enumerable:!0,configurable:!0,get:()=>e.vnode.el}),e.exposed=i;function a(n){t.value=n,n&&(Object.defineProperty(i,`$el`,{enumerable:!0,configurable:!0,get:()=>n instanceof Element?n:n.$el}),e.exposed=i)}return{forwardRef:a,currentRef:t,currentElement:n}}function _d(e){let t=Ts(),n=Object.keys(t?.type.props??{}).reduce((e,n)=>{let r=(t?.type.props[n]).default;return r!==void 0&&(e[n]=r),e},{}),r=Cn(e);return G(()=>{let e={},i=t?.vnode.props??{};return Object.keys(i).forEach(t=>{e[D(t)]=i[t]}),Object.keys({...n,...e}).reduce((e,t)=>(r.value[t]!==void 0&&(e[t]=r.value[t]),e),{})})}function vd(e,t){let n=_d(e),r=t?pd(t):{};return G(()=>({...n.value,...r}))}var yd=function(e){return typeof document>`u`?null:(Array.isArray(e)?e[0]:e).ownerDocument.body},bd=new WeakMap,xd=new WeakMap,Sd={},Cd=0,wd=function(e){return e&&(e.host||wd(e.parentNode))},Td=function(e,t){return t.map(function(t){if(e.contains(t))return t;var n=wd(t);return n&&e.contains(n)?n:(console.error(`aria-hidden`,t,`in not contained inside`,e,`. Doing nothing`),null)}).filter(function(e){return!!e})},Ed=function(e,t,n,r){var i=Td(t,Array.isArray(e)?e:[e]);Sd[n]||(Sd[n]=new WeakMap);var a=Sd[n],o=[],s=new Set,c=new Set(i),l=function(e){!e||s.has(e)||(s.add(e),l(e.parentNode))};i.forEach(l);var u=function(e){!e||c.has(e)||Array.prototype.forEach.call(e.children,function(e){if(s.has(e))u(e);else try{var t=e.getAttribute(r),i=t!==null&&t!==`false`,c=(bd.get(e)||0)+1,l=(a.get(e)||0)+1;bd.set(e,c),a.set(e,l),o.push(e),c===1&&i&&xd.set(e,!0),l===1&&e.setAttribute(n,`true`),i||e.setAttribute(r,`true`)}catch(t){console.error(`aria-hidden: cannot operate on `,e,t)}})};return u(t),s.clear(),Cd++,function(){o.forEach(function(e){var t=bd.get(e)-1,i=a.get(e)-1;bd.set(e,t),a.set(e,i),t||(xd.has(e)||e.removeAttribute(r),xd.delete(e)),i||e.removeAttribute(n)}),Cd--,Cd||(bd=new WeakMap,bd=new WeakMap,xd=new WeakMap,Sd={})}},Dd=function(e,t,n){n===void 0&&(n=`data-aria-hidden`);var r=Array.from(Array.isArray(e)?e:[e]),i=t||yd(e);return i?(r.push.apply(r,Array.from(i.querySelectorAll(`[aria-live], script`))),Ed(r,i,n,`aria-hidden`)):function(){return null}};function Od(e){let t;L(()=>Yu(e),e=>{e?t=Dd(e):t&&t()}),Ii(()=>{t&&t()})}var kd=0;function Ad(e,t=`reka`){if(e)return e;if(`useId`in ou)return`${t}-${qr?.()}`;let n=Du({useId:void 0});return n.useId?`${t}-${n.useId()}`:`${t}-${++kd}`}function jd(e){let t=M(),n=G(()=>t.value?.width??0),r=G(()=>t.value?.height??0);return Mi(()=>{let n=Yu(e);if(n){t.value={width:n.offsetWidth,height:n.offsetHeight};let e=new ResizeObserver(e=>{if(!Array.isArray(e)||!e.length)return;let r=e[0],i,a;if(`borderBoxSize`in r){let e=r.borderBoxSize,t=Array.isArray(e)?e[0]:e;i=t.inlineSize,a=t.blockSize}else i=n.offsetWidth,a=n.offsetHeight;t.value={width:i,height:a}});return e.observe(n,{box:`border-box`}),()=>e.unobserve(n)}else t.value=void 0}),{width:n,height:r}}function Md(e,t){let n=M(e);function r(e){return t[n.value][e]??n.value}return{state:n,dispatch:e=>{n.value=r(e)}}}function Nd(e){let t=Wu(``,1e3);return{search:t,handleTypeaheadSearch:(n,r)=>{if(t.value+=n,e)e(n);else{let e=xu(),n=r.map(e=>({...e,textValue:e.value?.textValue??e.ref.textContent?.trim()??``})),i=n.find(t=>t.ref===e),a=Fd(n.map(e=>e.textValue),t.value,i?.textValue),o=n.find(e=>e.textValue===a);return o&&o.ref.focus(),o?.ref}},resetTypeahead:()=>{t.value=``}}}function Pd(e,t){return e.map((n,r)=>e[(t+r)%e.length])}function Fd(e,t,n){let r=t.length>1&&Array.from(t).every(e=>e===t[0])?t[0]:t,i=n?e.indexOf(n):-1,a=Pd(e,Math.max(i,0));r.length===1&&(a=a.filter(e=>e!==n));let o=a.find(e=>e.toLowerCase().startsWith(r.toLowerCase()));return o===n?void 0:o}function Id(e,t){let n=M({}),r=M(`none`),i=M(e),a=e.value?`mounted`:`unmounted`,o,s=t.value?.ownerDocument.defaultView??Ju,{state:c,dispatch:l}=Md(a,{mounted:{UNMOUNT:`unmounted`,ANIMATION_OUT:`unmountSuspended`},unmountSuspended:{MOUNT:`mounted`,ANIMATION_END:`unmounted`},unmounted:{MOUNT:`mounted`}}),u=e=>{if(Nu){let n=new CustomEvent(e,{bubbles:!1,cancelable:!1});t.value?.dispatchEvent(n)}};L(e,async(e,i)=>{let a=i!==e;if(await $n(),a){let a=r.value,o=Ld(t.value);e?(l(`MOUNT`),u(`enter`),o===`none`&&u(`after-enter`)):o===`none`||o===`undefined`||n.value?.display===`none`?(l(`UNMOUNT`),u(`leave`),u(`after-leave`)):i&&a!==o?(l(`ANIMATION_OUT`),u(`leave`)):(l(`UNMOUNT`),u(`after-leave`))}},{immediate:!0});let d=e=>{let n=Ld(t.value),r=n.includes(CSS.escape(e.animationName)),a=c.value===`mounted`?`enter`:`leave`;if(e.target===t.value&&r&&(u(`after-${a}`),l(`ANIMATION_END`),!i.value)){let e=t.value.style.animationFillMode;t.value.style.animationFillMode=`forwards`,o=s?.setTimeout(()=>{t.value?.style.animationFillMode===`forwards`&&(t.value.style.animationFillMode=e)})}e.target===t.value&&n===`none`&&l(`ANIMATION_END`)},f=e=>{e.target===t.value&&(r.value=Ld(t.value))},p=L(t,(e,t)=>{e?(n.value=getComputedStyle(e),e.addEventListener(`animationstart`,f),e.addEventListener(`animationcancel`,d),e.addEventListener(`animationend`,d)):(l(`ANIMATION_END`),o!==void 0&&s?.clearTimeout(o),t?.removeEventListener(`animationstart`,f),t?.removeEventListener(`animationcancel`,d),t?.removeEventListener(`animationend`,d))},{immediate:!0}),m=L(c,()=>{let e=Ld(t.value);r.value=c.value===`mounted`?e:`none`});return Ii(()=>{p(),m()}),{isPresent:G(()=>[`mounted`,`unmountSuspended`].includes(c.value))}}function Ld(e){return e&&getComputedStyle(e).animationName||`none`}var Rd=F({name:`Presence`,props:{present:{type:Boolean,required:!0},forceMount:{type:Boolean}},slots:{},setup(e,{slots:t,expose:n}){let{present:r,forceMount:i}=bn(e),a=M(),{isPresent:o}=Id(r,a);n({present:o});let s=t.default({present:o.value});s=Cu(s||[]);let c=Ts();if(s&&s?.length>1){let e=c?.parent?.type.name?`<${c.parent.type.name} />`:`component`;throw Error([`Detected an invalid children for \`${e}\` for  \`Presence\` component.`,``,"Note: Presence works similarly to `v-if` directly, but it waits for animation/transition to finished before unmounting. So it expect only one direct child of valid VNode type.",`You can apply a few solutions:`,["Provide a single child element so that `presence` directive attach correctly.",`Ensure the first child is an actual element instead of a raw text node or comment node.`].map(e=>`  - ${e}`).join(`
 `)].join(`
-`))}return()=>i.value||r.value||o.value?K(t.default({present:o.value})[0],{ref:e=>{let t=Yu(e);return t?.hasAttribute===void 0||(t?.hasAttribute(`data-reka-popper-content-wrapper`)?a.value=t.firstElementChild:a.value=t),t}}):null}}),zd=F({name:`PrimitiveSlot`,inheritAttrs:!1,setup(e,{attrs:t,slots:n}){return()=>{if(!n.default)return null;let e=Cu(n.default()),r=e.findIndex(e=>e.type!==Qo);if(r===-1)return e;let i=e[r];delete i.props?.ref;let a=i.props?W(t,i.props):t,o=ps({...i,props:{}},a);return e.length===1?o:(e[r]=o,e)}}}),Bd=[`area`,`img`,`input`],Vd=F({name:`Primitive`,inheritAttrs:!1,props:{asChild:{type:Boolean,default:!1},as:{type:[String,Object],default:`div`}},setup(e,{attrs:t,slots:n}){let r=e.asChild?`template`:e.as;return typeof r==`string`&&Bd.includes(r)?()=>K(r,t):r===`template`?()=>K(zd,t,{default:n.default}):()=>K(e.as,t,{default:n.default})}});function Hd(){let e=M();return{primitiveElement:e,currentElement:G(()=>[`#text`,`#comment`].includes(e.value?.$el.nodeName)?e.value?.$el.nextElementSibling:Yu(e))}}var[Ud,Wd]=bu(`DialogRoot`),Gd=F({inheritAttrs:!1,__name:`DialogRoot`,props:{open:{type:Boolean,required:!1,default:void 0},defaultOpen:{type:Boolean,required:!1,default:!1},modal:{type:Boolean,required:!1,default:!0}},emits:[`update:open`],setup(e,{emit:t}){let n=e,r=rd(n,`open`,t,{defaultValue:n.defaultOpen,passive:n.open===void 0}),i=M(),a=M(),{modal:o}=bn(n);return Wd({open:r,modal:o,openModal:()=>{r.value=!0},onOpenChange:e=>{r.value=e},onOpenToggle:()=>{r.value=!r.value},contentId:``,titleId:``,descriptionId:``,triggerElement:i,contentElement:a}),(e,t)=>I(e.$slots,`default`,{open:N(r),close:()=>r.value=!1})}}),Kd=F({__name:`DialogClose`,props:{asChild:{type:Boolean,required:!1},as:{type:null,required:!1,default:`button`}},setup(e){let t=e;q();let n=Ud();return(e,r)=>(z(),V(N(Vd),W(t,{type:e.as===`button`?`button`:void 0,onClick:r[0]||=e=>N(n).onOpenChange(!1)}),{default:P(()=>[I(e.$slots,`default`)]),_:3},16,[`type`]))}}),qd=`dismissableLayer.pointerDownOutside`,Jd=`dismissableLayer.focusOutside`;function Yd(e,t){let n=t.closest(`[data-dismissable-layer]`),r=e.dataset.dismissableLayer===``?e:e.querySelector(`[data-dismissable-layer]`),i=Array.from(e.ownerDocument.querySelectorAll(`[data-dismissable-layer]`));return!!(n&&(r===n||i.indexOf(r)<i.indexOf(n)))}function Xd(e,t,n=!0){let r=t?.value?.ownerDocument??globalThis?.document,i=M(!1),a=M(()=>{});return Ua(o=>{if(!Nu||!hn(n))return;let s=async n=>{let o=n.target;if(!(!t?.value||!o)){if(Yd(t.value,o)){i.value=!1;return}if(n.target&&!i.value){let t={originalEvent:n};function i(){Su(qd,e,t)}n.pointerType===`touch`?(r.removeEventListener(`click`,a.value),a.value=i,r.addEventListener(`click`,a.value,{once:!0})):i()}else r.removeEventListener(`click`,a.value);i.value=!1}},c=window.setTimeout(()=>{r.addEventListener(`pointerdown`,s)},0);o(()=>{window.clearTimeout(c),r.removeEventListener(`pointerdown`,s),r.removeEventListener(`click`,a.value)})}),{onPointerDownCapture:()=>{hn(n)&&(i.value=!0)}}}function Zd(e,t,n=!0){let r=t?.value?.ownerDocument??globalThis?.document,i=M(!1);return Ua(a=>{if(!Nu||!hn(n))return;let o=async n=>{if(!t?.value)return;await $n(),await $n();let r=n.target;!t.value||!r||Yd(t.value,r)||n.target&&!i.value&&Su(Jd,e,{originalEvent:n})};r.addEventListener(`focusin`,o),a(()=>r.removeEventListener(`focusin`,o))}),{onFocusCapture:()=>{hn(n)&&(i.value=!0)},onBlurCapture:()=>{hn(n)&&(i.value=!1)}}}var Qd=Zt({layersRoot:new Set,layersWithOutsidePointerEventsDisabled:new Set,branches:new Set}),$d=F({__name:`DismissableLayer`,props:{disableOutsidePointerEvents:{type:Boolean,required:!1,default:!1},asChild:{type:Boolean,required:!1},as:{type:null,required:!1}},emits:[`escapeKeyDown`,`pointerDownOutside`,`focusOutside`,`interactOutside`,`dismiss`],setup(e,{emit:t}){let n=e,r=t,{forwardRef:i,currentElement:a}=q(),o=G(()=>a.value?.ownerDocument??globalThis.document),s=G(()=>Qd.layersRoot),c=G(()=>a.value?Array.from(s.value).indexOf(a.value):-1),l=G(()=>Qd.layersWithOutsidePointerEventsDisabled.size>0),u=G(()=>{let e=Array.from(s.value),[t]=[...Qd.layersWithOutsidePointerEventsDisabled].slice(-1),n=e.indexOf(t);return c.value>=n}),d=Xd(async e=>{let t=[...Qd.branches].some(t=>t?.contains(e.target));!u.value||t||(r(`pointerDownOutside`,e),r(`interactOutside`,e),await $n(),e.defaultPrevented||r(`dismiss`))},a),f=Zd(e=>{[...Qd.branches].some(t=>t?.contains(e.target))||(r(`focusOutside`,e),r(`interactOutside`,e),e.defaultPrevented||r(`dismiss`))},a);ed(`Escape`,e=>{c.value===s.value.size-1&&(r(`escapeKeyDown`,e),e.defaultPrevented||r(`dismiss`))});let p;return Ua(e=>{a.value&&(n.disableOutsidePointerEvents&&(Qd.layersWithOutsidePointerEventsDisabled.size===0&&(p=o.value.body.style.pointerEvents,o.value.body.style.pointerEvents=`none`),Qd.layersWithOutsidePointerEventsDisabled.add(a.value)),s.value.add(a.value),e(()=>{n.disableOutsidePointerEvents&&Qd.layersWithOutsidePointerEventsDisabled.size===1&&(o.value.body.style.pointerEvents=p)}))}),Ua(e=>{e(()=>{a.value&&(s.value.delete(a.value),Qd.layersWithOutsidePointerEventsDisabled.delete(a.value))})}),(e,t)=>(z(),V(N(Vd),{ref:N(i),"as-child":e.asChild,as:e.as,"data-dismissable-layer":``,style:me({pointerEvents:l.value?u.value?`auto`:`none`:void 0}),onFocusCapture:N(f).onFocusCapture,onBlurCapture:N(f).onBlurCapture,onPointerdownCapture:N(d).onPointerDownCapture},{default:P(()=>[I(e.$slots,`default`)]),_:3},8,[`as-child`,`as`,`style`,`onFocusCapture`,`onBlurCapture`,`onPointerdownCapture`]))}}),ef=ju(()=>M([]));function tf(){let e=ef();return{add(t){let n=e.value[0];t!==n&&n?.pause(),e.value=nf(e.value,t),e.value.unshift(t)},remove(t){e.value=nf(e.value,t),e.value[0]?.resume()}}}function nf(e,t){let n=[...e],r=n.indexOf(t);return r!==-1&&n.splice(r,1),n}function rf(e){return e.filter(e=>e.tagName!==`A`)}var af=`focusScope.autoFocusOnMount`,of=`focusScope.autoFocusOnUnmount`,sf={bubbles:!1,cancelable:!0};function cf(e,{select:t=!1}={}){let n=xu();for(let r of e)if(mf(r,{select:t}),xu()!==n)return!0}function lf(e){let t=uf(e);return[df(t,e),df(t.reverse(),e)]}function uf(e){let t=[],n=document.createTreeWalker(e,NodeFilter.SHOW_ELEMENT,{acceptNode:e=>{let t=e.tagName===`INPUT`&&e.type===`hidden`;return e.disabled||e.hidden||t?NodeFilter.FILTER_SKIP:e.tabIndex>=0?NodeFilter.FILTER_ACCEPT:NodeFilter.FILTER_SKIP}});for(;n.nextNode();)t.push(n.currentNode);return t}function df(e,t){for(let n of e)if(!ff(n,{upTo:t}))return n}function ff(e,{upTo:t}){if(getComputedStyle(e).visibility===`hidden`)return!0;for(;e;){if(t!==void 0&&e===t)return!1;if(getComputedStyle(e).display===`none`)return!0;e=e.parentElement}return!1}function pf(e){return e instanceof HTMLInputElement&&`select`in e}function mf(e,{select:t=!1}={}){if(e&&e.focus){let n=xu();e.focus({preventScroll:!0}),e!==n&&pf(e)&&t&&e.select()}}var hf=F({__name:`FocusScope`,props:{loop:{type:Boolean,required:!1,default:!1},trapped:{type:Boolean,required:!1,default:!1},asChild:{type:Boolean,required:!1},as:{type:null,required:!1}},emits:[`mountAutoFocus`,`unmountAutoFocus`],setup(e,{emit:t}){let n=e,r=t,{currentRef:i,currentElement:a}=q(),o=M(null),s=tf(),c=Zt({paused:!1,pause(){this.paused=!0},resume(){this.paused=!1}});Ua(e=>{if(!Nu)return;let t=a.value;if(!n.trapped)return;function r(e){if(c.paused||!t)return;let n=e.target;t.contains(n)?o.value=n:mf(o.value,{select:!0})}function i(e){if(c.paused||!t)return;let n=e.relatedTarget;n!==null&&(t.contains(n)||mf(o.value,{select:!0}))}function s(e){t.contains(o.value)||mf(t)}document.addEventListener(`focusin`,r),document.addEventListener(`focusout`,i);let l=new MutationObserver(s);t&&l.observe(t,{childList:!0,subtree:!0}),e(()=>{document.removeEventListener(`focusin`,r),document.removeEventListener(`focusout`,i),l.disconnect()})}),Ua(async e=>{let t=a.value;if(await $n(),!t)return;s.add(c);let n=xu();if(!t.contains(n)){let e=new CustomEvent(af,sf);t.addEventListener(af,e=>r(`mountAutoFocus`,e)),t.dispatchEvent(e),e.defaultPrevented||(cf(rf(uf(t)),{select:!0}),xu()===n&&mf(t))}e(()=>{t.removeEventListener(af,e=>r(`mountAutoFocus`,e));let e=new CustomEvent(of,sf),i=e=>{r(`unmountAutoFocus`,e)};t.addEventListener(of,i),t.dispatchEvent(e),setTimeout(()=>{e.defaultPrevented||mf(n??document.body,{select:!0}),t.removeEventListener(of,i),s.remove(c)},0)})});function l(e){if(!n.loop&&!n.trapped||c.paused)return;let t=e.key===`Tab`&&!e.altKey&&!e.ctrlKey&&!e.metaKey,r=xu();if(t&&r){let t=e.currentTarget,[i,a]=lf(t);i&&a?!e.shiftKey&&r===a?(e.preventDefault(),n.loop&&mf(i,{select:!0})):e.shiftKey&&r===i&&(e.preventDefault(),n.loop&&mf(a,{select:!0})):r===t&&e.preventDefault()}}return(e,t)=>(z(),V(N(Vd),{ref_key:`currentRef`,ref:i,tabindex:`-1`,"as-child":e.asChild,as:e.as,onKeydown:l},{default:P(()=>[I(e.$slots,`default`)]),_:3},8,[`as-child`,`as`]))}}),gf=`menu.itemSelect`,_f=[`Enter`,` `],vf=[`ArrowDown`,`PageUp`,`Home`],yf=[`ArrowUp`,`PageDown`,`End`],bf=[...vf,...yf];[..._f],[..._f];function xf(e){return e?`open`:`closed`}function Sf(e){let t=xu();for(let n of e)if(n===t||(n.focus(),xu()!==t))return}function Cf(e,t){let{x:n,y:r}=e,i=!1;for(let e=0,a=t.length-1;e<t.length;a=e++){let o=t[e].x,s=t[e].y,c=t[a].x,l=t[a].y;s>r!=l>r&&n<(c-o)*(r-s)/(l-s)+o&&(i=!i)}return i}function wf(e,t){return t?Cf({x:e.clientX,y:e.clientY},t):!1}function Tf(e){return e.pointerType===`mouse`}var Ef=F({__name:`DialogContentImpl`,props:{forceMount:{type:Boolean,required:!1},trapFocus:{type:Boolean,required:!1},disableOutsidePointerEvents:{type:Boolean,required:!1},asChild:{type:Boolean,required:!1},as:{type:null,required:!1}},emits:[`escapeKeyDown`,`pointerDownOutside`,`focusOutside`,`interactOutside`,`openAutoFocus`,`closeAutoFocus`],setup(e,{emit:t}){let n=e,r=t,i=Ud(),{forwardRef:a,currentElement:o}=q();return i.titleId||=Ad(void 0,`reka-dialog-title`),i.descriptionId||=Ad(void 0,`reka-dialog-description`),Mi(()=>{i.contentElement=o,xu()!==document.body&&(i.triggerElement.value=xu())}),(e,t)=>(z(),V(N(hf),{"as-child":``,loop:``,trapped:n.trapFocus,onMountAutoFocus:t[5]||=e=>r(`openAutoFocus`,e),onUnmountAutoFocus:t[6]||=e=>r(`closeAutoFocus`,e)},{default:P(()=>[U(N($d),W({id:N(i).contentId,ref:N(a),as:e.as,"as-child":e.asChild,"disable-outside-pointer-events":e.disableOutsidePointerEvents,role:`dialog`,"aria-describedby":N(i).descriptionId,"aria-labelledby":N(i).titleId,"data-state":N(xf)(N(i).open.value)},e.$attrs,{onDismiss:t[0]||=e=>N(i).onOpenChange(!1),onEscapeKeyDown:t[1]||=e=>r(`escapeKeyDown`,e),onFocusOutside:t[2]||=e=>r(`focusOutside`,e),onInteractOutside:t[3]||=e=>r(`interactOutside`,e),onPointerDownOutside:t[4]||=e=>r(`pointerDownOutside`,e)}),{default:P(()=>[I(e.$slots,`default`)]),_:3},16,[`id`,`as`,`as-child`,`disable-outside-pointer-events`,`aria-describedby`,`aria-labelledby`,`data-state`])]),_:3},8,[`trapped`]))}}),Df=F({__name:`DialogContentModal`,props:{forceMount:{type:Boolean,required:!1},trapFocus:{type:Boolean,required:!1},disableOutsidePointerEvents:{type:Boolean,required:!1},asChild:{type:Boolean,required:!1},as:{type:null,required:!1}},emits:[`escapeKeyDown`,`pointerDownOutside`,`focusOutside`,`interactOutside`,`openAutoFocus`,`closeAutoFocus`],setup(e,{emit:t}){let n=e,r=t,i=Ud(),a=pd(r),{forwardRef:o,currentElement:s}=q();return Od(s),(e,t)=>(z(),V(Ef,W({...n,...N(a)},{ref:N(o),"trap-focus":N(i).open.value,"disable-outside-pointer-events":!0,onCloseAutoFocus:t[0]||=e=>{e.defaultPrevented||(e.preventDefault(),N(i).triggerElement.value?.focus())},onPointerDownOutside:t[1]||=e=>{let t=e.detail.originalEvent,n=t.button===0&&t.ctrlKey===!0;(t.button===2||n)&&e.preventDefault()},onFocusOutside:t[2]||=e=>{e.preventDefault()}}),{default:P(()=>[I(e.$slots,`default`)]),_:3},16,[`trap-focus`]))}}),Of=F({__name:`DialogContentNonModal`,props:{forceMount:{type:Boolean,required:!1},trapFocus:{type:Boolean,required:!1},disableOutsidePointerEvents:{type:Boolean,required:!1},asChild:{type:Boolean,required:!1},as:{type:null,required:!1}},emits:[`escapeKeyDown`,`pointerDownOutside`,`focusOutside`,`interactOutside`,`openAutoFocus`,`closeAutoFocus`],setup(e,{emit:t}){let n=e,r=pd(t);q();let i=Ud(),a=M(!1),o=M(!1);return(e,t)=>(z(),V(Ef,W({...n,...N(r)},{"trap-focus":!1,"disable-outside-pointer-events":!1,onCloseAutoFocus:t[0]||=e=>{e.defaultPrevented||(a.value||N(i).triggerElement.value?.focus(),e.preventDefault()),a.value=!1,o.value=!1},onInteractOutside:t[1]||=e=>{e.defaultPrevented||(a.value=!0,e.detail.originalEvent.type===`pointerdown`&&(o.value=!0));let t=e.target;N(i).triggerElement.value?.contains(t)&&e.preventDefault(),e.detail.originalEvent.type===`focusin`&&o.value&&e.preventDefault()}}),{default:P(()=>[I(e.$slots,`default`)]),_:3},16))}}),kf=F({__name:`DialogContent`,props:{forceMount:{type:Boolean,required:!1},disableOutsidePointerEvents:{type:Boolean,required:!1},asChild:{type:Boolean,required:!1},as:{type:null,required:!1}},emits:[`escapeKeyDown`,`pointerDownOutside`,`focusOutside`,`interactOutside`,`openAutoFocus`,`closeAutoFocus`],setup(e,{emit:t}){let n=e,r=t,i=Ud(),a=pd(r),{forwardRef:o}=q();return(e,t)=>(z(),V(N(Rd),{present:e.forceMount||N(i).open.value},{default:P(()=>[N(i).modal.value?(z(),V(Df,W({key:0,ref:N(o)},{...n,...N(a),...e.$attrs}),{default:P(()=>[I(e.$slots,`default`)]),_:3},16)):(z(),V(Of,W({key:1,ref:N(o)},{...n,...N(a),...e.$attrs}),{default:P(()=>[I(e.$slots,`default`)]),_:3},16))]),_:3},8,[`present`]))}}),Af=F({__name:`DialogDescription`,props:{asChild:{type:Boolean,required:!1},as:{type:null,required:!1,default:`p`}},setup(e){let t=e;q();let n=Ud();return(e,r)=>(z(),V(N(Vd),W(t,{id:N(n).descriptionId}),{default:P(()=>[I(e.$slots,`default`)]),_:3},16,[`id`]))}}),jf=F({__name:`DialogOverlayImpl`,props:{asChild:{type:Boolean,required:!1},as:{type:null,required:!1}},setup(e){let t=Ud();return ld(!0),q(),(e,n)=>(z(),V(N(Vd),{as:e.as,"as-child":e.asChild,"data-state":N(t).open.value?`open`:`closed`,style:{"pointer-events":`auto`}},{default:P(()=>[I(e.$slots,`default`)]),_:3},8,[`as`,`as-child`,`data-state`]))}}),Mf=F({__name:`DialogOverlay`,props:{forceMount:{type:Boolean,required:!1},asChild:{type:Boolean,required:!1},as:{type:null,required:!1}},setup(e){let t=Ud(),{forwardRef:n}=q();return(e,r)=>N(t)?.modal.value?(z(),V(N(Rd),{key:0,present:e.forceMount||N(t).open.value},{default:P(()=>[U(jf,W(e.$attrs,{ref:N(n),as:e.as,"as-child":e.asChild}),{default:P(()=>[I(e.$slots,`default`)]),_:3},16,[`as`,`as-child`])]),_:3},8,[`present`])):gs(`v-if`,!0)}}),Nf=F({__name:`Teleport`,props:{to:{type:null,required:!1,default:`body`},disabled:{type:Boolean,required:!1},defer:{type:Boolean,required:!1},forceMount:{type:Boolean,required:!1}},setup(e){let t=Zu();return(e,n)=>N(t)||e.forceMount?(z(),V(kr,{key:0,to:e.to,disabled:e.disabled,defer:e.defer},[I(e.$slots,`default`)],8,[`to`,`disabled`,`defer`])):gs(`v-if`,!0)}}),Pf=F({__name:`DialogPortal`,props:{to:{type:null,required:!1},disabled:{type:Boolean,required:!1},defer:{type:Boolean,required:!1},forceMount:{type:Boolean,required:!1}},setup(e){let t=e;return(e,n)=>(z(),V(N(Nf),ye(fs(t)),{default:P(()=>[I(e.$slots,`default`)]),_:3},16))}}),Ff=F({__name:`DialogTitle`,props:{asChild:{type:Boolean,required:!1},as:{type:null,required:!1,default:`h2`}},setup(e){let t=e,n=Ud();return q(),(e,r)=>(z(),V(N(Vd),W(t,{id:N(n).titleId}),{default:P(()=>[I(e.$slots,`default`)]),_:3},16,[`id`]))}}),If=F({__name:`DialogTrigger`,props:{asChild:{type:Boolean,required:!1},as:{type:null,required:!1,default:`button`}},setup(e){let t=e,n=Ud(),{forwardRef:r,currentElement:i}=q();return n.contentId||=Ad(void 0,`reka-dialog-content`),Mi(()=>{n.triggerElement.value=i.value}),(e,i)=>(z(),V(N(Vd),W(t,{ref:N(r),type:e.as===`button`?`button`:void 0,"aria-haspopup":`dialog`,"aria-expanded":N(n).open.value||!1,"aria-controls":N(n).open.value?N(n).contentId:void 0,"data-state":N(n).open.value?`open`:`closed`,onClick:N(n).onOpenToggle}),{default:P(()=>[I(e.$slots,`default`)]),_:3},16,[`type`,`aria-expanded`,`aria-controls`,`data-state`,`onClick`]))}}),Lf=`data-reka-collection-item`;function Rf(e={}){let{key:t=``,isProvider:n=!1}=e,r=`${t}CollectionProvider`,i;if(n){let e=M(new Map);i={collectionRef:M(),itemMap:e},Ra(r,i)}else i=za(r);let a=(e=!1)=>{let t=i.collectionRef.value;if(!t)return[];let n=Array.from(t.querySelectorAll(`[${Lf}]`)),r=Array.from(i.itemMap.value.values()).sort((e,t)=>n.indexOf(e.ref)-n.indexOf(t.ref));return e?r:r.filter(e=>e.ref.dataset.disabled!==``)},o=F({name:`CollectionSlot`,setup(e,{slots:t}){let{primitiveElement:n,currentElement:r}=Hd();return L(r,()=>{i.collectionRef.value=r.value}),()=>K(zd,{ref:n},t)}}),s=F({name:`CollectionItem`,inheritAttrs:!1,props:{value:{validator:()=>!0}},setup(e,{slots:t,attrs:n}){let{primitiveElement:r,currentElement:a}=Hd();return Ua(t=>{if(a.value){let n=sn(a.value);i.itemMap.value.set(n,{ref:a.value,value:e.value}),t(()=>i.itemMap.value.delete(n))}}),()=>K(zd,{...n,[Lf]:``,ref:r},t)}});return{getItems:a,reactiveItems:G(()=>Array.from(i.itemMap.value.values())),itemMapSize:G(()=>i.itemMap.value.size),CollectionSlot:o,CollectionItem:s}}var zf=`rovingFocusGroup.onEntryFocus`,Bf={bubbles:!1,cancelable:!0};function Vf(e,t=!1){let n=xu();for(let r of e)if(r===n||(r.focus({preventScroll:t}),xu()!==n))return}var[Hf,Uf]=bu(`RovingFocusGroup`),Wf=F({__name:`RovingFocusGroup`,props:{orientation:{type:String,required:!1,default:void 0},dir:{type:String,required:!1},loop:{type:Boolean,required:!1,default:!1},currentTabStopId:{type:[String,null],required:!1},defaultCurrentTabStopId:{type:String,required:!1},preventScrollOnEntryFocus:{type:Boolean,required:!1,default:!1},asChild:{type:Boolean,required:!1},as:{type:null,required:!1}},emits:[`entryFocus`,`update:currentTabStopId`],setup(e,{expose:t,emit:n}){let r=e,i=n,{loop:a,orientation:o,dir:s}=bn(r),c=fd(s),l=rd(r,`currentTabStopId`,i,{defaultValue:r.defaultCurrentTabStopId,passive:r.currentTabStopId===void 0}),u=M(!1),d=M(!1),f=M(0),{getItems:p,CollectionSlot:m}=Rf({isProvider:!0});function h(e){let t=!d.value;if(e.currentTarget&&e.target===e.currentTarget&&t&&!u.value){let t=new CustomEvent(zf,Bf);if(e.currentTarget.dispatchEvent(t),i(`entryFocus`,t),!t.defaultPrevented){let e=p().map(e=>e.ref).filter(e=>e.dataset.disabled!==``);Vf([e.find(e=>e.getAttribute(`data-active`)===``),e.find(e=>e.getAttribute(`data-highlighted`)===``),e.find(e=>e.id===l.value),...e].filter(Boolean),r.preventScrollOnEntryFocus)}}d.value=!1}function g(){setTimeout(()=>{d.value=!1},1)}return t({getItems:p}),Uf({loop:a,dir:c,orientation:o,currentTabStopId:l,onItemFocus:e=>{l.value=e},onItemShiftTab:()=>{u.value=!0},onFocusableItemAdd:()=>{f.value++},onFocusableItemRemove:()=>{f.value--}}),(e,t)=>(z(),V(N(m),null,{default:P(()=>[U(N(Vd),{tabindex:u.value||f.value===0?-1:0,"data-orientation":N(o),as:e.as,"as-child":e.asChild,dir:N(c),style:{outline:`none`},onMousedown:t[0]||=e=>d.value=!0,onMouseup:g,onFocus:h,onBlur:t[1]||=e=>u.value=!1},{default:P(()=>[I(e.$slots,`default`)]),_:3},8,[`tabindex`,`data-orientation`,`as`,`as-child`,`dir`])]),_:3}))}}),[Gf,Kf]=bu(`PopperRoot`),qf=F({inheritAttrs:!1,__name:`PopperRoot`,setup(e){let t=M();return Kf({anchor:t,onAnchorChange:e=>t.value=e}),(e,t)=>I(e.$slots,`default`)}}),Jf=F({__name:`PopperAnchor`,props:{reference:{type:null,required:!1},asChild:{type:Boolean,required:!1},as:{type:null,required:!1}},setup(e){let t=e,{forwardRef:n,currentElement:r}=q(),i=Gf();return Wa(()=>{i.onAnchorChange(t.reference??r.value)}),(e,t)=>(z(),V(N(Vd),{ref:N(n),as:e.as,"as-child":e.asChild},{default:P(()=>[I(e.$slots,`default`)]),_:3},8,[`as`,`as-child`]))}});function Yf(e){return e!==null}function Xf(e){return{name:`transformOrigin`,options:e,fn(t){let{placement:n,rects:r,middlewareData:i}=t,a=i.arrow?.centerOffset!==0,o=a?0:e.arrowWidth,s=a?0:e.arrowHeight,[c,l]=Zf(n),u={start:`0%`,center:`50%`,end:`100%`}[l],d=(i.arrow?.x??0)+o/2,f=(i.arrow?.y??0)+s/2,p=``,m=``;return c===`bottom`?(p=a?u:`${d}px`,m=`${-s}px`):c===`top`?(p=a?u:`${d}px`,m=`${r.floating.height+s}px`):c===`right`?(p=`${-s}px`,m=a?u:`${f}px`):c===`left`&&(p=`${r.floating.width+s}px`,m=a?u:`${f}px`),{data:{x:p,y:m}}}}}function Zf(e){let[t,n=`center`]=e.split(`-`);return[t,n]}var Qf=[`top`,`right`,`bottom`,`left`],$f=Math.min,ep=Math.max,tp=Math.round,np=Math.floor,rp=e=>({x:e,y:e}),ip={left:`right`,right:`left`,bottom:`top`,top:`bottom`},ap={start:`end`,end:`start`};function op(e,t,n){return ep(e,$f(t,n))}function sp(e,t){return typeof e==`function`?e(t):e}function cp(e){return e.split(`-`)[0]}function lp(e){return e.split(`-`)[1]}function up(e){return e===`x`?`y`:`x`}function dp(e){return e===`y`?`height`:`width`}var fp=new Set([`top`,`bottom`]);function pp(e){return fp.has(cp(e))?`y`:`x`}function mp(e){return up(pp(e))}function hp(e,t,n){n===void 0&&(n=!1);let r=lp(e),i=mp(e),a=dp(i),o=i===`x`?r===(n?`end`:`start`)?`right`:`left`:r===`start`?`bottom`:`top`;return t.reference[a]>t.floating[a]&&(o=wp(o)),[o,wp(o)]}function gp(e){let t=wp(e);return[_p(e),t,_p(t)]}function _p(e){return e.replace(/start|end/g,e=>ap[e])}var vp=[`left`,`right`],yp=[`right`,`left`],bp=[`top`,`bottom`],xp=[`bottom`,`top`];function Sp(e,t,n){switch(e){case`top`:case`bottom`:return n?t?yp:vp:t?vp:yp;case`left`:case`right`:return t?bp:xp;default:return[]}}function Cp(e,t,n,r){let i=lp(e),a=Sp(cp(e),n===`start`,r);return i&&(a=a.map(e=>e+`-`+i),t&&(a=a.concat(a.map(_p)))),a}function wp(e){return e.replace(/left|right|bottom|top/g,e=>ip[e])}function Tp(e){return{top:0,right:0,bottom:0,left:0,...e}}function Ep(e){return typeof e==`number`?{top:e,right:e,bottom:e,left:e}:Tp(e)}function Dp(e){let{x:t,y:n,width:r,height:i}=e;return{width:r,height:i,top:n,left:t,right:t+r,bottom:n+i,x:t,y:n}}function Op(e,t,n){let{reference:r,floating:i}=e,a=pp(t),o=mp(t),s=dp(o),c=cp(t),l=a===`y`,u=r.x+r.width/2-i.width/2,d=r.y+r.height/2-i.height/2,f=r[s]/2-i[s]/2,p;switch(c){case`top`:p={x:u,y:r.y-i.height};break;case`bottom`:p={x:u,y:r.y+r.height};break;case`right`:p={x:r.x+r.width,y:d};break;case`left`:p={x:r.x-i.width,y:d};break;default:p={x:r.x,y:r.y}}switch(lp(t)){case`start`:p[o]-=f*(n&&l?-1:1);break;case`end`:p[o]+=f*(n&&l?-1:1);break}return p}var kp=async(e,t,n)=>{let{placement:r=`bottom`,strategy:i=`absolute`,middleware:a=[],platform:o}=n,s=a.filter(Boolean),c=await(o.isRTL==null?void 0:o.isRTL(t)),l=await o.getElementRects({reference:e,floating:t,strategy:i}),{x:u,y:d}=Op(l,r,c),f=r,p={},m=0;for(let n=0;n<s.length;n++){let{name:a,fn:h}=s[n],{x:g,y:_,data:v,reset:y}=await h({x:u,y:d,initialPlacement:r,placement:f,strategy:i,middlewareData:p,rects:l,platform:o,elements:{reference:e,floating:t}});u=g??u,d=_??d,p={...p,[a]:{...p[a],...v}},y&&m<=50&&(m++,typeof y==`object`&&(y.placement&&(f=y.placement),y.rects&&(l=y.rects===!0?await o.getElementRects({reference:e,floating:t,strategy:i}):y.rects),{x:u,y:d}=Op(l,f,c)),n=-1)}return{x:u,y:d,placement:f,strategy:i,middlewareData:p}};async function Ap(e,t){t===void 0&&(t={});let{x:n,y:r,platform:i,rects:a,elements:o,strategy:s}=e,{boundary:c=`clippingAncestors`,rootBoundary:l=`viewport`,elementContext:u=`floating`,altBoundary:d=!1,padding:f=0}=sp(t,e),p=Ep(f),m=o[d?u===`floating`?`reference`:`floating`:u],h=Dp(await i.getClippingRect({element:await(i.isElement==null?void 0:i.isElement(m))??!0?m:m.contextElement||await(i.getDocumentElement==null?void 0:i.getDocumentElement(o.floating)),boundary:c,rootBoundary:l,strategy:s})),g=u===`floating`?{x:n,y:r,width:a.floating.width,height:a.floating.height}:a.reference,_=await(i.getOffsetParent==null?void 0:i.getOffsetParent(o.floating)),v=await(i.isElement==null?void 0:i.isElement(_))&&await(i.getScale==null?void 0:i.getScale(_))||{x:1,y:1},y=Dp(i.convertOffsetParentRelativeRectToViewportRelativeRect?await i.convertOffsetParentRelativeRectToViewportRelativeRect({elements:o,rect:g,offsetParent:_,strategy:s}):g);return{top:(h.top-y.top+p.top)/v.y,bottom:(y.bottom-h.bottom+p.bottom)/v.y,left:(h.left-y.left+p.left)/v.x,right:(y.right-h.right+p.right)/v.x}}var jp=e=>({name:`arrow`,options:e,async fn(t){let{x:n,y:r,placement:i,rects:a,platform:o,elements:s,middlewareData:c}=t,{element:l,padding:u=0}=sp(e,t)||{};if(l==null)return{};let d=Ep(u),f={x:n,y:r},p=mp(i),m=dp(p),h=await o.getDimensions(l),g=p===`y`,_=g?`top`:`left`,v=g?`bottom`:`right`,y=g?`clientHeight`:`clientWidth`,b=a.reference[m]+a.reference[p]-f[p]-a.floating[m],x=f[p]-a.reference[p],S=await(o.getOffsetParent==null?void 0:o.getOffsetParent(l)),C=S?S[y]:0;(!C||!await(o.isElement==null?void 0:o.isElement(S)))&&(C=s.floating[y]||a.floating[m]);let w=b/2-x/2,T=C/2-h[m]/2-1,ee=$f(d[_],T),E=$f(d[v],T),te=ee,ne=C-h[m]-E,re=C/2-h[m]/2+w,D=op(te,re,ne),ie=!c.arrow&&lp(i)!=null&&re!==D&&a.reference[m]/2-(re<te?ee:E)-h[m]/2<0,O=ie?re<te?re-te:re-ne:0;return{[p]:f[p]+O,data:{[p]:D,centerOffset:re-D-O,...ie&&{alignmentOffset:O}},reset:ie}}}),Mp=function(e){return e===void 0&&(e={}),{name:`flip`,options:e,async fn(t){var n;let{placement:r,middlewareData:i,rects:a,initialPlacement:o,platform:s,elements:c}=t,{mainAxis:l=!0,crossAxis:u=!0,fallbackPlacements:d,fallbackStrategy:f=`bestFit`,fallbackAxisSideDirection:p=`none`,flipAlignment:m=!0,...h}=sp(e,t);if((n=i.arrow)!=null&&n.alignmentOffset)return{};let g=cp(r),_=pp(o),v=cp(o)===o,y=await(s.isRTL==null?void 0:s.isRTL(c.floating)),b=d||(v||!m?[wp(o)]:gp(o)),x=p!==`none`;!d&&x&&b.push(...Cp(o,m,p,y));let S=[o,...b],C=await Ap(t,h),w=[],T=i.flip?.overflows||[];if(l&&w.push(C[g]),u){let e=hp(r,a,y);w.push(C[e[0]],C[e[1]])}if(T=[...T,{placement:r,overflows:w}],!w.every(e=>e<=0)){let e=(i.flip?.index||0)+1,t=S[e];if(t&&(!(u===`alignment`&&_!==pp(t))||T.every(e=>pp(e.placement)===_?e.overflows[0]>0:!0)))return{data:{index:e,overflows:T},reset:{placement:t}};let n=T.filter(e=>e.overflows[0]<=0).sort((e,t)=>e.overflows[1]-t.overflows[1])[0]?.placement;if(!n)switch(f){case`bestFit`:{let e=T.filter(e=>{if(x){let t=pp(e.placement);return t===_||t===`y`}return!0}).map(e=>[e.placement,e.overflows.filter(e=>e>0).reduce((e,t)=>e+t,0)]).sort((e,t)=>e[1]-t[1])[0]?.[0];e&&(n=e);break}case`initialPlacement`:n=o;break}if(r!==n)return{reset:{placement:n}}}return{}}}};function Np(e,t){return{top:e.top-t.height,right:e.right-t.width,bottom:e.bottom-t.height,left:e.left-t.width}}function Pp(e){return Qf.some(t=>e[t]>=0)}var Fp=function(e){return e===void 0&&(e={}),{name:`hide`,options:e,async fn(t){let{rects:n}=t,{strategy:r=`referenceHidden`,...i}=sp(e,t);switch(r){case`referenceHidden`:{let e=Np(await Ap(t,{...i,elementContext:`reference`}),n.reference);return{data:{referenceHiddenOffsets:e,referenceHidden:Pp(e)}}}case`escaped`:{let e=Np(await Ap(t,{...i,altBoundary:!0}),n.floating);return{data:{escapedOffsets:e,escaped:Pp(e)}}}default:return{}}}}},Ip=new Set([`left`,`top`]);async function Lp(e,t){let{placement:n,platform:r,elements:i}=e,a=await(r.isRTL==null?void 0:r.isRTL(i.floating)),o=cp(n),s=lp(n),c=pp(n)===`y`,l=Ip.has(o)?-1:1,u=a&&c?-1:1,d=sp(t,e),{mainAxis:f,crossAxis:p,alignmentAxis:m}=typeof d==`number`?{mainAxis:d,crossAxis:0,alignmentAxis:null}:{mainAxis:d.mainAxis||0,crossAxis:d.crossAxis||0,alignmentAxis:d.alignmentAxis};return s&&typeof m==`number`&&(p=s===`end`?m*-1:m),c?{x:p*u,y:f*l}:{x:f*l,y:p*u}}var Rp=function(e){return e===void 0&&(e=0),{name:`offset`,options:e,async fn(t){var n;let{x:r,y:i,placement:a,middlewareData:o}=t,s=await Lp(t,e);return a===o.offset?.placement&&(n=o.arrow)!=null&&n.alignmentOffset?{}:{x:r+s.x,y:i+s.y,data:{...s,placement:a}}}}},zp=function(e){return e===void 0&&(e={}),{name:`shift`,options:e,async fn(t){let{x:n,y:r,placement:i}=t,{mainAxis:a=!0,crossAxis:o=!1,limiter:s={fn:e=>{let{x:t,y:n}=e;return{x:t,y:n}}},...c}=sp(e,t),l={x:n,y:r},u=await Ap(t,c),d=pp(cp(i)),f=up(d),p=l[f],m=l[d];if(a){let e=f===`y`?`top`:`left`,t=f===`y`?`bottom`:`right`,n=p+u[e],r=p-u[t];p=op(n,p,r)}if(o){let e=d===`y`?`top`:`left`,t=d===`y`?`bottom`:`right`,n=m+u[e],r=m-u[t];m=op(n,m,r)}let h=s.fn({...t,[f]:p,[d]:m});return{...h,data:{x:h.x-n,y:h.y-r,enabled:{[f]:a,[d]:o}}}}}},Bp=function(e){return e===void 0&&(e={}),{options:e,fn(t){let{x:n,y:r,placement:i,rects:a,middlewareData:o}=t,{offset:s=0,mainAxis:c=!0,crossAxis:l=!0}=sp(e,t),u={x:n,y:r},d=pp(i),f=up(d),p=u[f],m=u[d],h=sp(s,t),g=typeof h==`number`?{mainAxis:h,crossAxis:0}:{mainAxis:0,crossAxis:0,...h};if(c){let e=f===`y`?`height`:`width`,t=a.reference[f]-a.floating[e]+g.mainAxis,n=a.reference[f]+a.reference[e]-g.mainAxis;p<t?p=t:p>n&&(p=n)}if(l){let e=f===`y`?`width`:`height`,t=Ip.has(cp(i)),n=a.reference[d]-a.floating[e]+(t&&o.offset?.[d]||0)+(t?0:g.crossAxis),r=a.reference[d]+a.reference[e]+(t?0:o.offset?.[d]||0)-(t?g.crossAxis:0);m<n?m=n:m>r&&(m=r)}return{[f]:p,[d]:m}}}},Vp=function(e){return e===void 0&&(e={}),{name:`size`,options:e,async fn(t){var n,r;let{placement:i,rects:a,platform:o,elements:s}=t,{apply:c=()=>{},...l}=sp(e,t),u=await Ap(t,l),d=cp(i),f=lp(i),p=pp(i)===`y`,{width:m,height:h}=a.floating,g,_;d===`top`||d===`bottom`?(g=d,_=f===(await(o.isRTL==null?void 0:o.isRTL(s.floating))?`start`:`end`)?`left`:`right`):(_=d,g=f===`end`?`top`:`bottom`);let v=h-u.top-u.bottom,y=m-u.left-u.right,b=$f(h-u[g],v),x=$f(m-u[_],y),S=!t.middlewareData.shift,C=b,w=x;if((n=t.middlewareData.shift)!=null&&n.enabled.x&&(w=y),(r=t.middlewareData.shift)!=null&&r.enabled.y&&(C=v),S&&!f){let e=ep(u.left,0),t=ep(u.right,0),n=ep(u.top,0),r=ep(u.bottom,0);p?w=m-2*(e!==0||t!==0?e+t:ep(u.left,u.right)):C=h-2*(n!==0||r!==0?n+r:ep(u.top,u.bottom))}await c({...t,availableWidth:w,availableHeight:C});let T=await o.getDimensions(s.floating);return m!==T.width||h!==T.height?{reset:{rects:!0}}:{}}}};function Hp(){return typeof window<`u`}function Up(e){return Kp(e)?(e.nodeName||``).toLowerCase():`#document`}function Wp(e){var t;return(e==null||(t=e.ownerDocument)==null?void 0:t.defaultView)||window}function Gp(e){return((Kp(e)?e.ownerDocument:e.document)||window.document)?.documentElement}function Kp(e){return Hp()?e instanceof Node||e instanceof Wp(e).Node:!1}function qp(e){return Hp()?e instanceof Element||e instanceof Wp(e).Element:!1}function Jp(e){return Hp()?e instanceof HTMLElement||e instanceof Wp(e).HTMLElement:!1}function Yp(e){return!Hp()||typeof ShadowRoot>`u`?!1:e instanceof ShadowRoot||e instanceof Wp(e).ShadowRoot}var Xp=new Set([`inline`,`contents`]);function Zp(e){let{overflow:t,overflowX:n,overflowY:r,display:i}=um(e);return/auto|scroll|overlay|hidden|clip/.test(t+r+n)&&!Xp.has(i)}var Qp=new Set([`table`,`td`,`th`]);function $p(e){return Qp.has(Up(e))}var em=[`:popover-open`,`:modal`];function tm(e){return em.some(t=>{try{return e.matches(t)}catch{return!1}})}var nm=[`transform`,`translate`,`scale`,`rotate`,`perspective`],rm=[`transform`,`translate`,`scale`,`rotate`,`perspective`,`filter`],im=[`paint`,`layout`,`strict`,`content`];function am(e){let t=sm(),n=qp(e)?um(e):e;return nm.some(e=>n[e]?n[e]!==`none`:!1)||(n.containerType?n.containerType!==`normal`:!1)||!t&&(n.backdropFilter?n.backdropFilter!==`none`:!1)||!t&&(n.filter?n.filter!==`none`:!1)||rm.some(e=>(n.willChange||``).includes(e))||im.some(e=>(n.contain||``).includes(e))}function om(e){let t=fm(e);for(;Jp(t)&&!lm(t);){if(am(t))return t;if(tm(t))return null;t=fm(t)}return null}function sm(){return typeof CSS>`u`||!CSS.supports?!1:CSS.supports(`-webkit-backdrop-filter`,`none`)}var cm=new Set([`html`,`body`,`#document`]);function lm(e){return cm.has(Up(e))}function um(e){return Wp(e).getComputedStyle(e)}function dm(e){return qp(e)?{scrollLeft:e.scrollLeft,scrollTop:e.scrollTop}:{scrollLeft:e.scrollX,scrollTop:e.scrollY}}function fm(e){if(Up(e)===`html`)return e;let t=e.assignedSlot||e.parentNode||Yp(e)&&e.host||Gp(e);return Yp(t)?t.host:t}function pm(e){let t=fm(e);return lm(t)?e.ownerDocument?e.ownerDocument.body:e.body:Jp(t)&&Zp(t)?t:pm(t)}function mm(e,t,n){t===void 0&&(t=[]),n===void 0&&(n=!0);let r=pm(e),i=r===e.ownerDocument?.body,a=Wp(r);if(i){let e=hm(a);return t.concat(a,a.visualViewport||[],Zp(r)?r:[],e&&n?mm(e):[])}return t.concat(r,mm(r,[],n))}function hm(e){return e.parent&&Object.getPrototypeOf(e.parent)?e.frameElement:null}function gm(e){let t=um(e),n=parseFloat(t.width)||0,r=parseFloat(t.height)||0,i=Jp(e),a=i?e.offsetWidth:n,o=i?e.offsetHeight:r,s=tp(n)!==a||tp(r)!==o;return s&&(n=a,r=o),{width:n,height:r,$:s}}function _m(e){return qp(e)?e:e.contextElement}function vm(e){let t=_m(e);if(!Jp(t))return rp(1);let n=t.getBoundingClientRect(),{width:r,height:i,$:a}=gm(t),o=(a?tp(n.width):n.width)/r,s=(a?tp(n.height):n.height)/i;return(!o||!Number.isFinite(o))&&(o=1),(!s||!Number.isFinite(s))&&(s=1),{x:o,y:s}}var ym=rp(0);function bm(e){let t=Wp(e);return!sm()||!t.visualViewport?ym:{x:t.visualViewport.offsetLeft,y:t.visualViewport.offsetTop}}function xm(e,t,n){return t===void 0&&(t=!1),!n||t&&n!==Wp(e)?!1:t}function Sm(e,t,n,r){t===void 0&&(t=!1),n===void 0&&(n=!1);let i=e.getBoundingClientRect(),a=_m(e),o=rp(1);t&&(r?qp(r)&&(o=vm(r)):o=vm(e));let s=xm(a,n,r)?bm(a):rp(0),c=(i.left+s.x)/o.x,l=(i.top+s.y)/o.y,u=i.width/o.x,d=i.height/o.y;if(a){let e=Wp(a),t=r&&qp(r)?Wp(r):r,n=e,i=hm(n);for(;i&&r&&t!==n;){let e=vm(i),t=i.getBoundingClientRect(),r=um(i),a=t.left+(i.clientLeft+parseFloat(r.paddingLeft))*e.x,o=t.top+(i.clientTop+parseFloat(r.paddingTop))*e.y;c*=e.x,l*=e.y,u*=e.x,d*=e.y,c+=a,l+=o,n=Wp(i),i=hm(n)}}return Dp({width:u,height:d,x:c,y:l})}function Cm(e,t){let n=dm(e).scrollLeft;return t?t.left+n:Sm(Gp(e)).left+n}function wm(e,t){let n=e.getBoundingClientRect();return{x:n.left+t.scrollLeft-Cm(e,n),y:n.top+t.scrollTop}}function Tm(e){let{elements:t,rect:n,offsetParent:r,strategy:i}=e,a=i===`fixed`,o=Gp(r),s=t?tm(t.floating):!1;if(r===o||s&&a)return n;let c={scrollLeft:0,scrollTop:0},l=rp(1),u=rp(0),d=Jp(r);if((d||!d&&!a)&&((Up(r)!==`body`||Zp(o))&&(c=dm(r)),Jp(r))){let e=Sm(r);l=vm(r),u.x=e.x+r.clientLeft,u.y=e.y+r.clientTop}let f=o&&!d&&!a?wm(o,c):rp(0);return{width:n.width*l.x,height:n.height*l.y,x:n.x*l.x-c.scrollLeft*l.x+u.x+f.x,y:n.y*l.y-c.scrollTop*l.y+u.y+f.y}}function Em(e){return Array.from(e.getClientRects())}function Dm(e){let t=Gp(e),n=dm(e),r=e.ownerDocument.body,i=ep(t.scrollWidth,t.clientWidth,r.scrollWidth,r.clientWidth),a=ep(t.scrollHeight,t.clientHeight,r.scrollHeight,r.clientHeight),o=-n.scrollLeft+Cm(e),s=-n.scrollTop;return um(r).direction===`rtl`&&(o+=ep(t.clientWidth,r.clientWidth)-i),{width:i,height:a,x:o,y:s}}var Om=25;function km(e,t){let n=Wp(e),r=Gp(e),i=n.visualViewport,a=r.clientWidth,o=r.clientHeight,s=0,c=0;if(i){a=i.width,o=i.height;let e=sm();(!e||e&&t===`fixed`)&&(s=i.offsetLeft,c=i.offsetTop)}let l=Cm(r);if(l<=0){let e=r.ownerDocument,t=e.body,n=getComputedStyle(t),i=e.compatMode===`CSS1Compat`&&parseFloat(n.marginLeft)+parseFloat(n.marginRight)||0,o=Math.abs(r.clientWidth-t.clientWidth-i);o<=Om&&(a-=o)}else l<=Om&&(a+=l);return{width:a,height:o,x:s,y:c}}var Am=new Set([`absolute`,`fixed`]);function jm(e,t){let n=Sm(e,!0,t===`fixed`),r=n.top+e.clientTop,i=n.left+e.clientLeft,a=Jp(e)?vm(e):rp(1);return{width:e.clientWidth*a.x,height:e.clientHeight*a.y,x:i*a.x,y:r*a.y}}function Mm(e,t,n){let r;if(t===`viewport`)r=km(e,n);else if(t===`document`)r=Dm(Gp(e));else if(qp(t))r=jm(t,n);else{let n=bm(e);r={x:t.x-n.x,y:t.y-n.y,width:t.width,height:t.height}}return Dp(r)}function Nm(e,t){let n=fm(e);return n===t||!qp(n)||lm(n)?!1:um(n).position===`fixed`||Nm(n,t)}function Pm(e,t){let n=t.get(e);if(n)return n;let r=mm(e,[],!1).filter(e=>qp(e)&&Up(e)!==`body`),i=null,a=um(e).position===`fixed`,o=a?fm(e):e;for(;qp(o)&&!lm(o);){let t=um(o),n=am(o);!n&&t.position===`fixed`&&(i=null),(a?!n&&!i:!n&&t.position===`static`&&i&&Am.has(i.position)||Zp(o)&&!n&&Nm(e,o))?r=r.filter(e=>e!==o):i=t,o=fm(o)}return t.set(e,r),r}function Fm(e){let{element:t,boundary:n,rootBoundary:r,strategy:i}=e,a=[...n===`clippingAncestors`?tm(t)?[]:Pm(t,this._c):[].concat(n),r],o=a[0],s=a.reduce((e,n)=>{let r=Mm(t,n,i);return e.top=ep(r.top,e.top),e.right=$f(r.right,e.right),e.bottom=$f(r.bottom,e.bottom),e.left=ep(r.left,e.left),e},Mm(t,o,i));return{width:s.right-s.left,height:s.bottom-s.top,x:s.left,y:s.top}}function Im(e){let{width:t,height:n}=gm(e);return{width:t,height:n}}function Lm(e,t,n){let r=Jp(t),i=Gp(t),a=n===`fixed`,o=Sm(e,!0,a,t),s={scrollLeft:0,scrollTop:0},c=rp(0);function l(){c.x=Cm(i)}if(r||!r&&!a)if((Up(t)!==`body`||Zp(i))&&(s=dm(t)),r){let e=Sm(t,!0,a,t);c.x=e.x+t.clientLeft,c.y=e.y+t.clientTop}else i&&l();a&&!r&&i&&l();let u=i&&!r&&!a?wm(i,s):rp(0);return{x:o.left+s.scrollLeft-c.x-u.x,y:o.top+s.scrollTop-c.y-u.y,width:o.width,height:o.height}}function Rm(e){return um(e).position===`static`}function zm(e,t){if(!Jp(e)||um(e).position===`fixed`)return null;if(t)return t(e);let n=e.offsetParent;return Gp(e)===n&&(n=n.ownerDocument.body),n}function Bm(e,t){let n=Wp(e);if(tm(e))return n;if(!Jp(e)){let t=fm(e);for(;t&&!lm(t);){if(qp(t)&&!Rm(t))return t;t=fm(t)}return n}let r=zm(e,t);for(;r&&$p(r)&&Rm(r);)r=zm(r,t);return r&&lm(r)&&Rm(r)&&!am(r)?n:r||om(e)||n}var Vm=async function(e){let t=this.getOffsetParent||Bm,n=this.getDimensions,r=await n(e.floating);return{reference:Lm(e.reference,await t(e.floating),e.strategy),floating:{x:0,y:0,width:r.width,height:r.height}}};function Hm(e){return um(e).direction===`rtl`}var Um={convertOffsetParentRelativeRectToViewportRelativeRect:Tm,getDocumentElement:Gp,getClippingRect:Fm,getOffsetParent:Bm,getElementRects:Vm,getClientRects:Em,getDimensions:Im,getScale:vm,isElement:qp,isRTL:Hm};function Wm(e,t){return e.x===t.x&&e.y===t.y&&e.width===t.width&&e.height===t.height}function Gm(e,t){let n=null,r,i=Gp(e);function a(){var e;clearTimeout(r),(e=n)==null||e.disconnect(),n=null}function o(s,c){s===void 0&&(s=!1),c===void 0&&(c=1),a();let l=e.getBoundingClientRect(),{left:u,top:d,width:f,height:p}=l;if(s||t(),!f||!p)return;let m=np(d),h=np(i.clientWidth-(u+f)),g=np(i.clientHeight-(d+p)),_=np(u),v={rootMargin:-m+`px `+-h+`px `+-g+`px `+-_+`px`,threshold:ep(0,$f(1,c))||1},y=!0;function b(t){let n=t[0].intersectionRatio;if(n!==c){if(!y)return o();n?o(!1,n):r=setTimeout(()=>{o(!1,1e-7)},1e3)}n===1&&!Wm(l,e.getBoundingClientRect())&&o(),y=!1}try{n=new IntersectionObserver(b,{...v,root:i.ownerDocument})}catch{n=new IntersectionObserver(b,v)}n.observe(e)}return o(!0),a}function Km(e,t,n,r){r===void 0&&(r={});let{ancestorScroll:i=!0,ancestorResize:a=!0,elementResize:o=typeof ResizeObserver==`function`,layoutShift:s=typeof IntersectionObserver==`function`,animationFrame:c=!1}=r,l=_m(e),u=i||a?[...l?mm(l):[],...mm(t)]:[];u.forEach(e=>{i&&e.addEventListener(`scroll`,n,{passive:!0}),a&&e.addEventListener(`resize`,n)});let d=l&&s?Gm(l,n):null,f=-1,p=null;o&&(p=new ResizeObserver(e=>{let[r]=e;r&&r.target===l&&p&&(p.unobserve(t),cancelAnimationFrame(f),f=requestAnimationFrame(()=>{var e;(e=p)==null||e.observe(t)})),n()}),l&&!c&&p.observe(l),p.observe(t));let m,h=c?Sm(e):null;c&&g();function g(){let t=Sm(e);h&&!Wm(h,t)&&n(),h=t,m=requestAnimationFrame(g)}return n(),()=>{var e;u.forEach(e=>{i&&e.removeEventListener(`scroll`,n),a&&e.removeEventListener(`resize`,n)}),d?.(),(e=p)==null||e.disconnect(),p=null,c&&cancelAnimationFrame(m)}}var qm=Rp,Jm=zp,Ym=Mp,Xm=Vp,Zm=Fp,Qm=jp,$m=Bp,eh=(e,t,n)=>{let r=new Map,i={platform:Um,...n},a={...i.platform,_c:r};return kp(e,t,{...i,platform:a})};function th(e){return typeof e==`object`&&!!e&&`$el`in e}function nh(e){if(th(e)){let t=e.$el;return Kp(t)&&Up(t)===`#comment`?null:t}return e}function rh(e){return typeof e==`function`?e():N(e)}function ih(e){return{name:`arrow`,options:e,fn(t){let n=nh(rh(e.element));return n==null?{}:Qm({element:n,padding:e.padding}).fn(t)}}}function ah(e){return typeof window>`u`?1:(e.ownerDocument.defaultView||window).devicePixelRatio||1}function oh(e,t){let n=ah(e);return Math.round(t*n)/n}function sh(e,t,n){n===void 0&&(n={});let r=n.whileElementsMounted,i=G(()=>rh(n.open)??!0),a=G(()=>rh(n.middleware)),o=G(()=>rh(n.placement)??`bottom`),s=G(()=>rh(n.strategy)??`absolute`),c=G(()=>rh(n.transform)??!0),l=G(()=>nh(e.value)),u=G(()=>nh(t.value)),d=M(0),f=M(0),p=M(s.value),m=M(o.value),h=dn({}),g=M(!1),_=G(()=>{let e={position:p.value,left:`0`,top:`0`};if(!u.value)return e;let t=oh(u.value,d.value),n=oh(u.value,f.value);return c.value?{...e,transform:`translate(`+t+`px, `+n+`px)`,...ah(u.value)>=1.5&&{willChange:`transform`}}:{position:p.value,left:t+`px`,top:n+`px`}}),v;function y(){if(l.value==null||u.value==null)return;let e=i.value;eh(l.value,u.value,{middleware:a.value,placement:o.value,strategy:s.value}).then(t=>{d.value=t.x,f.value=t.y,p.value=t.strategy,m.value=t.placement,h.value=t.middlewareData,g.value=e!==!1})}function b(){typeof v==`function`&&(v(),v=void 0)}function x(){if(b(),r===void 0){y();return}if(l.value!=null&&u.value!=null){v=r(l.value,u.value,y);return}}function S(){i.value||(g.value=!1)}return L([a,o,s,i],y,{flush:`sync`}),L([l,u],x,{flush:`sync`}),L(i,S,{flush:`sync`}),Pe()&&Fe(b),{x:en(d),y:en(f),strategy:en(p),placement:en(m),middlewareData:en(h),isPositioned:en(g),floatingStyles:_,update:y}}var ch={side:`bottom`,sideOffset:0,sideFlip:!0,align:`center`,alignOffset:0,alignFlip:!0,arrowPadding:0,avoidCollisions:!0,collisionBoundary:()=>[],collisionPadding:0,sticky:`partial`,hideWhenDetached:!1,positionStrategy:`fixed`,updatePositionStrategy:`optimized`,prioritizePosition:!1},[lh,uh]=bu(`PopperContent`),dh=F({inheritAttrs:!1,__name:`PopperContent`,props:ha({side:{type:null,required:!1},sideOffset:{type:Number,required:!1},sideFlip:{type:Boolean,required:!1},align:{type:null,required:!1},alignOffset:{type:Number,required:!1},alignFlip:{type:Boolean,required:!1},avoidCollisions:{type:Boolean,required:!1},collisionBoundary:{type:null,required:!1},collisionPadding:{type:[Number,Object],required:!1},arrowPadding:{type:Number,required:!1},sticky:{type:String,required:!1},hideWhenDetached:{type:Boolean,required:!1},positionStrategy:{type:String,required:!1},updatePositionStrategy:{type:String,required:!1},disableUpdateOnLayoutShift:{type:Boolean,required:!1},prioritizePosition:{type:Boolean,required:!1},reference:{type:null,required:!1},asChild:{type:Boolean,required:!1},as:{type:null,required:!1}},{...ch}),emits:[`placed`],setup(e,{emit:t}){let n=e,r=t,i=Gf(),{forwardRef:a,currentElement:o}=q(),s=M(),c=M(),{width:l,height:u}=jd(c),d=G(()=>n.side+(n.align===`center`?``:`-${n.align}`)),f=G(()=>typeof n.collisionPadding==`number`?n.collisionPadding:{top:0,right:0,bottom:0,left:0,...n.collisionPadding}),p=G(()=>Array.isArray(n.collisionBoundary)?n.collisionBoundary:[n.collisionBoundary]),m=G(()=>({padding:f.value,boundary:p.value.filter(Yf),altBoundary:p.value.length>0})),h=G(()=>({mainAxis:n.sideFlip,crossAxis:n.alignFlip})),g=ku(()=>[qm({mainAxis:n.sideOffset+u.value,alignmentAxis:n.alignOffset}),n.prioritizePosition&&n.avoidCollisions&&Ym({...m.value,...h.value}),n.avoidCollisions&&Jm({mainAxis:!0,crossAxis:!!n.prioritizePosition,limiter:n.sticky===`partial`?$m():void 0,...m.value}),!n.prioritizePosition&&n.avoidCollisions&&Ym({...m.value,...h.value}),Xm({...m.value,apply:({elements:e,rects:t,availableWidth:n,availableHeight:r})=>{let{width:i,height:a}=t.reference,o=e.floating.style;o.setProperty(`--reka-popper-available-width`,`${n}px`),o.setProperty(`--reka-popper-available-height`,`${r}px`),o.setProperty(`--reka-popper-anchor-width`,`${i}px`),o.setProperty(`--reka-popper-anchor-height`,`${a}px`)}}),c.value&&ih({element:c.value,padding:n.arrowPadding}),Xf({arrowWidth:l.value,arrowHeight:u.value}),n.hideWhenDetached&&Zm({strategy:`referenceHidden`,...m.value})]),{floatingStyles:_,placement:v,isPositioned:y,middlewareData:b,update:x}=sh(G(()=>n.reference??i.anchor.value),s,{strategy:n.positionStrategy,placement:d,whileElementsMounted:(...e)=>Km(...e,{layoutShift:!n.disableUpdateOnLayoutShift,animationFrame:n.updatePositionStrategy===`always`}),middleware:g}),S=G(()=>Zf(v.value)[0]),C=G(()=>Zf(v.value)[1]);Wa(()=>{y.value&&r(`placed`)});let w=G(()=>b.value.arrow?.centerOffset!==0),T=M(``);return Ua(()=>{o.value&&(T.value=window.getComputedStyle(o.value).zIndex)}),uh({placedSide:S,onArrowChange:e=>c.value=e,arrowX:G(()=>b.value.arrow?.x??0),arrowY:G(()=>b.value.arrow?.y??0),shouldHideArrow:w}),(e,t)=>(z(),B(`div`,{ref_key:`floatingRef`,ref:s,"data-reka-popper-content-wrapper":``,style:me({...N(_),transform:N(y)?N(_).transform:`translate(0, -200%)`,minWidth:`max-content`,zIndex:T.value,"--reka-popper-transform-origin":[N(b).transformOrigin?.x,N(b).transformOrigin?.y].join(` `),...N(b).hide?.referenceHidden&&{visibility:`hidden`,pointerEvents:`none`}})},[U(N(Vd),W({ref:N(a)},e.$attrs,{"as-child":n.asChild,as:e.as,"data-side":S.value,"data-align":C.value,style:{animation:N(y)?void 0:`none`}}),{default:P(()=>[I(e.$slots,`default`)]),_:3},16,[`as-child`,`as`,`data-side`,`data-align`,`style`])],4))}}),fh=F({__name:`MenuAnchor`,props:{reference:{type:null,required:!1},asChild:{type:Boolean,required:!1},as:{type:null,required:!1}},setup(e){let t=e;return(e,n)=>(z(),V(N(Jf),ye(fs(t)),{default:P(()=>[I(e.$slots,`default`)]),_:3},16))}});function ph(){let e=M(!1);return Mi(()=>{Xu(`keydown`,()=>{e.value=!0},{capture:!0,passive:!0}),Xu([`pointerdown`,`pointermove`],()=>{e.value=!1},{capture:!0,passive:!0})}),e}var mh=Mu(ph),[hh,gh]=bu([`MenuRoot`,`MenuSub`],`MenuContext`),[_h,vh]=bu(`MenuRoot`),yh=F({__name:`MenuRoot`,props:{open:{type:Boolean,required:!1,default:!1},dir:{type:String,required:!1},modal:{type:Boolean,required:!1,default:!0}},emits:[`update:open`],setup(e,{emit:t}){let n=e,r=t,{modal:i,dir:a}=bn(n),o=fd(a),s=rd(n,`open`,r),c=M(),l=mh();return gh({open:s,onOpenChange:e=>{s.value=e},content:c,onContentChange:e=>{c.value=e}}),vh({onClose:()=>{s.value=!1},isUsingKeyboardRef:l,dir:o,modal:i}),(e,t)=>(z(),V(N(qf),null,{default:P(()=>[I(e.$slots,`default`)]),_:3}))}}),[bh,xh]=bu(`MenuContent`),Sh=F({__name:`MenuContentImpl`,props:ha({loop:{type:Boolean,required:!1},disableOutsidePointerEvents:{type:Boolean,required:!1},disableOutsideScroll:{type:Boolean,required:!1},trapFocus:{type:Boolean,required:!1},side:{type:null,required:!1},sideOffset:{type:Number,required:!1},sideFlip:{type:Boolean,required:!1},align:{type:null,required:!1},alignOffset:{type:Number,required:!1},alignFlip:{type:Boolean,required:!1},avoidCollisions:{type:Boolean,required:!1},collisionBoundary:{type:null,required:!1},collisionPadding:{type:[Number,Object],required:!1},arrowPadding:{type:Number,required:!1},sticky:{type:String,required:!1},hideWhenDetached:{type:Boolean,required:!1},positionStrategy:{type:String,required:!1},updatePositionStrategy:{type:String,required:!1},disableUpdateOnLayoutShift:{type:Boolean,required:!1},prioritizePosition:{type:Boolean,required:!1},reference:{type:null,required:!1},asChild:{type:Boolean,required:!1},as:{type:null,required:!1}},{...ch}),emits:[`escapeKeyDown`,`pointerDownOutside`,`focusOutside`,`interactOutside`,`entryFocus`,`openAutoFocus`,`closeAutoFocus`,`dismiss`],setup(e,{emit:t}){let n=e,r=t,i=hh(),a=_h(),{trapFocus:o,disableOutsidePointerEvents:s,loop:c}=bn(n);hd(),ld(s.value);let l=M(``),u=M(0),d=M(0),f=M(null),p=M(`right`),m=M(0),h=M(null),g=M(),{forwardRef:_,currentElement:v}=q(),{handleTypeaheadSearch:y}=Nd();L(v,e=>{i.onContentChange(e)}),Ii(()=>{window.clearTimeout(u.value)});function b(e){return p.value===f.value?.side&&wf(e,f.value?.area)}async function x(e){r(`openAutoFocus`,e),!e.defaultPrevented&&(e.preventDefault(),v.value?.focus({preventScroll:!0}))}function S(e){if(e.defaultPrevented)return;let t=e.target.closest(`[data-reka-menu-content]`)===e.currentTarget,n=e.ctrlKey||e.altKey||e.metaKey,r=e.key.length===1,i=Tu(e,xu(),v.value,{loop:c.value,arrowKeyOptions:`vertical`,dir:a?.dir.value,focus:!0,attributeName:`[data-reka-collection-item]:not([data-disabled])`});if(i)return i?.focus();if(e.code===`Space`)return;let o=g.value?.getItems()??[];if(t&&(e.key===`Tab`&&e.preventDefault(),!n&&r&&y(e.key,o)),e.target!==v.value||!bf.includes(e.key))return;e.preventDefault();let s=[...o.map(e=>e.ref)];yf.includes(e.key)&&s.reverse(),Sf(s)}function C(e){e?.currentTarget?.contains?.(e.target)||(window.clearTimeout(u.value),l.value=``)}function w(e){if(!Tf(e))return;let t=e.target,n=m.value!==e.clientX;(e?.currentTarget)?.contains(t)&&n&&(p.value=e.clientX>m.value?`right`:`left`,m.value=e.clientX)}return xh({onItemEnter:e=>!!b(e),onItemLeave:e=>{b(e)||(v.value?.focus(),h.value=null)},onTriggerLeave:e=>!!b(e),searchRef:l,pointerGraceTimerRef:d,onPointerGraceIntentChange:e=>{f.value=e}}),(e,t)=>(z(),V(N(hf),{"as-child":``,trapped:N(o),onMountAutoFocus:x,onUnmountAutoFocus:t[7]||=e=>r(`closeAutoFocus`,e)},{default:P(()=>[U(N($d),{"as-child":``,"disable-outside-pointer-events":N(s),onEscapeKeyDown:t[2]||=e=>r(`escapeKeyDown`,e),onPointerDownOutside:t[3]||=e=>r(`pointerDownOutside`,e),onFocusOutside:t[4]||=e=>r(`focusOutside`,e),onInteractOutside:t[5]||=e=>r(`interactOutside`,e),onDismiss:t[6]||=e=>r(`dismiss`)},{default:P(()=>[U(N(Wf),{ref_key:`rovingFocusGroupRef`,ref:g,"current-tab-stop-id":h.value,"onUpdate:currentTabStopId":t[0]||=e=>h.value=e,"as-child":``,orientation:`vertical`,dir:N(a).dir.value,loop:N(c),onEntryFocus:t[1]||=e=>{r(`entryFocus`,e),N(a).isUsingKeyboardRef.value||e.preventDefault()}},{default:P(()=>[U(N(dh),{ref:N(_),role:`menu`,as:e.as,"as-child":e.asChild,"aria-orientation":`vertical`,"data-reka-menu-content":``,"data-state":N(xf)(N(i).open.value),dir:N(a).dir.value,side:e.side,"side-offset":e.sideOffset,align:e.align,"align-offset":e.alignOffset,"avoid-collisions":e.avoidCollisions,"collision-boundary":e.collisionBoundary,"collision-padding":e.collisionPadding,"arrow-padding":e.arrowPadding,"prioritize-position":e.prioritizePosition,"position-strategy":e.positionStrategy,"update-position-strategy":e.updatePositionStrategy,sticky:e.sticky,"hide-when-detached":e.hideWhenDetached,reference:e.reference,onKeydown:S,onBlur:C,onPointermove:w},{default:P(()=>[I(e.$slots,`default`)]),_:3},8,[`as`,`as-child`,`data-state`,`dir`,`side`,`side-offset`,`align`,`align-offset`,`avoid-collisions`,`collision-boundary`,`collision-padding`,`arrow-padding`,`prioritize-position`,`position-strategy`,`update-position-strategy`,`sticky`,`hide-when-detached`,`reference`])]),_:3},8,[`current-tab-stop-id`,`dir`,`loop`])]),_:3},8,[`disable-outside-pointer-events`])]),_:3},8,[`trapped`]))}}),Ch=F({inheritAttrs:!1,__name:`MenuItemImpl`,props:{disabled:{type:Boolean,required:!1},textValue:{type:String,required:!1},asChild:{type:Boolean,required:!1},as:{type:null,required:!1}},setup(e){let t=e,n=bh(),{forwardRef:r}=q(),{CollectionItem:i}=Rf(),a=M(!1);async function o(e){e.defaultPrevented||Tf(e)&&(t.disabled?n.onItemLeave(e):n.onItemEnter(e)||e.currentTarget?.focus({preventScroll:!0}))}async function s(e){await $n(),!e.defaultPrevented&&Tf(e)&&n.onItemLeave(e)}return(e,t)=>(z(),V(N(i),{value:{textValue:e.textValue}},{default:P(()=>[U(N(Vd),W({ref:N(r),role:`menuitem`,tabindex:`-1`},e.$attrs,{as:e.as,"as-child":e.asChild,"aria-disabled":e.disabled||void 0,"data-disabled":e.disabled?``:void 0,"data-highlighted":a.value?``:void 0,onPointermove:o,onPointerleave:s,onFocus:t[0]||=async t=>{await $n(),!(t.defaultPrevented||e.disabled)&&(a.value=!0)},onBlur:t[1]||=async e=>{await $n(),!e.defaultPrevented&&(a.value=!1)}}),{default:P(()=>[I(e.$slots,`default`)]),_:3},16,[`as`,`as-child`,`aria-disabled`,`data-disabled`,`data-highlighted`])]),_:3},8,[`value`]))}}),wh=F({__name:`MenuItem`,props:{disabled:{type:Boolean,required:!1},textValue:{type:String,required:!1},asChild:{type:Boolean,required:!1},as:{type:null,required:!1}},emits:[`select`],setup(e,{emit:t}){let n=e,r=t,{forwardRef:i,currentElement:a}=q(),o=_h(),s=bh(),c=M(!1);async function l(){let e=a.value;if(!n.disabled&&e){let e=new CustomEvent(gf,{bubbles:!0,cancelable:!0});r(`select`,e),await $n(),e.defaultPrevented?c.value=!1:o.onClose()}}return(e,t)=>(z(),V(Ch,W(n,{ref:N(i),onClick:l,onPointerdown:t[0]||=()=>{c.value=!0},onPointerup:t[1]||=async e=>{await $n(),!e.defaultPrevented&&(c.value||e.currentTarget?.click())},onKeydown:t[2]||=async t=>{let n=N(s).searchRef.value!==``;e.disabled||n&&t.key===` `||N(_f).includes(t.key)&&(t.currentTarget.click(),t.preventDefault())}}),{default:P(()=>[I(e.$slots,`default`)]),_:3},16))}}),Th=F({__name:`MenuRootContentModal`,props:{loop:{type:Boolean,required:!1},side:{type:null,required:!1},sideOffset:{type:Number,required:!1},sideFlip:{type:Boolean,required:!1},align:{type:null,required:!1},alignOffset:{type:Number,required:!1},alignFlip:{type:Boolean,required:!1},avoidCollisions:{type:Boolean,required:!1},collisionBoundary:{type:null,required:!1},collisionPadding:{type:[Number,Object],required:!1},arrowPadding:{type:Number,required:!1},sticky:{type:String,required:!1},hideWhenDetached:{type:Boolean,required:!1},positionStrategy:{type:String,required:!1},updatePositionStrategy:{type:String,required:!1},disableUpdateOnLayoutShift:{type:Boolean,required:!1},prioritizePosition:{type:Boolean,required:!1},reference:{type:null,required:!1},asChild:{type:Boolean,required:!1},as:{type:null,required:!1}},emits:[`escapeKeyDown`,`pointerDownOutside`,`focusOutside`,`interactOutside`,`entryFocus`,`openAutoFocus`,`closeAutoFocus`],setup(e,{emit:t}){let n=e,r=t,i=vd(n,r),a=hh(),{forwardRef:o,currentElement:s}=q();return Od(s),(e,t)=>(z(),V(Sh,W(N(i),{ref:N(o),"trap-focus":N(a).open.value,"disable-outside-pointer-events":N(a).open.value,"disable-outside-scroll":!0,onDismiss:t[0]||=e=>N(a).onOpenChange(!1),onFocusOutside:t[1]||=Wl(e=>r(`focusOutside`,e),[`prevent`])}),{default:P(()=>[I(e.$slots,`default`)]),_:3},16,[`trap-focus`,`disable-outside-pointer-events`]))}}),Eh=F({__name:`MenuRootContentNonModal`,props:{loop:{type:Boolean,required:!1},side:{type:null,required:!1},sideOffset:{type:Number,required:!1},sideFlip:{type:Boolean,required:!1},align:{type:null,required:!1},alignOffset:{type:Number,required:!1},alignFlip:{type:Boolean,required:!1},avoidCollisions:{type:Boolean,required:!1},collisionBoundary:{type:null,required:!1},collisionPadding:{type:[Number,Object],required:!1},arrowPadding:{type:Number,required:!1},sticky:{type:String,required:!1},hideWhenDetached:{type:Boolean,required:!1},positionStrategy:{type:String,required:!1},updatePositionStrategy:{type:String,required:!1},disableUpdateOnLayoutShift:{type:Boolean,required:!1},prioritizePosition:{type:Boolean,required:!1},reference:{type:null,required:!1},asChild:{type:Boolean,required:!1},as:{type:null,required:!1}},emits:[`escapeKeyDown`,`pointerDownOutside`,`focusOutside`,`interactOutside`,`entryFocus`,`openAutoFocus`,`closeAutoFocus`],setup(e,{emit:t}){let n=vd(e,t),r=hh();return(e,t)=>(z(),V(Sh,W(N(n),{"trap-focus":!1,"disable-outside-pointer-events":!1,"disable-outside-scroll":!1,onDismiss:t[0]||=e=>N(r).onOpenChange(!1)}),{default:P(()=>[I(e.$slots,`default`)]),_:3},16))}}),Dh=F({__name:`MenuContent`,props:{forceMount:{type:Boolean,required:!1},loop:{type:Boolean,required:!1},side:{type:null,required:!1},sideOffset:{type:Number,required:!1},sideFlip:{type:Boolean,required:!1},align:{type:null,required:!1},alignOffset:{type:Number,required:!1},alignFlip:{type:Boolean,required:!1},avoidCollisions:{type:Boolean,required:!1},collisionBoundary:{type:null,required:!1},collisionPadding:{type:[Number,Object],required:!1},arrowPadding:{type:Number,required:!1},sticky:{type:String,required:!1},hideWhenDetached:{type:Boolean,required:!1},positionStrategy:{type:String,required:!1},updatePositionStrategy:{type:String,required:!1},disableUpdateOnLayoutShift:{type:Boolean,required:!1},prioritizePosition:{type:Boolean,required:!1},reference:{type:null,required:!1},asChild:{type:Boolean,required:!1},as:{type:null,required:!1}},emits:[`escapeKeyDown`,`pointerDownOutside`,`focusOutside`,`interactOutside`,`entryFocus`,`openAutoFocus`,`closeAutoFocus`],setup(e,{emit:t}){let n=vd(e,t),r=hh(),i=_h();return(e,t)=>(z(),V(N(Rd),{present:e.forceMount||N(r).open.value},{default:P(()=>[N(i).modal.value?(z(),V(Th,ye(W({key:0},{...e.$attrs,...N(n)})),{default:P(()=>[I(e.$slots,`default`)]),_:3},16)):(z(),V(Eh,ye(W({key:1},{...e.$attrs,...N(n)})),{default:P(()=>[I(e.$slots,`default`)]),_:3},16))]),_:3},8,[`present`]))}}),Oh=F({__name:`MenuPortal`,props:{to:{type:null,required:!1},disabled:{type:Boolean,required:!1},defer:{type:Boolean,required:!1},forceMount:{type:Boolean,required:!1}},setup(e){let t=e;return(e,n)=>(z(),V(N(Nf),ye(fs(t)),{default:P(()=>[I(e.$slots,`default`)]),_:3},16))}}),[kh,Ah]=bu(`DropdownMenuRoot`),jh=F({__name:`DropdownMenuRoot`,props:{defaultOpen:{type:Boolean,required:!1},open:{type:Boolean,required:!1,default:void 0},dir:{type:String,required:!1},modal:{type:Boolean,required:!1,default:!0}},emits:[`update:open`],setup(e,{emit:t}){let n=e,r=t;q();let i=rd(n,`open`,r,{defaultValue:n.defaultOpen,passive:n.open===void 0}),a=M(),{modal:o,dir:s}=bn(n),c=fd(s);return Ah({open:i,onOpenChange:e=>{i.value=e},onOpenToggle:()=>{i.value=!i.value},triggerId:``,triggerElement:a,contentId:``,modal:o,dir:c}),(e,t)=>(z(),V(N(yh),{open:N(i),"onUpdate:open":t[0]||=e=>un(i)?i.value=e:null,dir:N(c),modal:N(o)},{default:P(()=>[I(e.$slots,`default`,{open:N(i)})]),_:3},8,[`open`,`dir`,`modal`]))}}),Mh=F({__name:`DropdownMenuContent`,props:{forceMount:{type:Boolean,required:!1},loop:{type:Boolean,required:!1},side:{type:null,required:!1},sideOffset:{type:Number,required:!1},sideFlip:{type:Boolean,required:!1},align:{type:null,required:!1},alignOffset:{type:Number,required:!1},alignFlip:{type:Boolean,required:!1},avoidCollisions:{type:Boolean,required:!1},collisionBoundary:{type:null,required:!1},collisionPadding:{type:[Number,Object],required:!1},arrowPadding:{type:Number,required:!1},sticky:{type:String,required:!1},hideWhenDetached:{type:Boolean,required:!1},positionStrategy:{type:String,required:!1},updatePositionStrategy:{type:String,required:!1},disableUpdateOnLayoutShift:{type:Boolean,required:!1},prioritizePosition:{type:Boolean,required:!1},reference:{type:null,required:!1},asChild:{type:Boolean,required:!1},as:{type:null,required:!1}},emits:[`escapeKeyDown`,`pointerDownOutside`,`focusOutside`,`interactOutside`,`closeAutoFocus`],setup(e,{emit:t}){let n=vd(e,t);q();let r=kh(),i=M(!1);function a(e){e.defaultPrevented||(i.value||setTimeout(()=>{r.triggerElement.value?.focus()},0),i.value=!1,e.preventDefault())}return r.contentId||=Ad(void 0,`reka-dropdown-menu-content`),(e,t)=>(z(),V(N(Dh),W(N(n),{id:N(r).contentId,"aria-labelledby":N(r)?.triggerId,style:{"--reka-dropdown-menu-content-transform-origin":`var(--reka-popper-transform-origin)`,"--reka-dropdown-menu-content-available-width":`var(--reka-popper-available-width)`,"--reka-dropdown-menu-content-available-height":`var(--reka-popper-available-height)`,"--reka-dropdown-menu-trigger-width":`var(--reka-popper-anchor-width)`,"--reka-dropdown-menu-trigger-height":`var(--reka-popper-anchor-height)`},onCloseAutoFocus:a,onInteractOutside:t[0]||=e=>{if(e.defaultPrevented)return;let t=e.detail.originalEvent,n=t.button===0&&t.ctrlKey===!0,a=t.button===2||n;(!N(r).modal.value||a)&&(i.value=!0),N(r).triggerElement.value?.contains(e.target)&&e.preventDefault()}}),{default:P(()=>[I(e.$slots,`default`)]),_:3},16,[`id`,`aria-labelledby`]))}}),Nh=F({__name:`DropdownMenuItem`,props:{disabled:{type:Boolean,required:!1},textValue:{type:String,required:!1},asChild:{type:Boolean,required:!1},as:{type:null,required:!1}},emits:[`select`],setup(e,{emit:t}){let n=e,r=pd(t);return q(),(e,t)=>(z(),V(N(wh),ye(fs({...n,...N(r)})),{default:P(()=>[I(e.$slots,`default`)]),_:3},16))}}),Ph=F({__name:`DropdownMenuPortal`,props:{to:{type:null,required:!1},disabled:{type:Boolean,required:!1},defer:{type:Boolean,required:!1},forceMount:{type:Boolean,required:!1}},setup(e){let t=e;return(e,n)=>(z(),V(N(Oh),ye(fs(t)),{default:P(()=>[I(e.$slots,`default`)]),_:3},16))}}),Fh=F({__name:`DropdownMenuTrigger`,props:{disabled:{type:Boolean,required:!1},asChild:{type:Boolean,required:!1},as:{type:null,required:!1,default:`button`}},setup(e){let t=e,n=kh(),{forwardRef:r,currentElement:i}=q();return Mi(()=>{n.triggerElement=i}),n.triggerId||=Ad(void 0,`reka-dropdown-menu-trigger`),(e,i)=>(z(),V(N(fh),{"as-child":``},{default:P(()=>[U(N(Vd),{id:N(n).triggerId,ref:N(r),type:e.as===`button`?`button`:void 0,"as-child":t.asChild,as:e.as,"aria-haspopup":`menu`,"aria-expanded":N(n).open.value,"aria-controls":N(n).open.value?N(n).contentId:void 0,"data-disabled":e.disabled?``:void 0,disabled:e.disabled,"data-state":N(n).open.value?`open`:`closed`,onClick:i[0]||=async t=>{!e.disabled&&t.button===0&&t.ctrlKey===!1&&(N(n)?.onOpenToggle(),await $n(),N(n).open.value&&t.preventDefault())},onKeydown:i[1]||=Kl(t=>{e.disabled||([`Enter`,` `].includes(t.key)&&N(n).onOpenToggle(),t.key===`ArrowDown`&&N(n).onOpenChange(!0),[`Enter`,` `,`ArrowDown`].includes(t.key)&&t.preventDefault())},[`enter`,`space`,`arrow-down`])},{default:P(()=>[I(e.$slots,`default`)]),_:3},8,[`id`,`type`,`as-child`,`as`,`aria-expanded`,`aria-controls`,`data-disabled`,`disabled`,`data-state`])]),_:3}))}}),Ih=F({__name:`Label`,props:{for:{type:String,required:!1},asChild:{type:Boolean,required:!1},as:{type:null,required:!1,default:`label`}},setup(e){let t=e;return q(),(e,n)=>(z(),V(N(Vd),W(t,{onMousedown:n[0]||=e=>{!e.defaultPrevented&&e.detail>1&&e.preventDefault()}}),{default:P(()=>[I(e.$slots,`default`)]),_:3},16))}}),[Lh,Rh]=bu([`NavigationMenuRoot`,`NavigationMenuSub`],`NavigationMenuContext`),zh=F({__name:`NavigationMenuRoot`,props:{modelValue:{type:String,required:!1,default:void 0},defaultValue:{type:String,required:!1},dir:{type:String,required:!1},orientation:{type:String,required:!1,default:`horizontal`},delayDuration:{type:Number,required:!1,default:200},skipDelayDuration:{type:Number,required:!1,default:300},disableClickTrigger:{type:Boolean,required:!1,default:!1},disableHoverTrigger:{type:Boolean,required:!1,default:!1},disablePointerLeaveClose:{type:Boolean,required:!1},unmountOnHide:{type:Boolean,required:!1,default:!0},asChild:{type:Boolean,required:!1},as:{type:null,required:!1,default:`nav`}},emits:[`update:modelValue`],setup(e,{emit:t}){let n=e,r=rd(n,`modelValue`,t,{defaultValue:n.defaultValue??``,passive:n.modelValue===void 0}),i=M(``),{forwardRef:a,currentElement:o}=q(),s=M(),c=M(),l=M(),{getItems:u,CollectionSlot:d}=Rf({key:`NavigationMenu`,isProvider:!0}),{delayDuration:f,skipDelayDuration:p,dir:m,disableClickTrigger:h,disableHoverTrigger:g,unmountOnHide:_}=bn(n),v=fd(m),y=Wu(!1,p),b=Gu(e=>{typeof e==`string`&&(i.value=r.value,r.value=e)},G(()=>r.value!==``||y.value?150:f.value));return Ua(()=>{r.value&&(l.value=u().map(e=>e.ref).find(e=>e.id.includes(r.value)))}),Rh({isRootMenu:!0,modelValue:r,previousValue:i,baseId:Ad(void 0,`reka-navigation-menu`),disableClickTrigger:h,disableHoverTrigger:g,dir:v,unmountOnHide:_,orientation:n.orientation,rootNavigationMenu:o,indicatorTrack:s,activeTrigger:l,onIndicatorTrackChange:e=>{s.value=e},viewport:c,onViewportChange:e=>{c.value=e},onTriggerEnter:e=>{b(e)},onTriggerLeave:()=>{y.value=!0,b(``)},onContentEnter:()=>{b()},onContentLeave:()=>{n.disablePointerLeaveClose||b(``)},onItemSelect:e=>{i.value=r.value,r.value=e},onItemDismiss:()=>{i.value=r.value,r.value=``}}),(e,t)=>(z(),V(N(d),null,{default:P(()=>[U(N(Vd),{ref:N(a),"aria-label":`Main`,as:e.as,"as-child":e.asChild,"data-orientation":e.orientation,dir:N(v),"data-reka-navigation-menu":``},{default:P(()=>[I(e.$slots,`default`,{modelValue:N(r)})]),_:3},8,[`as`,`as-child`,`data-orientation`,`dir`])]),_:3}))}});function Bh(e){return e?`open`:`closed`}function Vh(e,t){return`${e}-content-${t}`}var Hh=`navigationMenu.linkSelect`,Uh=`navigationMenu.rootContentDismiss`;function Wh(e){let t=[],n=document.createTreeWalker(e,NodeFilter.SHOW_ELEMENT,{acceptNode:e=>{let t=e.tagName===`INPUT`&&e.type===`hidden`;return e.disabled||e.hidden||t?NodeFilter.FILTER_SKIP:e.tabIndex>=0?NodeFilter.FILTER_ACCEPT:NodeFilter.FILTER_SKIP}});for(;n.nextNode();)t.push(n.currentNode);return t}function Gh(e){let t=xu();return e.some(e=>e===t?!0:(e.focus(),xu()!==t))}function Kh(e){return e.forEach(e=>{e.dataset.tabindex=e.getAttribute(`tabindex`)||``,e.setAttribute(`tabindex`,`-1`)}),()=>{e.forEach(e=>{let t=e.dataset.tabindex;e.setAttribute(`tabindex`,t)})}}function qh(e){return t=>t.pointerType===`mouse`?e(t):void 0}var[Jh,Yh]=bu(`NavigationMenuItem`),Xh=F({__name:`NavigationMenuItem`,props:{value:{type:String,required:!1},asChild:{type:Boolean,required:!1},as:{type:null,required:!1,default:`li`}},setup(e){let t=e;q();let{getItems:n}=Rf({key:`NavigationMenu`}),r=Lh(),i=Ad(t.value),a=M(),o=M(),s=Vh(r.baseId,i),c=()=>({}),l=M(!1);async function u(e=`start`){let t=document.getElementById(s);if(t){c();let n=Wh(t);n.length&&Gh(e===`start`?n:n.reverse())}}function d(){let e=document.getElementById(s);if(e){let t=Wh(e);t.length&&(c=Kh(t))}}Yh({value:i,contentId:s,triggerRef:a,focusProxyRef:o,wasEscapeCloseRef:l,onEntryKeyDown:u,onFocusProxyEnter:u,onContentFocusOutside:d,onRootContentClose:d});function f(){r.onItemDismiss(),a.value?.focus()}function p(e){let t=xu();if(e.keyCode===32||e.key===`Enter`)if(r.modelValue.value===i){f(),e.preventDefault();return}else{e.target.click(),e.preventDefault();return}let a=n().filter(e=>e.ref.parentElement?.hasAttribute(`data-menu-item`)).map(e=>e.ref);if(!a.includes(t))return;let o=Tu(e,t,void 0,{itemsArray:a,loop:!1});o&&o?.focus(),e.preventDefault(),e.stopPropagation()}return(e,t)=>(z(),V(N(Vd),{"as-child":e.asChild,as:e.as,"data-menu-item":``,onKeydown:Kl(p,[`up`,`down`,`left`,`right`,`home`,`end`,`space`])},{default:P(()=>[I(e.$slots,`default`)]),_:3},8,[`as-child`,`as`]))}}),Zh=F({__name:`NavigationMenuLink`,props:{active:{type:Boolean,required:!1},asChild:{type:Boolean,required:!1},as:{type:null,required:!1,default:`a`}},emits:[`select`],setup(e,{emit:t}){let n=e,r=t,{CollectionItem:i}=Rf({key:`NavigationMenu`});q();async function a(e){let t=new CustomEvent(Hh,{bubbles:!0,cancelable:!0,detail:{originalEvent:e}});if(r(`select`,t),!t.defaultPrevented&&!e.metaKey){let t=new CustomEvent(Uh,{bubbles:!0,cancelable:!0});e.target?.dispatchEvent(t)}}return(e,t)=>(z(),V(N(i),null,{default:P(()=>[U(N(Vd),{as:e.as,"data-active":e.active?``:void 0,"aria-current":e.active?`page`:void 0,"as-child":n.asChild,onClick:a},{default:P(()=>[I(e.$slots,`default`)]),_:3},8,[`as`,`data-active`,`aria-current`,`as-child`])]),_:3}))}}),Qh=F({inheritAttrs:!1,__name:`NavigationMenuList`,props:{asChild:{type:Boolean,required:!1},as:{type:null,required:!1,default:`ul`}},setup(e){let t=e,n=Lh(),{forwardRef:r,currentElement:i}=q();return Mi(()=>{n.onIndicatorTrackChange(i.value)}),(e,i)=>(z(),V(N(Vd),{ref:N(r),style:{position:`relative`}},{default:P(()=>[U(N(Vd),W(e.$attrs,{"as-child":t.asChild,as:e.as,"data-orientation":N(n).orientation}),{default:P(()=>[I(e.$slots,`default`)]),_:3},16,[`as-child`,`as`,`data-orientation`])]),_:3},512))}}),$h=F({inheritAttrs:!1,__name:`NavigationMenuViewport`,props:{forceMount:{type:Boolean,required:!1},align:{type:String,required:!1,default:`center`},asChild:{type:Boolean,required:!1},as:{type:null,required:!1}},setup(e){let t=e,{forwardRef:n,currentElement:r}=q(),i=Lh(),{activeTrigger:a,rootNavigationMenu:o,modelValue:s}=i,c=M(),l=M(),u=G(()=>!!i.modelValue.value);L(r,()=>{i.onViewportChange(r.value)});let d=M();L([s,u],()=>{$n(()=>{r.value&&requestAnimationFrame(()=>{d.value=r.value?.querySelector(`[data-state=open]`)})})},{immediate:!0});function f(){if(d.value&&a.value&&o.value){let e=document.documentElement.offsetWidth,n=document.documentElement.offsetHeight,r=o.value.getBoundingClientRect(),i=a.value.getBoundingClientRect(),{offsetWidth:s,offsetHeight:c}=d.value,u=i.left-r.left,f=i.top-r.top,p=null,m=null;switch(t.align){case`start`:p=u,m=f;break;case`end`:p=u-s+i.width,m=f-c+i.height;break;default:p=u-s/2+i.width/2,m=f-c/2+i.height/2}p+r.left<10&&(p=10-r.left);let h=p+r.left+s;h>e-10&&(p-=h-e+10,p<10-r.left&&(p=10-r.left)),m+r.top<10&&(m=10-r.top);let g=m+r.top+c;g>n-10&&(m-=g-n+10,m<10-r.top&&(m=10-r.top)),p=Math.round(p),m=Math.round(m),l.value={left:p,top:m}}}return nd(d,()=>{d.value&&(c.value={width:d.value.offsetWidth,height:d.value.offsetHeight},f())}),nd([globalThis.document?.body,o],()=>{f()}),(e,t)=>(z(),V(N(Rd),{present:e.forceMount||u.value,"force-mount":!N(i).unmountOnHide.value,onAfterLeave:t[2]||=()=>{c.value=void 0,l.value=void 0}},{default:P(({present:r})=>[U(N(Vd),W(e.$attrs,{ref:N(n),as:e.as,"as-child":e.asChild,"data-state":N(Bh)(u.value),"data-orientation":N(i).orientation,style:{pointerEvents:!u.value&&N(i).isRootMenu?`none`:void 0,"--reka-navigation-menu-viewport-width":c.value?`${c.value?.width}px`:void 0,"--reka-navigation-menu-viewport-height":c.value?`${c.value?.height}px`:void 0,"--reka-navigation-menu-viewport-left":l.value?`${l.value?.left}px`:void 0,"--reka-navigation-menu-viewport-top":l.value?`${l.value?.top}px`:void 0},hidden:!r,onPointerenter:t[0]||=e=>N(i).onContentEnter(N(i).modelValue.value),onPointerleave:t[1]||=e=>N(qh)(()=>N(i).onContentLeave())(e)}),{default:P(()=>[I(e.$slots,`default`)]),_:2},1040,[`as`,`as-child`,`data-state`,`data-orientation`,`style`,`hidden`])]),_:3},8,[`present`,`force-mount`]))}}),eg=(e,t)=>{let n=Array(e.length+t.length);for(let t=0;t<e.length;t++)n[t]=e[t];for(let r=0;r<t.length;r++)n[e.length+r]=t[r];return n},tg=(e,t)=>({classGroupId:e,validator:t}),ng=(e=new Map,t=null,n)=>({nextPart:e,validators:t,classGroupId:n}),rg=`-`,ig=[],ag=`arbitrary..`,og=e=>{let t=lg(e),{conflictingClassGroups:n,conflictingClassGroupModifiers:r}=e;return{getClassGroupId:e=>{if(e.startsWith(`[`)&&e.endsWith(`]`))return cg(e);let n=e.split(rg);return sg(n,n[0]===``&&n.length>1?1:0,t)},getConflictingClassGroupIds:(e,t)=>{if(t){let t=r[e],i=n[e];return t?i?eg(i,t):t:i||ig}return n[e]||ig}}},sg=(e,t,n)=>{if(e.length-t===0)return n.classGroupId;let r=e[t],i=n.nextPart.get(r);if(i){let n=sg(e,t+1,i);if(n)return n}let a=n.validators;if(a===null)return;let o=t===0?e.join(rg):e.slice(t).join(rg),s=a.length;for(let e=0;e<s;e++){let t=a[e];if(t.validator(o))return t.classGroupId}},cg=e=>e.slice(1,-1).indexOf(`:`)===-1?void 0:(()=>{let t=e.slice(1,-1),n=t.indexOf(`:`),r=t.slice(0,n);return r?ag+r:void 0})(),lg=e=>{let{theme:t,classGroups:n}=e;return ug(n,t)},ug=(e,t)=>{let n=ng();for(let r in e){let i=e[r];dg(i,n,r,t)}return n},dg=(e,t,n,r)=>{let i=e.length;for(let a=0;a<i;a++){let i=e[a];fg(i,t,n,r)}},fg=(e,t,n,r)=>{if(typeof e==`string`){pg(e,t,n);return}if(typeof e==`function`){mg(e,t,n,r);return}hg(e,t,n,r)},pg=(e,t,n)=>{let r=e===``?t:gg(t,e);r.classGroupId=n},mg=(e,t,n,r)=>{if(_g(e)){dg(e(r),t,n,r);return}t.validators===null&&(t.validators=[]),t.validators.push(tg(n,e))},hg=(e,t,n,r)=>{let i=Object.entries(e),a=i.length;for(let e=0;e<a;e++){let[a,o]=i[e];dg(o,gg(t,a),n,r)}},gg=(e,t)=>{let n=e,r=t.split(rg),i=r.length;for(let e=0;e<i;e++){let t=r[e],i=n.nextPart.get(t);i||(i=ng(),n.nextPart.set(t,i)),n=i}return n},_g=e=>`isThemeGetter`in e&&e.isThemeGetter===!0,vg=e=>{if(e<1)return{get:()=>void 0,set:()=>{}};let t=0,n=Object.create(null),r=Object.create(null),i=(i,a)=>{n[i]=a,t++,t>e&&(t=0,r=n,n=Object.create(null))};return{get(e){let t=n[e];if(t!==void 0)return t;if((t=r[e])!==void 0)return i(e,t),t},set(e,t){e in n?n[e]=t:i(e,t)}}},yg=`!`,bg=`:`,xg=[],Sg=(e,t,n,r,i)=>({modifiers:e,hasImportantModifier:t,baseClassName:n,maybePostfixModifierPosition:r,isExternal:i}),Cg=e=>{let{prefix:t,experimentalParseClassName:n}=e,r=e=>{let t=[],n=0,r=0,i=0,a,o=e.length;for(let s=0;s<o;s++){let o=e[s];if(n===0&&r===0){if(o===bg){t.push(e.slice(i,s)),i=s+1;continue}if(o===`/`){a=s;continue}}o===`[`?n++:o===`]`?n--:o===`(`?r++:o===`)`&&r--}let s=t.length===0?e:e.slice(i),c=s,l=!1;s.endsWith(yg)?(c=s.slice(0,-1),l=!0):s.startsWith(yg)&&(c=s.slice(1),l=!0);let u=a&&a>i?a-i:void 0;return Sg(t,l,c,u)};if(t){let e=t+bg,n=r;r=t=>t.startsWith(e)?n(t.slice(e.length)):Sg(xg,!1,t,void 0,!0)}if(n){let e=r;r=t=>n({className:t,parseClassName:e})}return r},wg=e=>{let t=new Map;return e.orderSensitiveModifiers.forEach((e,n)=>{t.set(e,1e6+n)}),e=>{let n=[],r=[];for(let i=0;i<e.length;i++){let a=e[i],o=a[0]===`[`,s=t.has(a);o||s?(r.length>0&&(r.sort(),n.push(...r),r=[]),n.push(a)):r.push(a)}return r.length>0&&(r.sort(),n.push(...r)),n}},Tg=e=>({cache:vg(e.cacheSize),parseClassName:Cg(e),sortModifiers:wg(e),...og(e)}),Eg=/\s+/,Dg=(e,t)=>{let{parseClassName:n,getClassGroupId:r,getConflictingClassGroupIds:i,sortModifiers:a}=t,o=[],s=e.trim().split(Eg),c=``;for(let e=s.length-1;e>=0;--e){let t=s[e],{isExternal:l,modifiers:u,hasImportantModifier:d,baseClassName:f,maybePostfixModifierPosition:p}=n(t);if(l){c=t+(c.length>0?` `+c:c);continue}let m=!!p,h=r(m?f.substring(0,p):f);if(!h){if(!m){c=t+(c.length>0?` `+c:c);continue}if(h=r(f),!h){c=t+(c.length>0?` `+c:c);continue}m=!1}let g=u.length===0?``:u.length===1?u[0]:a(u).join(`:`),_=d?g+yg:g,v=_+h;if(o.indexOf(v)>-1)continue;o.push(v);let y=i(h,m);for(let e=0;e<y.length;++e){let t=y[e];o.push(_+t)}c=t+(c.length>0?` `+c:c)}return c},Og=(...e)=>{let t=0,n,r,i=``;for(;t<e.length;)(n=e[t++])&&(r=kg(n))&&(i&&(i+=` `),i+=r);return i},kg=e=>{if(typeof e==`string`)return e;let t,n=``;for(let r=0;r<e.length;r++)e[r]&&(t=kg(e[r]))&&(n&&(n+=` `),n+=t);return n},Ag=(e,...t)=>{let n,r,i,a,o=o=>(n=Tg(t.reduce((e,t)=>t(e),e())),r=n.cache.get,i=n.cache.set,a=s,s(o)),s=e=>{let t=r(e);if(t)return t;let a=Dg(e,n);return i(e,a),a};return a=o,(...e)=>a(Og(...e))},jg=[],Mg=e=>{let t=t=>t[e]||jg;return t.isThemeGetter=!0,t},Ng=/^\[(?:(\w[\w-]*):)?(.+)\]$/i,Pg=/^\((?:(\w[\w-]*):)?(.+)\)$/i,Fg=/^\d+\/\d+$/,Ig=/^(\d+(\.\d+)?)?(xs|sm|md|lg|xl)$/,Lg=/\d+(%|px|r?em|[sdl]?v([hwib]|min|max)|pt|pc|in|cm|mm|cap|ch|ex|r?lh|cq(w|h|i|b|min|max))|\b(calc|min|max|clamp)\(.+\)|^0$/,Rg=/^(rgba?|hsla?|hwb|(ok)?(lab|lch)|color-mix)\(.+\)$/,zg=/^(inset_)?-?((\d+)?\.?(\d+)[a-z]+|0)_-?((\d+)?\.?(\d+)[a-z]+|0)/,Bg=/^(url|image|image-set|cross-fade|element|(repeating-)?(linear|radial|conic)-gradient)\(.+\)$/,Vg=e=>Fg.test(e),J=e=>!!e&&!Number.isNaN(Number(e)),Hg=e=>!!e&&Number.isInteger(Number(e)),Ug=e=>e.endsWith(`%`)&&J(e.slice(0,-1)),Wg=e=>Ig.test(e),Gg=()=>!0,Kg=e=>Lg.test(e)&&!Rg.test(e),qg=()=>!1,Jg=e=>zg.test(e),Yg=e=>Bg.test(e),Xg=e=>!Y(e)&&!X(e),Zg=e=>l_(e,p_,qg),Y=e=>Ng.test(e),Qg=e=>l_(e,m_,Kg),$g=e=>l_(e,h_,J),e_=e=>l_(e,d_,qg),t_=e=>l_(e,f_,Yg),n_=e=>l_(e,__,Jg),X=e=>Pg.test(e),r_=e=>u_(e,m_),i_=e=>u_(e,g_),a_=e=>u_(e,d_),o_=e=>u_(e,p_),s_=e=>u_(e,f_),c_=e=>u_(e,__,!0),l_=(e,t,n)=>{let r=Ng.exec(e);return r?r[1]?t(r[1]):n(r[2]):!1},u_=(e,t,n=!1)=>{let r=Pg.exec(e);return r?r[1]?t(r[1]):n:!1},d_=e=>e===`position`||e===`percentage`,f_=e=>e===`image`||e===`url`,p_=e=>e===`length`||e===`size`||e===`bg-size`,m_=e=>e===`length`,h_=e=>e===`number`,g_=e=>e===`family-name`,__=e=>e===`shadow`,v_=Ag(()=>{let e=Mg(`color`),t=Mg(`font`),n=Mg(`text`),r=Mg(`font-weight`),i=Mg(`tracking`),a=Mg(`leading`),o=Mg(`breakpoint`),s=Mg(`container`),c=Mg(`spacing`),l=Mg(`radius`),u=Mg(`shadow`),d=Mg(`inset-shadow`),f=Mg(`text-shadow`),p=Mg(`drop-shadow`),m=Mg(`blur`),h=Mg(`perspective`),g=Mg(`aspect`),_=Mg(`ease`),v=Mg(`animate`),y=()=>[`auto`,`avoid`,`all`,`avoid-page`,`page`,`left`,`right`,`column`],b=()=>[`center`,`top`,`bottom`,`left`,`right`,`top-left`,`left-top`,`top-right`,`right-top`,`bottom-right`,`right-bottom`,`bottom-left`,`left-bottom`],x=()=>[...b(),X,Y],S=()=>[`auto`,`hidden`,`clip`,`visible`,`scroll`],C=()=>[`auto`,`contain`,`none`],w=()=>[X,Y,c],T=()=>[Vg,`full`,`auto`,...w()],ee=()=>[Hg,`none`,`subgrid`,X,Y],E=()=>[`auto`,{span:[`full`,Hg,X,Y]},Hg,X,Y],te=()=>[Hg,`auto`,X,Y],ne=()=>[`auto`,`min`,`max`,`fr`,X,Y],re=()=>[`start`,`end`,`center`,`between`,`around`,`evenly`,`stretch`,`baseline`,`center-safe`,`end-safe`],D=()=>[`start`,`end`,`center`,`stretch`,`center-safe`,`end-safe`],ie=()=>[`auto`,...w()],O=()=>[Vg,`auto`,`full`,`dvw`,`dvh`,`lvw`,`lvh`,`svw`,`svh`,`min`,`max`,`fit`,...w()],k=()=>[e,X,Y],ae=()=>[...b(),a_,e_,{position:[X,Y]}],oe=()=>[`no-repeat`,{repeat:[``,`x`,`y`,`space`,`round`]}],se=()=>[`auto`,`cover`,`contain`,o_,Zg,{size:[X,Y]}],ce=()=>[Ug,r_,Qg],le=()=>[``,`none`,`full`,l,X,Y],ue=()=>[``,J,r_,Qg],de=()=>[`solid`,`dashed`,`dotted`,`double`],fe=()=>[`normal`,`multiply`,`screen`,`overlay`,`darken`,`lighten`,`color-dodge`,`color-burn`,`hard-light`,`soft-light`,`difference`,`exclusion`,`hue`,`saturation`,`color`,`luminosity`],pe=()=>[J,Ug,a_,e_],me=()=>[``,`none`,m,X,Y],he=()=>[`none`,J,X,Y],ge=()=>[`none`,J,X,Y],_e=()=>[J,X,Y],ve=()=>[Vg,`full`,...w()];return{cacheSize:500,theme:{animate:[`spin`,`ping`,`pulse`,`bounce`],aspect:[`video`],blur:[Wg],breakpoint:[Wg],color:[Gg],container:[Wg],"drop-shadow":[Wg],ease:[`in`,`out`,`in-out`],font:[Xg],"font-weight":[`thin`,`extralight`,`light`,`normal`,`medium`,`semibold`,`bold`,`extrabold`,`black`],"inset-shadow":[Wg],leading:[`none`,`tight`,`snug`,`normal`,`relaxed`,`loose`],perspective:[`dramatic`,`near`,`normal`,`midrange`,`distant`,`none`],radius:[Wg],shadow:[Wg],spacing:[`px`,J],text:[Wg],"text-shadow":[Wg],tracking:[`tighter`,`tight`,`normal`,`wide`,`wider`,`widest`]},classGroups:{aspect:[{aspect:[`auto`,`square`,Vg,Y,X,g]}],container:[`container`],columns:[{columns:[J,Y,X,s]}],"break-after":[{"break-after":y()}],"break-before":[{"break-before":y()}],"break-inside":[{"break-inside":[`auto`,`avoid`,`avoid-page`,`avoid-column`]}],"box-decoration":[{"box-decoration":[`slice`,`clone`]}],box:[{box:[`border`,`content`]}],display:[`block`,`inline-block`,`inline`,`flex`,`inline-flex`,`table`,`inline-table`,`table-caption`,`table-cell`,`table-column`,`table-column-group`,`table-footer-group`,`table-header-group`,`table-row-group`,`table-row`,`flow-root`,`grid`,`inline-grid`,`contents`,`list-item`,`hidden`],sr:[`sr-only`,`not-sr-only`],float:[{float:[`right`,`left`,`none`,`start`,`end`]}],clear:[{clear:[`left`,`right`,`both`,`none`,`start`,`end`]}],isolation:[`isolate`,`isolation-auto`],"object-fit":[{object:[`contain`,`cover`,`fill`,`none`,`scale-down`]}],"object-position":[{object:x()}],overflow:[{overflow:S()}],"overflow-x":[{"overflow-x":S()}],"overflow-y":[{"overflow-y":S()}],overscroll:[{overscroll:C()}],"overscroll-x":[{"overscroll-x":C()}],"overscroll-y":[{"overscroll-y":C()}],position:[`static`,`fixed`,`absolute`,`relative`,`sticky`],inset:[{inset:T()}],"inset-x":[{"inset-x":T()}],"inset-y":[{"inset-y":T()}],start:[{start:T()}],end:[{end:T()}],top:[{top:T()}],right:[{right:T()}],bottom:[{bottom:T()}],left:[{left:T()}],visibility:[`visible`,`invisible`,`collapse`],z:[{z:[Hg,`auto`,X,Y]}],basis:[{basis:[Vg,`full`,`auto`,s,...w()]}],"flex-direction":[{flex:[`row`,`row-reverse`,`col`,`col-reverse`]}],"flex-wrap":[{flex:[`nowrap`,`wrap`,`wrap-reverse`]}],flex:[{flex:[J,Vg,`auto`,`initial`,`none`,Y]}],grow:[{grow:[``,J,X,Y]}],shrink:[{shrink:[``,J,X,Y]}],order:[{order:[Hg,`first`,`last`,`none`,X,Y]}],"grid-cols":[{"grid-cols":ee()}],"col-start-end":[{col:E()}],"col-start":[{"col-start":te()}],"col-end":[{"col-end":te()}],"grid-rows":[{"grid-rows":ee()}],"row-start-end":[{row:E()}],"row-start":[{"row-start":te()}],"row-end":[{"row-end":te()}],"grid-flow":[{"grid-flow":[`row`,`col`,`dense`,`row-dense`,`col-dense`]}],"auto-cols":[{"auto-cols":ne()}],"auto-rows":[{"auto-rows":ne()}],gap:[{gap:w()}],"gap-x":[{"gap-x":w()}],"gap-y":[{"gap-y":w()}],"justify-content":[{justify:[...re(),`normal`]}],"justify-items":[{"justify-items":[...D(),`normal`]}],"justify-self":[{"justify-self":[`auto`,...D()]}],"align-content":[{content:[`normal`,...re()]}],"align-items":[{items:[...D(),{baseline:[``,`last`]}]}],"align-self":[{self:[`auto`,...D(),{baseline:[``,`last`]}]}],"place-content":[{"place-content":re()}],"place-items":[{"place-items":[...D(),`baseline`]}],"place-self":[{"place-self":[`auto`,...D()]}],p:[{p:w()}],px:[{px:w()}],py:[{py:w()}],ps:[{ps:w()}],pe:[{pe:w()}],pt:[{pt:w()}],pr:[{pr:w()}],pb:[{pb:w()}],pl:[{pl:w()}],m:[{m:ie()}],mx:[{mx:ie()}],my:[{my:ie()}],ms:[{ms:ie()}],me:[{me:ie()}],mt:[{mt:ie()}],mr:[{mr:ie()}],mb:[{mb:ie()}],ml:[{ml:ie()}],"space-x":[{"space-x":w()}],"space-x-reverse":[`space-x-reverse`],"space-y":[{"space-y":w()}],"space-y-reverse":[`space-y-reverse`],size:[{size:O()}],w:[{w:[s,`screen`,...O()]}],"min-w":[{"min-w":[s,`screen`,`none`,...O()]}],"max-w":[{"max-w":[s,`screen`,`none`,`prose`,{screen:[o]},...O()]}],h:[{h:[`screen`,`lh`,...O()]}],"min-h":[{"min-h":[`screen`,`lh`,`none`,...O()]}],"max-h":[{"max-h":[`screen`,`lh`,...O()]}],"font-size":[{text:[`base`,n,r_,Qg]}],"font-smoothing":[`antialiased`,`subpixel-antialiased`],"font-style":[`italic`,`not-italic`],"font-weight":[{font:[r,X,$g]}],"font-stretch":[{"font-stretch":[`ultra-condensed`,`extra-condensed`,`condensed`,`semi-condensed`,`normal`,`semi-expanded`,`expanded`,`extra-expanded`,`ultra-expanded`,Ug,Y]}],"font-family":[{font:[i_,Y,t]}],"fvn-normal":[`normal-nums`],"fvn-ordinal":[`ordinal`],"fvn-slashed-zero":[`slashed-zero`],"fvn-figure":[`lining-nums`,`oldstyle-nums`],"fvn-spacing":[`proportional-nums`,`tabular-nums`],"fvn-fraction":[`diagonal-fractions`,`stacked-fractions`],tracking:[{tracking:[i,X,Y]}],"line-clamp":[{"line-clamp":[J,`none`,X,$g]}],leading:[{leading:[a,...w()]}],"list-image":[{"list-image":[`none`,X,Y]}],"list-style-position":[{list:[`inside`,`outside`]}],"list-style-type":[{list:[`disc`,`decimal`,`none`,X,Y]}],"text-alignment":[{text:[`left`,`center`,`right`,`justify`,`start`,`end`]}],"placeholder-color":[{placeholder:k()}],"text-color":[{text:k()}],"text-decoration":[`underline`,`overline`,`line-through`,`no-underline`],"text-decoration-style":[{decoration:[...de(),`wavy`]}],"text-decoration-thickness":[{decoration:[J,`from-font`,`auto`,X,Qg]}],"text-decoration-color":[{decoration:k()}],"underline-offset":[{"underline-offset":[J,`auto`,X,Y]}],"text-transform":[`uppercase`,`lowercase`,`capitalize`,`normal-case`],"text-overflow":[`truncate`,`text-ellipsis`,`text-clip`],"text-wrap":[{text:[`wrap`,`nowrap`,`balance`,`pretty`]}],indent:[{indent:w()}],"vertical-align":[{align:[`baseline`,`top`,`middle`,`bottom`,`text-top`,`text-bottom`,`sub`,`super`,X,Y]}],whitespace:[{whitespace:[`normal`,`nowrap`,`pre`,`pre-line`,`pre-wrap`,`break-spaces`]}],break:[{break:[`normal`,`words`,`all`,`keep`]}],wrap:[{wrap:[`break-word`,`anywhere`,`normal`]}],hyphens:[{hyphens:[`none`,`manual`,`auto`]}],content:[{content:[`none`,X,Y]}],"bg-attachment":[{bg:[`fixed`,`local`,`scroll`]}],"bg-clip":[{"bg-clip":[`border`,`padding`,`content`,`text`]}],"bg-origin":[{"bg-origin":[`border`,`padding`,`content`]}],"bg-position":[{bg:ae()}],"bg-repeat":[{bg:oe()}],"bg-size":[{bg:se()}],"bg-image":[{bg:[`none`,{linear:[{to:[`t`,`tr`,`r`,`br`,`b`,`bl`,`l`,`tl`]},Hg,X,Y],radial:[``,X,Y],conic:[Hg,X,Y]},s_,t_]}],"bg-color":[{bg:k()}],"gradient-from-pos":[{from:ce()}],"gradient-via-pos":[{via:ce()}],"gradient-to-pos":[{to:ce()}],"gradient-from":[{from:k()}],"gradient-via":[{via:k()}],"gradient-to":[{to:k()}],rounded:[{rounded:le()}],"rounded-s":[{"rounded-s":le()}],"rounded-e":[{"rounded-e":le()}],"rounded-t":[{"rounded-t":le()}],"rounded-r":[{"rounded-r":le()}],"rounded-b":[{"rounded-b":le()}],"rounded-l":[{"rounded-l":le()}],"rounded-ss":[{"rounded-ss":le()}],"rounded-se":[{"rounded-se":le()}],"rounded-ee":[{"rounded-ee":le()}],"rounded-es":[{"rounded-es":le()}],"rounded-tl":[{"rounded-tl":le()}],"rounded-tr":[{"rounded-tr":le()}],"rounded-br":[{"rounded-br":le()}],"rounded-bl":[{"rounded-bl":le()}],"border-w":[{border:ue()}],"border-w-x":[{"border-x":ue()}],"border-w-y":[{"border-y":ue()}],"border-w-s":[{"border-s":ue()}],"border-w-e":[{"border-e":ue()}],"border-w-t":[{"border-t":ue()}],"border-w-r":[{"border-r":ue()}],"border-w-b":[{"border-b":ue()}],"border-w-l":[{"border-l":ue()}],"divide-x":[{"divide-x":ue()}],"divide-x-reverse":[`divide-x-reverse`],"divide-y":[{"divide-y":ue()}],"divide-y-reverse":[`divide-y-reverse`],"border-style":[{border:[...de(),`hidden`,`none`]}],"divide-style":[{divide:[...de(),`hidden`,`none`]}],"border-color":[{border:k()}],"border-color-x":[{"border-x":k()}],"border-color-y":[{"border-y":k()}],"border-color-s":[{"border-s":k()}],"border-color-e":[{"border-e":k()}],"border-color-t":[{"border-t":k()}],"border-color-r":[{"border-r":k()}],"border-color-b":[{"border-b":k()}],"border-color-l":[{"border-l":k()}],"divide-color":[{divide:k()}],"outline-style":[{outline:[...de(),`none`,`hidden`]}],"outline-offset":[{"outline-offset":[J,X,Y]}],"outline-w":[{outline:[``,J,r_,Qg]}],"outline-color":[{outline:k()}],shadow:[{shadow:[``,`none`,u,c_,n_]}],"shadow-color":[{shadow:k()}],"inset-shadow":[{"inset-shadow":[`none`,d,c_,n_]}],"inset-shadow-color":[{"inset-shadow":k()}],"ring-w":[{ring:ue()}],"ring-w-inset":[`ring-inset`],"ring-color":[{ring:k()}],"ring-offset-w":[{"ring-offset":[J,Qg]}],"ring-offset-color":[{"ring-offset":k()}],"inset-ring-w":[{"inset-ring":ue()}],"inset-ring-color":[{"inset-ring":k()}],"text-shadow":[{"text-shadow":[`none`,f,c_,n_]}],"text-shadow-color":[{"text-shadow":k()}],opacity:[{opacity:[J,X,Y]}],"mix-blend":[{"mix-blend":[...fe(),`plus-darker`,`plus-lighter`]}],"bg-blend":[{"bg-blend":fe()}],"mask-clip":[{"mask-clip":[`border`,`padding`,`content`,`fill`,`stroke`,`view`]},`mask-no-clip`],"mask-composite":[{mask:[`add`,`subtract`,`intersect`,`exclude`]}],"mask-image-linear-pos":[{"mask-linear":[J]}],"mask-image-linear-from-pos":[{"mask-linear-from":pe()}],"mask-image-linear-to-pos":[{"mask-linear-to":pe()}],"mask-image-linear-from-color":[{"mask-linear-from":k()}],"mask-image-linear-to-color":[{"mask-linear-to":k()}],"mask-image-t-from-pos":[{"mask-t-from":pe()}],"mask-image-t-to-pos":[{"mask-t-to":pe()}],"mask-image-t-from-color":[{"mask-t-from":k()}],"mask-image-t-to-color":[{"mask-t-to":k()}],"mask-image-r-from-pos":[{"mask-r-from":pe()}],"mask-image-r-to-pos":[{"mask-r-to":pe()}],"mask-image-r-from-color":[{"mask-r-from":k()}],"mask-image-r-to-color":[{"mask-r-to":k()}],"mask-image-b-from-pos":[{"mask-b-from":pe()}],"mask-image-b-to-pos":[{"mask-b-to":pe()}],"mask-image-b-from-color":[{"mask-b-from":k()}],"mask-image-b-to-color":[{"mask-b-to":k()}],"mask-image-l-from-pos":[{"mask-l-from":pe()}],"mask-image-l-to-pos":[{"mask-l-to":pe()}],"mask-image-l-from-color":[{"mask-l-from":k()}],"mask-image-l-to-color":[{"mask-l-to":k()}],"mask-image-x-from-pos":[{"mask-x-from":pe()}],"mask-image-x-to-pos":[{"mask-x-to":pe()}],"mask-image-x-from-color":[{"mask-x-from":k()}],"mask-image-x-to-color":[{"mask-x-to":k()}],"mask-image-y-from-pos":[{"mask-y-from":pe()}],"mask-image-y-to-pos":[{"mask-y-to":pe()}],"mask-image-y-from-color":[{"mask-y-from":k()}],"mask-image-y-to-color":[{"mask-y-to":k()}],"mask-image-radial":[{"mask-radial":[X,Y]}],"mask-image-radial-from-pos":[{"mask-radial-from":pe()}],"mask-image-radial-to-pos":[{"mask-radial-to":pe()}],"mask-image-radial-from-color":[{"mask-radial-from":k()}],"mask-image-radial-to-color":[{"mask-radial-to":k()}],"mask-image-radial-shape":[{"mask-radial":[`circle`,`ellipse`]}],"mask-image-radial-size":[{"mask-radial":[{closest:[`side`,`corner`],farthest:[`side`,`corner`]}]}],"mask-image-radial-pos":[{"mask-radial-at":b()}],"mask-image-conic-pos":[{"mask-conic":[J]}],"mask-image-conic-from-pos":[{"mask-conic-from":pe()}],"mask-image-conic-to-pos":[{"mask-conic-to":pe()}],"mask-image-conic-from-color":[{"mask-conic-from":k()}],"mask-image-conic-to-color":[{"mask-conic-to":k()}],"mask-mode":[{mask:[`alpha`,`luminance`,`match`]}],"mask-origin":[{"mask-origin":[`border`,`padding`,`content`,`fill`,`stroke`,`view`]}],"mask-position":[{mask:ae()}],"mask-repeat":[{mask:oe()}],"mask-size":[{mask:se()}],"mask-type":[{"mask-type":[`alpha`,`luminance`]}],"mask-image":[{mask:[`none`,X,Y]}],filter:[{filter:[``,`none`,X,Y]}],blur:[{blur:me()}],brightness:[{brightness:[J,X,Y]}],contrast:[{contrast:[J,X,Y]}],"drop-shadow":[{"drop-shadow":[``,`none`,p,c_,n_]}],"drop-shadow-color":[{"drop-shadow":k()}],grayscale:[{grayscale:[``,J,X,Y]}],"hue-rotate":[{"hue-rotate":[J,X,Y]}],invert:[{invert:[``,J,X,Y]}],saturate:[{saturate:[J,X,Y]}],sepia:[{sepia:[``,J,X,Y]}],"backdrop-filter":[{"backdrop-filter":[``,`none`,X,Y]}],"backdrop-blur":[{"backdrop-blur":me()}],"backdrop-brightness":[{"backdrop-brightness":[J,X,Y]}],"backdrop-contrast":[{"backdrop-contrast":[J,X,Y]}],"backdrop-grayscale":[{"backdrop-grayscale":[``,J,X,Y]}],"backdrop-hue-rotate":[{"backdrop-hue-rotate":[J,X,Y]}],"backdrop-invert":[{"backdrop-invert":[``,J,X,Y]}],"backdrop-opacity":[{"backdrop-opacity":[J,X,Y]}],"backdrop-saturate":[{"backdrop-saturate":[J,X,Y]}],"backdrop-sepia":[{"backdrop-sepia":[``,J,X,Y]}],"border-collapse":[{border:[`collapse`,`separate`]}],"border-spacing":[{"border-spacing":w()}],"border-spacing-x":[{"border-spacing-x":w()}],"border-spacing-y":[{"border-spacing-y":w()}],"table-layout":[{table:[`auto`,`fixed`]}],caption:[{caption:[`top`,`bottom`]}],transition:[{transition:[``,`all`,`colors`,`opacity`,`shadow`,`transform`,`none`,X,Y]}],"transition-behavior":[{transition:[`normal`,`discrete`]}],duration:[{duration:[J,`initial`,X,Y]}],ease:[{ease:[`linear`,`initial`,_,X,Y]}],delay:[{delay:[J,X,Y]}],animate:[{animate:[`none`,v,X,Y]}],backface:[{backface:[`hidden`,`visible`]}],perspective:[{perspective:[h,X,Y]}],"perspective-origin":[{"perspective-origin":x()}],rotate:[{rotate:he()}],"rotate-x":[{"rotate-x":he()}],"rotate-y":[{"rotate-y":he()}],"rotate-z":[{"rotate-z":he()}],scale:[{scale:ge()}],"scale-x":[{"scale-x":ge()}],"scale-y":[{"scale-y":ge()}],"scale-z":[{"scale-z":ge()}],"scale-3d":[`scale-3d`],skew:[{skew:_e()}],"skew-x":[{"skew-x":_e()}],"skew-y":[{"skew-y":_e()}],transform:[{transform:[X,Y,``,`none`,`gpu`,`cpu`]}],"transform-origin":[{origin:x()}],"transform-style":[{transform:[`3d`,`flat`]}],translate:[{translate:ve()}],"translate-x":[{"translate-x":ve()}],"translate-y":[{"translate-y":ve()}],"translate-z":[{"translate-z":ve()}],"translate-none":[`translate-none`],accent:[{accent:k()}],appearance:[{appearance:[`none`,`auto`]}],"caret-color":[{caret:k()}],"color-scheme":[{scheme:[`normal`,`dark`,`light`,`light-dark`,`only-dark`,`only-light`]}],cursor:[{cursor:[`auto`,`default`,`pointer`,`wait`,`text`,`move`,`help`,`not-allowed`,`none`,`context-menu`,`progress`,`cell`,`crosshair`,`vertical-text`,`alias`,`copy`,`no-drop`,`grab`,`grabbing`,`all-scroll`,`col-resize`,`row-resize`,`n-resize`,`e-resize`,`s-resize`,`w-resize`,`ne-resize`,`nw-resize`,`se-resize`,`sw-resize`,`ew-resize`,`ns-resize`,`nesw-resize`,`nwse-resize`,`zoom-in`,`zoom-out`,X,Y]}],"field-sizing":[{"field-sizing":[`fixed`,`content`]}],"pointer-events":[{"pointer-events":[`auto`,`none`]}],resize:[{resize:[`none`,``,`y`,`x`]}],"scroll-behavior":[{scroll:[`auto`,`smooth`]}],"scroll-m":[{"scroll-m":w()}],"scroll-mx":[{"scroll-mx":w()}],"scroll-my":[{"scroll-my":w()}],"scroll-ms":[{"scroll-ms":w()}],"scroll-me":[{"scroll-me":w()}],"scroll-mt":[{"scroll-mt":w()}],"scroll-mr":[{"scroll-mr":w()}],"scroll-mb":[{"scroll-mb":w()}],"scroll-ml":[{"scroll-ml":w()}],"scroll-p":[{"scroll-p":w()}],"scroll-px":[{"scroll-px":w()}],"scroll-py":[{"scroll-py":w()}],"scroll-ps":[{"scroll-ps":w()}],"scroll-pe":[{"scroll-pe":w()}],"scroll-pt":[{"scroll-pt":w()}],"scroll-pr":[{"scroll-pr":w()}],"scroll-pb":[{"scroll-pb":w()}],"scroll-pl":[{"scroll-pl":w()}],"snap-align":[{snap:[`start`,`end`,`center`,`align-none`]}],"snap-stop":[{snap:[`normal`,`always`]}],"snap-type":[{snap:[`none`,`x`,`y`,`both`]}],"snap-strictness":[{snap:[`mandatory`,`proximity`]}],touch:[{touch:[`auto`,`none`,`manipulation`]}],"touch-x":[{"touch-pan":[`x`,`left`,`right`]}],"touch-y":[{"touch-pan":[`y`,`up`,`down`]}],"touch-pz":[`touch-pinch-zoom`],select:[{select:[`none`,`text`,`all`,`auto`]}],"will-change":[{"will-change":[`auto`,`scroll`,`contents`,`transform`,X,Y]}],fill:[{fill:[`none`,...k()]}],"stroke-w":[{stroke:[J,r_,Qg,$g]}],stroke:[{stroke:[`none`,...k()]}],"forced-color-adjust":[{"forced-color-adjust":[`auto`,`none`]}]},conflictingClassGroups:{overflow:[`overflow-x`,`overflow-y`],overscroll:[`overscroll-x`,`overscroll-y`],inset:[`inset-x`,`inset-y`,`start`,`end`,`top`,`right`,`bottom`,`left`],"inset-x":[`right`,`left`],"inset-y":[`top`,`bottom`],flex:[`basis`,`grow`,`shrink`],gap:[`gap-x`,`gap-y`],p:[`px`,`py`,`ps`,`pe`,`pt`,`pr`,`pb`,`pl`],px:[`pr`,`pl`],py:[`pt`,`pb`],m:[`mx`,`my`,`ms`,`me`,`mt`,`mr`,`mb`,`ml`],mx:[`mr`,`ml`],my:[`mt`,`mb`],size:[`w`,`h`],"font-size":[`leading`],"fvn-normal":[`fvn-ordinal`,`fvn-slashed-zero`,`fvn-figure`,`fvn-spacing`,`fvn-fraction`],"fvn-ordinal":[`fvn-normal`],"fvn-slashed-zero":[`fvn-normal`],"fvn-figure":[`fvn-normal`],"fvn-spacing":[`fvn-normal`],"fvn-fraction":[`fvn-normal`],"line-clamp":[`display`,`overflow`],rounded:[`rounded-s`,`rounded-e`,`rounded-t`,`rounded-r`,`rounded-b`,`rounded-l`,`rounded-ss`,`rounded-se`,`rounded-ee`,`rounded-es`,`rounded-tl`,`rounded-tr`,`rounded-br`,`rounded-bl`],"rounded-s":[`rounded-ss`,`rounded-es`],"rounded-e":[`rounded-se`,`rounded-ee`],"rounded-t":[`rounded-tl`,`rounded-tr`],"rounded-r":[`rounded-tr`,`rounded-br`],"rounded-b":[`rounded-br`,`rounded-bl`],"rounded-l":[`rounded-tl`,`rounded-bl`],"border-spacing":[`border-spacing-x`,`border-spacing-y`],"border-w":[`border-w-x`,`border-w-y`,`border-w-s`,`border-w-e`,`border-w-t`,`border-w-r`,`border-w-b`,`border-w-l`],"border-w-x":[`border-w-r`,`border-w-l`],"border-w-y":[`border-w-t`,`border-w-b`],"border-color":[`border-color-x`,`border-color-y`,`border-color-s`,`border-color-e`,`border-color-t`,`border-color-r`,`border-color-b`,`border-color-l`],"border-color-x":[`border-color-r`,`border-color-l`],"border-color-y":[`border-color-t`,`border-color-b`],translate:[`translate-x`,`translate-y`,`translate-none`],"translate-none":[`translate`,`translate-x`,`translate-y`,`translate-z`],"scroll-m":[`scroll-mx`,`scroll-my`,`scroll-ms`,`scroll-me`,`scroll-mt`,`scroll-mr`,`scroll-mb`,`scroll-ml`],"scroll-mx":[`scroll-mr`,`scroll-ml`],"scroll-my":[`scroll-mt`,`scroll-mb`],"scroll-p":[`scroll-px`,`scroll-py`,`scroll-ps`,`scroll-pe`,`scroll-pt`,`scroll-pr`,`scroll-pb`,`scroll-pl`],"scroll-px":[`scroll-pr`,`scroll-pl`],"scroll-py":[`scroll-pt`,`scroll-pb`],touch:[`touch-x`,`touch-y`,`touch-pz`],"touch-x":[`touch`],"touch-y":[`touch`],"touch-pz":[`touch`]},conflictingClassGroupModifiers:{"font-size":[`leading`]},orderSensitiveModifiers:[`*`,`**`,`after`,`backdrop`,`before`,`details-content`,`file`,`first-letter`,`first-line`,`marker`,`placeholder`,`selection`]}});function Z(...e){return v_(lu(e))}function y_(e,t=2){if(e===0)return`0B`;let n=1024,r=t<0?0:t,i=[`B`,`K`,`M`,`G`,`T`,`P`,`E`,`Z`,`Y`],a=Math.floor(Math.log(e)/Math.log(n));return parseFloat((e/n**+a).toFixed(r))+(i[a]||`B`)}function b_(e){let t=new Date(e*1e3),n=t.getDate().toString().padStart(2,`0`),r=(t.getMonth()+1).toString().padStart(2,`0`);return`${t.getFullYear().toString().padStart(2,`0`)}:${r}:${n} ${t.getHours().toString().padStart(2,`0`)}:${t.getMinutes().toString().padStart(2,`0`)}:${t.getSeconds().toString().padStart(2,`0`)}`}var x_={class:`absolute top-full left-0 isolate z-50 flex justify-center`},S_=F({__name:`NavigationMenuViewport`,props:{forceMount:{type:Boolean},align:{},asChild:{type:Boolean},as:{},class:{}},setup(e){let t=e,n=_d(_u(t,`class`));return(e,r)=>(z(),B(`div`,x_,[U(N($h),W({"data-slot":`navigation-menu-viewport`},N(n),{class:N(Z)(`origin-top-center bg-popover text-popover-foreground data-[state=open]:animate-in data-[state=closed]:animate-out data-[state=closed]:zoom-out-95 data-[state=open]:zoom-in-90 relative mt-1.5 h-[var(--reka-navigation-menu-viewport-height)] w-full overflow-hidden rounded-md border shadow md:w-[var(--reka-navigation-menu-viewport-width)] left-[var(--reka-navigation-menu-viewport-left)]`,t.class)}),null,16,[`class`])]))}}),C_=F({__name:`NavigationMenu`,props:{modelValue:{},defaultValue:{},dir:{},orientation:{},delayDuration:{},skipDelayDuration:{},disableClickTrigger:{type:Boolean},disableHoverTrigger:{type:Boolean},disablePointerLeaveClose:{type:Boolean},unmountOnHide:{type:Boolean},asChild:{type:Boolean},as:{},class:{},viewport:{type:Boolean,default:!0}},emits:[`update:modelValue`],setup(e,{emit:t}){let n=e,r=t,i=vd(_u(n,`class`,`viewport`),r);return(t,r)=>(z(),V(N(zh),W({"data-slot":`navigation-menu`,"data-viewport":e.viewport},N(i),{class:N(Z)(`group/navigation-menu relative flex max-w-max flex-1 items-center justify-center`,n.class)}),{default:P(n=>[I(t.$slots,`default`,ye(fs(n))),e.viewport?(z(),V(S_,{key:0})):gs(``,!0)]),_:3},16,[`data-viewport`,`class`]))}}),w_=F({__name:`NavigationMenuItem`,props:{value:{},asChild:{type:Boolean},as:{},class:{}},setup(e){let t=e,n=_u(t,`class`);return(e,r)=>(z(),V(N(Xh),W({"data-slot":`navigation-menu-item`},N(n),{class:N(Z)(`relative`,t.class)}),{default:P(()=>[I(e.$slots,`default`)]),_:3},16,[`class`]))}}),T_=F({__name:`NavigationMenuLink`,props:{active:{type:Boolean},asChild:{type:Boolean},as:{},class:{}},emits:[`select`],setup(e,{emit:t}){let n=e,r=t,i=vd(_u(n,`class`),r);return(e,t)=>(z(),V(N(Zh),W({"data-slot":`navigation-menu-link`},N(i),{class:N(Z)(`data-[active=true]:focus:bg-accent data-[active=true]:hover:bg-accent data-[active=true]:bg-accent/50 data-[active=true]:text-accent-foreground hover:bg-accent hover:text-accent-foreground focus:bg-accent focus:text-accent-foreground ring-ring/10 dark:ring-ring/20 dark:outline-ring/40 outline-ring/50 [&_svg:not([class*='text-'])]:text-muted-foreground flex flex-col gap-1 rounded-sm p-2 text-sm transition-[color,box-shadow] focus-visible:ring-4 focus-visible:outline-1 [&_svg:not([class*='size-'])]:size-4`,n.class)}),{default:P(()=>[I(e.$slots,`default`)]),_:3},16,[`class`]))}}),E_=F({__name:`NavigationMenuList`,props:{asChild:{type:Boolean},as:{},class:{}},setup(e){let t=e,n=_d(_u(t,`class`));return(e,r)=>(z(),V(N(Qh),W({"data-slot":`navigation-menu-list`},N(n),{class:N(Z)(`group flex flex-1 list-none items-center justify-center gap-1`,t.class)}),{default:P(()=>[I(e.$slots,`default`)]),_:3},16,[`class`]))}}),D_=e=>e.replace(/([a-z0-9])([A-Z])/g,`$1-$2`).toLowerCase(),O_=e=>e.replace(/^([A-Z])|[\s-_]+(\w)/g,(e,t,n)=>n?n.toUpperCase():t.toLowerCase()),k_=e=>{let t=O_(e);return t.charAt(0).toUpperCase()+t.slice(1)},A_=(...e)=>e.filter((e,t,n)=>!!e&&e.trim()!==``&&n.indexOf(e)===t).join(` `).trim(),j_=e=>e===``,M_={xmlns:`http://www.w3.org/2000/svg`,width:24,height:24,viewBox:`0 0 24 24`,fill:`none`,stroke:`currentColor`,"stroke-width":2,"stroke-linecap":`round`,"stroke-linejoin":`round`},N_=({name:e,iconNode:t,absoluteStrokeWidth:n,"absolute-stroke-width":r,strokeWidth:i,"stroke-width":a,size:o=M_.width,color:s=M_.stroke,...c},{slots:l})=>K(`svg`,{...M_,...c,width:o,height:o,stroke:s,"stroke-width":j_(n)||j_(r)||n===!0||r===!0?Number(i||a||M_[`stroke-width`])*24/Number(o):i||a||M_[`stroke-width`],class:A_(`lucide`,c.class,...e?[`lucide-${D_(k_(e))}-icon`,`lucide-${D_(e)}`]:[`lucide-icon`])},[...t.map(e=>K(...e)),...l.default?[l.default()]:[]]),P_=(e,t)=>(n,{slots:r,attrs:i})=>K(N_,{...i,...n,iconNode:t,name:e},r),F_=P_(`chevron-right`,[[`path`,{d:`m9 18 6-6-6-6`,key:`mthhwq`}]]),I_=P_(`ellipsis`,[[`circle`,{cx:`12`,cy:`12`,r:`1`,key:`41hilf`}],[`circle`,{cx:`19`,cy:`12`,r:`1`,key:`1wjl8i`}],[`circle`,{cx:`5`,cy:`12`,r:`1`,key:`1pcz8c`}]]),L_=P_(`file-text`,[[`path`,{d:`M6 22a2 2 0 0 1-2-2V4a2 2 0 0 1 2-2h8a2.4 2.4 0 0 1 1.704.706l3.588 3.588A2.4 2.4 0 0 1 20 8v12a2 2 0 0 1-2 2z`,key:`1oefj6`}],[`path`,{d:`M14 2v5a1 1 0 0 0 1 1h5`,key:`wfsgrz`}],[`path`,{d:`M10 9H8`,key:`b1mrlr`}],[`path`,{d:`M16 13H8`,key:`t4e002`}],[`path`,{d:`M16 17H8`,key:`z1uh3a`}]]),R_=P_(`file-up`,[[`path`,{d:`M6 22a2 2 0 0 1-2-2V4a2 2 0 0 1 2-2h8a2.4 2.4 0 0 1 1.704.706l3.588 3.588A2.4 2.4 0 0 1 20 8v12a2 2 0 0 1-2 2z`,key:`1oefj6`}],[`path`,{d:`M14 2v5a1 1 0 0 0 1 1h5`,key:`wfsgrz`}],[`path`,{d:`M12 12v6`,key:`3ahymv`}],[`path`,{d:`m15 15-3-3-3 3`,key:`15xj92`}]]),z_=P_(`file`,[[`path`,{d:`M6 22a2 2 0 0 1-2-2V4a2 2 0 0 1 2-2h8a2.4 2.4 0 0 1 1.704.706l3.588 3.588A2.4 2.4 0 0 1 20 8v12a2 2 0 0 1-2 2z`,key:`1oefj6`}],[`path`,{d:`M14 2v5a1 1 0 0 0 1 1h5`,key:`wfsgrz`}]]),B_=P_(`folder-plus`,[[`path`,{d:`M12 10v6`,key:`1bos4e`}],[`path`,{d:`M9 13h6`,key:`1uhe8q`}],[`path`,{d:`M20 20a2 2 0 0 0 2-2V8a2 2 0 0 0-2-2h-7.9a2 2 0 0 1-1.69-.9L9.6 3.9A2 2 0 0 0 7.93 3H4a2 2 0 0 0-2 2v13a2 2 0 0 0 2 2Z`,key:`1kt360`}]]),V_=P_(`folder-symlink`,[[`path`,{d:`M2 9.35V5a2 2 0 0 1 2-2h3.9a2 2 0 0 1 1.69.9l.81 1.2a2 2 0 0 0 1.67.9H20a2 2 0 0 1 2 2v10a2 2 0 0 1-2 2H4a2 2 0 0 1-2-2v-3a2 2 0 0 1 2-2h7`,key:`y8kt7d`}],[`path`,{d:`m8 16 3-3-3-3`,key:`rlqrt1`}]]),H_=P_(`folder`,[[`path`,{d:`M20 20a2 2 0 0 0 2-2V8a2 2 0 0 0-2-2h-7.9a2 2 0 0 1-1.69-.9L9.6 3.9A2 2 0 0 0 7.93 3H4a2 2 0 0 0-2 2v13a2 2 0 0 0 2 2Z`,key:`1kt360`}]]),U_=P_(`house`,[[`path`,{d:`M15 21v-8a1 1 0 0 0-1-1h-4a1 1 0 0 0-1 1v8`,key:`5wwlr5`}],[`path`,{d:`M3 10a2 2 0 0 1 .709-1.528l7-6a2 2 0 0 1 2.582 0l7 6A2 2 0 0 1 21 10v9a2 2 0 0 1-2 2H5a2 2 0 0 1-2-2z`,key:`r6nss1`}]]),W_=P_(`server`,[[`rect`,{width:`20`,height:`8`,x:`2`,y:`2`,rx:`2`,ry:`2`,key:`ngkwjq`}],[`rect`,{width:`20`,height:`8`,x:`2`,y:`14`,rx:`2`,ry:`2`,key:`iecqi9`}],[`line`,{x1:`6`,x2:`6.01`,y1:`6`,y2:`6`,key:`16zg32`}],[`line`,{x1:`6`,x2:`6.01`,y1:`18`,y2:`18`,key:`nzw8ys`}]]),G_=P_(`upload`,[[`path`,{d:`M12 3v12`,key:`1x0j5s`}],[`path`,{d:`m17 8-5-5-5 5`,key:`7q97r8`}],[`path`,{d:`M21 15v4a2 2 0 0 1-2 2H5a2 2 0 0 1-2-2v-4`,key:`ih7n3h`}]]),K_=P_(`x`,[[`path`,{d:`M18 6 6 18`,key:`1bl5f8`}],[`path`,{d:`m6 6 12 12`,key:`d8bk6v`}]]),q_={class:`flex items-center gap-2 text-3xl font-bold`},J_=F({__name:`AppNavBar`,setup(e){return(e,t)=>(z(),V(N(C_),{viewport:!1},{default:P(()=>[U(N(E_),null,{default:P(()=>[U(N(w_),null,{default:P(()=>[U(N(T_),null,{default:P(()=>[H(`span`,q_,[U(N(W_),{class:`size-10`}),t[0]||=ms(`RSHttpServer`,-1)])]),_:1})]),_:1})]),_:1})]),_:1}))}}),Y_=F({__name:`Breadcrumb`,props:{class:{}},setup(e){let t=e;return(e,n)=>(z(),B(`nav`,{"aria-label":`breadcrumb`,"data-slot":`breadcrumb`,class:A(t.class)},[I(e.$slots,`default`)],2))}}),X_=F({__name:`BreadcrumbEllipsis`,props:{class:{}},setup(e){let t=e;return(e,n)=>(z(),B(`span`,{"data-slot":`breadcrumb-ellipsis`,role:`presentation`,"aria-hidden":`true`,class:A(N(Z)(`flex size-9 items-center justify-center`,t.class))},[I(e.$slots,`default`,{},()=>[U(N(I_),{class:`size-4`})]),n[0]||=H(`span`,{class:`sr-only`},`More`,-1)],2))}}),Z_=F({__name:`BreadcrumbItem`,props:{class:{}},setup(e){let t=e;return(e,n)=>(z(),B(`li`,{"data-slot":`breadcrumb-item`,class:A(N(Z)(`inline-flex items-center gap-1.5`,t.class))},[I(e.$slots,`default`)],2))}}),Q_=F({__name:`BreadcrumbLink`,props:{asChild:{type:Boolean},as:{default:`a`},class:{}},setup(e){let t=e;return(n,r)=>(z(),V(N(Vd),{"data-slot":`breadcrumb-link`,as:e.as,"as-child":e.asChild,class:A(N(Z)(`hover:text-foreground transition-colors`,t.class))},{default:P(()=>[I(n.$slots,`default`)]),_:3},8,[`as`,`as-child`,`class`]))}}),$_=F({__name:`BreadcrumbList`,props:{class:{}},setup(e){let t=e;return(e,n)=>(z(),B(`ol`,{"data-slot":`breadcrumb-list`,class:A(N(Z)(`text-muted-foreground flex flex-wrap items-center gap-1.5 text-sm break-words sm:gap-2.5`,t.class))},[I(e.$slots,`default`)],2))}}),ev=F({__name:`BreadcrumbPage`,props:{class:{}},setup(e){let t=e;return(e,n)=>(z(),B(`span`,{"data-slot":`breadcrumb-page`,role:`link`,"aria-disabled":`true`,"aria-current":`page`,class:A(N(Z)(`text-foreground font-normal`,t.class))},[I(e.$slots,`default`)],2))}}),tv=F({__name:`BreadcrumbSeparator`,props:{class:{}},setup(e){let t=e;return(e,n)=>(z(),B(`li`,{"data-slot":`breadcrumb-separator`,role:`presentation`,"aria-hidden":`true`,class:A(N(Z)(`[&>svg]:size-3.5`,t.class))},[I(e.$slots,`default`,{},()=>[U(N(F_))])],2))}}),nv=F({__name:`DropdownMenu`,props:{defaultOpen:{type:Boolean},open:{type:Boolean},dir:{},modal:{type:Boolean}},emits:[`update:open`],setup(e,{emit:t}){let n=vd(e,t);return(e,t)=>(z(),V(N(jh),W({"data-slot":`dropdown-menu`},N(n)),{default:P(t=>[I(e.$slots,`default`,ye(fs(t)))]),_:3},16))}}),rv=F({inheritAttrs:!1,__name:`DropdownMenuContent`,props:{forceMount:{type:Boolean},loop:{type:Boolean},side:{},sideOffset:{default:4},sideFlip:{type:Boolean},align:{},alignOffset:{},alignFlip:{type:Boolean},avoidCollisions:{type:Boolean},collisionBoundary:{},collisionPadding:{},arrowPadding:{},sticky:{},hideWhenDetached:{type:Boolean},positionStrategy:{},updatePositionStrategy:{},disableUpdateOnLayoutShift:{type:Boolean},prioritizePosition:{type:Boolean},reference:{},asChild:{type:Boolean},as:{},class:{}},emits:[`escapeKeyDown`,`pointerDownOutside`,`focusOutside`,`interactOutside`,`closeAutoFocus`],setup(e,{emit:t}){let n=e,r=t,i=vd(_u(n,`class`),r);return(e,t)=>(z(),V(N(Ph),null,{default:P(()=>[U(N(Mh),W({"data-slot":`dropdown-menu-content`},{...e.$attrs,...N(i)},{class:N(Z)(`bg-popover text-popover-foreground data-[state=open]:animate-in data-[state=closed]:animate-out data-[state=closed]:fade-out-0 data-[state=open]:fade-in-0 data-[state=closed]:zoom-out-95 data-[state=open]:zoom-in-95 data-[side=bottom]:slide-in-from-top-2 data-[side=left]:slide-in-from-right-2 data-[side=right]:slide-in-from-left-2 data-[side=top]:slide-in-from-bottom-2 z-50 max-h-(--reka-dropdown-menu-content-available-height) min-w-[8rem] origin-(--reka-dropdown-menu-content-transform-origin) overflow-x-hidden overflow-y-auto rounded-md border p-1 shadow-md`,n.class)}),{default:P(()=>[I(e.$slots,`default`)]),_:3},16,[`class`])]),_:3}))}}),iv=F({__name:`DropdownMenuItem`,props:{disabled:{type:Boolean},textValue:{},asChild:{type:Boolean},as:{},class:{},inset:{type:Boolean},variant:{default:`default`}},setup(e){let t=e,n=_d(_u(t,`inset`,`variant`,`class`));return(r,i)=>(z(),V(N(Nh),W({"data-slot":`dropdown-menu-item`,"data-inset":e.inset?``:void 0,"data-variant":e.variant},N(n),{class:N(Z)(`focus:bg-accent focus:text-accent-foreground data-[variant=destructive]:text-destructive data-[variant=destructive]:focus:bg-destructive/10 dark:data-[variant=destructive]:focus:bg-destructive/20 data-[variant=destructive]:focus:text-destructive data-[variant=destructive]:*:[svg]:!text-destructive [&_svg:not([class*='text-'])]:text-muted-foreground relative flex cursor-default items-center gap-2 rounded-sm px-2 py-1.5 text-sm outline-hidden select-none data-[disabled]:pointer-events-none data-[disabled]:opacity-50 data-[inset]:pl-8 [&_svg]:pointer-events-none [&_svg]:shrink-0 [&_svg:not([class*='size-'])]:size-4`,t.class)}),{default:P(()=>[I(r.$slots,`default`)]),_:3},16,[`data-inset`,`data-variant`,`class`]))}}),av=F({__name:`DropdownMenuTrigger`,props:{disabled:{type:Boolean},asChild:{type:Boolean},as:{}},setup(e){let t=_d(e);return(e,n)=>(z(),V(N(Fh),W({"data-slot":`dropdown-menu-trigger`},N(t)),{default:P(()=>[I(e.$slots,`default`)]),_:3},16))}}),ov=F({__name:`AppBreadCrumb`,props:{rootpath:{},refreshEntryInfo:{type:Function}},emits:[`update:rootpath`],setup(e,{emit:t}){let n=e,r=t,i=G(()=>n.rootpath?n.rootpath.split(`/`).filter(Boolean):[]);function a(e,t){return i.value.slice(e,t).join(`/`)}function o(e){r(`update:rootpath`,e),n.refreshEntryInfo(e)}return(e,t)=>(z(),V(N(Y_),null,{default:P(()=>[U(N($_),null,{default:P(()=>[U(N(Z_),null,{default:P(()=>[U(N(Q_),{onClick:t[0]||=()=>o(``)},{default:P(()=>[U(N(U_))]),_:1})]),_:1}),i.value.length>2?(z(),V(N(tv),{key:0})):gs(``,!0),i.value.length>2?(z(),V(N(Z_),{key:1},{default:P(()=>[U(N(nv),null,{default:P(()=>[U(N(av),{class:`flex items-center gap-1`},{default:P(()=>[U(N(X_),{class:`h-4 w-4`}),t[2]||=H(`span`,{class:`sr-only`},`Toggle menu`,-1)]),_:1}),U(N(rv),{align:`start`},{default:P(()=>[(z(!0),B(R,null,Yi(i.value.slice(0,i.value.length-2),(e,t)=>(z(),V(N(iv),{key:t,onClick:()=>o(a(0,t+1))},{default:P(()=>[ms(De(e),1)]),_:2},1032,[`onClick`]))),128))]),_:1})]),_:1})]),_:1})):gs(``,!0),i.value.length>1?(z(),V(N(tv),{key:2})):gs(``,!0),i.value.length>1?(z(),V(N(Z_),{key:3},{default:P(()=>[U(N(Q_),{onClick:t[1]||=()=>o(a(0,i.value.length-1))},{default:P(()=>[ms(De(i.value[i.value.length-2]),1)]),_:1})]),_:1})):gs(``,!0),i.value.length>0?(z(),V(N(tv),{key:4})):gs(``,!0),i.value.length>0?(z(),V(N(Z_),{key:5},{default:P(()=>[U(N(ev),null,{default:P(()=>[ms(De(i.value[i.value.length-1]),1)]),_:1})]),_:1})):gs(``,!0)]),_:1})]),_:1}))}}),sv=F({__name:`Button`,props:{variant:{},size:{},class:{},asChild:{type:Boolean},as:{default:`button`}},setup(e){let t=e;return(n,r)=>(z(),V(N(Vd),{"data-slot":`button`,as:e.as,"as-child":e.asChild,class:A(N(Z)(N(cv)({variant:e.variant,size:e.size}),t.class))},{default:P(()=>[I(n.$slots,`default`)]),_:3},8,[`as`,`as-child`,`class`]))}});const cv=fu(`inline-flex items-center justify-center gap-2 whitespace-nowrap rounded-md text-sm font-medium transition-all disabled:pointer-events-none disabled:opacity-50 [&_svg]:pointer-events-none [&_svg:not([class*='size-'])]:size-4 shrink-0 [&_svg]:shrink-0 outline-none focus-visible:border-ring focus-visible:ring-ring/50 focus-visible:ring-[3px] aria-invalid:ring-destructive/20 dark:aria-invalid:ring-destructive/40 aria-invalid:border-destructive`,{variants:{variant:{default:`bg-primary text-primary-foreground hover:bg-primary/90`,destructive:`bg-destructive text-white hover:bg-destructive/90 focus-visible:ring-destructive/20 dark:focus-visible:ring-destructive/40 dark:bg-destructive/60`,outline:`border bg-background shadow-xs hover:bg-accent hover:text-accent-foreground dark:bg-input/30 dark:border-input dark:hover:bg-input/50`,secondary:`bg-secondary text-secondary-foreground hover:bg-secondary/80`,ghost:`hover:bg-accent hover:text-accent-foreground dark:hover:bg-accent/50`,link:`text-primary underline-offset-4 hover:underline`},size:{default:`h-9 px-4 py-2 has-[>svg]:px-3`,sm:`h-8 rounded-md gap-1.5 px-3 has-[>svg]:px-2.5`,lg:`h-10 rounded-md px-6 has-[>svg]:px-4`,icon:`size-9`,"icon-sm":`size-8`,"icon-lg":`size-10`}},defaultVariants:{variant:`default`,size:`default`}});var lv=F({__name:`Dialog`,props:{open:{type:Boolean},defaultOpen:{type:Boolean},modal:{type:Boolean}},emits:[`update:open`],setup(e,{emit:t}){let n=vd(e,t);return(e,t)=>(z(),V(N(Gd),W({"data-slot":`dialog`},N(n)),{default:P(t=>[I(e.$slots,`default`,ye(fs(t)))]),_:3},16))}}),uv=F({__name:`DialogClose`,props:{asChild:{type:Boolean},as:{}},setup(e){let t=e;return(e,n)=>(z(),V(N(Kd),W({"data-slot":`dialog-close`},t),{default:P(()=>[I(e.$slots,`default`)]),_:3},16))}}),dv=F({__name:`DialogOverlay`,props:{forceMount:{type:Boolean},asChild:{type:Boolean},as:{},class:{}},setup(e){let t=e,n=_u(t,`class`);return(e,r)=>(z(),V(N(Mf),W({"data-slot":`dialog-overlay`},N(n),{class:N(Z)(`data-[state=open]:animate-in data-[state=closed]:animate-out data-[state=closed]:fade-out-0 data-[state=open]:fade-in-0 fixed inset-0 z-50 bg-black/80`,t.class)}),{default:P(()=>[I(e.$slots,`default`)]),_:3},16,[`class`]))}}),fv=F({inheritAttrs:!1,__name:`DialogContent`,props:{forceMount:{type:Boolean},disableOutsidePointerEvents:{type:Boolean},asChild:{type:Boolean},as:{},class:{},showCloseButton:{type:Boolean,default:!0}},emits:[`escapeKeyDown`,`pointerDownOutside`,`focusOutside`,`interactOutside`,`openAutoFocus`,`closeAutoFocus`],setup(e,{emit:t}){let n=e,r=t,i=vd(_u(n,`class`),r);return(t,r)=>(z(),V(N(Pf),null,{default:P(()=>[U(dv),U(N(kf),W({"data-slot":`dialog-content`},{...t.$attrs,...N(i)},{class:N(Z)(`bg-background data-[state=open]:animate-in data-[state=closed]:animate-out data-[state=closed]:fade-out-0 data-[state=open]:fade-in-0 data-[state=closed]:zoom-out-95 data-[state=open]:zoom-in-95 fixed top-[50%] left-[50%] z-50 grid w-full max-w-[calc(100%-2rem)] translate-x-[-50%] translate-y-[-50%] gap-4 rounded-lg border p-6 shadow-lg duration-200 sm:max-w-lg`,n.class)}),{default:P(()=>[I(t.$slots,`default`),e.showCloseButton?(z(),V(N(Kd),{key:0,"data-slot":`dialog-close`,class:`ring-offset-background focus:ring-ring data-[state=open]:bg-accent data-[state=open]:text-muted-foreground absolute top-4 right-4 rounded-xs opacity-70 transition-opacity hover:opacity-100 focus:ring-2 focus:ring-offset-2 focus:outline-hidden disabled:pointer-events-none [&_svg]:pointer-events-none [&_svg]:shrink-0 [&_svg:not([class*='size-'])]:size-4`},{default:P(()=>[U(N(K_)),r[0]||=H(`span`,{class:`sr-only`},`Close`,-1)]),_:1})):gs(``,!0)]),_:3},16,[`class`])]),_:3}))}}),pv=F({__name:`DialogDescription`,props:{asChild:{type:Boolean},as:{},class:{}},setup(e){let t=e,n=_d(_u(t,`class`));return(e,r)=>(z(),V(N(Af),W({"data-slot":`dialog-description`},N(n),{class:N(Z)(`text-muted-foreground text-sm`,t.class)}),{default:P(()=>[I(e.$slots,`default`)]),_:3},16,[`class`]))}}),mv=F({__name:`DialogFooter`,props:{class:{}},setup(e){let t=e;return(e,n)=>(z(),B(`div`,{"data-slot":`dialog-footer`,class:A(N(Z)(`flex flex-col-reverse gap-2 sm:flex-row sm:justify-end`,t.class))},[I(e.$slots,`default`)],2))}}),hv=F({__name:`DialogHeader`,props:{class:{}},setup(e){let t=e;return(e,n)=>(z(),B(`div`,{"data-slot":`dialog-header`,class:A(N(Z)(`flex flex-col gap-2 text-center sm:text-left`,t.class))},[I(e.$slots,`default`)],2))}}),gv=F({__name:`DialogTitle`,props:{asChild:{type:Boolean},as:{},class:{}},setup(e){let t=e,n=_d(_u(t,`class`));return(e,r)=>(z(),V(N(Ff),W({"data-slot":`dialog-title`},N(n),{class:N(Z)(`text-lg leading-none font-semibold`,t.class)}),{default:P(()=>[I(e.$slots,`default`)]),_:3},16,[`class`]))}}),_v=F({__name:`DialogTrigger`,props:{asChild:{type:Boolean},as:{}},setup(e){let t=e;return(e,n)=>(z(),V(N(If),W({"data-slot":`dialog-trigger`},t),{default:P(()=>[I(e.$slots,`default`)]),_:3},16))}}),vv=F({__name:`Input`,props:{defaultValue:{},modelValue:{},class:{}},emits:[`update:modelValue`],setup(e,{emit:t}){let n=e,r=yu(n,`modelValue`,t,{passive:!0,defaultValue:n.defaultValue});return(e,t)=>_r((z(),B(`input`,{"onUpdate:modelValue":t[0]||=e=>un(r)?r.value=e:null,"data-slot":`input`,class:A(N(Z)(`file:text-foreground placeholder:text-muted-foreground selection:bg-primary selection:text-primary-foreground dark:bg-input/30 border-input h-9 w-full min-w-0 rounded-md border bg-transparent px-3 py-1 text-base shadow-xs transition-[color,box-shadow] outline-none file:inline-flex file:h-7 file:border-0 file:bg-transparent file:text-sm file:font-medium disabled:pointer-events-none disabled:cursor-not-allowed disabled:opacity-50 md:text-sm`,`focus-visible:border-ring focus-visible:ring-ring/50 focus-visible:ring-[3px]`,`aria-invalid:ring-destructive/20 dark:aria-invalid:ring-destructive/40 aria-invalid:border-destructive`,n.class))},null,2)),[[Al,N(r)]])}}),yv=F({__name:`Label`,props:{for:{},asChild:{type:Boolean},as:{},class:{}},setup(e){let t=e,n=_u(t,`class`);return(e,r)=>(z(),V(N(Ih),W({"data-slot":`label`},N(n),{class:N(Z)(`flex items-center gap-2 text-sm leading-none font-medium select-none group-data-[disabled=true]:pointer-events-none group-data-[disabled=true]:opacity-50 peer-disabled:cursor-not-allowed peer-disabled:opacity-50`,t.class)}),{default:P(()=>[I(e.$slots,`default`)]),_:3},16,[`class`]))}}),bv=1,xv=new class{subscribers;toasts;dismissedToasts;constructor(){this.subscribers=[],this.toasts=[],this.dismissedToasts=new Set}subscribe=e=>(this.subscribers.push(e),()=>{let t=this.subscribers.indexOf(e);this.subscribers.splice(t,1)});publish=e=>{this.subscribers.forEach(t=>t(e))};addToast=e=>{this.publish(e),this.toasts=[...this.toasts,e]};create=e=>{let{message:t,...n}=e,r=typeof e.id==`number`||e.id&&e.id?.length>0?e.id:bv++,i=this.toasts.find(e=>e.id===r),a=e.dismissible===void 0?!0:e.dismissible;return this.dismissedToasts.has(r)&&this.dismissedToasts.delete(r),i?this.toasts=this.toasts.map(n=>n.id===r?(this.publish({...n,...e,id:r,title:t}),{...n,...e,id:r,dismissible:a,title:t}):n):this.addToast({title:t,...n,dismissible:a,id:r}),r};dismiss=e=>(e?(this.dismissedToasts.add(e),requestAnimationFrame(()=>this.subscribers.forEach(t=>t({id:e,dismiss:!0})))):this.toasts.forEach(e=>{this.subscribers.forEach(t=>t({id:e.id,dismiss:!0}))}),e);message=(e,t)=>this.create({...t,message:e,type:`default`});error=(e,t)=>this.create({...t,type:`error`,message:e});success=(e,t)=>this.create({...t,type:`success`,message:e});info=(e,t)=>this.create({...t,type:`info`,message:e});warning=(e,t)=>this.create({...t,type:`warning`,message:e});loading=(e,t)=>this.create({...t,type:`loading`,message:e});promise=(e,t)=>{if(!t)return;let n;t.loading!==void 0&&(n=this.create({...t,promise:e,type:`loading`,message:t.loading,description:typeof t.description==`function`?void 0:t.description}));let r=Promise.resolve(e instanceof Function?e():e),i=n!==void 0,a,o=r.then(async e=>{if(a=[`resolve`,e],os(e))i=!1,this.create({id:n,type:`default`,message:e});else if(Cv(e)&&!e.ok){i=!1;let r=typeof t.error==`function`?await t.error(`HTTP error! status: ${e.status}`):t.error,a=typeof t.description==`function`?await t.description(`HTTP error! status: ${e.status}`):t.description,o=typeof r==`object`&&!os(r)?r:{message:r||``,id:n||``};this.create({id:n,type:`error`,description:a,...o})}else if(e instanceof Error){i=!1;let r=typeof t.error==`function`?await t.error(e):t.error,a=typeof t.description==`function`?await t.description(e):t.description,o=typeof r==`object`&&!os(r)?r:{message:r||``,id:n||``};this.create({id:n,type:`error`,description:a,...o})}else if(t.success!==void 0){i=!1;let r=typeof t.success==`function`?await t.success(e):t.success,a=typeof t.description==`function`?await t.description(e):t.description,o=typeof r==`object`&&!os(r)?r:{message:r||``,id:n||``};this.create({id:n,type:`success`,description:a,...o})}}).catch(async e=>{if(a=[`reject`,e],t.error!==void 0){i=!1;let r=typeof t.error==`function`?await t.error(e):t.error,a=typeof t.description==`function`?await t.description(e):t.description,o=typeof r==`object`&&!os(r)?r:{message:r||``,id:n||``};this.create({id:n,type:`error`,description:a,...o})}}).finally(()=>{i&&(this.dismiss(n),n=void 0),t.finally?.()}),s=()=>new Promise((e,t)=>o.then(()=>a[0]===`reject`?t(a[1]):e(a[1])).catch(t));return typeof n!=`string`&&typeof n!=`number`?{unwrap:s}:Object.assign(n,{unwrap:s})};custom=(e,t)=>{let n=t?.id||bv++,r=this.toasts.find(e=>e.id===n),i=t?.dismissible===void 0?!0:t.dismissible;return this.dismissedToasts.has(n)&&this.dismissedToasts.delete(n),r?this.toasts=this.toasts.map(r=>r.id===n?(this.publish({...r,component:e,dismissible:i,id:n,...t}),{...r,component:e,dismissible:i,id:n,...t}):r):this.addToast({component:e,dismissible:i,id:n,...t}),n};getActiveToasts=()=>this.toasts.filter(e=>!this.dismissedToasts.has(e.id))};function Sv(e,t){let n=t?.id||bv++;return xv.create({message:e,id:n,type:`default`,...t}),n}var Cv=e=>e&&typeof e==`object`&&`ok`in e&&typeof e.ok==`boolean`&&`status`in e&&typeof e.status==`number`,wv=Sv,Tv=Object.assign(wv,{success:xv.success,info:xv.info,warning:xv.warning,error:xv.error,custom:xv.custom,message:xv.message,promise:xv.promise,dismiss:xv.dismiss,loading:xv.loading},{getHistory:()=>xv.toasts,getToasts:()=>xv.getActiveToasts()});function Ev(e){return e.label!==void 0}var Dv=3,Ov=`24px`,kv=`16px`,Av=4e3,jv=356,Mv=14,Nv=45,Pv=200;function Fv(){let e=M(!1);return Ua(()=>{let t=()=>{e.value=document.hidden};return document.addEventListener(`visibilitychange`,t),()=>window.removeEventListener(`visibilitychange`,t)}),{isDocumentHidden:e}}function Iv(...e){return e.filter(Boolean).join(` `)}function Lv(e){let[t,n]=e.split(`-`),r=[];return t&&r.push(t),n&&r.push(n),r}function Rv(e,t){let n={};return[e,t].forEach((e,t)=>{let r=t===1,i=r?`--mobile-offset`:`--offset`,a=r?kv:Ov;function o(e){[`top`,`right`,`bottom`,`left`].forEach(t=>{n[`${i}-${t}`]=typeof e==`number`?`${e}px`:e})}typeof e==`number`||typeof e==`string`?o(e):typeof e==`object`?[`top`,`right`,`bottom`,`left`].forEach(t=>{e[t]===void 0?n[`${i}-${t}`]=a:n[`${i}-${t}`]=typeof e[t]==`number`?`${e[t]}px`:e[t]}):o(a)}),n}var zv=[`data-rich-colors`,`data-styled`,`data-mounted`,`data-promise`,`data-swiped`,`data-removed`,`data-visible`,`data-y-position`,`data-x-position`,`data-index`,`data-front`,`data-swiping`,`data-dismissible`,`data-type`,`data-invert`,`data-swipe-out`,`data-swipe-direction`,`data-expanded`,`data-testid`],Bv=[`aria-label`,`data-disabled`,`data-close-button-position`],Vv=F({__name:`Toast`,props:{toast:{},toasts:{},index:{},swipeDirections:{},expanded:{type:Boolean},invert:{type:Boolean},heights:{},gap:{},position:{},closeButtonPosition:{},visibleToasts:{},expandByDefault:{type:Boolean},closeButton:{type:Boolean},interacting:{type:Boolean},style:{},cancelButtonStyle:{},actionButtonStyle:{},duration:{},class:{},unstyled:{type:Boolean},descriptionClass:{},loadingIcon:{},classes:{},icons:{},closeButtonAriaLabel:{},defaultRichColors:{type:Boolean}},emits:[`update:heights`,`update:height`,`removeToast`],setup(e,{emit:t}){let n=e,r=t,i=M(null),a=M(null),o=M(!1),s=M(!1),c=M(!1),l=M(!1),u=M(!1),d=M(0),f=M(0),p=M(n.toast.duration||n.duration||Av),m=M(null),h=M(null),g=G(()=>n.index===0),_=G(()=>n.index+1<=n.visibleToasts),v=G(()=>n.toast.type),y=G(()=>n.toast.dismissible!==!1),b=G(()=>n.toast.class||``),x=G(()=>n.descriptionClass||``),S=G(()=>{let e=n.toast.position||n.position,t=n.heights.filter(t=>t.position===e).findIndex(e=>e.toastId===n.toast.id);return t>=0?t:0}),C=G(()=>{let e=n.toast.position||n.position;return n.heights.filter(t=>t.position===e).reduce((e,t,n)=>n>=S.value?e:e+t.height,0)}),w=G(()=>S.value*n.gap+C.value||0),T=G(()=>n.toast.closeButton??n.closeButton),ee=G(()=>n.toast.duration||n.duration||Av),E=M(0),te=M(0),ne=M(null),re=G(()=>n.position.split(`-`)),D=G(()=>re.value[0]),ie=G(()=>re.value[1]),O=G(()=>typeof n.toast.title!=`string`),k=G(()=>typeof n.toast.description!=`string`),{isDocumentHidden:ae}=Fv(),oe=G(()=>v.value&&v.value===`loading`);Mi(()=>{o.value=!0,p.value=ee.value}),Ua(async()=>{if(!o.value||!h.value)return;await $n();let e=h.value,t=e.style.height;e.style.height=`auto`;let i=e.getBoundingClientRect().height;e.style.height=t,f.value=i,r(`update:height`,{toastId:n.toast.id,height:i,position:n.toast.position||n.position})});function se(){s.value=!0,d.value=w.value,setTimeout(()=>{r(`removeToast`,n.toast)},Pv)}function ce(){if(oe.value||!y.value)return{};se(),n.toast.onDismiss?.(n.toast)}function le(e){e.button!==2&&(oe.value||!y.value||(m.value=new Date,d.value=w.value,e.target.setPointerCapture(e.pointerId),e.target.tagName!==`BUTTON`&&(c.value=!0,ne.value={x:e.clientX,y:e.clientY})))}function ue(){if(l.value||!y.value)return;ne.value=null;let e=Number(h.value?.style.getPropertyValue(`--swipe-amount-x`).replace(`px`,``)||0),t=Number(h.value?.style.getPropertyValue(`--swipe-amount-y`).replace(`px`,``)||0),r=new Date().getTime()-(m.value?.getTime()||0),o=i.value===`x`?e:t,s=Math.abs(o)/r;if(Math.abs(o)>=Nv||s>.11){d.value=w.value,n.toast.onDismiss?.(n.toast),i.value===`x`?a.value=e>0?`right`:`left`:a.value=t>0?`down`:`up`,se(),l.value=!0;return}else h.value?.style.setProperty(`--swipe-amount-x`,`0px`),h.value?.style.setProperty(`--swipe-amount-y`,`0px`);u.value=!1,c.value=!1,i.value=null}function de(e){if(!ne.value||!y.value||(window?.getSelection()?.toString()?.length??!1))return;let t=e.clientY-ne.value.y,r=e.clientX-ne.value.x,a=n.swipeDirections??Lv(n.position);!i.value&&(Math.abs(r)>1||Math.abs(t)>1)&&(i.value=Math.abs(r)>Math.abs(t)?`x`:`y`);let o={x:0,y:0},s=e=>1/(1.5+Math.abs(e)/20);if(i.value===`y`){if(a.includes(`top`)||a.includes(`bottom`))if(a.includes(`top`)&&t<0||a.includes(`bottom`)&&t>0)o.y=t;else{let e=t*s(t);o.y=Math.abs(e)<Math.abs(t)?e:t}}else if(i.value===`x`&&(a.includes(`left`)||a.includes(`right`)))if(a.includes(`left`)&&r<0||a.includes(`right`)&&r>0)o.x=r;else{let e=r*s(r);o.x=Math.abs(e)<Math.abs(r)?e:r}(Math.abs(o.x)>0||Math.abs(o.y)>0)&&(u.value=!0),h.value?.style.setProperty(`--swipe-amount-x`,`${o.x}px`),h.value?.style.setProperty(`--swipe-amount-y`,`${o.y}px`)}Mi(()=>{if(o.value=!0,!h.value)return;let e=h.value.getBoundingClientRect().height;f.value=e,r(`update:heights`,[{toastId:n.toast.id,height:e,position:n.toast.position},...n.heights])}),Fi(()=>{h.value&&r(`removeToast`,n.toast)}),Ua(e=>{if(n.toast.promise&&v.value===`loading`||n.toast.duration===1/0||n.toast.type===`loading`)return;let t;n.expanded||n.interacting||ae.value?(()=>{if(te.value<E.value){let e=new Date().getTime()-E.value;p.value-=e}te.value=new Date().getTime()})():p.value!==1/0&&(E.value=new Date().getTime(),t=setTimeout(()=>{n.toast.onAutoClose?.(n.toast),se()},p.value)),e(()=>{clearTimeout(t)})}),L(()=>n.toast.delete,e=>{e!==void 0&&e&&(se(),n.toast.onDismiss?.(n.toast))},{deep:!0});function fe(){c.value=!1,i.value=null,ne.value=null}return(e,t)=>(z(),B(`li`,{tabindex:`0`,ref_key:`toastRef`,ref:h,class:A(N(Iv)(n.class,b.value,e.classes?.toast,e.toast.classes?.toast,e.classes?.[v.value],e.toast?.classes?.[v.value])),"data-sonner-toast":``,"data-rich-colors":e.toast.richColors??e.defaultRichColors,"data-styled":!(e.toast.component||e.toast?.unstyled||e.unstyled),"data-mounted":o.value,"data-promise":!!e.toast.promise,"data-swiped":u.value,"data-removed":s.value,"data-visible":_.value,"data-y-position":D.value,"data-x-position":ie.value,"data-index":e.index,"data-front":g.value,"data-swiping":c.value,"data-dismissible":y.value,"data-type":v.value,"data-invert":e.toast.invert||e.invert,"data-swipe-out":l.value,"data-swipe-direction":a.value,"data-expanded":!!(e.expanded||e.expandByDefault&&o.value),"data-testid":e.toast.testId,style:me({"--index":e.index,"--toasts-before":e.index,"--z-index":e.toasts.length-e.index,"--offset":`${s.value?d.value:w.value}px`,"--initial-height":e.expandByDefault?`auto`:`${f.value}px`,...e.style,...n.toast.style}),onDragend:fe,onPointerdown:le,onPointerup:ue,onPointermove:de},[T.value&&!e.toast.component&&v.value!==`loading`?(z(),B(`button`,{key:0,"aria-label":e.closeButtonAriaLabel||`Close toast`,"data-disabled":oe.value,"data-close-button":`true`,"data-close-button-position":e.closeButtonPosition,class:A(N(Iv)(e.classes?.closeButton,e.toast?.classes?.closeButton)),onClick:ce},[e.icons?.close?(z(),V(Gi(e.icons?.close),{key:0})):I(e.$slots,`close-icon`,{key:1})],10,Bv)):gs(`v-if`,!0),e.toast.component?(z(),V(Gi(e.toast.component),W({key:1},e.toast.componentProps,{onCloseToast:ce,isPaused:e.$props.expanded||e.$props.interacting||N(ae)}),null,16,[`isPaused`])):(z(),B(R,{key:2},[v.value!==`default`||e.toast.icon||e.toast.promise?(z(),B(`div`,{key:0,"data-icon":``,class:A(N(Iv)(e.classes?.icon,e.toast?.classes?.icon))},[e.toast.icon?(z(),V(Gi(e.toast.icon),{key:0})):(z(),B(R,{key:1},[v.value===`loading`?I(e.$slots,`loading-icon`,{key:0}):v.value===`success`?I(e.$slots,`success-icon`,{key:1}):v.value===`error`?I(e.$slots,`error-icon`,{key:2}):v.value===`warning`?I(e.$slots,`warning-icon`,{key:3}):v.value===`info`?I(e.$slots,`info-icon`,{key:4}):gs(`v-if`,!0)],64))],2)):gs(`v-if`,!0),H(`div`,{"data-content":``,class:A(N(Iv)(e.classes?.content,e.toast?.classes?.content))},[H(`div`,{"data-title":``,class:A(N(Iv)(e.classes?.title,e.toast.classes?.title))},[O.value?(z(),V(Gi(e.toast.title),ye(W({key:0},e.toast.componentProps)),null,16)):(z(),B(R,{key:1},[ms(De(e.toast.title),1)],64))],2),e.toast.description?(z(),B(`div`,{key:0,"data-description":``,class:A(N(Iv)(e.descriptionClass,x.value,e.classes?.description,e.toast.classes?.description))},[k.value?(z(),V(Gi(e.toast.description),ye(W({key:0},e.toast.componentProps)),null,16)):(z(),B(R,{key:1},[ms(De(e.toast.description),1)],64))],2)):gs(`v-if`,!0)],2),e.toast.cancel?(z(),B(`button`,{key:1,style:me(e.toast.cancelButtonStyle||e.cancelButtonStyle),class:A(N(Iv)(e.classes?.cancelButton,e.toast.classes?.cancelButton)),"data-button":``,"data-cancel":``,onClick:t[0]||=t=>{N(Ev)(e.toast.cancel)&&y.value&&(e.toast.cancel.onClick?.(t),se())}},De(N(Ev)(e.toast.cancel)?e.toast.cancel?.label:e.toast.cancel),7)):gs(`v-if`,!0),e.toast.action?(z(),B(`button`,{key:2,style:me(e.toast.actionButtonStyle||e.actionButtonStyle),class:A(N(Iv)(e.classes?.actionButton,e.toast.classes?.actionButton)),"data-button":``,"data-action":``,onClick:t[1]||=t=>{N(Ev)(e.toast.action)&&(e.toast.action.onClick?.(t),!t.defaultPrevented&&se())}},De(N(Ev)(e.toast.action)?e.toast.action?.label:e.toast.action),7)):gs(`v-if`,!0)],64))],46,zv))}}),Hv=(e,t)=>{let n=e.__vccOpts||e;for(let[e,r]of t)n[e]=r;return n},Uv={},Wv={xmlns:`http://www.w3.org/2000/svg`,width:`12`,height:`12`,viewBox:`0 0 24 24`,fill:`none`,stroke:`currentColor`,"stoke-width":`1.5`,"stroke-linecap":`round`,"stroke-linejoin":`round`};function Gv(e,t){return z(),B(`svg`,Wv,t[0]||=[H(`line`,{x1:`18`,y1:`6`,x2:`6`,y2:`18`},null,-1),H(`line`,{x1:`6`,y1:`6`,x2:`18`,y2:`18`},null,-1)])}var Kv=Hv(Uv,[[`render`,Gv]]),qv=[`data-visible`],Jv={class:`sonner-spinner`},Yv=F({__name:`Loader`,props:{visible:{type:Boolean}},setup(e){let t=Array(12).fill(0);return(e,n)=>(z(),B(`div`,{class:`sonner-loading-wrapper`,"data-visible":e.visible},[H(`div`,Jv,[(z(!0),B(R,null,Yi(N(t),e=>(z(),B(`div`,{key:`spinner-bar-${e}`,class:`sonner-loading-bar`}))),128))])],8,qv))}}),Xv={},Zv={xmlns:`http://www.w3.org/2000/svg`,viewBox:`0 0 20 20`,fill:`currentColor`,height:`20`,width:`20`};function Qv(e,t){return z(),B(`svg`,Zv,t[0]||=[H(`path`,{"fill-rule":`evenodd`,d:`M10 18a8 8 0 100-16 8 8 0 000 16zm3.857-9.809a.75.75 0 00-1.214-.882l-3.483 4.79-1.88-1.88a.75.75 0 10-1.06 1.061l2.5 2.5a.75.75 0 001.137-.089l4-5.5z`,"clip-rule":`evenodd`},null,-1)])}var $v=Hv(Xv,[[`render`,Qv]]),ey={},ty={xmlns:`http://www.w3.org/2000/svg`,viewBox:`0 0 20 20`,fill:`currentColor`,height:`20`,width:`20`};function ny(e,t){return z(),B(`svg`,ty,t[0]||=[H(`path`,{"fill-rule":`evenodd`,d:`M18 10a8 8 0 11-16 0 8 8 0 0116 0zm-7-4a1 1 0 11-2 0 1 1 0 012 0zM9 9a.75.75 0 000 1.5h.253a.25.25 0 01.244.304l-.459 2.066A1.75 1.75 0 0010.747 15H11a.75.75 0 000-1.5h-.253a.25.25 0 01-.244-.304l.459-2.066A1.75 1.75 0 009.253 9H9z`,"clip-rule":`evenodd`},null,-1)])}var ry=Hv(ey,[[`render`,ny]]),iy={},ay={xmlns:`http://www.w3.org/2000/svg`,viewBox:`0 0 24 24`,fill:`currentColor`,height:`20`,width:`20`};function oy(e,t){return z(),B(`svg`,ay,t[0]||=[H(`path`,{"fill-rule":`evenodd`,d:`M9.401 3.003c1.155-2 4.043-2 5.197 0l7.355 12.748c1.154 2-.29 4.5-2.599 4.5H4.645c-2.309 0-3.752-2.5-2.598-4.5L9.4 3.003zM12 8.25a.75.75 0 01.75.75v3.75a.75.75 0 01-1.5 0V9a.75.75 0 01.75-.75zm0 8.25a.75.75 0 100-1.5.75.75 0 000 1.5z`,"clip-rule":`evenodd`},null,-1)])}var sy=Hv(iy,[[`render`,oy]]),cy={},ly={xmlns:`http://www.w3.org/2000/svg`,viewBox:`0 0 20 20`,fill:`currentColor`,height:`20`,width:`20`};function uy(e,t){return z(),B(`svg`,ly,t[0]||=[H(`path`,{"fill-rule":`evenodd`,d:`M18 10a8 8 0 11-16 0 8 8 0 0116 0zm-8-5a.75.75 0 01.75.75v4.5a.75.75 0 01-1.5 0v-4.5A.75.75 0 0110 5zm0 10a1 1 0 100-2 1 1 0 000 2z`,"clip-rule":`evenodd`},null,-1)])}var dy=Hv(cy,[[`render`,uy]]),fy=[`aria-label`],py=[`data-sonner-theme`,`dir`,`data-theme`,`data-rich-colors`,`data-y-position`,`data-x-position`],my=typeof window<`u`&&typeof document<`u`;function hy(){if(typeof window>`u`||typeof document>`u`)return`ltr`;let e=document.documentElement.getAttribute(`dir`);return e===`auto`||!e?window.getComputedStyle(document.documentElement).direction:e}var gy=F({name:`Toaster`,inheritAttrs:!1,__name:`Toaster`,props:{id:{},invert:{type:Boolean,default:!1},theme:{default:`light`},position:{default:`bottom-right`},closeButtonPosition:{default:`top-left`},hotkey:{default:()=>[`altKey`,`KeyT`]},richColors:{type:Boolean,default:!1},expand:{type:Boolean,default:!1},duration:{},gap:{default:Mv},visibleToasts:{default:Dv},closeButton:{type:Boolean,default:!1},toastOptions:{default:()=>({})},class:{default:``},style:{},offset:{default:Ov},mobileOffset:{default:kv},dir:{default:`auto`},swipeDirections:{},icons:{},containerAriaLabel:{default:`Notifications`}},setup(e){let t=e,n=fa(),r=M([]),i=G(()=>t.id?r.value.filter(e=>e.toasterId===t.id):r.value.filter(e=>!e.toasterId));function a(e,t){return i.value.filter(n=>!n.position&&t===0||n.position===e)}let o=G(()=>{let e=i.value.filter(e=>e.position).map(e=>e.position);return e.length>0?Array.from(new Set([t.position].concat(e))):[t.position]}),s=G(()=>{let e={};return o.value.forEach(t=>{e[t]=r.value.filter(e=>e.position===t)}),e}),c=M([]),l=M({}),u=M(!1);Ua(()=>{o.value.forEach(e=>{e in l.value||(l.value[e]=!1)})});let d=M(t.theme===`system`?typeof window<`u`&&window.matchMedia&&window.matchMedia(`(prefers-color-scheme: dark)`).matches?`dark`:`light`:t.theme),f=M(null),p=M(null),m=M(!1),h=t.hotkey.join(`+`).replace(/Key/g,``).replace(/Digit/g,``);function g(e){r.value.find(t=>t.id===e.id)?.delete||xv.dismiss(e.id),r.value=r.value.filter(({id:t})=>t!==e.id),setTimeout(()=>{r.value.find(t=>t.id===e.id)||(c.value=c.value.filter(t=>t.toastId!==e.id))},Pv+50)}function _(e){m.value&&!e.currentTarget?.contains?.(e.relatedTarget)&&(m.value=!1,p.value&&=(p.value.focus({preventScroll:!0}),null))}function v(e){e.target instanceof HTMLElement&&e.target.dataset.dismissible===`false`||m.value||(m.value=!0,p.value=e.relatedTarget)}function y(e){e.target&&e.target instanceof HTMLElement&&e.target.dataset.dismissible===`false`||(u.value=!0)}Ua(e=>{e(xv.subscribe(e=>{if(e.dismiss){requestAnimationFrame(()=>{r.value=r.value.map(t=>t.id===e.id?{...t,delete:!0}:t)});return}$n(()=>{let t=r.value.findIndex(t=>t.id===e.id);t===-1?r.value=[e,...r.value]:r.value=[...r.value.slice(0,t),{...r.value[t],...e},...r.value.slice(t+1)]})}))}),Ua(e=>{if(typeof window>`u`)return;if(t.theme!==`system`){d.value=t.theme;return}let n=window.matchMedia(`(prefers-color-scheme: dark)`),r=e=>{d.value=e?`dark`:`light`};r(n.matches);let i=e=>{r(e.matches)};try{n.addEventListener(`change`,i)}catch{n.addListener(i)}e(()=>{try{n.removeEventListener(`change`,i)}catch{n.removeListener(i)}})}),Ua(()=>{f.value&&p.value&&(p.value.focus({preventScroll:!0}),p.value=null,m.value=!1)}),Ua(()=>{r.value.length<=1&&Object.keys(l.value).forEach(e=>{l.value[e]=!1})}),Ua(e=>{function n(e){let n=t.hotkey.every(t=>e[t]||e.code===t),r=Array.isArray(f.value)?f.value[0]:f.value;n&&(o.value.forEach(e=>{l.value[e]=!0}),r?.focus());let i=document.activeElement===f.value||r?.contains(document.activeElement);e.code===`Escape`&&i&&o.value.forEach(e=>{l.value[e]=!1})}my&&(document.addEventListener(`keydown`,n),e(()=>{document.removeEventListener(`keydown`,n)}))});function b(e){let t=e.currentTarget,n=t.getAttribute(`data-y-position`)+`-`+t.getAttribute(`data-x-position`);l.value[n]=!0}function x(e){if(!u.value){let t=e.currentTarget,n=t.getAttribute(`data-y-position`)+`-`+t.getAttribute(`data-x-position`);l.value[n]=!1}}function S(){Object.keys(l.value).forEach(e=>{l.value[e]=!1})}function C(){u.value=!1}function w(e){c.value=e}function T(e){let t=c.value.findIndex(t=>t.toastId===e.toastId);if(t!==-1)c.value[t]=e;else{let t=c.value.findIndex(t=>t.position===e.position);t===-1?c.value.unshift(e):c.value.splice(t,0,e)}}return(e,r)=>(z(),B(R,null,[gs(` Remove item from normal navigation flow, only available via hotkey `),H(`section`,{"aria-label":`${e.containerAriaLabel} ${N(h)}`,tabIndex:-1,"aria-live":`polite`,"aria-relevant":`additions text`,"aria-atomic":`false`},[(z(!0),B(R,null,Yi(o.value,(r,i)=>(z(),B(`ol`,W({key:r,ref_for:!0,ref_key:`listRef`,ref:f,"data-sonner-toaster":``,"data-sonner-theme":d.value,class:t.class,dir:e.dir===`auto`?hy():e.dir,tabIndex:-1,"data-theme":e.theme,"data-rich-colors":e.richColors,"data-y-position":r.split(`-`)[0],"data-x-position":r.split(`-`)[1],style:{"--front-toast-height":`${c.value[0]?.height||0}px`,"--width":`${N(jv)}px`,"--gap":`${e.gap}px`,...e.style,...N(n).style,...N(Rv)(e.offset,e.mobileOffset)}},{ref_for:!0},e.$attrs,{onBlur:_,onFocus:v,onMouseenter:b,onMousemove:b,onMouseleave:x,onDragend:S,onPointerdown:y,onPointerup:C}),[(z(!0),B(R,null,Yi(a(r,i),(n,i)=>(z(),V(Vv,{key:n.id,heights:c.value,icons:e.icons,index:i,toast:n,defaultRichColors:e.richColors,duration:e.toastOptions?.duration??e.duration,class:A(e.toastOptions?.class??``),descriptionClass:e.toastOptions?.descriptionClass,invert:e.invert,visibleToasts:e.visibleToasts,closeButton:e.toastOptions?.closeButton??e.closeButton,interacting:u.value,position:r,closeButtonPosition:e.toastOptions?.closeButtonPosition??e.closeButtonPosition,style:me(e.toastOptions?.style),unstyled:e.toastOptions?.unstyled,classes:e.toastOptions?.classes,cancelButtonStyle:e.toastOptions?.cancelButtonStyle,actionButtonStyle:e.toastOptions?.actionButtonStyle,"close-button-aria-label":e.toastOptions?.closeButtonAriaLabel,toasts:s.value[r],expandByDefault:e.expand,gap:e.gap,expanded:l.value[r]||!1,swipeDirections:t.swipeDirections,"onUpdate:heights":w,"onUpdate:height":T,onRemoveToast:g},{"close-icon":P(()=>[I(e.$slots,`close-icon`,{},()=>[U(Kv)])]),"loading-icon":P(()=>[I(e.$slots,`loading-icon`,{},()=>[U(Yv,{visible:n.type===`loading`},null,8,[`visible`])])]),"success-icon":P(()=>[I(e.$slots,`success-icon`,{},()=>[U($v)])]),"error-icon":P(()=>[I(e.$slots,`error-icon`,{},()=>[U(dy)])]),"warning-icon":P(()=>[I(e.$slots,`warning-icon`,{},()=>[U(sy)])]),"info-icon":P(()=>[I(e.$slots,`info-icon`,{},()=>[U(ry)])]),_:2},1032,[`heights`,`icons`,`index`,`toast`,`defaultRichColors`,`duration`,`class`,`descriptionClass`,`invert`,`visibleToasts`,`closeButton`,`interacting`,`position`,`closeButtonPosition`,`style`,`unstyled`,`classes`,`cancelButtonStyle`,`actionButtonStyle`,`close-button-aria-label`,`toasts`,`expandByDefault`,`gap`,`expanded`,`swipeDirections`]))),128))],16,py))),128))],8,fy)],2112))}}),_y={class:`grid gap-4`},vy={class:`grid gap-3`},yy=F({__name:`AppCreateFolder`,props:{rootpath:{},refreshEntryInfo:{type:Function}},setup(e){let t=e,n=M(!1),r=M(``);function i(){n.value&&=!1;let e=`/create/${t.rootpath?.trim()?t.rootpath.trim()+`/`:``}${r.value.trim()}`;fetch(e).then(e=>e.json()).then(e=>{e.code===200&&Tv.success(e.message)}).catch(e=>Tv.error(e)),r.value=``,t.refreshEntryInfo(t.rootpath)}return(e,t)=>(z(),V(N(lv),{open:n.value,"onUpdate:open":t[1]||=e=>n.value=e},{default:P(()=>[H(`form`,null,[U(N(_v),{"as-child":``},{default:P(()=>[U(N(sv),{variant:`outline`},{default:P(()=>[U(N(B_)),t[2]||=ms(`new folder `,-1)]),_:1})]),_:1}),U(N(fv),{class:`sm:max-w-[425px]`},{default:P(()=>[U(N(hv),null,{default:P(()=>[U(N(gv),null,{default:P(()=>[...t[3]||=[ms(`Create Folder`,-1)]]),_:1}),U(N(pv),null,{default:P(()=>[...t[4]||=[ms(` Create a new folder in the current location. `,-1)]]),_:1})]),_:1}),H(`div`,_y,[H(`div`,vy,[U(N(yv),{for:`fname`},{default:P(()=>[...t[5]||=[ms(`folder name`,-1)]]),_:1}),U(N(vv),{id:`fname`,modelValue:r.value,"onUpdate:modelValue":t[0]||=e=>r.value=e,"default-value":``,placeholder:`please input folder name`},null,8,[`modelValue`])])]),U(N(mv),null,{default:P(()=>[U(N(uv),{"as-child":``},{default:P(()=>[U(N(sv),{variant:`outline`},{default:P(()=>[...t[6]||=[ms(` Cancel `,-1)]]),_:1})]),_:1}),U(N(sv),{type:`submit`,onClick:i},{default:P(()=>[...t[7]||=[ms(` Save `,-1)]]),_:1})]),_:1})]),_:1})])]),_:1},8,[`open`]))}}),by={class:`grid gap-4`},xy={class:`grid gap-3`},Sy={key:0,class:`max-h-[200px] overflow-y-auto space-y-2`},Cy={class:`flex items-center gap-2 overflow-hidden`},wy={class:`flex flex-col truncate`},Ty={class:`truncate font-medium`},Ey={class:`text-xs text-gray-500`},Dy={key:1,class:`text-center py-4 text-sm text-gray-500 border-dashed border-2 rounded-md`},Oy=F({__name:`AppUploadFiles`,props:{rootpath:{},refreshEntryInfo:{type:Function}},setup(e){let t=e,n=M(!1),r=M(!1),i=M([]),a=e=>{let t=e.target;t.files&&t.files.length>0&&(i.value=[...i.value,...Array.from(t.files)],t.value=``)},o=e=>{i.value.splice(e,1)},s=e=>{if(e===0)return`0B`;let t=1024,n=[`B`,`K`,`M`,`G`],r=Math.floor(Math.log(e)/Math.log(t));return(e/t**+r).toFixed(2)+n[r]},c=async()=>{if(i.value.length!==0){try{r.value=!0,console.log(`Start uploading files...`);let e=new FormData;i.value.forEach(t=>{e.append(`files`,t)}),console.log(`Uploading:`,i.value.map(e=>e.name));let a=`/upload/${t.rootpath?.trim()?t.rootpath.trim()+`/`:``}`;console.log(`AppUploadFiles url:`,a),await fetch(a,{method:`POST`,body:e}).then(e=>e.json()).then(e=>{Tv.success(e.message)}).catch(e=>Tv.error(e)),n.value=!1,i.value=[]}catch(e){console.error(`Upload failed:`,e)}finally{r.value=!1}t.refreshEntryInfo(t.rootpath)}};return(e,t)=>(z(),V(N(lv),{open:n.value,"onUpdate:open":t[0]||=e=>n.value=e},{default:P(()=>[H(`form`,null,[U(N(_v),{"as-child":``},{default:P(()=>[U(N(sv),{variant:`outline`},{default:P(()=>[U(N(R_)),t[1]||=ms(` Upload Files `,-1)]),_:1})]),_:1}),U(N(fv),{class:`sm:max-w-[425px]`},{default:P(()=>[U(N(hv),null,{default:P(()=>[U(N(gv),null,{default:P(()=>[...t[2]||=[ms(`Upload Files`,-1)]]),_:1}),U(N(pv),null,{default:P(()=>[...t[3]||=[ms(` upload files in the current location. `,-1)]]),_:1})]),_:1}),H(`div`,by,[H(`div`,xy,[U(N(yv),{for:`file-upload`},{default:P(()=>[...t[4]||=[ms(`select files`,-1)]]),_:1}),U(N(vv),{id:`file-upload`,type:`file`,multiple:``,onChange:a})]),i.value.length>0?(z(),B(`div`,Sy,[(z(!0),B(R,null,Yi(i.value,(e,t)=>(z(),B(`div`,{key:t+e.name,class:`flex items-center justify-between p-2 border rounded-md text-sm`},[H(`div`,Cy,[U(N(L_),{class:`h-4 w-4 text-blue-500 flex-shrink-0`}),H(`div`,wy,[H(`span`,Ty,De(e.name),1),H(`span`,Ey,De(s(e.size)),1)])]),U(N(sv),{variant:`ghost`,size:`icon`,class:`h-6 w-6 text-gray-500 hover:text-red-500`,onClick:e=>o(t)},{default:P(()=>[U(N(K_),{class:`h-4 w-4`})]),_:1},8,[`onClick`])]))),128))])):(z(),B(`div`,Dy,` No files selected `))]),U(N(mv),null,{default:P(()=>[U(N(uv),{"as-child":``},{default:P(()=>[U(N(sv),{variant:`outline`},{default:P(()=>[...t[5]||=[ms(` Cancel `,-1)]]),_:1})]),_:1}),U(N(sv),{type:`submit`,onClick:c,disabled:r.value||i.value.length===0},{default:P(()=>[r.value?gs(``,!0):(z(),V(N(G_),{key:0,class:`mr-2 h-4 w-4`})),ms(` `+De(r.value?`Uploading...`:`Upload`),1)]),_:1},8,[`disabled`])]),_:1})]),_:1})])]),_:1},8,[`open`]))}});function ky(e,t){return typeof e==`function`?e(t):e}function Ay(e,t){return n=>{t.setState(t=>({...t,[e]:ky(n,t[e])}))}}function jy(e){return e instanceof Function}function My(e){return Array.isArray(e)&&e.every(e=>typeof e==`number`)}function Ny(e,t){let n=[],r=e=>{e.forEach(e=>{n.push(e);let i=t(e);i!=null&&i.length&&r(i)})};return r(e),n}function Q(e,t,n){let r=[],i;return a=>{let o;n.key&&n.debug&&(o=Date.now());let s=e(a);if(!(s.length!==r.length||s.some((e,t)=>r[t]!==e)))return i;r=s;let c;if(n.key&&n.debug&&(c=Date.now()),i=t(...s),n==null||n.onChange==null||n.onChange(i),n.key&&n.debug&&n!=null&&n.debug()){let e=Math.round((Date.now()-o)*100)/100,t=Math.round((Date.now()-c)*100)/100,r=t/16,i=(e,t)=>{for(e=String(e);e.length<t;)e=` `+e;return e};console.info(`%c⏱ ${i(t,5)} /${i(e,5)} ms`,`
+`))}return()=>i.value||r.value||o.value?K(t.default({present:o.value})[0],{ref:e=>{let t=Yu(e);return t?.hasAttribute===void 0||(t?.hasAttribute(`data-reka-popper-content-wrapper`)?a.value=t.firstElementChild:a.value=t),t}}):null}}),zd=F({name:`PrimitiveSlot`,inheritAttrs:!1,setup(e,{attrs:t,slots:n}){return()=>{if(!n.default)return null;let e=Cu(n.default()),r=e.findIndex(e=>e.type!==Qo);if(r===-1)return e;let i=e[r];delete i.props?.ref;let a=i.props?W(t,i.props):t,o=ps({...i,props:{}},a);return e.length===1?o:(e[r]=o,e)}}}),Bd=[`area`,`img`,`input`],Vd=F({name:`Primitive`,inheritAttrs:!1,props:{asChild:{type:Boolean,default:!1},as:{type:[String,Object],default:`div`}},setup(e,{attrs:t,slots:n}){let r=e.asChild?`template`:e.as;return typeof r==`string`&&Bd.includes(r)?()=>K(r,t):r===`template`?()=>K(zd,t,{default:n.default}):()=>K(e.as,t,{default:n.default})}});function Hd(){let e=M();return{primitiveElement:e,currentElement:G(()=>[`#text`,`#comment`].includes(e.value?.$el.nodeName)?e.value?.$el.nextElementSibling:Yu(e))}}var[Ud,Wd]=bu(`DialogRoot`),Gd=F({inheritAttrs:!1,__name:`DialogRoot`,props:{open:{type:Boolean,required:!1,default:void 0},defaultOpen:{type:Boolean,required:!1,default:!1},modal:{type:Boolean,required:!1,default:!0}},emits:[`update:open`],setup(e,{emit:t}){let n=e,r=rd(n,`open`,t,{defaultValue:n.defaultOpen,passive:n.open===void 0}),i=M(),a=M(),{modal:o}=bn(n);return Wd({open:r,modal:o,openModal:()=>{r.value=!0},onOpenChange:e=>{r.value=e},onOpenToggle:()=>{r.value=!r.value},contentId:``,titleId:``,descriptionId:``,triggerElement:i,contentElement:a}),(e,t)=>I(e.$slots,`default`,{open:N(r),close:()=>r.value=!1})}}),Kd=F({__name:`DialogClose`,props:{asChild:{type:Boolean,required:!1},as:{type:null,required:!1,default:`button`}},setup(e){let t=e;q();let n=Ud();return(e,r)=>(z(),V(N(Vd),W(t,{type:e.as===`button`?`button`:void 0,onClick:r[0]||=e=>N(n).onOpenChange(!1)}),{default:P(()=>[I(e.$slots,`default`)]),_:3},16,[`type`]))}}),qd=`dismissableLayer.pointerDownOutside`,Jd=`dismissableLayer.focusOutside`;function Yd(e,t){let n=t.closest(`[data-dismissable-layer]`),r=e.dataset.dismissableLayer===``?e:e.querySelector(`[data-dismissable-layer]`),i=Array.from(e.ownerDocument.querySelectorAll(`[data-dismissable-layer]`));return!!(n&&(r===n||i.indexOf(r)<i.indexOf(n)))}function Xd(e,t,n=!0){let r=t?.value?.ownerDocument??globalThis?.document,i=M(!1),a=M(()=>{});return Ua(o=>{if(!Nu||!hn(n))return;let s=async n=>{let o=n.target;if(!(!t?.value||!o)){if(Yd(t.value,o)){i.value=!1;return}if(n.target&&!i.value){let t={originalEvent:n};function i(){Su(qd,e,t)}n.pointerType===`touch`?(r.removeEventListener(`click`,a.value),a.value=i,r.addEventListener(`click`,a.value,{once:!0})):i()}else r.removeEventListener(`click`,a.value);i.value=!1}},c=window.setTimeout(()=>{r.addEventListener(`pointerdown`,s)},0);o(()=>{window.clearTimeout(c),r.removeEventListener(`pointerdown`,s),r.removeEventListener(`click`,a.value)})}),{onPointerDownCapture:()=>{hn(n)&&(i.value=!0)}}}function Zd(e,t,n=!0){let r=t?.value?.ownerDocument??globalThis?.document,i=M(!1);return Ua(a=>{if(!Nu||!hn(n))return;let o=async n=>{if(!t?.value)return;await $n(),await $n();let r=n.target;!t.value||!r||Yd(t.value,r)||n.target&&!i.value&&Su(Jd,e,{originalEvent:n})};r.addEventListener(`focusin`,o),a(()=>r.removeEventListener(`focusin`,o))}),{onFocusCapture:()=>{hn(n)&&(i.value=!0)},onBlurCapture:()=>{hn(n)&&(i.value=!1)}}}var Qd=Zt({layersRoot:new Set,layersWithOutsidePointerEventsDisabled:new Set,branches:new Set}),$d=F({__name:`DismissableLayer`,props:{disableOutsidePointerEvents:{type:Boolean,required:!1,default:!1},asChild:{type:Boolean,required:!1},as:{type:null,required:!1}},emits:[`escapeKeyDown`,`pointerDownOutside`,`focusOutside`,`interactOutside`,`dismiss`],setup(e,{emit:t}){let n=e,r=t,{forwardRef:i,currentElement:a}=q(),o=G(()=>a.value?.ownerDocument??globalThis.document),s=G(()=>Qd.layersRoot),c=G(()=>a.value?Array.from(s.value).indexOf(a.value):-1),l=G(()=>Qd.layersWithOutsidePointerEventsDisabled.size>0),u=G(()=>{let e=Array.from(s.value),[t]=[...Qd.layersWithOutsidePointerEventsDisabled].slice(-1),n=e.indexOf(t);return c.value>=n}),d=Xd(async e=>{let t=[...Qd.branches].some(t=>t?.contains(e.target));!u.value||t||(r(`pointerDownOutside`,e),r(`interactOutside`,e),await $n(),e.defaultPrevented||r(`dismiss`))},a),f=Zd(e=>{[...Qd.branches].some(t=>t?.contains(e.target))||(r(`focusOutside`,e),r(`interactOutside`,e),e.defaultPrevented||r(`dismiss`))},a);ed(`Escape`,e=>{c.value===s.value.size-1&&(r(`escapeKeyDown`,e),e.defaultPrevented||r(`dismiss`))});let p;return Ua(e=>{a.value&&(n.disableOutsidePointerEvents&&(Qd.layersWithOutsidePointerEventsDisabled.size===0&&(p=o.value.body.style.pointerEvents,o.value.body.style.pointerEvents=`none`),Qd.layersWithOutsidePointerEventsDisabled.add(a.value)),s.value.add(a.value),e(()=>{n.disableOutsidePointerEvents&&Qd.layersWithOutsidePointerEventsDisabled.size===1&&(o.value.body.style.pointerEvents=p)}))}),Ua(e=>{e(()=>{a.value&&(s.value.delete(a.value),Qd.layersWithOutsidePointerEventsDisabled.delete(a.value))})}),(e,t)=>(z(),V(N(Vd),{ref:N(i),"as-child":e.asChild,as:e.as,"data-dismissable-layer":``,style:me({pointerEvents:l.value?u.value?`auto`:`none`:void 0}),onFocusCapture:N(f).onFocusCapture,onBlurCapture:N(f).onBlurCapture,onPointerdownCapture:N(d).onPointerDownCapture},{default:P(()=>[I(e.$slots,`default`)]),_:3},8,[`as-child`,`as`,`style`,`onFocusCapture`,`onBlurCapture`,`onPointerdownCapture`]))}}),ef=ju(()=>M([]));function tf(){let e=ef();return{add(t){let n=e.value[0];t!==n&&n?.pause(),e.value=nf(e.value,t),e.value.unshift(t)},remove(t){e.value=nf(e.value,t),e.value[0]?.resume()}}}function nf(e,t){let n=[...e],r=n.indexOf(t);return r!==-1&&n.splice(r,1),n}function rf(e){return e.filter(e=>e.tagName!==`A`)}var af=`focusScope.autoFocusOnMount`,of=`focusScope.autoFocusOnUnmount`,sf={bubbles:!1,cancelable:!0};function cf(e,{select:t=!1}={}){let n=xu();for(let r of e)if(mf(r,{select:t}),xu()!==n)return!0}function lf(e){let t=uf(e);return[df(t,e),df(t.reverse(),e)]}function uf(e){let t=[],n=document.createTreeWalker(e,NodeFilter.SHOW_ELEMENT,{acceptNode:e=>{let t=e.tagName===`INPUT`&&e.type===`hidden`;return e.disabled||e.hidden||t?NodeFilter.FILTER_SKIP:e.tabIndex>=0?NodeFilter.FILTER_ACCEPT:NodeFilter.FILTER_SKIP}});for(;n.nextNode();)t.push(n.currentNode);return t}function df(e,t){for(let n of e)if(!ff(n,{upTo:t}))return n}function ff(e,{upTo:t}){if(getComputedStyle(e).visibility===`hidden`)return!0;for(;e;){if(t!==void 0&&e===t)return!1;if(getComputedStyle(e).display===`none`)return!0;e=e.parentElement}return!1}function pf(e){return e instanceof HTMLInputElement&&`select`in e}function mf(e,{select:t=!1}={}){if(e&&e.focus){let n=xu();e.focus({preventScroll:!0}),e!==n&&pf(e)&&t&&e.select()}}var hf=F({__name:`FocusScope`,props:{loop:{type:Boolean,required:!1,default:!1},trapped:{type:Boolean,required:!1,default:!1},asChild:{type:Boolean,required:!1},as:{type:null,required:!1}},emits:[`mountAutoFocus`,`unmountAutoFocus`],setup(e,{emit:t}){let n=e,r=t,{currentRef:i,currentElement:a}=q(),o=M(null),s=tf(),c=Zt({paused:!1,pause(){this.paused=!0},resume(){this.paused=!1}});Ua(e=>{if(!Nu)return;let t=a.value;if(!n.trapped)return;function r(e){if(c.paused||!t)return;let n=e.target;t.contains(n)?o.value=n:mf(o.value,{select:!0})}function i(e){if(c.paused||!t)return;let n=e.relatedTarget;n!==null&&(t.contains(n)||mf(o.value,{select:!0}))}function s(e){t.contains(o.value)||mf(t)}document.addEventListener(`focusin`,r),document.addEventListener(`focusout`,i);let l=new MutationObserver(s);t&&l.observe(t,{childList:!0,subtree:!0}),e(()=>{document.removeEventListener(`focusin`,r),document.removeEventListener(`focusout`,i),l.disconnect()})}),Ua(async e=>{let t=a.value;if(await $n(),!t)return;s.add(c);let n=xu();if(!t.contains(n)){let e=new CustomEvent(af,sf);t.addEventListener(af,e=>r(`mountAutoFocus`,e)),t.dispatchEvent(e),e.defaultPrevented||(cf(rf(uf(t)),{select:!0}),xu()===n&&mf(t))}e(()=>{t.removeEventListener(af,e=>r(`mountAutoFocus`,e));let e=new CustomEvent(of,sf),i=e=>{r(`unmountAutoFocus`,e)};t.addEventListener(of,i),t.dispatchEvent(e),setTimeout(()=>{e.defaultPrevented||mf(n??document.body,{select:!0}),t.removeEventListener(of,i),s.remove(c)},0)})});function l(e){if(!n.loop&&!n.trapped||c.paused)return;let t=e.key===`Tab`&&!e.altKey&&!e.ctrlKey&&!e.metaKey,r=xu();if(t&&r){let t=e.currentTarget,[i,a]=lf(t);i&&a?!e.shiftKey&&r===a?(e.preventDefault(),n.loop&&mf(i,{select:!0})):e.shiftKey&&r===i&&(e.preventDefault(),n.loop&&mf(a,{select:!0})):r===t&&e.preventDefault()}}return(e,t)=>(z(),V(N(Vd),{ref_key:`currentRef`,ref:i,tabindex:`-1`,"as-child":e.asChild,as:e.as,onKeydown:l},{default:P(()=>[I(e.$slots,`default`)]),_:3},8,[`as-child`,`as`]))}}),gf=`menu.itemSelect`,_f=[`Enter`,` `],vf=[`ArrowDown`,`PageUp`,`Home`],yf=[`ArrowUp`,`PageDown`,`End`],bf=[...vf,...yf];[..._f],[..._f];function xf(e){return e?`open`:`closed`}function Sf(e){let t=xu();for(let n of e)if(n===t||(n.focus(),xu()!==t))return}function Cf(e,t){let{x:n,y:r}=e,i=!1;for(let e=0,a=t.length-1;e<t.length;a=e++){let o=t[e].x,s=t[e].y,c=t[a].x,l=t[a].y;s>r!=l>r&&n<(c-o)*(r-s)/(l-s)+o&&(i=!i)}return i}function wf(e,t){return t?Cf({x:e.clientX,y:e.clientY},t):!1}function Tf(e){return e.pointerType===`mouse`}var Ef=F({__name:`DialogContentImpl`,props:{forceMount:{type:Boolean,required:!1},trapFocus:{type:Boolean,required:!1},disableOutsidePointerEvents:{type:Boolean,required:!1},asChild:{type:Boolean,required:!1},as:{type:null,required:!1}},emits:[`escapeKeyDown`,`pointerDownOutside`,`focusOutside`,`interactOutside`,`openAutoFocus`,`closeAutoFocus`],setup(e,{emit:t}){let n=e,r=t,i=Ud(),{forwardRef:a,currentElement:o}=q();return i.titleId||=Ad(void 0,`reka-dialog-title`),i.descriptionId||=Ad(void 0,`reka-dialog-description`),Mi(()=>{i.contentElement=o,xu()!==document.body&&(i.triggerElement.value=xu())}),(e,t)=>(z(),V(N(hf),{"as-child":``,loop:``,trapped:n.trapFocus,onMountAutoFocus:t[5]||=e=>r(`openAutoFocus`,e),onUnmountAutoFocus:t[6]||=e=>r(`closeAutoFocus`,e)},{default:P(()=>[U(N($d),W({id:N(i).contentId,ref:N(a),as:e.as,"as-child":e.asChild,"disable-outside-pointer-events":e.disableOutsidePointerEvents,role:`dialog`,"aria-describedby":N(i).descriptionId,"aria-labelledby":N(i).titleId,"data-state":N(xf)(N(i).open.value)},e.$attrs,{onDismiss:t[0]||=e=>N(i).onOpenChange(!1),onEscapeKeyDown:t[1]||=e=>r(`escapeKeyDown`,e),onFocusOutside:t[2]||=e=>r(`focusOutside`,e),onInteractOutside:t[3]||=e=>r(`interactOutside`,e),onPointerDownOutside:t[4]||=e=>r(`pointerDownOutside`,e)}),{default:P(()=>[I(e.$slots,`default`)]),_:3},16,[`id`,`as`,`as-child`,`disable-outside-pointer-events`,`aria-describedby`,`aria-labelledby`,`data-state`])]),_:3},8,[`trapped`]))}}),Df=F({__name:`DialogContentModal`,props:{forceMount:{type:Boolean,required:!1},trapFocus:{type:Boolean,required:!1},disableOutsidePointerEvents:{type:Boolean,required:!1},asChild:{type:Boolean,required:!1},as:{type:null,required:!1}},emits:[`escapeKeyDown`,`pointerDownOutside`,`focusOutside`,`interactOutside`,`openAutoFocus`,`closeAutoFocus`],setup(e,{emit:t}){let n=e,r=t,i=Ud(),a=pd(r),{forwardRef:o,currentElement:s}=q();return Od(s),(e,t)=>(z(),V(Ef,W({...n,...N(a)},{ref:N(o),"trap-focus":N(i).open.value,"disable-outside-pointer-events":!0,onCloseAutoFocus:t[0]||=e=>{e.defaultPrevented||(e.preventDefault(),N(i).triggerElement.value?.focus())},onPointerDownOutside:t[1]||=e=>{let t=e.detail.originalEvent,n=t.button===0&&t.ctrlKey===!0;(t.button===2||n)&&e.preventDefault()},onFocusOutside:t[2]||=e=>{e.preventDefault()}}),{default:P(()=>[I(e.$slots,`default`)]),_:3},16,[`trap-focus`]))}}),Of=F({__name:`DialogContentNonModal`,props:{forceMount:{type:Boolean,required:!1},trapFocus:{type:Boolean,required:!1},disableOutsidePointerEvents:{type:Boolean,required:!1},asChild:{type:Boolean,required:!1},as:{type:null,required:!1}},emits:[`escapeKeyDown`,`pointerDownOutside`,`focusOutside`,`interactOutside`,`openAutoFocus`,`closeAutoFocus`],setup(e,{emit:t}){let n=e,r=pd(t);q();let i=Ud(),a=M(!1),o=M(!1);return(e,t)=>(z(),V(Ef,W({...n,...N(r)},{"trap-focus":!1,"disable-outside-pointer-events":!1,onCloseAutoFocus:t[0]||=e=>{e.defaultPrevented||(a.value||N(i).triggerElement.value?.focus(),e.preventDefault()),a.value=!1,o.value=!1},onInteractOutside:t[1]||=e=>{e.defaultPrevented||(a.value=!0,e.detail.originalEvent.type===`pointerdown`&&(o.value=!0));let t=e.target;N(i).triggerElement.value?.contains(t)&&e.preventDefault(),e.detail.originalEvent.type===`focusin`&&o.value&&e.preventDefault()}}),{default:P(()=>[I(e.$slots,`default`)]),_:3},16))}}),kf=F({__name:`DialogContent`,props:{forceMount:{type:Boolean,required:!1},disableOutsidePointerEvents:{type:Boolean,required:!1},asChild:{type:Boolean,required:!1},as:{type:null,required:!1}},emits:[`escapeKeyDown`,`pointerDownOutside`,`focusOutside`,`interactOutside`,`openAutoFocus`,`closeAutoFocus`],setup(e,{emit:t}){let n=e,r=t,i=Ud(),a=pd(r),{forwardRef:o}=q();return(e,t)=>(z(),V(N(Rd),{present:e.forceMount||N(i).open.value},{default:P(()=>[N(i).modal.value?(z(),V(Df,W({key:0,ref:N(o)},{...n,...N(a),...e.$attrs}),{default:P(()=>[I(e.$slots,`default`)]),_:3},16)):(z(),V(Of,W({key:1,ref:N(o)},{...n,...N(a),...e.$attrs}),{default:P(()=>[I(e.$slots,`default`)]),_:3},16))]),_:3},8,[`present`]))}}),Af=F({__name:`DialogDescription`,props:{asChild:{type:Boolean,required:!1},as:{type:null,required:!1,default:`p`}},setup(e){let t=e;q();let n=Ud();return(e,r)=>(z(),V(N(Vd),W(t,{id:N(n).descriptionId}),{default:P(()=>[I(e.$slots,`default`)]),_:3},16,[`id`]))}}),jf=F({__name:`DialogOverlayImpl`,props:{asChild:{type:Boolean,required:!1},as:{type:null,required:!1}},setup(e){let t=Ud();return ld(!0),q(),(e,n)=>(z(),V(N(Vd),{as:e.as,"as-child":e.asChild,"data-state":N(t).open.value?`open`:`closed`,style:{"pointer-events":`auto`}},{default:P(()=>[I(e.$slots,`default`)]),_:3},8,[`as`,`as-child`,`data-state`]))}}),Mf=F({__name:`DialogOverlay`,props:{forceMount:{type:Boolean,required:!1},asChild:{type:Boolean,required:!1},as:{type:null,required:!1}},setup(e){let t=Ud(),{forwardRef:n}=q();return(e,r)=>N(t)?.modal.value?(z(),V(N(Rd),{key:0,present:e.forceMount||N(t).open.value},{default:P(()=>[U(jf,W(e.$attrs,{ref:N(n),as:e.as,"as-child":e.asChild}),{default:P(()=>[I(e.$slots,`default`)]),_:3},16,[`as`,`as-child`])]),_:3},8,[`present`])):gs(`v-if`,!0)}}),Nf=F({__name:`Teleport`,props:{to:{type:null,required:!1,default:`body`},disabled:{type:Boolean,required:!1},defer:{type:Boolean,required:!1},forceMount:{type:Boolean,required:!1}},setup(e){let t=Zu();return(e,n)=>N(t)||e.forceMount?(z(),V(kr,{key:0,to:e.to,disabled:e.disabled,defer:e.defer},[I(e.$slots,`default`)],8,[`to`,`disabled`,`defer`])):gs(`v-if`,!0)}}),Pf=F({__name:`DialogPortal`,props:{to:{type:null,required:!1},disabled:{type:Boolean,required:!1},defer:{type:Boolean,required:!1},forceMount:{type:Boolean,required:!1}},setup(e){let t=e;return(e,n)=>(z(),V(N(Nf),ye(fs(t)),{default:P(()=>[I(e.$slots,`default`)]),_:3},16))}}),Ff=F({__name:`DialogTitle`,props:{asChild:{type:Boolean,required:!1},as:{type:null,required:!1,default:`h2`}},setup(e){let t=e,n=Ud();return q(),(e,r)=>(z(),V(N(Vd),W(t,{id:N(n).titleId}),{default:P(()=>[I(e.$slots,`default`)]),_:3},16,[`id`]))}}),If=F({__name:`DialogTrigger`,props:{asChild:{type:Boolean,required:!1},as:{type:null,required:!1,default:`button`}},setup(e){let t=e,n=Ud(),{forwardRef:r,currentElement:i}=q();return n.contentId||=Ad(void 0,`reka-dialog-content`),Mi(()=>{n.triggerElement.value=i.value}),(e,i)=>(z(),V(N(Vd),W(t,{ref:N(r),type:e.as===`button`?`button`:void 0,"aria-haspopup":`dialog`,"aria-expanded":N(n).open.value||!1,"aria-controls":N(n).open.value?N(n).contentId:void 0,"data-state":N(n).open.value?`open`:`closed`,onClick:N(n).onOpenToggle}),{default:P(()=>[I(e.$slots,`default`)]),_:3},16,[`type`,`aria-expanded`,`aria-controls`,`data-state`,`onClick`]))}}),Lf=`data-reka-collection-item`;function Rf(e={}){let{key:t=``,isProvider:n=!1}=e,r=`${t}CollectionProvider`,i;if(n){let e=M(new Map);i={collectionRef:M(),itemMap:e},Ra(r,i)}else i=za(r);let a=(e=!1)=>{let t=i.collectionRef.value;if(!t)return[];let n=Array.from(t.querySelectorAll(`[${Lf}]`)),r=Array.from(i.itemMap.value.values()).sort((e,t)=>n.indexOf(e.ref)-n.indexOf(t.ref));return e?r:r.filter(e=>e.ref.dataset.disabled!==``)},o=F({name:`CollectionSlot`,setup(e,{slots:t}){let{primitiveElement:n,currentElement:r}=Hd();return L(r,()=>{i.collectionRef.value=r.value}),()=>K(zd,{ref:n},t)}}),s=F({name:`CollectionItem`,inheritAttrs:!1,props:{value:{validator:()=>!0}},setup(e,{slots:t,attrs:n}){let{primitiveElement:r,currentElement:a}=Hd();return Ua(t=>{if(a.value){let n=sn(a.value);i.itemMap.value.set(n,{ref:a.value,value:e.value}),t(()=>i.itemMap.value.delete(n))}}),()=>K(zd,{...n,[Lf]:``,ref:r},t)}});return{getItems:a,reactiveItems:G(()=>Array.from(i.itemMap.value.values())),itemMapSize:G(()=>i.itemMap.value.size),CollectionSlot:o,CollectionItem:s}}var zf=`rovingFocusGroup.onEntryFocus`,Bf={bubbles:!1,cancelable:!0};function Vf(e,t=!1){let n=xu();for(let r of e)if(r===n||(r.focus({preventScroll:t}),xu()!==n))return}var[Hf,Uf]=bu(`RovingFocusGroup`),Wf=F({__name:`RovingFocusGroup`,props:{orientation:{type:String,required:!1,default:void 0},dir:{type:String,required:!1},loop:{type:Boolean,required:!1,default:!1},currentTabStopId:{type:[String,null],required:!1},defaultCurrentTabStopId:{type:String,required:!1},preventScrollOnEntryFocus:{type:Boolean,required:!1,default:!1},asChild:{type:Boolean,required:!1},as:{type:null,required:!1}},emits:[`entryFocus`,`update:currentTabStopId`],setup(e,{expose:t,emit:n}){let r=e,i=n,{loop:a,orientation:o,dir:s}=bn(r),c=fd(s),l=rd(r,`currentTabStopId`,i,{defaultValue:r.defaultCurrentTabStopId,passive:r.currentTabStopId===void 0}),u=M(!1),d=M(!1),f=M(0),{getItems:p,CollectionSlot:m}=Rf({isProvider:!0});function h(e){let t=!d.value;if(e.currentTarget&&e.target===e.currentTarget&&t&&!u.value){let t=new CustomEvent(zf,Bf);if(e.currentTarget.dispatchEvent(t),i(`entryFocus`,t),!t.defaultPrevented){let e=p().map(e=>e.ref).filter(e=>e.dataset.disabled!==``);Vf([e.find(e=>e.getAttribute(`data-active`)===``),e.find(e=>e.getAttribute(`data-highlighted`)===``),e.find(e=>e.id===l.value),...e].filter(Boolean),r.preventScrollOnEntryFocus)}}d.value=!1}function g(){setTimeout(()=>{d.value=!1},1)}return t({getItems:p}),Uf({loop:a,dir:c,orientation:o,currentTabStopId:l,onItemFocus:e=>{l.value=e},onItemShiftTab:()=>{u.value=!0},onFocusableItemAdd:()=>{f.value++},onFocusableItemRemove:()=>{f.value--}}),(e,t)=>(z(),V(N(m),null,{default:P(()=>[U(N(Vd),{tabindex:u.value||f.value===0?-1:0,"data-orientation":N(o),as:e.as,"as-child":e.asChild,dir:N(c),style:{outline:`none`},onMousedown:t[0]||=e=>d.value=!0,onMouseup:g,onFocus:h,onBlur:t[1]||=e=>u.value=!1},{default:P(()=>[I(e.$slots,`default`)]),_:3},8,[`tabindex`,`data-orientation`,`as`,`as-child`,`dir`])]),_:3}))}}),[Gf,Kf]=bu(`PopperRoot`),qf=F({inheritAttrs:!1,__name:`PopperRoot`,setup(e){let t=M();return Kf({anchor:t,onAnchorChange:e=>t.value=e}),(e,t)=>I(e.$slots,`default`)}}),Jf=F({__name:`PopperAnchor`,props:{reference:{type:null,required:!1},asChild:{type:Boolean,required:!1},as:{type:null,required:!1}},setup(e){let t=e,{forwardRef:n,currentElement:r}=q(),i=Gf();return Wa(()=>{i.onAnchorChange(t.reference??r.value)}),(e,t)=>(z(),V(N(Vd),{ref:N(n),as:e.as,"as-child":e.asChild},{default:P(()=>[I(e.$slots,`default`)]),_:3},8,[`as`,`as-child`]))}});function Yf(e){return e!==null}function Xf(e){return{name:`transformOrigin`,options:e,fn(t){let{placement:n,rects:r,middlewareData:i}=t,a=i.arrow?.centerOffset!==0,o=a?0:e.arrowWidth,s=a?0:e.arrowHeight,[c,l]=Zf(n),u={start:`0%`,center:`50%`,end:`100%`}[l],d=(i.arrow?.x??0)+o/2,f=(i.arrow?.y??0)+s/2,p=``,m=``;return c===`bottom`?(p=a?u:`${d}px`,m=`${-s}px`):c===`top`?(p=a?u:`${d}px`,m=`${r.floating.height+s}px`):c===`right`?(p=`${-s}px`,m=a?u:`${f}px`):c===`left`&&(p=`${r.floating.width+s}px`,m=a?u:`${f}px`),{data:{x:p,y:m}}}}}function Zf(e){let[t,n=`center`]=e.split(`-`);return[t,n]}var Qf=[`top`,`right`,`bottom`,`left`],$f=Math.min,ep=Math.max,tp=Math.round,np=Math.floor,rp=e=>({x:e,y:e}),ip={left:`right`,right:`left`,bottom:`top`,top:`bottom`},ap={start:`end`,end:`start`};function op(e,t,n){return ep(e,$f(t,n))}function sp(e,t){return typeof e==`function`?e(t):e}function cp(e){return e.split(`-`)[0]}function lp(e){return e.split(`-`)[1]}function up(e){return e===`x`?`y`:`x`}function dp(e){return e===`y`?`height`:`width`}var fp=new Set([`top`,`bottom`]);function pp(e){return fp.has(cp(e))?`y`:`x`}function mp(e){return up(pp(e))}function hp(e,t,n){n===void 0&&(n=!1);let r=lp(e),i=mp(e),a=dp(i),o=i===`x`?r===(n?`end`:`start`)?`right`:`left`:r===`start`?`bottom`:`top`;return t.reference[a]>t.floating[a]&&(o=wp(o)),[o,wp(o)]}function gp(e){let t=wp(e);return[_p(e),t,_p(t)]}function _p(e){return e.replace(/start|end/g,e=>ap[e])}var vp=[`left`,`right`],yp=[`right`,`left`],bp=[`top`,`bottom`],xp=[`bottom`,`top`];function Sp(e,t,n){switch(e){case`top`:case`bottom`:return n?t?yp:vp:t?vp:yp;case`left`:case`right`:return t?bp:xp;default:return[]}}function Cp(e,t,n,r){let i=lp(e),a=Sp(cp(e),n===`start`,r);return i&&(a=a.map(e=>e+`-`+i),t&&(a=a.concat(a.map(_p)))),a}function wp(e){return e.replace(/left|right|bottom|top/g,e=>ip[e])}function Tp(e){return{top:0,right:0,bottom:0,left:0,...e}}function Ep(e){return typeof e==`number`?{top:e,right:e,bottom:e,left:e}:Tp(e)}function Dp(e){let{x:t,y:n,width:r,height:i}=e;return{width:r,height:i,top:n,left:t,right:t+r,bottom:n+i,x:t,y:n}}function Op(e,t,n){let{reference:r,floating:i}=e,a=pp(t),o=mp(t),s=dp(o),c=cp(t),l=a===`y`,u=r.x+r.width/2-i.width/2,d=r.y+r.height/2-i.height/2,f=r[s]/2-i[s]/2,p;switch(c){case`top`:p={x:u,y:r.y-i.height};break;case`bottom`:p={x:u,y:r.y+r.height};break;case`right`:p={x:r.x+r.width,y:d};break;case`left`:p={x:r.x-i.width,y:d};break;default:p={x:r.x,y:r.y}}switch(lp(t)){case`start`:p[o]-=f*(n&&l?-1:1);break;case`end`:p[o]+=f*(n&&l?-1:1);break}return p}var kp=async(e,t,n)=>{let{placement:r=`bottom`,strategy:i=`absolute`,middleware:a=[],platform:o}=n,s=a.filter(Boolean),c=await(o.isRTL==null?void 0:o.isRTL(t)),l=await o.getElementRects({reference:e,floating:t,strategy:i}),{x:u,y:d}=Op(l,r,c),f=r,p={},m=0;for(let n=0;n<s.length;n++){let{name:a,fn:h}=s[n],{x:g,y:_,data:v,reset:y}=await h({x:u,y:d,initialPlacement:r,placement:f,strategy:i,middlewareData:p,rects:l,platform:o,elements:{reference:e,floating:t}});u=g??u,d=_??d,p={...p,[a]:{...p[a],...v}},y&&m<=50&&(m++,typeof y==`object`&&(y.placement&&(f=y.placement),y.rects&&(l=y.rects===!0?await o.getElementRects({reference:e,floating:t,strategy:i}):y.rects),{x:u,y:d}=Op(l,f,c)),n=-1)}return{x:u,y:d,placement:f,strategy:i,middlewareData:p}};async function Ap(e,t){t===void 0&&(t={});let{x:n,y:r,platform:i,rects:a,elements:o,strategy:s}=e,{boundary:c=`clippingAncestors`,rootBoundary:l=`viewport`,elementContext:u=`floating`,altBoundary:d=!1,padding:f=0}=sp(t,e),p=Ep(f),m=o[d?u===`floating`?`reference`:`floating`:u],h=Dp(await i.getClippingRect({element:await(i.isElement==null?void 0:i.isElement(m))??!0?m:m.contextElement||await(i.getDocumentElement==null?void 0:i.getDocumentElement(o.floating)),boundary:c,rootBoundary:l,strategy:s})),g=u===`floating`?{x:n,y:r,width:a.floating.width,height:a.floating.height}:a.reference,_=await(i.getOffsetParent==null?void 0:i.getOffsetParent(o.floating)),v=await(i.isElement==null?void 0:i.isElement(_))&&await(i.getScale==null?void 0:i.getScale(_))||{x:1,y:1},y=Dp(i.convertOffsetParentRelativeRectToViewportRelativeRect?await i.convertOffsetParentRelativeRectToViewportRelativeRect({elements:o,rect:g,offsetParent:_,strategy:s}):g);return{top:(h.top-y.top+p.top)/v.y,bottom:(y.bottom-h.bottom+p.bottom)/v.y,left:(h.left-y.left+p.left)/v.x,right:(y.right-h.right+p.right)/v.x}}var jp=e=>({name:`arrow`,options:e,async fn(t){let{x:n,y:r,placement:i,rects:a,platform:o,elements:s,middlewareData:c}=t,{element:l,padding:u=0}=sp(e,t)||{};if(l==null)return{};let d=Ep(u),f={x:n,y:r},p=mp(i),m=dp(p),h=await o.getDimensions(l),g=p===`y`,_=g?`top`:`left`,v=g?`bottom`:`right`,y=g?`clientHeight`:`clientWidth`,b=a.reference[m]+a.reference[p]-f[p]-a.floating[m],x=f[p]-a.reference[p],S=await(o.getOffsetParent==null?void 0:o.getOffsetParent(l)),C=S?S[y]:0;(!C||!await(o.isElement==null?void 0:o.isElement(S)))&&(C=s.floating[y]||a.floating[m]);let w=b/2-x/2,T=C/2-h[m]/2-1,ee=$f(d[_],T),E=$f(d[v],T),te=ee,ne=C-h[m]-E,re=C/2-h[m]/2+w,D=op(te,re,ne),ie=!c.arrow&&lp(i)!=null&&re!==D&&a.reference[m]/2-(re<te?ee:E)-h[m]/2<0,O=ie?re<te?re-te:re-ne:0;return{[p]:f[p]+O,data:{[p]:D,centerOffset:re-D-O,...ie&&{alignmentOffset:O}},reset:ie}}}),Mp=function(e){return e===void 0&&(e={}),{name:`flip`,options:e,async fn(t){var n;let{placement:r,middlewareData:i,rects:a,initialPlacement:o,platform:s,elements:c}=t,{mainAxis:l=!0,crossAxis:u=!0,fallbackPlacements:d,fallbackStrategy:f=`bestFit`,fallbackAxisSideDirection:p=`none`,flipAlignment:m=!0,...h}=sp(e,t);if((n=i.arrow)!=null&&n.alignmentOffset)return{};let g=cp(r),_=pp(o),v=cp(o)===o,y=await(s.isRTL==null?void 0:s.isRTL(c.floating)),b=d||(v||!m?[wp(o)]:gp(o)),x=p!==`none`;!d&&x&&b.push(...Cp(o,m,p,y));let S=[o,...b],C=await Ap(t,h),w=[],T=i.flip?.overflows||[];if(l&&w.push(C[g]),u){let e=hp(r,a,y);w.push(C[e[0]],C[e[1]])}if(T=[...T,{placement:r,overflows:w}],!w.every(e=>e<=0)){let e=(i.flip?.index||0)+1,t=S[e];if(t&&(!(u===`alignment`&&_!==pp(t))||T.every(e=>pp(e.placement)===_?e.overflows[0]>0:!0)))return{data:{index:e,overflows:T},reset:{placement:t}};let n=T.filter(e=>e.overflows[0]<=0).sort((e,t)=>e.overflows[1]-t.overflows[1])[0]?.placement;if(!n)switch(f){case`bestFit`:{let e=T.filter(e=>{if(x){let t=pp(e.placement);return t===_||t===`y`}return!0}).map(e=>[e.placement,e.overflows.filter(e=>e>0).reduce((e,t)=>e+t,0)]).sort((e,t)=>e[1]-t[1])[0]?.[0];e&&(n=e);break}case`initialPlacement`:n=o;break}if(r!==n)return{reset:{placement:n}}}return{}}}};function Np(e,t){return{top:e.top-t.height,right:e.right-t.width,bottom:e.bottom-t.height,left:e.left-t.width}}function Pp(e){return Qf.some(t=>e[t]>=0)}var Fp=function(e){return e===void 0&&(e={}),{name:`hide`,options:e,async fn(t){let{rects:n}=t,{strategy:r=`referenceHidden`,...i}=sp(e,t);switch(r){case`referenceHidden`:{let e=Np(await Ap(t,{...i,elementContext:`reference`}),n.reference);return{data:{referenceHiddenOffsets:e,referenceHidden:Pp(e)}}}case`escaped`:{let e=Np(await Ap(t,{...i,altBoundary:!0}),n.floating);return{data:{escapedOffsets:e,escaped:Pp(e)}}}default:return{}}}}},Ip=new Set([`left`,`top`]);async function Lp(e,t){let{placement:n,platform:r,elements:i}=e,a=await(r.isRTL==null?void 0:r.isRTL(i.floating)),o=cp(n),s=lp(n),c=pp(n)===`y`,l=Ip.has(o)?-1:1,u=a&&c?-1:1,d=sp(t,e),{mainAxis:f,crossAxis:p,alignmentAxis:m}=typeof d==`number`?{mainAxis:d,crossAxis:0,alignmentAxis:null}:{mainAxis:d.mainAxis||0,crossAxis:d.crossAxis||0,alignmentAxis:d.alignmentAxis};return s&&typeof m==`number`&&(p=s===`end`?m*-1:m),c?{x:p*u,y:f*l}:{x:f*l,y:p*u}}var Rp=function(e){return e===void 0&&(e=0),{name:`offset`,options:e,async fn(t){var n;let{x:r,y:i,placement:a,middlewareData:o}=t,s=await Lp(t,e);return a===o.offset?.placement&&(n=o.arrow)!=null&&n.alignmentOffset?{}:{x:r+s.x,y:i+s.y,data:{...s,placement:a}}}}},zp=function(e){return e===void 0&&(e={}),{name:`shift`,options:e,async fn(t){let{x:n,y:r,placement:i}=t,{mainAxis:a=!0,crossAxis:o=!1,limiter:s={fn:e=>{let{x:t,y:n}=e;return{x:t,y:n}}},...c}=sp(e,t),l={x:n,y:r},u=await Ap(t,c),d=pp(cp(i)),f=up(d),p=l[f],m=l[d];if(a){let e=f===`y`?`top`:`left`,t=f===`y`?`bottom`:`right`,n=p+u[e],r=p-u[t];p=op(n,p,r)}if(o){let e=d===`y`?`top`:`left`,t=d===`y`?`bottom`:`right`,n=m+u[e],r=m-u[t];m=op(n,m,r)}let h=s.fn({...t,[f]:p,[d]:m});return{...h,data:{x:h.x-n,y:h.y-r,enabled:{[f]:a,[d]:o}}}}}},Bp=function(e){return e===void 0&&(e={}),{options:e,fn(t){let{x:n,y:r,placement:i,rects:a,middlewareData:o}=t,{offset:s=0,mainAxis:c=!0,crossAxis:l=!0}=sp(e,t),u={x:n,y:r},d=pp(i),f=up(d),p=u[f],m=u[d],h=sp(s,t),g=typeof h==`number`?{mainAxis:h,crossAxis:0}:{mainAxis:0,crossAxis:0,...h};if(c){let e=f===`y`?`height`:`width`,t=a.reference[f]-a.floating[e]+g.mainAxis,n=a.reference[f]+a.reference[e]-g.mainAxis;p<t?p=t:p>n&&(p=n)}if(l){let e=f===`y`?`width`:`height`,t=Ip.has(cp(i)),n=a.reference[d]-a.floating[e]+(t&&o.offset?.[d]||0)+(t?0:g.crossAxis),r=a.reference[d]+a.reference[e]+(t?0:o.offset?.[d]||0)-(t?g.crossAxis:0);m<n?m=n:m>r&&(m=r)}return{[f]:p,[d]:m}}}},Vp=function(e){return e===void 0&&(e={}),{name:`size`,options:e,async fn(t){var n,r;let{placement:i,rects:a,platform:o,elements:s}=t,{apply:c=()=>{},...l}=sp(e,t),u=await Ap(t,l),d=cp(i),f=lp(i),p=pp(i)===`y`,{width:m,height:h}=a.floating,g,_;d===`top`||d===`bottom`?(g=d,_=f===(await(o.isRTL==null?void 0:o.isRTL(s.floating))?`start`:`end`)?`left`:`right`):(_=d,g=f===`end`?`top`:`bottom`);let v=h-u.top-u.bottom,y=m-u.left-u.right,b=$f(h-u[g],v),x=$f(m-u[_],y),S=!t.middlewareData.shift,C=b,w=x;if((n=t.middlewareData.shift)!=null&&n.enabled.x&&(w=y),(r=t.middlewareData.shift)!=null&&r.enabled.y&&(C=v),S&&!f){let e=ep(u.left,0),t=ep(u.right,0),n=ep(u.top,0),r=ep(u.bottom,0);p?w=m-2*(e!==0||t!==0?e+t:ep(u.left,u.right)):C=h-2*(n!==0||r!==0?n+r:ep(u.top,u.bottom))}await c({...t,availableWidth:w,availableHeight:C});let T=await o.getDimensions(s.floating);return m!==T.width||h!==T.height?{reset:{rects:!0}}:{}}}};function Hp(){return typeof window<`u`}function Up(e){return Kp(e)?(e.nodeName||``).toLowerCase():`#document`}function Wp(e){var t;return(e==null||(t=e.ownerDocument)==null?void 0:t.defaultView)||window}function Gp(e){return((Kp(e)?e.ownerDocument:e.document)||window.document)?.documentElement}function Kp(e){return Hp()?e instanceof Node||e instanceof Wp(e).Node:!1}function qp(e){return Hp()?e instanceof Element||e instanceof Wp(e).Element:!1}function Jp(e){return Hp()?e instanceof HTMLElement||e instanceof Wp(e).HTMLElement:!1}function Yp(e){return!Hp()||typeof ShadowRoot>`u`?!1:e instanceof ShadowRoot||e instanceof Wp(e).ShadowRoot}var Xp=new Set([`inline`,`contents`]);function Zp(e){let{overflow:t,overflowX:n,overflowY:r,display:i}=um(e);return/auto|scroll|overlay|hidden|clip/.test(t+r+n)&&!Xp.has(i)}var Qp=new Set([`table`,`td`,`th`]);function $p(e){return Qp.has(Up(e))}var em=[`:popover-open`,`:modal`];function tm(e){return em.some(t=>{try{return e.matches(t)}catch{return!1}})}var nm=[`transform`,`translate`,`scale`,`rotate`,`perspective`],rm=[`transform`,`translate`,`scale`,`rotate`,`perspective`,`filter`],im=[`paint`,`layout`,`strict`,`content`];function am(e){let t=sm(),n=qp(e)?um(e):e;return nm.some(e=>n[e]?n[e]!==`none`:!1)||(n.containerType?n.containerType!==`normal`:!1)||!t&&(n.backdropFilter?n.backdropFilter!==`none`:!1)||!t&&(n.filter?n.filter!==`none`:!1)||rm.some(e=>(n.willChange||``).includes(e))||im.some(e=>(n.contain||``).includes(e))}function om(e){let t=fm(e);for(;Jp(t)&&!lm(t);){if(am(t))return t;if(tm(t))return null;t=fm(t)}return null}function sm(){return typeof CSS>`u`||!CSS.supports?!1:CSS.supports(`-webkit-backdrop-filter`,`none`)}var cm=new Set([`html`,`body`,`#document`]);function lm(e){return cm.has(Up(e))}function um(e){return Wp(e).getComputedStyle(e)}function dm(e){return qp(e)?{scrollLeft:e.scrollLeft,scrollTop:e.scrollTop}:{scrollLeft:e.scrollX,scrollTop:e.scrollY}}function fm(e){if(Up(e)===`html`)return e;let t=e.assignedSlot||e.parentNode||Yp(e)&&e.host||Gp(e);return Yp(t)?t.host:t}function pm(e){let t=fm(e);return lm(t)?e.ownerDocument?e.ownerDocument.body:e.body:Jp(t)&&Zp(t)?t:pm(t)}function mm(e,t,n){t===void 0&&(t=[]),n===void 0&&(n=!0);let r=pm(e),i=r===e.ownerDocument?.body,a=Wp(r);if(i){let e=hm(a);return t.concat(a,a.visualViewport||[],Zp(r)?r:[],e&&n?mm(e):[])}return t.concat(r,mm(r,[],n))}function hm(e){return e.parent&&Object.getPrototypeOf(e.parent)?e.frameElement:null}function gm(e){let t=um(e),n=parseFloat(t.width)||0,r=parseFloat(t.height)||0,i=Jp(e),a=i?e.offsetWidth:n,o=i?e.offsetHeight:r,s=tp(n)!==a||tp(r)!==o;return s&&(n=a,r=o),{width:n,height:r,$:s}}function _m(e){return qp(e)?e:e.contextElement}function vm(e){let t=_m(e);if(!Jp(t))return rp(1);let n=t.getBoundingClientRect(),{width:r,height:i,$:a}=gm(t),o=(a?tp(n.width):n.width)/r,s=(a?tp(n.height):n.height)/i;return(!o||!Number.isFinite(o))&&(o=1),(!s||!Number.isFinite(s))&&(s=1),{x:o,y:s}}var ym=rp(0);function bm(e){let t=Wp(e);return!sm()||!t.visualViewport?ym:{x:t.visualViewport.offsetLeft,y:t.visualViewport.offsetTop}}function xm(e,t,n){return t===void 0&&(t=!1),!n||t&&n!==Wp(e)?!1:t}function Sm(e,t,n,r){t===void 0&&(t=!1),n===void 0&&(n=!1);let i=e.getBoundingClientRect(),a=_m(e),o=rp(1);t&&(r?qp(r)&&(o=vm(r)):o=vm(e));let s=xm(a,n,r)?bm(a):rp(0),c=(i.left+s.x)/o.x,l=(i.top+s.y)/o.y,u=i.width/o.x,d=i.height/o.y;if(a){let e=Wp(a),t=r&&qp(r)?Wp(r):r,n=e,i=hm(n);for(;i&&r&&t!==n;){let e=vm(i),t=i.getBoundingClientRect(),r=um(i),a=t.left+(i.clientLeft+parseFloat(r.paddingLeft))*e.x,o=t.top+(i.clientTop+parseFloat(r.paddingTop))*e.y;c*=e.x,l*=e.y,u*=e.x,d*=e.y,c+=a,l+=o,n=Wp(i),i=hm(n)}}return Dp({width:u,height:d,x:c,y:l})}function Cm(e,t){let n=dm(e).scrollLeft;return t?t.left+n:Sm(Gp(e)).left+n}function wm(e,t){let n=e.getBoundingClientRect();return{x:n.left+t.scrollLeft-Cm(e,n),y:n.top+t.scrollTop}}function Tm(e){let{elements:t,rect:n,offsetParent:r,strategy:i}=e,a=i===`fixed`,o=Gp(r),s=t?tm(t.floating):!1;if(r===o||s&&a)return n;let c={scrollLeft:0,scrollTop:0},l=rp(1),u=rp(0),d=Jp(r);if((d||!d&&!a)&&((Up(r)!==`body`||Zp(o))&&(c=dm(r)),Jp(r))){let e=Sm(r);l=vm(r),u.x=e.x+r.clientLeft,u.y=e.y+r.clientTop}let f=o&&!d&&!a?wm(o,c):rp(0);return{width:n.width*l.x,height:n.height*l.y,x:n.x*l.x-c.scrollLeft*l.x+u.x+f.x,y:n.y*l.y-c.scrollTop*l.y+u.y+f.y}}function Em(e){return Array.from(e.getClientRects())}function Dm(e){let t=Gp(e),n=dm(e),r=e.ownerDocument.body,i=ep(t.scrollWidth,t.clientWidth,r.scrollWidth,r.clientWidth),a=ep(t.scrollHeight,t.clientHeight,r.scrollHeight,r.clientHeight),o=-n.scrollLeft+Cm(e),s=-n.scrollTop;return um(r).direction===`rtl`&&(o+=ep(t.clientWidth,r.clientWidth)-i),{width:i,height:a,x:o,y:s}}var Om=25;function km(e,t){let n=Wp(e),r=Gp(e),i=n.visualViewport,a=r.clientWidth,o=r.clientHeight,s=0,c=0;if(i){a=i.width,o=i.height;let e=sm();(!e||e&&t===`fixed`)&&(s=i.offsetLeft,c=i.offsetTop)}let l=Cm(r);if(l<=0){let e=r.ownerDocument,t=e.body,n=getComputedStyle(t),i=e.compatMode===`CSS1Compat`&&parseFloat(n.marginLeft)+parseFloat(n.marginRight)||0,o=Math.abs(r.clientWidth-t.clientWidth-i);o<=Om&&(a-=o)}else l<=Om&&(a+=l);return{width:a,height:o,x:s,y:c}}var Am=new Set([`absolute`,`fixed`]);function jm(e,t){let n=Sm(e,!0,t===`fixed`),r=n.top+e.clientTop,i=n.left+e.clientLeft,a=Jp(e)?vm(e):rp(1);return{width:e.clientWidth*a.x,height:e.clientHeight*a.y,x:i*a.x,y:r*a.y}}function Mm(e,t,n){let r;if(t===`viewport`)r=km(e,n);else if(t===`document`)r=Dm(Gp(e));else if(qp(t))r=jm(t,n);else{let n=bm(e);r={x:t.x-n.x,y:t.y-n.y,width:t.width,height:t.height}}return Dp(r)}function Nm(e,t){let n=fm(e);return n===t||!qp(n)||lm(n)?!1:um(n).position===`fixed`||Nm(n,t)}function Pm(e,t){let n=t.get(e);if(n)return n;let r=mm(e,[],!1).filter(e=>qp(e)&&Up(e)!==`body`),i=null,a=um(e).position===`fixed`,o=a?fm(e):e;for(;qp(o)&&!lm(o);){let t=um(o),n=am(o);!n&&t.position===`fixed`&&(i=null),(a?!n&&!i:!n&&t.position===`static`&&i&&Am.has(i.position)||Zp(o)&&!n&&Nm(e,o))?r=r.filter(e=>e!==o):i=t,o=fm(o)}return t.set(e,r),r}function Fm(e){let{element:t,boundary:n,rootBoundary:r,strategy:i}=e,a=[...n===`clippingAncestors`?tm(t)?[]:Pm(t,this._c):[].concat(n),r],o=a[0],s=a.reduce((e,n)=>{let r=Mm(t,n,i);return e.top=ep(r.top,e.top),e.right=$f(r.right,e.right),e.bottom=$f(r.bottom,e.bottom),e.left=ep(r.left,e.left),e},Mm(t,o,i));return{width:s.right-s.left,height:s.bottom-s.top,x:s.left,y:s.top}}function Im(e){let{width:t,height:n}=gm(e);return{width:t,height:n}}function Lm(e,t,n){let r=Jp(t),i=Gp(t),a=n===`fixed`,o=Sm(e,!0,a,t),s={scrollLeft:0,scrollTop:0},c=rp(0);function l(){c.x=Cm(i)}if(r||!r&&!a)if((Up(t)!==`body`||Zp(i))&&(s=dm(t)),r){let e=Sm(t,!0,a,t);c.x=e.x+t.clientLeft,c.y=e.y+t.clientTop}else i&&l();a&&!r&&i&&l();let u=i&&!r&&!a?wm(i,s):rp(0);return{x:o.left+s.scrollLeft-c.x-u.x,y:o.top+s.scrollTop-c.y-u.y,width:o.width,height:o.height}}function Rm(e){return um(e).position===`static`}function zm(e,t){if(!Jp(e)||um(e).position===`fixed`)return null;if(t)return t(e);let n=e.offsetParent;return Gp(e)===n&&(n=n.ownerDocument.body),n}function Bm(e,t){let n=Wp(e);if(tm(e))return n;if(!Jp(e)){let t=fm(e);for(;t&&!lm(t);){if(qp(t)&&!Rm(t))return t;t=fm(t)}return n}let r=zm(e,t);for(;r&&$p(r)&&Rm(r);)r=zm(r,t);return r&&lm(r)&&Rm(r)&&!am(r)?n:r||om(e)||n}var Vm=async function(e){let t=this.getOffsetParent||Bm,n=this.getDimensions,r=await n(e.floating);return{reference:Lm(e.reference,await t(e.floating),e.strategy),floating:{x:0,y:0,width:r.width,height:r.height}}};function Hm(e){return um(e).direction===`rtl`}var Um={convertOffsetParentRelativeRectToViewportRelativeRect:Tm,getDocumentElement:Gp,getClippingRect:Fm,getOffsetParent:Bm,getElementRects:Vm,getClientRects:Em,getDimensions:Im,getScale:vm,isElement:qp,isRTL:Hm};function Wm(e,t){return e.x===t.x&&e.y===t.y&&e.width===t.width&&e.height===t.height}function Gm(e,t){let n=null,r,i=Gp(e);function a(){var e;clearTimeout(r),(e=n)==null||e.disconnect(),n=null}function o(s,c){s===void 0&&(s=!1),c===void 0&&(c=1),a();let l=e.getBoundingClientRect(),{left:u,top:d,width:f,height:p}=l;if(s||t(),!f||!p)return;let m=np(d),h=np(i.clientWidth-(u+f)),g=np(i.clientHeight-(d+p)),_=np(u),v={rootMargin:-m+`px `+-h+`px `+-g+`px `+-_+`px`,threshold:ep(0,$f(1,c))||1},y=!0;function b(t){let n=t[0].intersectionRatio;if(n!==c){if(!y)return o();n?o(!1,n):r=setTimeout(()=>{o(!1,1e-7)},1e3)}n===1&&!Wm(l,e.getBoundingClientRect())&&o(),y=!1}try{n=new IntersectionObserver(b,{...v,root:i.ownerDocument})}catch{n=new IntersectionObserver(b,v)}n.observe(e)}return o(!0),a}function Km(e,t,n,r){r===void 0&&(r={});let{ancestorScroll:i=!0,ancestorResize:a=!0,elementResize:o=typeof ResizeObserver==`function`,layoutShift:s=typeof IntersectionObserver==`function`,animationFrame:c=!1}=r,l=_m(e),u=i||a?[...l?mm(l):[],...mm(t)]:[];u.forEach(e=>{i&&e.addEventListener(`scroll`,n,{passive:!0}),a&&e.addEventListener(`resize`,n)});let d=l&&s?Gm(l,n):null,f=-1,p=null;o&&(p=new ResizeObserver(e=>{let[r]=e;r&&r.target===l&&p&&(p.unobserve(t),cancelAnimationFrame(f),f=requestAnimationFrame(()=>{var e;(e=p)==null||e.observe(t)})),n()}),l&&!c&&p.observe(l),p.observe(t));let m,h=c?Sm(e):null;c&&g();function g(){let t=Sm(e);h&&!Wm(h,t)&&n(),h=t,m=requestAnimationFrame(g)}return n(),()=>{var e;u.forEach(e=>{i&&e.removeEventListener(`scroll`,n),a&&e.removeEventListener(`resize`,n)}),d?.(),(e=p)==null||e.disconnect(),p=null,c&&cancelAnimationFrame(m)}}var qm=Rp,Jm=zp,Ym=Mp,Xm=Vp,Zm=Fp,Qm=jp,$m=Bp,eh=(e,t,n)=>{let r=new Map,i={platform:Um,...n},a={...i.platform,_c:r};return kp(e,t,{...i,platform:a})};function th(e){return typeof e==`object`&&!!e&&`$el`in e}function nh(e){if(th(e)){let t=e.$el;return Kp(t)&&Up(t)===`#comment`?null:t}return e}function rh(e){return typeof e==`function`?e():N(e)}function ih(e){return{name:`arrow`,options:e,fn(t){let n=nh(rh(e.element));return n==null?{}:Qm({element:n,padding:e.padding}).fn(t)}}}function ah(e){return typeof window>`u`?1:(e.ownerDocument.defaultView||window).devicePixelRatio||1}function oh(e,t){let n=ah(e);return Math.round(t*n)/n}function sh(e,t,n){n===void 0&&(n={});let r=n.whileElementsMounted,i=G(()=>rh(n.open)??!0),a=G(()=>rh(n.middleware)),o=G(()=>rh(n.placement)??`bottom`),s=G(()=>rh(n.strategy)??`absolute`),c=G(()=>rh(n.transform)??!0),l=G(()=>nh(e.value)),u=G(()=>nh(t.value)),d=M(0),f=M(0),p=M(s.value),m=M(o.value),h=dn({}),g=M(!1),_=G(()=>{let e={position:p.value,left:`0`,top:`0`};if(!u.value)return e;let t=oh(u.value,d.value),n=oh(u.value,f.value);return c.value?{...e,transform:`translate(`+t+`px, `+n+`px)`,...ah(u.value)>=1.5&&{willChange:`transform`}}:{position:p.value,left:t+`px`,top:n+`px`}}),v;function y(){if(l.value==null||u.value==null)return;let e=i.value;eh(l.value,u.value,{middleware:a.value,placement:o.value,strategy:s.value}).then(t=>{d.value=t.x,f.value=t.y,p.value=t.strategy,m.value=t.placement,h.value=t.middlewareData,g.value=e!==!1})}function b(){typeof v==`function`&&(v(),v=void 0)}function x(){if(b(),r===void 0){y();return}if(l.value!=null&&u.value!=null){v=r(l.value,u.value,y);return}}function S(){i.value||(g.value=!1)}return L([a,o,s,i],y,{flush:`sync`}),L([l,u],x,{flush:`sync`}),L(i,S,{flush:`sync`}),Pe()&&Fe(b),{x:en(d),y:en(f),strategy:en(p),placement:en(m),middlewareData:en(h),isPositioned:en(g),floatingStyles:_,update:y}}var ch={side:`bottom`,sideOffset:0,sideFlip:!0,align:`center`,alignOffset:0,alignFlip:!0,arrowPadding:0,avoidCollisions:!0,collisionBoundary:()=>[],collisionPadding:0,sticky:`partial`,hideWhenDetached:!1,positionStrategy:`fixed`,updatePositionStrategy:`optimized`,prioritizePosition:!1},[lh,uh]=bu(`PopperContent`),dh=F({inheritAttrs:!1,__name:`PopperContent`,props:ha({side:{type:null,required:!1},sideOffset:{type:Number,required:!1},sideFlip:{type:Boolean,required:!1},align:{type:null,required:!1},alignOffset:{type:Number,required:!1},alignFlip:{type:Boolean,required:!1},avoidCollisions:{type:Boolean,required:!1},collisionBoundary:{type:null,required:!1},collisionPadding:{type:[Number,Object],required:!1},arrowPadding:{type:Number,required:!1},sticky:{type:String,required:!1},hideWhenDetached:{type:Boolean,required:!1},positionStrategy:{type:String,required:!1},updatePositionStrategy:{type:String,required:!1},disableUpdateOnLayoutShift:{type:Boolean,required:!1},prioritizePosition:{type:Boolean,required:!1},reference:{type:null,required:!1},asChild:{type:Boolean,required:!1},as:{type:null,required:!1}},{...ch}),emits:[`placed`],setup(e,{emit:t}){let n=e,r=t,i=Gf(),{forwardRef:a,currentElement:o}=q(),s=M(),c=M(),{width:l,height:u}=jd(c),d=G(()=>n.side+(n.align===`center`?``:`-${n.align}`)),f=G(()=>typeof n.collisionPadding==`number`?n.collisionPadding:{top:0,right:0,bottom:0,left:0,...n.collisionPadding}),p=G(()=>Array.isArray(n.collisionBoundary)?n.collisionBoundary:[n.collisionBoundary]),m=G(()=>({padding:f.value,boundary:p.value.filter(Yf),altBoundary:p.value.length>0})),h=G(()=>({mainAxis:n.sideFlip,crossAxis:n.alignFlip})),g=ku(()=>[qm({mainAxis:n.sideOffset+u.value,alignmentAxis:n.alignOffset}),n.prioritizePosition&&n.avoidCollisions&&Ym({...m.value,...h.value}),n.avoidCollisions&&Jm({mainAxis:!0,crossAxis:!!n.prioritizePosition,limiter:n.sticky===`partial`?$m():void 0,...m.value}),!n.prioritizePosition&&n.avoidCollisions&&Ym({...m.value,...h.value}),Xm({...m.value,apply:({elements:e,rects:t,availableWidth:n,availableHeight:r})=>{let{width:i,height:a}=t.reference,o=e.floating.style;o.setProperty(`--reka-popper-available-width`,`${n}px`),o.setProperty(`--reka-popper-available-height`,`${r}px`),o.setProperty(`--reka-popper-anchor-width`,`${i}px`),o.setProperty(`--reka-popper-anchor-height`,`${a}px`)}}),c.value&&ih({element:c.value,padding:n.arrowPadding}),Xf({arrowWidth:l.value,arrowHeight:u.value}),n.hideWhenDetached&&Zm({strategy:`referenceHidden`,...m.value})]),{floatingStyles:_,placement:v,isPositioned:y,middlewareData:b,update:x}=sh(G(()=>n.reference??i.anchor.value),s,{strategy:n.positionStrategy,placement:d,whileElementsMounted:(...e)=>Km(...e,{layoutShift:!n.disableUpdateOnLayoutShift,animationFrame:n.updatePositionStrategy===`always`}),middleware:g}),S=G(()=>Zf(v.value)[0]),C=G(()=>Zf(v.value)[1]);Wa(()=>{y.value&&r(`placed`)});let w=G(()=>b.value.arrow?.centerOffset!==0),T=M(``);return Ua(()=>{o.value&&(T.value=window.getComputedStyle(o.value).zIndex)}),uh({placedSide:S,onArrowChange:e=>c.value=e,arrowX:G(()=>b.value.arrow?.x??0),arrowY:G(()=>b.value.arrow?.y??0),shouldHideArrow:w}),(e,t)=>(z(),B(`div`,{ref_key:`floatingRef`,ref:s,"data-reka-popper-content-wrapper":``,style:me({...N(_),transform:N(y)?N(_).transform:`translate(0, -200%)`,minWidth:`max-content`,zIndex:T.value,"--reka-popper-transform-origin":[N(b).transformOrigin?.x,N(b).transformOrigin?.y].join(` `),...N(b).hide?.referenceHidden&&{visibility:`hidden`,pointerEvents:`none`}})},[U(N(Vd),W({ref:N(a)},e.$attrs,{"as-child":n.asChild,as:e.as,"data-side":S.value,"data-align":C.value,style:{animation:N(y)?void 0:`none`}}),{default:P(()=>[I(e.$slots,`default`)]),_:3},16,[`as-child`,`as`,`data-side`,`data-align`,`style`])],4))}}),fh=F({__name:`MenuAnchor`,props:{reference:{type:null,required:!1},asChild:{type:Boolean,required:!1},as:{type:null,required:!1}},setup(e){let t=e;return(e,n)=>(z(),V(N(Jf),ye(fs(t)),{default:P(()=>[I(e.$slots,`default`)]),_:3},16))}});function ph(){let e=M(!1);return Mi(()=>{Xu(`keydown`,()=>{e.value=!0},{capture:!0,passive:!0}),Xu([`pointerdown`,`pointermove`],()=>{e.value=!1},{capture:!0,passive:!0})}),e}var mh=Mu(ph),[hh,gh]=bu([`MenuRoot`,`MenuSub`],`MenuContext`),[_h,vh]=bu(`MenuRoot`),yh=F({__name:`MenuRoot`,props:{open:{type:Boolean,required:!1,default:!1},dir:{type:String,required:!1},modal:{type:Boolean,required:!1,default:!0}},emits:[`update:open`],setup(e,{emit:t}){let n=e,r=t,{modal:i,dir:a}=bn(n),o=fd(a),s=rd(n,`open`,r),c=M(),l=mh();return gh({open:s,onOpenChange:e=>{s.value=e},content:c,onContentChange:e=>{c.value=e}}),vh({onClose:()=>{s.value=!1},isUsingKeyboardRef:l,dir:o,modal:i}),(e,t)=>(z(),V(N(qf),null,{default:P(()=>[I(e.$slots,`default`)]),_:3}))}}),[bh,xh]=bu(`MenuContent`),Sh=F({__name:`MenuContentImpl`,props:ha({loop:{type:Boolean,required:!1},disableOutsidePointerEvents:{type:Boolean,required:!1},disableOutsideScroll:{type:Boolean,required:!1},trapFocus:{type:Boolean,required:!1},side:{type:null,required:!1},sideOffset:{type:Number,required:!1},sideFlip:{type:Boolean,required:!1},align:{type:null,required:!1},alignOffset:{type:Number,required:!1},alignFlip:{type:Boolean,required:!1},avoidCollisions:{type:Boolean,required:!1},collisionBoundary:{type:null,required:!1},collisionPadding:{type:[Number,Object],required:!1},arrowPadding:{type:Number,required:!1},sticky:{type:String,required:!1},hideWhenDetached:{type:Boolean,required:!1},positionStrategy:{type:String,required:!1},updatePositionStrategy:{type:String,required:!1},disableUpdateOnLayoutShift:{type:Boolean,required:!1},prioritizePosition:{type:Boolean,required:!1},reference:{type:null,required:!1},asChild:{type:Boolean,required:!1},as:{type:null,required:!1}},{...ch}),emits:[`escapeKeyDown`,`pointerDownOutside`,`focusOutside`,`interactOutside`,`entryFocus`,`openAutoFocus`,`closeAutoFocus`,`dismiss`],setup(e,{emit:t}){let n=e,r=t,i=hh(),a=_h(),{trapFocus:o,disableOutsidePointerEvents:s,loop:c}=bn(n);hd(),ld(s.value);let l=M(``),u=M(0),d=M(0),f=M(null),p=M(`right`),m=M(0),h=M(null),g=M(),{forwardRef:_,currentElement:v}=q(),{handleTypeaheadSearch:y}=Nd();L(v,e=>{i.onContentChange(e)}),Ii(()=>{window.clearTimeout(u.value)});function b(e){return p.value===f.value?.side&&wf(e,f.value?.area)}async function x(e){r(`openAutoFocus`,e),!e.defaultPrevented&&(e.preventDefault(),v.value?.focus({preventScroll:!0}))}function S(e){if(e.defaultPrevented)return;let t=e.target.closest(`[data-reka-menu-content]`)===e.currentTarget,n=e.ctrlKey||e.altKey||e.metaKey,r=e.key.length===1,i=Tu(e,xu(),v.value,{loop:c.value,arrowKeyOptions:`vertical`,dir:a?.dir.value,focus:!0,attributeName:`[data-reka-collection-item]:not([data-disabled])`});if(i)return i?.focus();if(e.code===`Space`)return;let o=g.value?.getItems()??[];if(t&&(e.key===`Tab`&&e.preventDefault(),!n&&r&&y(e.key,o)),e.target!==v.value||!bf.includes(e.key))return;e.preventDefault();let s=[...o.map(e=>e.ref)];yf.includes(e.key)&&s.reverse(),Sf(s)}function C(e){e?.currentTarget?.contains?.(e.target)||(window.clearTimeout(u.value),l.value=``)}function w(e){if(!Tf(e))return;let t=e.target,n=m.value!==e.clientX;(e?.currentTarget)?.contains(t)&&n&&(p.value=e.clientX>m.value?`right`:`left`,m.value=e.clientX)}return xh({onItemEnter:e=>!!b(e),onItemLeave:e=>{b(e)||(v.value?.focus(),h.value=null)},onTriggerLeave:e=>!!b(e),searchRef:l,pointerGraceTimerRef:d,onPointerGraceIntentChange:e=>{f.value=e}}),(e,t)=>(z(),V(N(hf),{"as-child":``,trapped:N(o),onMountAutoFocus:x,onUnmountAutoFocus:t[7]||=e=>r(`closeAutoFocus`,e)},{default:P(()=>[U(N($d),{"as-child":``,"disable-outside-pointer-events":N(s),onEscapeKeyDown:t[2]||=e=>r(`escapeKeyDown`,e),onPointerDownOutside:t[3]||=e=>r(`pointerDownOutside`,e),onFocusOutside:t[4]||=e=>r(`focusOutside`,e),onInteractOutside:t[5]||=e=>r(`interactOutside`,e),onDismiss:t[6]||=e=>r(`dismiss`)},{default:P(()=>[U(N(Wf),{ref_key:`rovingFocusGroupRef`,ref:g,"current-tab-stop-id":h.value,"onUpdate:currentTabStopId":t[0]||=e=>h.value=e,"as-child":``,orientation:`vertical`,dir:N(a).dir.value,loop:N(c),onEntryFocus:t[1]||=e=>{r(`entryFocus`,e),N(a).isUsingKeyboardRef.value||e.preventDefault()}},{default:P(()=>[U(N(dh),{ref:N(_),role:`menu`,as:e.as,"as-child":e.asChild,"aria-orientation":`vertical`,"data-reka-menu-content":``,"data-state":N(xf)(N(i).open.value),dir:N(a).dir.value,side:e.side,"side-offset":e.sideOffset,align:e.align,"align-offset":e.alignOffset,"avoid-collisions":e.avoidCollisions,"collision-boundary":e.collisionBoundary,"collision-padding":e.collisionPadding,"arrow-padding":e.arrowPadding,"prioritize-position":e.prioritizePosition,"position-strategy":e.positionStrategy,"update-position-strategy":e.updatePositionStrategy,sticky:e.sticky,"hide-when-detached":e.hideWhenDetached,reference:e.reference,onKeydown:S,onBlur:C,onPointermove:w},{default:P(()=>[I(e.$slots,`default`)]),_:3},8,[`as`,`as-child`,`data-state`,`dir`,`side`,`side-offset`,`align`,`align-offset`,`avoid-collisions`,`collision-boundary`,`collision-padding`,`arrow-padding`,`prioritize-position`,`position-strategy`,`update-position-strategy`,`sticky`,`hide-when-detached`,`reference`])]),_:3},8,[`current-tab-stop-id`,`dir`,`loop`])]),_:3},8,[`disable-outside-pointer-events`])]),_:3},8,[`trapped`]))}}),Ch=F({inheritAttrs:!1,__name:`MenuItemImpl`,props:{disabled:{type:Boolean,required:!1},textValue:{type:String,required:!1},asChild:{type:Boolean,required:!1},as:{type:null,required:!1}},setup(e){let t=e,n=bh(),{forwardRef:r}=q(),{CollectionItem:i}=Rf(),a=M(!1);async function o(e){e.defaultPrevented||Tf(e)&&(t.disabled?n.onItemLeave(e):n.onItemEnter(e)||e.currentTarget?.focus({preventScroll:!0}))}async function s(e){await $n(),!e.defaultPrevented&&Tf(e)&&n.onItemLeave(e)}return(e,t)=>(z(),V(N(i),{value:{textValue:e.textValue}},{default:P(()=>[U(N(Vd),W({ref:N(r),role:`menuitem`,tabindex:`-1`},e.$attrs,{as:e.as,"as-child":e.asChild,"aria-disabled":e.disabled||void 0,"data-disabled":e.disabled?``:void 0,"data-highlighted":a.value?``:void 0,onPointermove:o,onPointerleave:s,onFocus:t[0]||=async t=>{await $n(),!(t.defaultPrevented||e.disabled)&&(a.value=!0)},onBlur:t[1]||=async e=>{await $n(),!e.defaultPrevented&&(a.value=!1)}}),{default:P(()=>[I(e.$slots,`default`)]),_:3},16,[`as`,`as-child`,`aria-disabled`,`data-disabled`,`data-highlighted`])]),_:3},8,[`value`]))}}),wh=F({__name:`MenuItem`,props:{disabled:{type:Boolean,required:!1},textValue:{type:String,required:!1},asChild:{type:Boolean,required:!1},as:{type:null,required:!1}},emits:[`select`],setup(e,{emit:t}){let n=e,r=t,{forwardRef:i,currentElement:a}=q(),o=_h(),s=bh(),c=M(!1);async function l(){let e=a.value;if(!n.disabled&&e){let e=new CustomEvent(gf,{bubbles:!0,cancelable:!0});r(`select`,e),await $n(),e.defaultPrevented?c.value=!1:o.onClose()}}return(e,t)=>(z(),V(Ch,W(n,{ref:N(i),onClick:l,onPointerdown:t[0]||=()=>{c.value=!0},onPointerup:t[1]||=async e=>{await $n(),!e.defaultPrevented&&(c.value||e.currentTarget?.click())},onKeydown:t[2]||=async t=>{let n=N(s).searchRef.value!==``;e.disabled||n&&t.key===` `||N(_f).includes(t.key)&&(t.currentTarget.click(),t.preventDefault())}}),{default:P(()=>[I(e.$slots,`default`)]),_:3},16))}}),Th=F({__name:`MenuRootContentModal`,props:{loop:{type:Boolean,required:!1},side:{type:null,required:!1},sideOffset:{type:Number,required:!1},sideFlip:{type:Boolean,required:!1},align:{type:null,required:!1},alignOffset:{type:Number,required:!1},alignFlip:{type:Boolean,required:!1},avoidCollisions:{type:Boolean,required:!1},collisionBoundary:{type:null,required:!1},collisionPadding:{type:[Number,Object],required:!1},arrowPadding:{type:Number,required:!1},sticky:{type:String,required:!1},hideWhenDetached:{type:Boolean,required:!1},positionStrategy:{type:String,required:!1},updatePositionStrategy:{type:String,required:!1},disableUpdateOnLayoutShift:{type:Boolean,required:!1},prioritizePosition:{type:Boolean,required:!1},reference:{type:null,required:!1},asChild:{type:Boolean,required:!1},as:{type:null,required:!1}},emits:[`escapeKeyDown`,`pointerDownOutside`,`focusOutside`,`interactOutside`,`entryFocus`,`openAutoFocus`,`closeAutoFocus`],setup(e,{emit:t}){let n=e,r=t,i=vd(n,r),a=hh(),{forwardRef:o,currentElement:s}=q();return Od(s),(e,t)=>(z(),V(Sh,W(N(i),{ref:N(o),"trap-focus":N(a).open.value,"disable-outside-pointer-events":N(a).open.value,"disable-outside-scroll":!0,onDismiss:t[0]||=e=>N(a).onOpenChange(!1),onFocusOutside:t[1]||=Wl(e=>r(`focusOutside`,e),[`prevent`])}),{default:P(()=>[I(e.$slots,`default`)]),_:3},16,[`trap-focus`,`disable-outside-pointer-events`]))}}),Eh=F({__name:`MenuRootContentNonModal`,props:{loop:{type:Boolean,required:!1},side:{type:null,required:!1},sideOffset:{type:Number,required:!1},sideFlip:{type:Boolean,required:!1},align:{type:null,required:!1},alignOffset:{type:Number,required:!1},alignFlip:{type:Boolean,required:!1},avoidCollisions:{type:Boolean,required:!1},collisionBoundary:{type:null,required:!1},collisionPadding:{type:[Number,Object],required:!1},arrowPadding:{type:Number,required:!1},sticky:{type:String,required:!1},hideWhenDetached:{type:Boolean,required:!1},positionStrategy:{type:String,required:!1},updatePositionStrategy:{type:String,required:!1},disableUpdateOnLayoutShift:{type:Boolean,required:!1},prioritizePosition:{type:Boolean,required:!1},reference:{type:null,required:!1},asChild:{type:Boolean,required:!1},as:{type:null,required:!1}},emits:[`escapeKeyDown`,`pointerDownOutside`,`focusOutside`,`interactOutside`,`entryFocus`,`openAutoFocus`,`closeAutoFocus`],setup(e,{emit:t}){let n=vd(e,t),r=hh();return(e,t)=>(z(),V(Sh,W(N(n),{"trap-focus":!1,"disable-outside-pointer-events":!1,"disable-outside-scroll":!1,onDismiss:t[0]||=e=>N(r).onOpenChange(!1)}),{default:P(()=>[I(e.$slots,`default`)]),_:3},16))}}),Dh=F({__name:`MenuContent`,props:{forceMount:{type:Boolean,required:!1},loop:{type:Boolean,required:!1},side:{type:null,required:!1},sideOffset:{type:Number,required:!1},sideFlip:{type:Boolean,required:!1},align:{type:null,required:!1},alignOffset:{type:Number,required:!1},alignFlip:{type:Boolean,required:!1},avoidCollisions:{type:Boolean,required:!1},collisionBoundary:{type:null,required:!1},collisionPadding:{type:[Number,Object],required:!1},arrowPadding:{type:Number,required:!1},sticky:{type:String,required:!1},hideWhenDetached:{type:Boolean,required:!1},positionStrategy:{type:String,required:!1},updatePositionStrategy:{type:String,required:!1},disableUpdateOnLayoutShift:{type:Boolean,required:!1},prioritizePosition:{type:Boolean,required:!1},reference:{type:null,required:!1},asChild:{type:Boolean,required:!1},as:{type:null,required:!1}},emits:[`escapeKeyDown`,`pointerDownOutside`,`focusOutside`,`interactOutside`,`entryFocus`,`openAutoFocus`,`closeAutoFocus`],setup(e,{emit:t}){let n=vd(e,t),r=hh(),i=_h();return(e,t)=>(z(),V(N(Rd),{present:e.forceMount||N(r).open.value},{default:P(()=>[N(i).modal.value?(z(),V(Th,ye(W({key:0},{...e.$attrs,...N(n)})),{default:P(()=>[I(e.$slots,`default`)]),_:3},16)):(z(),V(Eh,ye(W({key:1},{...e.$attrs,...N(n)})),{default:P(()=>[I(e.$slots,`default`)]),_:3},16))]),_:3},8,[`present`]))}}),Oh=F({__name:`MenuPortal`,props:{to:{type:null,required:!1},disabled:{type:Boolean,required:!1},defer:{type:Boolean,required:!1},forceMount:{type:Boolean,required:!1}},setup(e){let t=e;return(e,n)=>(z(),V(N(Nf),ye(fs(t)),{default:P(()=>[I(e.$slots,`default`)]),_:3},16))}}),[kh,Ah]=bu(`DropdownMenuRoot`),jh=F({__name:`DropdownMenuRoot`,props:{defaultOpen:{type:Boolean,required:!1},open:{type:Boolean,required:!1,default:void 0},dir:{type:String,required:!1},modal:{type:Boolean,required:!1,default:!0}},emits:[`update:open`],setup(e,{emit:t}){let n=e,r=t;q();let i=rd(n,`open`,r,{defaultValue:n.defaultOpen,passive:n.open===void 0}),a=M(),{modal:o,dir:s}=bn(n),c=fd(s);return Ah({open:i,onOpenChange:e=>{i.value=e},onOpenToggle:()=>{i.value=!i.value},triggerId:``,triggerElement:a,contentId:``,modal:o,dir:c}),(e,t)=>(z(),V(N(yh),{open:N(i),"onUpdate:open":t[0]||=e=>un(i)?i.value=e:null,dir:N(c),modal:N(o)},{default:P(()=>[I(e.$slots,`default`,{open:N(i)})]),_:3},8,[`open`,`dir`,`modal`]))}}),Mh=F({__name:`DropdownMenuContent`,props:{forceMount:{type:Boolean,required:!1},loop:{type:Boolean,required:!1},side:{type:null,required:!1},sideOffset:{type:Number,required:!1},sideFlip:{type:Boolean,required:!1},align:{type:null,required:!1},alignOffset:{type:Number,required:!1},alignFlip:{type:Boolean,required:!1},avoidCollisions:{type:Boolean,required:!1},collisionBoundary:{type:null,required:!1},collisionPadding:{type:[Number,Object],required:!1},arrowPadding:{type:Number,required:!1},sticky:{type:String,required:!1},hideWhenDetached:{type:Boolean,required:!1},positionStrategy:{type:String,required:!1},updatePositionStrategy:{type:String,required:!1},disableUpdateOnLayoutShift:{type:Boolean,required:!1},prioritizePosition:{type:Boolean,required:!1},reference:{type:null,required:!1},asChild:{type:Boolean,required:!1},as:{type:null,required:!1}},emits:[`escapeKeyDown`,`pointerDownOutside`,`focusOutside`,`interactOutside`,`closeAutoFocus`],setup(e,{emit:t}){let n=vd(e,t);q();let r=kh(),i=M(!1);function a(e){e.defaultPrevented||(i.value||setTimeout(()=>{r.triggerElement.value?.focus()},0),i.value=!1,e.preventDefault())}return r.contentId||=Ad(void 0,`reka-dropdown-menu-content`),(e,t)=>(z(),V(N(Dh),W(N(n),{id:N(r).contentId,"aria-labelledby":N(r)?.triggerId,style:{"--reka-dropdown-menu-content-transform-origin":`var(--reka-popper-transform-origin)`,"--reka-dropdown-menu-content-available-width":`var(--reka-popper-available-width)`,"--reka-dropdown-menu-content-available-height":`var(--reka-popper-available-height)`,"--reka-dropdown-menu-trigger-width":`var(--reka-popper-anchor-width)`,"--reka-dropdown-menu-trigger-height":`var(--reka-popper-anchor-height)`},onCloseAutoFocus:a,onInteractOutside:t[0]||=e=>{if(e.defaultPrevented)return;let t=e.detail.originalEvent,n=t.button===0&&t.ctrlKey===!0,a=t.button===2||n;(!N(r).modal.value||a)&&(i.value=!0),N(r).triggerElement.value?.contains(e.target)&&e.preventDefault()}}),{default:P(()=>[I(e.$slots,`default`)]),_:3},16,[`id`,`aria-labelledby`]))}}),Nh=F({__name:`DropdownMenuItem`,props:{disabled:{type:Boolean,required:!1},textValue:{type:String,required:!1},asChild:{type:Boolean,required:!1},as:{type:null,required:!1}},emits:[`select`],setup(e,{emit:t}){let n=e,r=pd(t);return q(),(e,t)=>(z(),V(N(wh),ye(fs({...n,...N(r)})),{default:P(()=>[I(e.$slots,`default`)]),_:3},16))}}),Ph=F({__name:`DropdownMenuPortal`,props:{to:{type:null,required:!1},disabled:{type:Boolean,required:!1},defer:{type:Boolean,required:!1},forceMount:{type:Boolean,required:!1}},setup(e){let t=e;return(e,n)=>(z(),V(N(Oh),ye(fs(t)),{default:P(()=>[I(e.$slots,`default`)]),_:3},16))}}),Fh=F({__name:`DropdownMenuTrigger`,props:{disabled:{type:Boolean,required:!1},asChild:{type:Boolean,required:!1},as:{type:null,required:!1,default:`button`}},setup(e){let t=e,n=kh(),{forwardRef:r,currentElement:i}=q();return Mi(()=>{n.triggerElement=i}),n.triggerId||=Ad(void 0,`reka-dropdown-menu-trigger`),(e,i)=>(z(),V(N(fh),{"as-child":``},{default:P(()=>[U(N(Vd),{id:N(n).triggerId,ref:N(r),type:e.as===`button`?`button`:void 0,"as-child":t.asChild,as:e.as,"aria-haspopup":`menu`,"aria-expanded":N(n).open.value,"aria-controls":N(n).open.value?N(n).contentId:void 0,"data-disabled":e.disabled?``:void 0,disabled:e.disabled,"data-state":N(n).open.value?`open`:`closed`,onClick:i[0]||=async t=>{!e.disabled&&t.button===0&&t.ctrlKey===!1&&(N(n)?.onOpenToggle(),await $n(),N(n).open.value&&t.preventDefault())},onKeydown:i[1]||=Kl(t=>{e.disabled||([`Enter`,` `].includes(t.key)&&N(n).onOpenToggle(),t.key===`ArrowDown`&&N(n).onOpenChange(!0),[`Enter`,` `,`ArrowDown`].includes(t.key)&&t.preventDefault())},[`enter`,`space`,`arrow-down`])},{default:P(()=>[I(e.$slots,`default`)]),_:3},8,[`id`,`type`,`as-child`,`as`,`aria-expanded`,`aria-controls`,`data-disabled`,`disabled`,`data-state`])]),_:3}))}}),Ih=F({__name:`Label`,props:{for:{type:String,required:!1},asChild:{type:Boolean,required:!1},as:{type:null,required:!1,default:`label`}},setup(e){let t=e;return q(),(e,n)=>(z(),V(N(Vd),W(t,{onMousedown:n[0]||=e=>{!e.defaultPrevented&&e.detail>1&&e.preventDefault()}}),{default:P(()=>[I(e.$slots,`default`)]),_:3},16))}}),[Lh,Rh]=bu([`NavigationMenuRoot`,`NavigationMenuSub`],`NavigationMenuContext`),zh=F({__name:`NavigationMenuRoot`,props:{modelValue:{type:String,required:!1,default:void 0},defaultValue:{type:String,required:!1},dir:{type:String,required:!1},orientation:{type:String,required:!1,default:`horizontal`},delayDuration:{type:Number,required:!1,default:200},skipDelayDuration:{type:Number,required:!1,default:300},disableClickTrigger:{type:Boolean,required:!1,default:!1},disableHoverTrigger:{type:Boolean,required:!1,default:!1},disablePointerLeaveClose:{type:Boolean,required:!1},unmountOnHide:{type:Boolean,required:!1,default:!0},asChild:{type:Boolean,required:!1},as:{type:null,required:!1,default:`nav`}},emits:[`update:modelValue`],setup(e,{emit:t}){let n=e,r=rd(n,`modelValue`,t,{defaultValue:n.defaultValue??``,passive:n.modelValue===void 0}),i=M(``),{forwardRef:a,currentElement:o}=q(),s=M(),c=M(),l=M(),{getItems:u,CollectionSlot:d}=Rf({key:`NavigationMenu`,isProvider:!0}),{delayDuration:f,skipDelayDuration:p,dir:m,disableClickTrigger:h,disableHoverTrigger:g,unmountOnHide:_}=bn(n),v=fd(m),y=Wu(!1,p),b=Gu(e=>{typeof e==`string`&&(i.value=r.value,r.value=e)},G(()=>r.value!==``||y.value?150:f.value));return Ua(()=>{r.value&&(l.value=u().map(e=>e.ref).find(e=>e.id.includes(r.value)))}),Rh({isRootMenu:!0,modelValue:r,previousValue:i,baseId:Ad(void 0,`reka-navigation-menu`),disableClickTrigger:h,disableHoverTrigger:g,dir:v,unmountOnHide:_,orientation:n.orientation,rootNavigationMenu:o,indicatorTrack:s,activeTrigger:l,onIndicatorTrackChange:e=>{s.value=e},viewport:c,onViewportChange:e=>{c.value=e},onTriggerEnter:e=>{b(e)},onTriggerLeave:()=>{y.value=!0,b(``)},onContentEnter:()=>{b()},onContentLeave:()=>{n.disablePointerLeaveClose||b(``)},onItemSelect:e=>{i.value=r.value,r.value=e},onItemDismiss:()=>{i.value=r.value,r.value=``}}),(e,t)=>(z(),V(N(d),null,{default:P(()=>[U(N(Vd),{ref:N(a),"aria-label":`Main`,as:e.as,"as-child":e.asChild,"data-orientation":e.orientation,dir:N(v),"data-reka-navigation-menu":``},{default:P(()=>[I(e.$slots,`default`,{modelValue:N(r)})]),_:3},8,[`as`,`as-child`,`data-orientation`,`dir`])]),_:3}))}});function Bh(e){return e?`open`:`closed`}function Vh(e,t){return`${e}-content-${t}`}var Hh=`navigationMenu.linkSelect`,Uh=`navigationMenu.rootContentDismiss`;function Wh(e){let t=[],n=document.createTreeWalker(e,NodeFilter.SHOW_ELEMENT,{acceptNode:e=>{let t=e.tagName===`INPUT`&&e.type===`hidden`;return e.disabled||e.hidden||t?NodeFilter.FILTER_SKIP:e.tabIndex>=0?NodeFilter.FILTER_ACCEPT:NodeFilter.FILTER_SKIP}});for(;n.nextNode();)t.push(n.currentNode);return t}function Gh(e){let t=xu();return e.some(e=>e===t?!0:(e.focus(),xu()!==t))}function Kh(e){return e.forEach(e=>{e.dataset.tabindex=e.getAttribute(`tabindex`)||``,e.setAttribute(`tabindex`,`-1`)}),()=>{e.forEach(e=>{let t=e.dataset.tabindex;e.setAttribute(`tabindex`,t)})}}function qh(e){return t=>t.pointerType===`mouse`?e(t):void 0}var[Jh,Yh]=bu(`NavigationMenuItem`),Xh=F({__name:`NavigationMenuItem`,props:{value:{type:String,required:!1},asChild:{type:Boolean,required:!1},as:{type:null,required:!1,default:`li`}},setup(e){let t=e;q();let{getItems:n}=Rf({key:`NavigationMenu`}),r=Lh(),i=Ad(t.value),a=M(),o=M(),s=Vh(r.baseId,i),c=()=>({}),l=M(!1);async function u(e=`start`){let t=document.getElementById(s);if(t){c();let n=Wh(t);n.length&&Gh(e===`start`?n:n.reverse())}}function d(){let e=document.getElementById(s);if(e){let t=Wh(e);t.length&&(c=Kh(t))}}Yh({value:i,contentId:s,triggerRef:a,focusProxyRef:o,wasEscapeCloseRef:l,onEntryKeyDown:u,onFocusProxyEnter:u,onContentFocusOutside:d,onRootContentClose:d});function f(){r.onItemDismiss(),a.value?.focus()}function p(e){let t=xu();if(e.keyCode===32||e.key===`Enter`)if(r.modelValue.value===i){f(),e.preventDefault();return}else{e.target.click(),e.preventDefault();return}let a=n().filter(e=>e.ref.parentElement?.hasAttribute(`data-menu-item`)).map(e=>e.ref);if(!a.includes(t))return;let o=Tu(e,t,void 0,{itemsArray:a,loop:!1});o&&o?.focus(),e.preventDefault(),e.stopPropagation()}return(e,t)=>(z(),V(N(Vd),{"as-child":e.asChild,as:e.as,"data-menu-item":``,onKeydown:Kl(p,[`up`,`down`,`left`,`right`,`home`,`end`,`space`])},{default:P(()=>[I(e.$slots,`default`)]),_:3},8,[`as-child`,`as`]))}}),Zh=F({__name:`NavigationMenuLink`,props:{active:{type:Boolean,required:!1},asChild:{type:Boolean,required:!1},as:{type:null,required:!1,default:`a`}},emits:[`select`],setup(e,{emit:t}){let n=e,r=t,{CollectionItem:i}=Rf({key:`NavigationMenu`});q();async function a(e){let t=new CustomEvent(Hh,{bubbles:!0,cancelable:!0,detail:{originalEvent:e}});if(r(`select`,t),!t.defaultPrevented&&!e.metaKey){let t=new CustomEvent(Uh,{bubbles:!0,cancelable:!0});e.target?.dispatchEvent(t)}}return(e,t)=>(z(),V(N(i),null,{default:P(()=>[U(N(Vd),{as:e.as,"data-active":e.active?``:void 0,"aria-current":e.active?`page`:void 0,"as-child":n.asChild,onClick:a},{default:P(()=>[I(e.$slots,`default`)]),_:3},8,[`as`,`data-active`,`aria-current`,`as-child`])]),_:3}))}}),Qh=F({inheritAttrs:!1,__name:`NavigationMenuList`,props:{asChild:{type:Boolean,required:!1},as:{type:null,required:!1,default:`ul`}},setup(e){let t=e,n=Lh(),{forwardRef:r,currentElement:i}=q();return Mi(()=>{n.onIndicatorTrackChange(i.value)}),(e,i)=>(z(),V(N(Vd),{ref:N(r),style:{position:`relative`}},{default:P(()=>[U(N(Vd),W(e.$attrs,{"as-child":t.asChild,as:e.as,"data-orientation":N(n).orientation}),{default:P(()=>[I(e.$slots,`default`)]),_:3},16,[`as-child`,`as`,`data-orientation`])]),_:3},512))}}),$h=F({inheritAttrs:!1,__name:`NavigationMenuViewport`,props:{forceMount:{type:Boolean,required:!1},align:{type:String,required:!1,default:`center`},asChild:{type:Boolean,required:!1},as:{type:null,required:!1}},setup(e){let t=e,{forwardRef:n,currentElement:r}=q(),i=Lh(),{activeTrigger:a,rootNavigationMenu:o,modelValue:s}=i,c=M(),l=M(),u=G(()=>!!i.modelValue.value);L(r,()=>{i.onViewportChange(r.value)});let d=M();L([s,u],()=>{$n(()=>{r.value&&requestAnimationFrame(()=>{d.value=r.value?.querySelector(`[data-state=open]`)})})},{immediate:!0});function f(){if(d.value&&a.value&&o.value){let e=document.documentElement.offsetWidth,n=document.documentElement.offsetHeight,r=o.value.getBoundingClientRect(),i=a.value.getBoundingClientRect(),{offsetWidth:s,offsetHeight:c}=d.value,u=i.left-r.left,f=i.top-r.top,p=null,m=null;switch(t.align){case`start`:p=u,m=f;break;case`end`:p=u-s+i.width,m=f-c+i.height;break;default:p=u-s/2+i.width/2,m=f-c/2+i.height/2}p+r.left<10&&(p=10-r.left);let h=p+r.left+s;h>e-10&&(p-=h-e+10,p<10-r.left&&(p=10-r.left)),m+r.top<10&&(m=10-r.top);let g=m+r.top+c;g>n-10&&(m-=g-n+10,m<10-r.top&&(m=10-r.top)),p=Math.round(p),m=Math.round(m),l.value={left:p,top:m}}}return nd(d,()=>{d.value&&(c.value={width:d.value.offsetWidth,height:d.value.offsetHeight},f())}),nd([globalThis.document?.body,o],()=>{f()}),(e,t)=>(z(),V(N(Rd),{present:e.forceMount||u.value,"force-mount":!N(i).unmountOnHide.value,onAfterLeave:t[2]||=()=>{c.value=void 0,l.value=void 0}},{default:P(({present:r})=>[U(N(Vd),W(e.$attrs,{ref:N(n),as:e.as,"as-child":e.asChild,"data-state":N(Bh)(u.value),"data-orientation":N(i).orientation,style:{pointerEvents:!u.value&&N(i).isRootMenu?`none`:void 0,"--reka-navigation-menu-viewport-width":c.value?`${c.value?.width}px`:void 0,"--reka-navigation-menu-viewport-height":c.value?`${c.value?.height}px`:void 0,"--reka-navigation-menu-viewport-left":l.value?`${l.value?.left}px`:void 0,"--reka-navigation-menu-viewport-top":l.value?`${l.value?.top}px`:void 0},hidden:!r,onPointerenter:t[0]||=e=>N(i).onContentEnter(N(i).modelValue.value),onPointerleave:t[1]||=e=>N(qh)(()=>N(i).onContentLeave())(e)}),{default:P(()=>[I(e.$slots,`default`)]),_:2},1040,[`as`,`as-child`,`data-state`,`data-orientation`,`style`,`hidden`])]),_:3},8,[`present`,`force-mount`]))}}),eg=(e,t)=>{let n=Array(e.length+t.length);for(let t=0;t<e.length;t++)n[t]=e[t];for(let r=0;r<t.length;r++)n[e.length+r]=t[r];return n},tg=(e,t)=>({classGroupId:e,validator:t}),ng=(e=new Map,t=null,n)=>({nextPart:e,validators:t,classGroupId:n}),rg=`-`,ig=[],ag=`arbitrary..`,og=e=>{let t=lg(e),{conflictingClassGroups:n,conflictingClassGroupModifiers:r}=e;return{getClassGroupId:e=>{if(e.startsWith(`[`)&&e.endsWith(`]`))return cg(e);let n=e.split(rg);return sg(n,n[0]===``&&n.length>1?1:0,t)},getConflictingClassGroupIds:(e,t)=>{if(t){let t=r[e],i=n[e];return t?i?eg(i,t):t:i||ig}return n[e]||ig}}},sg=(e,t,n)=>{if(e.length-t===0)return n.classGroupId;let r=e[t],i=n.nextPart.get(r);if(i){let n=sg(e,t+1,i);if(n)return n}let a=n.validators;if(a===null)return;let o=t===0?e.join(rg):e.slice(t).join(rg),s=a.length;for(let e=0;e<s;e++){let t=a[e];if(t.validator(o))return t.classGroupId}},cg=e=>e.slice(1,-1).indexOf(`:`)===-1?void 0:(()=>{let t=e.slice(1,-1),n=t.indexOf(`:`),r=t.slice(0,n);return r?ag+r:void 0})(),lg=e=>{let{theme:t,classGroups:n}=e;return ug(n,t)},ug=(e,t)=>{let n=ng();for(let r in e){let i=e[r];dg(i,n,r,t)}return n},dg=(e,t,n,r)=>{let i=e.length;for(let a=0;a<i;a++){let i=e[a];fg(i,t,n,r)}},fg=(e,t,n,r)=>{if(typeof e==`string`){pg(e,t,n);return}if(typeof e==`function`){mg(e,t,n,r);return}hg(e,t,n,r)},pg=(e,t,n)=>{let r=e===``?t:gg(t,e);r.classGroupId=n},mg=(e,t,n,r)=>{if(_g(e)){dg(e(r),t,n,r);return}t.validators===null&&(t.validators=[]),t.validators.push(tg(n,e))},hg=(e,t,n,r)=>{let i=Object.entries(e),a=i.length;for(let e=0;e<a;e++){let[a,o]=i[e];dg(o,gg(t,a),n,r)}},gg=(e,t)=>{let n=e,r=t.split(rg),i=r.length;for(let e=0;e<i;e++){let t=r[e],i=n.nextPart.get(t);i||(i=ng(),n.nextPart.set(t,i)),n=i}return n},_g=e=>`isThemeGetter`in e&&e.isThemeGetter===!0,vg=e=>{if(e<1)return{get:()=>void 0,set:()=>{}};let t=0,n=Object.create(null),r=Object.create(null),i=(i,a)=>{n[i]=a,t++,t>e&&(t=0,r=n,n=Object.create(null))};return{get(e){let t=n[e];if(t!==void 0)return t;if((t=r[e])!==void 0)return i(e,t),t},set(e,t){e in n?n[e]=t:i(e,t)}}},yg=`!`,bg=`:`,xg=[],Sg=(e,t,n,r,i)=>({modifiers:e,hasImportantModifier:t,baseClassName:n,maybePostfixModifierPosition:r,isExternal:i}),Cg=e=>{let{prefix:t,experimentalParseClassName:n}=e,r=e=>{let t=[],n=0,r=0,i=0,a,o=e.length;for(let s=0;s<o;s++){let o=e[s];if(n===0&&r===0){if(o===bg){t.push(e.slice(i,s)),i=s+1;continue}if(o===`/`){a=s;continue}}o===`[`?n++:o===`]`?n--:o===`(`?r++:o===`)`&&r--}let s=t.length===0?e:e.slice(i),c=s,l=!1;s.endsWith(yg)?(c=s.slice(0,-1),l=!0):s.startsWith(yg)&&(c=s.slice(1),l=!0);let u=a&&a>i?a-i:void 0;return Sg(t,l,c,u)};if(t){let e=t+bg,n=r;r=t=>t.startsWith(e)?n(t.slice(e.length)):Sg(xg,!1,t,void 0,!0)}if(n){let e=r;r=t=>n({className:t,parseClassName:e})}return r},wg=e=>{let t=new Map;return e.orderSensitiveModifiers.forEach((e,n)=>{t.set(e,1e6+n)}),e=>{let n=[],r=[];for(let i=0;i<e.length;i++){let a=e[i],o=a[0]===`[`,s=t.has(a);o||s?(r.length>0&&(r.sort(),n.push(...r),r=[]),n.push(a)):r.push(a)}return r.length>0&&(r.sort(),n.push(...r)),n}},Tg=e=>({cache:vg(e.cacheSize),parseClassName:Cg(e),sortModifiers:wg(e),...og(e)}),Eg=/\s+/,Dg=(e,t)=>{let{parseClassName:n,getClassGroupId:r,getConflictingClassGroupIds:i,sortModifiers:a}=t,o=[],s=e.trim().split(Eg),c=``;for(let e=s.length-1;e>=0;--e){let t=s[e],{isExternal:l,modifiers:u,hasImportantModifier:d,baseClassName:f,maybePostfixModifierPosition:p}=n(t);if(l){c=t+(c.length>0?` `+c:c);continue}let m=!!p,h=r(m?f.substring(0,p):f);if(!h){if(!m){c=t+(c.length>0?` `+c:c);continue}if(h=r(f),!h){c=t+(c.length>0?` `+c:c);continue}m=!1}let g=u.length===0?``:u.length===1?u[0]:a(u).join(`:`),_=d?g+yg:g,v=_+h;if(o.indexOf(v)>-1)continue;o.push(v);let y=i(h,m);for(let e=0;e<y.length;++e){let t=y[e];o.push(_+t)}c=t+(c.length>0?` `+c:c)}return c},Og=(...e)=>{let t=0,n,r,i=``;for(;t<e.length;)(n=e[t++])&&(r=kg(n))&&(i&&(i+=` `),i+=r);return i},kg=e=>{if(typeof e==`string`)return e;let t,n=``;for(let r=0;r<e.length;r++)e[r]&&(t=kg(e[r]))&&(n&&(n+=` `),n+=t);return n},Ag=(e,...t)=>{let n,r,i,a,o=o=>(n=Tg(t.reduce((e,t)=>t(e),e())),r=n.cache.get,i=n.cache.set,a=s,s(o)),s=e=>{let t=r(e);if(t)return t;let a=Dg(e,n);return i(e,a),a};return a=o,(...e)=>a(Og(...e))},jg=[],Mg=e=>{let t=t=>t[e]||jg;return t.isThemeGetter=!0,t},Ng=/^\[(?:(\w[\w-]*):)?(.+)\]$/i,Pg=/^\((?:(\w[\w-]*):)?(.+)\)$/i,Fg=/^\d+\/\d+$/,Ig=/^(\d+(\.\d+)?)?(xs|sm|md|lg|xl)$/,Lg=/\d+(%|px|r?em|[sdl]?v([hwib]|min|max)|pt|pc|in|cm|mm|cap|ch|ex|r?lh|cq(w|h|i|b|min|max))|\b(calc|min|max|clamp)\(.+\)|^0$/,Rg=/^(rgba?|hsla?|hwb|(ok)?(lab|lch)|color-mix)\(.+\)$/,zg=/^(inset_)?-?((\d+)?\.?(\d+)[a-z]+|0)_-?((\d+)?\.?(\d+)[a-z]+|0)/,Bg=/^(url|image|image-set|cross-fade|element|(repeating-)?(linear|radial|conic)-gradient)\(.+\)$/,Vg=e=>Fg.test(e),J=e=>!!e&&!Number.isNaN(Number(e)),Hg=e=>!!e&&Number.isInteger(Number(e)),Ug=e=>e.endsWith(`%`)&&J(e.slice(0,-1)),Wg=e=>Ig.test(e),Gg=()=>!0,Kg=e=>Lg.test(e)&&!Rg.test(e),qg=()=>!1,Jg=e=>zg.test(e),Yg=e=>Bg.test(e),Xg=e=>!Y(e)&&!X(e),Zg=e=>l_(e,p_,qg),Y=e=>Ng.test(e),Qg=e=>l_(e,m_,Kg),$g=e=>l_(e,h_,J),e_=e=>l_(e,d_,qg),t_=e=>l_(e,f_,Yg),n_=e=>l_(e,__,Jg),X=e=>Pg.test(e),r_=e=>u_(e,m_),i_=e=>u_(e,g_),a_=e=>u_(e,d_),o_=e=>u_(e,p_),s_=e=>u_(e,f_),c_=e=>u_(e,__,!0),l_=(e,t,n)=>{let r=Ng.exec(e);return r?r[1]?t(r[1]):n(r[2]):!1},u_=(e,t,n=!1)=>{let r=Pg.exec(e);return r?r[1]?t(r[1]):n:!1},d_=e=>e===`position`||e===`percentage`,f_=e=>e===`image`||e===`url`,p_=e=>e===`length`||e===`size`||e===`bg-size`,m_=e=>e===`length`,h_=e=>e===`number`,g_=e=>e===`family-name`,__=e=>e===`shadow`,v_=Ag(()=>{let e=Mg(`color`),t=Mg(`font`),n=Mg(`text`),r=Mg(`font-weight`),i=Mg(`tracking`),a=Mg(`leading`),o=Mg(`breakpoint`),s=Mg(`container`),c=Mg(`spacing`),l=Mg(`radius`),u=Mg(`shadow`),d=Mg(`inset-shadow`),f=Mg(`text-shadow`),p=Mg(`drop-shadow`),m=Mg(`blur`),h=Mg(`perspective`),g=Mg(`aspect`),_=Mg(`ease`),v=Mg(`animate`),y=()=>[`auto`,`avoid`,`all`,`avoid-page`,`page`,`left`,`right`,`column`],b=()=>[`center`,`top`,`bottom`,`left`,`right`,`top-left`,`left-top`,`top-right`,`right-top`,`bottom-right`,`right-bottom`,`bottom-left`,`left-bottom`],x=()=>[...b(),X,Y],S=()=>[`auto`,`hidden`,`clip`,`visible`,`scroll`],C=()=>[`auto`,`contain`,`none`],w=()=>[X,Y,c],T=()=>[Vg,`full`,`auto`,...w()],ee=()=>[Hg,`none`,`subgrid`,X,Y],E=()=>[`auto`,{span:[`full`,Hg,X,Y]},Hg,X,Y],te=()=>[Hg,`auto`,X,Y],ne=()=>[`auto`,`min`,`max`,`fr`,X,Y],re=()=>[`start`,`end`,`center`,`between`,`around`,`evenly`,`stretch`,`baseline`,`center-safe`,`end-safe`],D=()=>[`start`,`end`,`center`,`stretch`,`center-safe`,`end-safe`],ie=()=>[`auto`,...w()],O=()=>[Vg,`auto`,`full`,`dvw`,`dvh`,`lvw`,`lvh`,`svw`,`svh`,`min`,`max`,`fit`,...w()],k=()=>[e,X,Y],ae=()=>[...b(),a_,e_,{position:[X,Y]}],oe=()=>[`no-repeat`,{repeat:[``,`x`,`y`,`space`,`round`]}],se=()=>[`auto`,`cover`,`contain`,o_,Zg,{size:[X,Y]}],ce=()=>[Ug,r_,Qg],le=()=>[``,`none`,`full`,l,X,Y],ue=()=>[``,J,r_,Qg],de=()=>[`solid`,`dashed`,`dotted`,`double`],fe=()=>[`normal`,`multiply`,`screen`,`overlay`,`darken`,`lighten`,`color-dodge`,`color-burn`,`hard-light`,`soft-light`,`difference`,`exclusion`,`hue`,`saturation`,`color`,`luminosity`],pe=()=>[J,Ug,a_,e_],me=()=>[``,`none`,m,X,Y],he=()=>[`none`,J,X,Y],ge=()=>[`none`,J,X,Y],_e=()=>[J,X,Y],ve=()=>[Vg,`full`,...w()];return{cacheSize:500,theme:{animate:[`spin`,`ping`,`pulse`,`bounce`],aspect:[`video`],blur:[Wg],breakpoint:[Wg],color:[Gg],container:[Wg],"drop-shadow":[Wg],ease:[`in`,`out`,`in-out`],font:[Xg],"font-weight":[`thin`,`extralight`,`light`,`normal`,`medium`,`semibold`,`bold`,`extrabold`,`black`],"inset-shadow":[Wg],leading:[`none`,`tight`,`snug`,`normal`,`relaxed`,`loose`],perspective:[`dramatic`,`near`,`normal`,`midrange`,`distant`,`none`],radius:[Wg],shadow:[Wg],spacing:[`px`,J],text:[Wg],"text-shadow":[Wg],tracking:[`tighter`,`tight`,`normal`,`wide`,`wider`,`widest`]},classGroups:{aspect:[{aspect:[`auto`,`square`,Vg,Y,X,g]}],container:[`container`],columns:[{columns:[J,Y,X,s]}],"break-after":[{"break-after":y()}],"break-before":[{"break-before":y()}],"break-inside":[{"break-inside":[`auto`,`avoid`,`avoid-page`,`avoid-column`]}],"box-decoration":[{"box-decoration":[`slice`,`clone`]}],box:[{box:[`border`,`content`]}],display:[`block`,`inline-block`,`inline`,`flex`,`inline-flex`,`table`,`inline-table`,`table-caption`,`table-cell`,`table-column`,`table-column-group`,`table-footer-group`,`table-header-group`,`table-row-group`,`table-row`,`flow-root`,`grid`,`inline-grid`,`contents`,`list-item`,`hidden`],sr:[`sr-only`,`not-sr-only`],float:[{float:[`right`,`left`,`none`,`start`,`end`]}],clear:[{clear:[`left`,`right`,`both`,`none`,`start`,`end`]}],isolation:[`isolate`,`isolation-auto`],"object-fit":[{object:[`contain`,`cover`,`fill`,`none`,`scale-down`]}],"object-position":[{object:x()}],overflow:[{overflow:S()}],"overflow-x":[{"overflow-x":S()}],"overflow-y":[{"overflow-y":S()}],overscroll:[{overscroll:C()}],"overscroll-x":[{"overscroll-x":C()}],"overscroll-y":[{"overscroll-y":C()}],position:[`static`,`fixed`,`absolute`,`relative`,`sticky`],inset:[{inset:T()}],"inset-x":[{"inset-x":T()}],"inset-y":[{"inset-y":T()}],start:[{start:T()}],end:[{end:T()}],top:[{top:T()}],right:[{right:T()}],bottom:[{bottom:T()}],left:[{left:T()}],visibility:[`visible`,`invisible`,`collapse`],z:[{z:[Hg,`auto`,X,Y]}],basis:[{basis:[Vg,`full`,`auto`,s,...w()]}],"flex-direction":[{flex:[`row`,`row-reverse`,`col`,`col-reverse`]}],"flex-wrap":[{flex:[`nowrap`,`wrap`,`wrap-reverse`]}],flex:[{flex:[J,Vg,`auto`,`initial`,`none`,Y]}],grow:[{grow:[``,J,X,Y]}],shrink:[{shrink:[``,J,X,Y]}],order:[{order:[Hg,`first`,`last`,`none`,X,Y]}],"grid-cols":[{"grid-cols":ee()}],"col-start-end":[{col:E()}],"col-start":[{"col-start":te()}],"col-end":[{"col-end":te()}],"grid-rows":[{"grid-rows":ee()}],"row-start-end":[{row:E()}],"row-start":[{"row-start":te()}],"row-end":[{"row-end":te()}],"grid-flow":[{"grid-flow":[`row`,`col`,`dense`,`row-dense`,`col-dense`]}],"auto-cols":[{"auto-cols":ne()}],"auto-rows":[{"auto-rows":ne()}],gap:[{gap:w()}],"gap-x":[{"gap-x":w()}],"gap-y":[{"gap-y":w()}],"justify-content":[{justify:[...re(),`normal`]}],"justify-items":[{"justify-items":[...D(),`normal`]}],"justify-self":[{"justify-self":[`auto`,...D()]}],"align-content":[{content:[`normal`,...re()]}],"align-items":[{items:[...D(),{baseline:[``,`last`]}]}],"align-self":[{self:[`auto`,...D(),{baseline:[``,`last`]}]}],"place-content":[{"place-content":re()}],"place-items":[{"place-items":[...D(),`baseline`]}],"place-self":[{"place-self":[`auto`,...D()]}],p:[{p:w()}],px:[{px:w()}],py:[{py:w()}],ps:[{ps:w()}],pe:[{pe:w()}],pt:[{pt:w()}],pr:[{pr:w()}],pb:[{pb:w()}],pl:[{pl:w()}],m:[{m:ie()}],mx:[{mx:ie()}],my:[{my:ie()}],ms:[{ms:ie()}],me:[{me:ie()}],mt:[{mt:ie()}],mr:[{mr:ie()}],mb:[{mb:ie()}],ml:[{ml:ie()}],"space-x":[{"space-x":w()}],"space-x-reverse":[`space-x-reverse`],"space-y":[{"space-y":w()}],"space-y-reverse":[`space-y-reverse`],size:[{size:O()}],w:[{w:[s,`screen`,...O()]}],"min-w":[{"min-w":[s,`screen`,`none`,...O()]}],"max-w":[{"max-w":[s,`screen`,`none`,`prose`,{screen:[o]},...O()]}],h:[{h:[`screen`,`lh`,...O()]}],"min-h":[{"min-h":[`screen`,`lh`,`none`,...O()]}],"max-h":[{"max-h":[`screen`,`lh`,...O()]}],"font-size":[{text:[`base`,n,r_,Qg]}],"font-smoothing":[`antialiased`,`subpixel-antialiased`],"font-style":[`italic`,`not-italic`],"font-weight":[{font:[r,X,$g]}],"font-stretch":[{"font-stretch":[`ultra-condensed`,`extra-condensed`,`condensed`,`semi-condensed`,`normal`,`semi-expanded`,`expanded`,`extra-expanded`,`ultra-expanded`,Ug,Y]}],"font-family":[{font:[i_,Y,t]}],"fvn-normal":[`normal-nums`],"fvn-ordinal":[`ordinal`],"fvn-slashed-zero":[`slashed-zero`],"fvn-figure":[`lining-nums`,`oldstyle-nums`],"fvn-spacing":[`proportional-nums`,`tabular-nums`],"fvn-fraction":[`diagonal-fractions`,`stacked-fractions`],tracking:[{tracking:[i,X,Y]}],"line-clamp":[{"line-clamp":[J,`none`,X,$g]}],leading:[{leading:[a,...w()]}],"list-image":[{"list-image":[`none`,X,Y]}],"list-style-position":[{list:[`inside`,`outside`]}],"list-style-type":[{list:[`disc`,`decimal`,`none`,X,Y]}],"text-alignment":[{text:[`left`,`center`,`right`,`justify`,`start`,`end`]}],"placeholder-color":[{placeholder:k()}],"text-color":[{text:k()}],"text-decoration":[`underline`,`overline`,`line-through`,`no-underline`],"text-decoration-style":[{decoration:[...de(),`wavy`]}],"text-decoration-thickness":[{decoration:[J,`from-font`,`auto`,X,Qg]}],"text-decoration-color":[{decoration:k()}],"underline-offset":[{"underline-offset":[J,`auto`,X,Y]}],"text-transform":[`uppercase`,`lowercase`,`capitalize`,`normal-case`],"text-overflow":[`truncate`,`text-ellipsis`,`text-clip`],"text-wrap":[{text:[`wrap`,`nowrap`,`balance`,`pretty`]}],indent:[{indent:w()}],"vertical-align":[{align:[`baseline`,`top`,`middle`,`bottom`,`text-top`,`text-bottom`,`sub`,`super`,X,Y]}],whitespace:[{whitespace:[`normal`,`nowrap`,`pre`,`pre-line`,`pre-wrap`,`break-spaces`]}],break:[{break:[`normal`,`words`,`all`,`keep`]}],wrap:[{wrap:[`break-word`,`anywhere`,`normal`]}],hyphens:[{hyphens:[`none`,`manual`,`auto`]}],content:[{content:[`none`,X,Y]}],"bg-attachment":[{bg:[`fixed`,`local`,`scroll`]}],"bg-clip":[{"bg-clip":[`border`,`padding`,`content`,`text`]}],"bg-origin":[{"bg-origin":[`border`,`padding`,`content`]}],"bg-position":[{bg:ae()}],"bg-repeat":[{bg:oe()}],"bg-size":[{bg:se()}],"bg-image":[{bg:[`none`,{linear:[{to:[`t`,`tr`,`r`,`br`,`b`,`bl`,`l`,`tl`]},Hg,X,Y],radial:[``,X,Y],conic:[Hg,X,Y]},s_,t_]}],"bg-color":[{bg:k()}],"gradient-from-pos":[{from:ce()}],"gradient-via-pos":[{via:ce()}],"gradient-to-pos":[{to:ce()}],"gradient-from":[{from:k()}],"gradient-via":[{via:k()}],"gradient-to":[{to:k()}],rounded:[{rounded:le()}],"rounded-s":[{"rounded-s":le()}],"rounded-e":[{"rounded-e":le()}],"rounded-t":[{"rounded-t":le()}],"rounded-r":[{"rounded-r":le()}],"rounded-b":[{"rounded-b":le()}],"rounded-l":[{"rounded-l":le()}],"rounded-ss":[{"rounded-ss":le()}],"rounded-se":[{"rounded-se":le()}],"rounded-ee":[{"rounded-ee":le()}],"rounded-es":[{"rounded-es":le()}],"rounded-tl":[{"rounded-tl":le()}],"rounded-tr":[{"rounded-tr":le()}],"rounded-br":[{"rounded-br":le()}],"rounded-bl":[{"rounded-bl":le()}],"border-w":[{border:ue()}],"border-w-x":[{"border-x":ue()}],"border-w-y":[{"border-y":ue()}],"border-w-s":[{"border-s":ue()}],"border-w-e":[{"border-e":ue()}],"border-w-t":[{"border-t":ue()}],"border-w-r":[{"border-r":ue()}],"border-w-b":[{"border-b":ue()}],"border-w-l":[{"border-l":ue()}],"divide-x":[{"divide-x":ue()}],"divide-x-reverse":[`divide-x-reverse`],"divide-y":[{"divide-y":ue()}],"divide-y-reverse":[`divide-y-reverse`],"border-style":[{border:[...de(),`hidden`,`none`]}],"divide-style":[{divide:[...de(),`hidden`,`none`]}],"border-color":[{border:k()}],"border-color-x":[{"border-x":k()}],"border-color-y":[{"border-y":k()}],"border-color-s":[{"border-s":k()}],"border-color-e":[{"border-e":k()}],"border-color-t":[{"border-t":k()}],"border-color-r":[{"border-r":k()}],"border-color-b":[{"border-b":k()}],"border-color-l":[{"border-l":k()}],"divide-color":[{divide:k()}],"outline-style":[{outline:[...de(),`none`,`hidden`]}],"outline-offset":[{"outline-offset":[J,X,Y]}],"outline-w":[{outline:[``,J,r_,Qg]}],"outline-color":[{outline:k()}],shadow:[{shadow:[``,`none`,u,c_,n_]}],"shadow-color":[{shadow:k()}],"inset-shadow":[{"inset-shadow":[`none`,d,c_,n_]}],"inset-shadow-color":[{"inset-shadow":k()}],"ring-w":[{ring:ue()}],"ring-w-inset":[`ring-inset`],"ring-color":[{ring:k()}],"ring-offset-w":[{"ring-offset":[J,Qg]}],"ring-offset-color":[{"ring-offset":k()}],"inset-ring-w":[{"inset-ring":ue()}],"inset-ring-color":[{"inset-ring":k()}],"text-shadow":[{"text-shadow":[`none`,f,c_,n_]}],"text-shadow-color":[{"text-shadow":k()}],opacity:[{opacity:[J,X,Y]}],"mix-blend":[{"mix-blend":[...fe(),`plus-darker`,`plus-lighter`]}],"bg-blend":[{"bg-blend":fe()}],"mask-clip":[{"mask-clip":[`border`,`padding`,`content`,`fill`,`stroke`,`view`]},`mask-no-clip`],"mask-composite":[{mask:[`add`,`subtract`,`intersect`,`exclude`]}],"mask-image-linear-pos":[{"mask-linear":[J]}],"mask-image-linear-from-pos":[{"mask-linear-from":pe()}],"mask-image-linear-to-pos":[{"mask-linear-to":pe()}],"mask-image-linear-from-color":[{"mask-linear-from":k()}],"mask-image-linear-to-color":[{"mask-linear-to":k()}],"mask-image-t-from-pos":[{"mask-t-from":pe()}],"mask-image-t-to-pos":[{"mask-t-to":pe()}],"mask-image-t-from-color":[{"mask-t-from":k()}],"mask-image-t-to-color":[{"mask-t-to":k()}],"mask-image-r-from-pos":[{"mask-r-from":pe()}],"mask-image-r-to-pos":[{"mask-r-to":pe()}],"mask-image-r-from-color":[{"mask-r-from":k()}],"mask-image-r-to-color":[{"mask-r-to":k()}],"mask-image-b-from-pos":[{"mask-b-from":pe()}],"mask-image-b-to-pos":[{"mask-b-to":pe()}],"mask-image-b-from-color":[{"mask-b-from":k()}],"mask-image-b-to-color":[{"mask-b-to":k()}],"mask-image-l-from-pos":[{"mask-l-from":pe()}],"mask-image-l-to-pos":[{"mask-l-to":pe()}],"mask-image-l-from-color":[{"mask-l-from":k()}],"mask-image-l-to-color":[{"mask-l-to":k()}],"mask-image-x-from-pos":[{"mask-x-from":pe()}],"mask-image-x-to-pos":[{"mask-x-to":pe()}],"mask-image-x-from-color":[{"mask-x-from":k()}],"mask-image-x-to-color":[{"mask-x-to":k()}],"mask-image-y-from-pos":[{"mask-y-from":pe()}],"mask-image-y-to-pos":[{"mask-y-to":pe()}],"mask-image-y-from-color":[{"mask-y-from":k()}],"mask-image-y-to-color":[{"mask-y-to":k()}],"mask-image-radial":[{"mask-radial":[X,Y]}],"mask-image-radial-from-pos":[{"mask-radial-from":pe()}],"mask-image-radial-to-pos":[{"mask-radial-to":pe()}],"mask-image-radial-from-color":[{"mask-radial-from":k()}],"mask-image-radial-to-color":[{"mask-radial-to":k()}],"mask-image-radial-shape":[{"mask-radial":[`circle`,`ellipse`]}],"mask-image-radial-size":[{"mask-radial":[{closest:[`side`,`corner`],farthest:[`side`,`corner`]}]}],"mask-image-radial-pos":[{"mask-radial-at":b()}],"mask-image-conic-pos":[{"mask-conic":[J]}],"mask-image-conic-from-pos":[{"mask-conic-from":pe()}],"mask-image-conic-to-pos":[{"mask-conic-to":pe()}],"mask-image-conic-from-color":[{"mask-conic-from":k()}],"mask-image-conic-to-color":[{"mask-conic-to":k()}],"mask-mode":[{mask:[`alpha`,`luminance`,`match`]}],"mask-origin":[{"mask-origin":[`border`,`padding`,`content`,`fill`,`stroke`,`view`]}],"mask-position":[{mask:ae()}],"mask-repeat":[{mask:oe()}],"mask-size":[{mask:se()}],"mask-type":[{"mask-type":[`alpha`,`luminance`]}],"mask-image":[{mask:[`none`,X,Y]}],filter:[{filter:[``,`none`,X,Y]}],blur:[{blur:me()}],brightness:[{brightness:[J,X,Y]}],contrast:[{contrast:[J,X,Y]}],"drop-shadow":[{"drop-shadow":[``,`none`,p,c_,n_]}],"drop-shadow-color":[{"drop-shadow":k()}],grayscale:[{grayscale:[``,J,X,Y]}],"hue-rotate":[{"hue-rotate":[J,X,Y]}],invert:[{invert:[``,J,X,Y]}],saturate:[{saturate:[J,X,Y]}],sepia:[{sepia:[``,J,X,Y]}],"backdrop-filter":[{"backdrop-filter":[``,`none`,X,Y]}],"backdrop-blur":[{"backdrop-blur":me()}],"backdrop-brightness":[{"backdrop-brightness":[J,X,Y]}],"backdrop-contrast":[{"backdrop-contrast":[J,X,Y]}],"backdrop-grayscale":[{"backdrop-grayscale":[``,J,X,Y]}],"backdrop-hue-rotate":[{"backdrop-hue-rotate":[J,X,Y]}],"backdrop-invert":[{"backdrop-invert":[``,J,X,Y]}],"backdrop-opacity":[{"backdrop-opacity":[J,X,Y]}],"backdrop-saturate":[{"backdrop-saturate":[J,X,Y]}],"backdrop-sepia":[{"backdrop-sepia":[``,J,X,Y]}],"border-collapse":[{border:[`collapse`,`separate`]}],"border-spacing":[{"border-spacing":w()}],"border-spacing-x":[{"border-spacing-x":w()}],"border-spacing-y":[{"border-spacing-y":w()}],"table-layout":[{table:[`auto`,`fixed`]}],caption:[{caption:[`top`,`bottom`]}],transition:[{transition:[``,`all`,`colors`,`opacity`,`shadow`,`transform`,`none`,X,Y]}],"transition-behavior":[{transition:[`normal`,`discrete`]}],duration:[{duration:[J,`initial`,X,Y]}],ease:[{ease:[`linear`,`initial`,_,X,Y]}],delay:[{delay:[J,X,Y]}],animate:[{animate:[`none`,v,X,Y]}],backface:[{backface:[`hidden`,`visible`]}],perspective:[{perspective:[h,X,Y]}],"perspective-origin":[{"perspective-origin":x()}],rotate:[{rotate:he()}],"rotate-x":[{"rotate-x":he()}],"rotate-y":[{"rotate-y":he()}],"rotate-z":[{"rotate-z":he()}],scale:[{scale:ge()}],"scale-x":[{"scale-x":ge()}],"scale-y":[{"scale-y":ge()}],"scale-z":[{"scale-z":ge()}],"scale-3d":[`scale-3d`],skew:[{skew:_e()}],"skew-x":[{"skew-x":_e()}],"skew-y":[{"skew-y":_e()}],transform:[{transform:[X,Y,``,`none`,`gpu`,`cpu`]}],"transform-origin":[{origin:x()}],"transform-style":[{transform:[`3d`,`flat`]}],translate:[{translate:ve()}],"translate-x":[{"translate-x":ve()}],"translate-y":[{"translate-y":ve()}],"translate-z":[{"translate-z":ve()}],"translate-none":[`translate-none`],accent:[{accent:k()}],appearance:[{appearance:[`none`,`auto`]}],"caret-color":[{caret:k()}],"color-scheme":[{scheme:[`normal`,`dark`,`light`,`light-dark`,`only-dark`,`only-light`]}],cursor:[{cursor:[`auto`,`default`,`pointer`,`wait`,`text`,`move`,`help`,`not-allowed`,`none`,`context-menu`,`progress`,`cell`,`crosshair`,`vertical-text`,`alias`,`copy`,`no-drop`,`grab`,`grabbing`,`all-scroll`,`col-resize`,`row-resize`,`n-resize`,`e-resize`,`s-resize`,`w-resize`,`ne-resize`,`nw-resize`,`se-resize`,`sw-resize`,`ew-resize`,`ns-resize`,`nesw-resize`,`nwse-resize`,`zoom-in`,`zoom-out`,X,Y]}],"field-sizing":[{"field-sizing":[`fixed`,`content`]}],"pointer-events":[{"pointer-events":[`auto`,`none`]}],resize:[{resize:[`none`,``,`y`,`x`]}],"scroll-behavior":[{scroll:[`auto`,`smooth`]}],"scroll-m":[{"scroll-m":w()}],"scroll-mx":[{"scroll-mx":w()}],"scroll-my":[{"scroll-my":w()}],"scroll-ms":[{"scroll-ms":w()}],"scroll-me":[{"scroll-me":w()}],"scroll-mt":[{"scroll-mt":w()}],"scroll-mr":[{"scroll-mr":w()}],"scroll-mb":[{"scroll-mb":w()}],"scroll-ml":[{"scroll-ml":w()}],"scroll-p":[{"scroll-p":w()}],"scroll-px":[{"scroll-px":w()}],"scroll-py":[{"scroll-py":w()}],"scroll-ps":[{"scroll-ps":w()}],"scroll-pe":[{"scroll-pe":w()}],"scroll-pt":[{"scroll-pt":w()}],"scroll-pr":[{"scroll-pr":w()}],"scroll-pb":[{"scroll-pb":w()}],"scroll-pl":[{"scroll-pl":w()}],"snap-align":[{snap:[`start`,`end`,`center`,`align-none`]}],"snap-stop":[{snap:[`normal`,`always`]}],"snap-type":[{snap:[`none`,`x`,`y`,`both`]}],"snap-strictness":[{snap:[`mandatory`,`proximity`]}],touch:[{touch:[`auto`,`none`,`manipulation`]}],"touch-x":[{"touch-pan":[`x`,`left`,`right`]}],"touch-y":[{"touch-pan":[`y`,`up`,`down`]}],"touch-pz":[`touch-pinch-zoom`],select:[{select:[`none`,`text`,`all`,`auto`]}],"will-change":[{"will-change":[`auto`,`scroll`,`contents`,`transform`,X,Y]}],fill:[{fill:[`none`,...k()]}],"stroke-w":[{stroke:[J,r_,Qg,$g]}],stroke:[{stroke:[`none`,...k()]}],"forced-color-adjust":[{"forced-color-adjust":[`auto`,`none`]}]},conflictingClassGroups:{overflow:[`overflow-x`,`overflow-y`],overscroll:[`overscroll-x`,`overscroll-y`],inset:[`inset-x`,`inset-y`,`start`,`end`,`top`,`right`,`bottom`,`left`],"inset-x":[`right`,`left`],"inset-y":[`top`,`bottom`],flex:[`basis`,`grow`,`shrink`],gap:[`gap-x`,`gap-y`],p:[`px`,`py`,`ps`,`pe`,`pt`,`pr`,`pb`,`pl`],px:[`pr`,`pl`],py:[`pt`,`pb`],m:[`mx`,`my`,`ms`,`me`,`mt`,`mr`,`mb`,`ml`],mx:[`mr`,`ml`],my:[`mt`,`mb`],size:[`w`,`h`],"font-size":[`leading`],"fvn-normal":[`fvn-ordinal`,`fvn-slashed-zero`,`fvn-figure`,`fvn-spacing`,`fvn-fraction`],"fvn-ordinal":[`fvn-normal`],"fvn-slashed-zero":[`fvn-normal`],"fvn-figure":[`fvn-normal`],"fvn-spacing":[`fvn-normal`],"fvn-fraction":[`fvn-normal`],"line-clamp":[`display`,`overflow`],rounded:[`rounded-s`,`rounded-e`,`rounded-t`,`rounded-r`,`rounded-b`,`rounded-l`,`rounded-ss`,`rounded-se`,`rounded-ee`,`rounded-es`,`rounded-tl`,`rounded-tr`,`rounded-br`,`rounded-bl`],"rounded-s":[`rounded-ss`,`rounded-es`],"rounded-e":[`rounded-se`,`rounded-ee`],"rounded-t":[`rounded-tl`,`rounded-tr`],"rounded-r":[`rounded-tr`,`rounded-br`],"rounded-b":[`rounded-br`,`rounded-bl`],"rounded-l":[`rounded-tl`,`rounded-bl`],"border-spacing":[`border-spacing-x`,`border-spacing-y`],"border-w":[`border-w-x`,`border-w-y`,`border-w-s`,`border-w-e`,`border-w-t`,`border-w-r`,`border-w-b`,`border-w-l`],"border-w-x":[`border-w-r`,`border-w-l`],"border-w-y":[`border-w-t`,`border-w-b`],"border-color":[`border-color-x`,`border-color-y`,`border-color-s`,`border-color-e`,`border-color-t`,`border-color-r`,`border-color-b`,`border-color-l`],"border-color-x":[`border-color-r`,`border-color-l`],"border-color-y":[`border-color-t`,`border-color-b`],translate:[`translate-x`,`translate-y`,`translate-none`],"translate-none":[`translate`,`translate-x`,`translate-y`,`translate-z`],"scroll-m":[`scroll-mx`,`scroll-my`,`scroll-ms`,`scroll-me`,`scroll-mt`,`scroll-mr`,`scroll-mb`,`scroll-ml`],"scroll-mx":[`scroll-mr`,`scroll-ml`],"scroll-my":[`scroll-mt`,`scroll-mb`],"scroll-p":[`scroll-px`,`scroll-py`,`scroll-ps`,`scroll-pe`,`scroll-pt`,`scroll-pr`,`scroll-pb`,`scroll-pl`],"scroll-px":[`scroll-pr`,`scroll-pl`],"scroll-py":[`scroll-pt`,`scroll-pb`],touch:[`touch-x`,`touch-y`,`touch-pz`],"touch-x":[`touch`],"touch-y":[`touch`],"touch-pz":[`touch`]},conflictingClassGroupModifiers:{"font-size":[`leading`]},orderSensitiveModifiers:[`*`,`**`,`after`,`backdrop`,`before`,`details-content`,`file`,`first-letter`,`first-line`,`marker`,`placeholder`,`selection`]}});function Z(...e){return v_(lu(e))}function y_(e,t=2){if(e===0)return`0B`;let n=1024,r=t<0?0:t,i=[`B`,`K`,`M`,`G`,`T`,`P`,`E`,`Z`,`Y`],a=Math.floor(Math.log(e)/Math.log(n));return parseFloat((e/n**+a).toFixed(r))+(i[a]||`B`)}function b_(e){let t=new Date(e*1e3),n=t.getDate().toString().padStart(2,`0`),r=(t.getMonth()+1).toString().padStart(2,`0`);return`${t.getFullYear().toString().padStart(2,`0`)}:${r}:${n} ${t.getHours().toString().padStart(2,`0`)}:${t.getMinutes().toString().padStart(2,`0`)}:${t.getSeconds().toString().padStart(2,`0`)}`}var x_={class:`absolute top-full left-0 isolate z-50 flex justify-center`},S_=F({__name:`NavigationMenuViewport`,props:{forceMount:{type:Boolean},align:{},asChild:{type:Boolean},as:{},class:{}},setup(e){let t=e,n=_d(_u(t,`class`));return(e,r)=>(z(),B(`div`,x_,[U(N($h),W({"data-slot":`navigation-menu-viewport`},N(n),{class:N(Z)(`origin-top-center bg-popover text-popover-foreground data-[state=open]:animate-in data-[state=closed]:animate-out data-[state=closed]:zoom-out-95 data-[state=open]:zoom-in-90 relative mt-1.5 h-[var(--reka-navigation-menu-viewport-height)] w-full overflow-hidden rounded-md border shadow md:w-[var(--reka-navigation-menu-viewport-width)] left-[var(--reka-navigation-menu-viewport-left)]`,t.class)}),null,16,[`class`])]))}}),C_=F({__name:`NavigationMenu`,props:{modelValue:{},defaultValue:{},dir:{},orientation:{},delayDuration:{},skipDelayDuration:{},disableClickTrigger:{type:Boolean},disableHoverTrigger:{type:Boolean},disablePointerLeaveClose:{type:Boolean},unmountOnHide:{type:Boolean},asChild:{type:Boolean},as:{},class:{},viewport:{type:Boolean,default:!0}},emits:[`update:modelValue`],setup(e,{emit:t}){let n=e,r=t,i=vd(_u(n,`class`,`viewport`),r);return(t,r)=>(z(),V(N(zh),W({"data-slot":`navigation-menu`,"data-viewport":e.viewport},N(i),{class:N(Z)(`group/navigation-menu relative flex max-w-max flex-1 items-center justify-center`,n.class)}),{default:P(n=>[I(t.$slots,`default`,ye(fs(n))),e.viewport?(z(),V(S_,{key:0})):gs(``,!0)]),_:3},16,[`data-viewport`,`class`]))}}),w_=F({__name:`NavigationMenuItem`,props:{value:{},asChild:{type:Boolean},as:{},class:{}},setup(e){let t=e,n=_u(t,`class`);return(e,r)=>(z(),V(N(Xh),W({"data-slot":`navigation-menu-item`},N(n),{class:N(Z)(`relative`,t.class)}),{default:P(()=>[I(e.$slots,`default`)]),_:3},16,[`class`]))}}),T_=F({__name:`NavigationMenuLink`,props:{active:{type:Boolean},asChild:{type:Boolean},as:{},class:{}},emits:[`select`],setup(e,{emit:t}){let n=e,r=t,i=vd(_u(n,`class`),r);return(e,t)=>(z(),V(N(Zh),W({"data-slot":`navigation-menu-link`},N(i),{class:N(Z)(`data-[active=true]:focus:bg-accent data-[active=true]:hover:bg-accent data-[active=true]:bg-accent/50 data-[active=true]:text-accent-foreground hover:bg-accent hover:text-accent-foreground focus:bg-accent focus:text-accent-foreground ring-ring/10 dark:ring-ring/20 dark:outline-ring/40 outline-ring/50 [&_svg:not([class*='text-'])]:text-muted-foreground flex flex-col gap-1 rounded-sm p-2 text-sm transition-[color,box-shadow] focus-visible:ring-4 focus-visible:outline-1 [&_svg:not([class*='size-'])]:size-4`,n.class)}),{default:P(()=>[I(e.$slots,`default`)]),_:3},16,[`class`]))}}),E_=F({__name:`NavigationMenuList`,props:{asChild:{type:Boolean},as:{},class:{}},setup(e){let t=e,n=_d(_u(t,`class`));return(e,r)=>(z(),V(N(Qh),W({"data-slot":`navigation-menu-list`},N(n),{class:N(Z)(`group flex flex-1 list-none items-center justify-center gap-1`,t.class)}),{default:P(()=>[I(e.$slots,`default`)]),_:3},16,[`class`]))}}),D_=e=>e.replace(/([a-z0-9])([A-Z])/g,`$1-$2`).toLowerCase(),O_=e=>e.replace(/^([A-Z])|[\s-_]+(\w)/g,(e,t,n)=>n?n.toUpperCase():t.toLowerCase()),k_=e=>{let t=O_(e);return t.charAt(0).toUpperCase()+t.slice(1)},A_=(...e)=>e.filter((e,t,n)=>!!e&&e.trim()!==``&&n.indexOf(e)===t).join(` `).trim(),j_=e=>e===``,M_={xmlns:`http://www.w3.org/2000/svg`,width:24,height:24,viewBox:`0 0 24 24`,fill:`none`,stroke:`currentColor`,"stroke-width":2,"stroke-linecap":`round`,"stroke-linejoin":`round`},N_=({name:e,iconNode:t,absoluteStrokeWidth:n,"absolute-stroke-width":r,strokeWidth:i,"stroke-width":a,size:o=M_.width,color:s=M_.stroke,...c},{slots:l})=>K(`svg`,{...M_,...c,width:o,height:o,stroke:s,"stroke-width":j_(n)||j_(r)||n===!0||r===!0?Number(i||a||M_[`stroke-width`])*24/Number(o):i||a||M_[`stroke-width`],class:A_(`lucide`,c.class,...e?[`lucide-${D_(k_(e))}-icon`,`lucide-${D_(e)}`]:[`lucide-icon`])},[...t.map(e=>K(...e)),...l.default?[l.default()]:[]]),P_=(e,t)=>(n,{slots:r,attrs:i})=>K(N_,{...i,...n,iconNode:t,name:e},r),F_=P_(`chevron-right`,[[`path`,{d:`m9 18 6-6-6-6`,key:`mthhwq`}]]),I_=P_(`ellipsis`,[[`circle`,{cx:`12`,cy:`12`,r:`1`,key:`41hilf`}],[`circle`,{cx:`19`,cy:`12`,r:`1`,key:`1wjl8i`}],[`circle`,{cx:`5`,cy:`12`,r:`1`,key:`1pcz8c`}]]),L_=P_(`file-text`,[[`path`,{d:`M6 22a2 2 0 0 1-2-2V4a2 2 0 0 1 2-2h8a2.4 2.4 0 0 1 1.704.706l3.588 3.588A2.4 2.4 0 0 1 20 8v12a2 2 0 0 1-2 2z`,key:`1oefj6`}],[`path`,{d:`M14 2v5a1 1 0 0 0 1 1h5`,key:`wfsgrz`}],[`path`,{d:`M10 9H8`,key:`b1mrlr`}],[`path`,{d:`M16 13H8`,key:`t4e002`}],[`path`,{d:`M16 17H8`,key:`z1uh3a`}]]),R_=P_(`file-up`,[[`path`,{d:`M6 22a2 2 0 0 1-2-2V4a2 2 0 0 1 2-2h8a2.4 2.4 0 0 1 1.704.706l3.588 3.588A2.4 2.4 0 0 1 20 8v12a2 2 0 0 1-2 2z`,key:`1oefj6`}],[`path`,{d:`M14 2v5a1 1 0 0 0 1 1h5`,key:`wfsgrz`}],[`path`,{d:`M12 12v6`,key:`3ahymv`}],[`path`,{d:`m15 15-3-3-3 3`,key:`15xj92`}]]),z_=P_(`file`,[[`path`,{d:`M6 22a2 2 0 0 1-2-2V4a2 2 0 0 1 2-2h8a2.4 2.4 0 0 1 1.704.706l3.588 3.588A2.4 2.4 0 0 1 20 8v12a2 2 0 0 1-2 2z`,key:`1oefj6`}],[`path`,{d:`M14 2v5a1 1 0 0 0 1 1h5`,key:`wfsgrz`}]]),B_=P_(`folder-plus`,[[`path`,{d:`M12 10v6`,key:`1bos4e`}],[`path`,{d:`M9 13h6`,key:`1uhe8q`}],[`path`,{d:`M20 20a2 2 0 0 0 2-2V8a2 2 0 0 0-2-2h-7.9a2 2 0 0 1-1.69-.9L9.6 3.9A2 2 0 0 0 7.93 3H4a2 2 0 0 0-2 2v13a2 2 0 0 0 2 2Z`,key:`1kt360`}]]),V_=P_(`folder-symlink`,[[`path`,{d:`M2 9.35V5a2 2 0 0 1 2-2h3.9a2 2 0 0 1 1.69.9l.81 1.2a2 2 0 0 0 1.67.9H20a2 2 0 0 1 2 2v10a2 2 0 0 1-2 2H4a2 2 0 0 1-2-2v-3a2 2 0 0 1 2-2h7`,key:`y8kt7d`}],[`path`,{d:`m8 16 3-3-3-3`,key:`rlqrt1`}]]),H_=P_(`folder`,[[`path`,{d:`M20 20a2 2 0 0 0 2-2V8a2 2 0 0 0-2-2h-7.9a2 2 0 0 1-1.69-.9L9.6 3.9A2 2 0 0 0 7.93 3H4a2 2 0 0 0-2 2v13a2 2 0 0 0 2 2Z`,key:`1kt360`}]]),U_=P_(`house`,[[`path`,{d:`M15 21v-8a1 1 0 0 0-1-1h-4a1 1 0 0 0-1 1v8`,key:`5wwlr5`}],[`path`,{d:`M3 10a2 2 0 0 1 .709-1.528l7-6a2 2 0 0 1 2.582 0l7 6A2 2 0 0 1 21 10v9a2 2 0 0 1-2 2H5a2 2 0 0 1-2-2z`,key:`r6nss1`}]]),W_=P_(`server`,[[`rect`,{width:`20`,height:`8`,x:`2`,y:`2`,rx:`2`,ry:`2`,key:`ngkwjq`}],[`rect`,{width:`20`,height:`8`,x:`2`,y:`14`,rx:`2`,ry:`2`,key:`iecqi9`}],[`line`,{x1:`6`,x2:`6.01`,y1:`6`,y2:`6`,key:`16zg32`}],[`line`,{x1:`6`,x2:`6.01`,y1:`18`,y2:`18`,key:`nzw8ys`}]]),G_=P_(`upload`,[[`path`,{d:`M12 3v12`,key:`1x0j5s`}],[`path`,{d:`m17 8-5-5-5 5`,key:`7q97r8`}],[`path`,{d:`M21 15v4a2 2 0 0 1-2 2H5a2 2 0 0 1-2-2v-4`,key:`ih7n3h`}]]),K_=P_(`x`,[[`path`,{d:`M18 6 6 18`,key:`1bl5f8`}],[`path`,{d:`m6 6 12 12`,key:`d8bk6v`}]]),q_={class:`flex items-center gap-2 text-3xl font-bold`},J_=F({__name:`AppNavBar`,setup(e){return(e,t)=>(z(),V(N(C_),{viewport:!1},{default:P(()=>[U(N(E_),null,{default:P(()=>[U(N(w_),null,{default:P(()=>[U(N(T_),null,{default:P(()=>[H(`span`,q_,[U(N(W_),{class:`size-10`}),t[0]||=ms(`RSHttpServer`,-1)])]),_:1})]),_:1})]),_:1})]),_:1}))}}),Y_=F({__name:`Breadcrumb`,props:{class:{}},setup(e){let t=e;return(e,n)=>(z(),B(`nav`,{"aria-label":`breadcrumb`,"data-slot":`breadcrumb`,class:A(t.class)},[I(e.$slots,`default`)],2))}}),X_=F({__name:`BreadcrumbEllipsis`,props:{class:{}},setup(e){let t=e;return(e,n)=>(z(),B(`span`,{"data-slot":`breadcrumb-ellipsis`,role:`presentation`,"aria-hidden":`true`,class:A(N(Z)(`flex size-9 items-center justify-center`,t.class))},[I(e.$slots,`default`,{},()=>[U(N(I_),{class:`size-4`})]),n[0]||=H(`span`,{class:`sr-only`},`More`,-1)],2))}}),Z_=F({__name:`BreadcrumbItem`,props:{class:{}},setup(e){let t=e;return(e,n)=>(z(),B(`li`,{"data-slot":`breadcrumb-item`,class:A(N(Z)(`inline-flex items-center gap-1.5`,t.class))},[I(e.$slots,`default`)],2))}}),Q_=F({__name:`BreadcrumbLink`,props:{asChild:{type:Boolean},as:{default:`a`},class:{}},setup(e){let t=e;return(n,r)=>(z(),V(N(Vd),{"data-slot":`breadcrumb-link`,as:e.as,"as-child":e.asChild,class:A(N(Z)(`hover:text-foreground transition-colors`,t.class))},{default:P(()=>[I(n.$slots,`default`)]),_:3},8,[`as`,`as-child`,`class`]))}}),$_=F({__name:`BreadcrumbList`,props:{class:{}},setup(e){let t=e;return(e,n)=>(z(),B(`ol`,{"data-slot":`breadcrumb-list`,class:A(N(Z)(`text-muted-foreground flex flex-wrap items-center gap-1.5 text-sm break-words sm:gap-2.5`,t.class))},[I(e.$slots,`default`)],2))}}),ev=F({__name:`BreadcrumbPage`,props:{class:{}},setup(e){let t=e;return(e,n)=>(z(),B(`span`,{"data-slot":`breadcrumb-page`,role:`link`,"aria-disabled":`true`,"aria-current":`page`,class:A(N(Z)(`text-foreground font-normal`,t.class))},[I(e.$slots,`default`)],2))}}),tv=F({__name:`BreadcrumbSeparator`,props:{class:{}},setup(e){let t=e;return(e,n)=>(z(),B(`li`,{"data-slot":`breadcrumb-separator`,role:`presentation`,"aria-hidden":`true`,class:A(N(Z)(`[&>svg]:size-3.5`,t.class))},[I(e.$slots,`default`,{},()=>[U(N(F_))])],2))}}),nv=F({__name:`DropdownMenu`,props:{defaultOpen:{type:Boolean},open:{type:Boolean},dir:{},modal:{type:Boolean}},emits:[`update:open`],setup(e,{emit:t}){let n=vd(e,t);return(e,t)=>(z(),V(N(jh),W({"data-slot":`dropdown-menu`},N(n)),{default:P(t=>[I(e.$slots,`default`,ye(fs(t)))]),_:3},16))}}),rv=F({inheritAttrs:!1,__name:`DropdownMenuContent`,props:{forceMount:{type:Boolean},loop:{type:Boolean},side:{},sideOffset:{default:4},sideFlip:{type:Boolean},align:{},alignOffset:{},alignFlip:{type:Boolean},avoidCollisions:{type:Boolean},collisionBoundary:{},collisionPadding:{},arrowPadding:{},sticky:{},hideWhenDetached:{type:Boolean},positionStrategy:{},updatePositionStrategy:{},disableUpdateOnLayoutShift:{type:Boolean},prioritizePosition:{type:Boolean},reference:{},asChild:{type:Boolean},as:{},class:{}},emits:[`escapeKeyDown`,`pointerDownOutside`,`focusOutside`,`interactOutside`,`closeAutoFocus`],setup(e,{emit:t}){let n=e,r=t,i=vd(_u(n,`class`),r);return(e,t)=>(z(),V(N(Ph),null,{default:P(()=>[U(N(Mh),W({"data-slot":`dropdown-menu-content`},{...e.$attrs,...N(i)},{class:N(Z)(`bg-popover text-popover-foreground data-[state=open]:animate-in data-[state=closed]:animate-out data-[state=closed]:fade-out-0 data-[state=open]:fade-in-0 data-[state=closed]:zoom-out-95 data-[state=open]:zoom-in-95 data-[side=bottom]:slide-in-from-top-2 data-[side=left]:slide-in-from-right-2 data-[side=right]:slide-in-from-left-2 data-[side=top]:slide-in-from-bottom-2 z-50 max-h-(--reka-dropdown-menu-content-available-height) min-w-[8rem] origin-(--reka-dropdown-menu-content-transform-origin) overflow-x-hidden overflow-y-auto rounded-md border p-1 shadow-md`,n.class)}),{default:P(()=>[I(e.$slots,`default`)]),_:3},16,[`class`])]),_:3}))}}),iv=F({__name:`DropdownMenuItem`,props:{disabled:{type:Boolean},textValue:{},asChild:{type:Boolean},as:{},class:{},inset:{type:Boolean},variant:{default:`default`}},setup(e){let t=e,n=_d(_u(t,`inset`,`variant`,`class`));return(r,i)=>(z(),V(N(Nh),W({"data-slot":`dropdown-menu-item`,"data-inset":e.inset?``:void 0,"data-variant":e.variant},N(n),{class:N(Z)(`focus:bg-accent focus:text-accent-foreground data-[variant=destructive]:text-destructive data-[variant=destructive]:focus:bg-destructive/10 dark:data-[variant=destructive]:focus:bg-destructive/20 data-[variant=destructive]:focus:text-destructive data-[variant=destructive]:*:[svg]:!text-destructive [&_svg:not([class*='text-'])]:text-muted-foreground relative flex cursor-default items-center gap-2 rounded-sm px-2 py-1.5 text-sm outline-hidden select-none data-[disabled]:pointer-events-none data-[disabled]:opacity-50 data-[inset]:pl-8 [&_svg]:pointer-events-none [&_svg]:shrink-0 [&_svg:not([class*='size-'])]:size-4`,t.class)}),{default:P(()=>[I(r.$slots,`default`)]),_:3},16,[`data-inset`,`data-variant`,`class`]))}}),av=F({__name:`DropdownMenuTrigger`,props:{disabled:{type:Boolean},asChild:{type:Boolean},as:{}},setup(e){let t=_d(e);return(e,n)=>(z(),V(N(Fh),W({"data-slot":`dropdown-menu-trigger`},N(t)),{default:P(()=>[I(e.$slots,`default`)]),_:3},16))}}),ov=F({__name:`AppBreadCrumb`,props:{rootpath:{},refreshEntryInfo:{type:Function}},emits:[`update:rootpath`],setup(e,{emit:t}){let n=e,r=t,i=G(()=>n.rootpath?n.rootpath.split(`/`).filter(Boolean):[]);function a(e,t){return i.value.slice(e,t).join(`/`)}function o(e){r(`update:rootpath`,e),n.refreshEntryInfo(e)}return(e,t)=>(z(),V(N(Y_),null,{default:P(()=>[U(N($_),null,{default:P(()=>[U(N(Z_),null,{default:P(()=>[U(N(Q_),{onClick:t[0]||=()=>o(``)},{default:P(()=>[U(N(U_))]),_:1})]),_:1}),i.value.length>2?(z(),V(N(tv),{key:0})):gs(``,!0),i.value.length>2?(z(),V(N(Z_),{key:1},{default:P(()=>[U(N(nv),null,{default:P(()=>[U(N(av),{class:`flex items-center gap-1`},{default:P(()=>[U(N(X_),{class:`h-4 w-4`}),t[2]||=H(`span`,{class:`sr-only`},`Toggle menu`,-1)]),_:1}),U(N(rv),{align:`start`},{default:P(()=>[(z(!0),B(R,null,Yi(i.value.slice(0,i.value.length-2),(e,t)=>(z(),V(N(iv),{key:t,onClick:()=>o(a(0,t+1))},{default:P(()=>[ms(De(e),1)]),_:2},1032,[`onClick`]))),128))]),_:1})]),_:1})]),_:1})):gs(``,!0),i.value.length>1?(z(),V(N(tv),{key:2})):gs(``,!0),i.value.length>1?(z(),V(N(Z_),{key:3},{default:P(()=>[U(N(Q_),{onClick:t[1]||=()=>o(a(0,i.value.length-1))},{default:P(()=>[ms(De(i.value[i.value.length-2]),1)]),_:1})]),_:1})):gs(``,!0),i.value.length>0?(z(),V(N(tv),{key:4})):gs(``,!0),i.value.length>0?(z(),V(N(Z_),{key:5},{default:P(()=>[U(N(ev),null,{default:P(()=>[ms(De(i.value[i.value.length-1]),1)]),_:1})]),_:1})):gs(``,!0)]),_:1})]),_:1}))}}),sv=F({__name:`Button`,props:{variant:{},size:{},class:{},asChild:{type:Boolean},as:{default:`button`}},setup(e){let t=e;return(n,r)=>(z(),V(N(Vd),{"data-slot":`button`,as:e.as,"as-child":e.asChild,class:A(N(Z)(N(cv)({variant:e.variant,size:e.size}),t.class))},{default:P(()=>[I(n.$slots,`default`)]),_:3},8,[`as`,`as-child`,`class`]))}});const cv=fu(`inline-flex items-center justify-center gap-2 whitespace-nowrap rounded-md text-sm font-medium transition-all disabled:pointer-events-none disabled:opacity-50 [&_svg]:pointer-events-none [&_svg:not([class*='size-'])]:size-4 shrink-0 [&_svg]:shrink-0 outline-none focus-visible:border-ring focus-visible:ring-ring/50 focus-visible:ring-[3px] aria-invalid:ring-destructive/20 dark:aria-invalid:ring-destructive/40 aria-invalid:border-destructive`,{variants:{variant:{default:`bg-primary text-primary-foreground hover:bg-primary/90`,destructive:`bg-destructive text-white hover:bg-destructive/90 focus-visible:ring-destructive/20 dark:focus-visible:ring-destructive/40 dark:bg-destructive/60`,outline:`border bg-background shadow-xs hover:bg-accent hover:text-accent-foreground dark:bg-input/30 dark:border-input dark:hover:bg-input/50`,secondary:`bg-secondary text-secondary-foreground hover:bg-secondary/80`,ghost:`hover:bg-accent hover:text-accent-foreground dark:hover:bg-accent/50`,link:`text-primary underline-offset-4 hover:underline`},size:{default:`h-9 px-4 py-2 has-[>svg]:px-3`,sm:`h-8 rounded-md gap-1.5 px-3 has-[>svg]:px-2.5`,lg:`h-10 rounded-md px-6 has-[>svg]:px-4`,icon:`size-9`,"icon-sm":`size-8`,"icon-lg":`size-10`}},defaultVariants:{variant:`default`,size:`default`}});var lv=F({__name:`Dialog`,props:{open:{type:Boolean},defaultOpen:{type:Boolean},modal:{type:Boolean}},emits:[`update:open`],setup(e,{emit:t}){let n=vd(e,t);return(e,t)=>(z(),V(N(Gd),W({"data-slot":`dialog`},N(n)),{default:P(t=>[I(e.$slots,`default`,ye(fs(t)))]),_:3},16))}}),uv=F({__name:`DialogClose`,props:{asChild:{type:Boolean},as:{}},setup(e){let t=e;return(e,n)=>(z(),V(N(Kd),W({"data-slot":`dialog-close`},t),{default:P(()=>[I(e.$slots,`default`)]),_:3},16))}}),dv=F({__name:`DialogOverlay`,props:{forceMount:{type:Boolean},asChild:{type:Boolean},as:{},class:{}},setup(e){let t=e,n=_u(t,`class`);return(e,r)=>(z(),V(N(Mf),W({"data-slot":`dialog-overlay`},N(n),{class:N(Z)(`data-[state=open]:animate-in data-[state=closed]:animate-out data-[state=closed]:fade-out-0 data-[state=open]:fade-in-0 fixed inset-0 z-50 bg-black/80`,t.class)}),{default:P(()=>[I(e.$slots,`default`)]),_:3},16,[`class`]))}}),fv=F({inheritAttrs:!1,__name:`DialogContent`,props:{forceMount:{type:Boolean},disableOutsidePointerEvents:{type:Boolean},asChild:{type:Boolean},as:{},class:{},showCloseButton:{type:Boolean,default:!0}},emits:[`escapeKeyDown`,`pointerDownOutside`,`focusOutside`,`interactOutside`,`openAutoFocus`,`closeAutoFocus`],setup(e,{emit:t}){let n=e,r=t,i=vd(_u(n,`class`),r);return(t,r)=>(z(),V(N(Pf),null,{default:P(()=>[U(dv),U(N(kf),W({"data-slot":`dialog-content`},{...t.$attrs,...N(i)},{class:N(Z)(`bg-background data-[state=open]:animate-in data-[state=closed]:animate-out data-[state=closed]:fade-out-0 data-[state=open]:fade-in-0 data-[state=closed]:zoom-out-95 data-[state=open]:zoom-in-95 fixed top-[50%] left-[50%] z-50 grid w-full max-w-[calc(100%-2rem)] translate-x-[-50%] translate-y-[-50%] gap-4 rounded-lg border p-6 shadow-lg duration-200 sm:max-w-lg`,n.class)}),{default:P(()=>[I(t.$slots,`default`),e.showCloseButton?(z(),V(N(Kd),{key:0,"data-slot":`dialog-close`,class:`ring-offset-background focus:ring-ring data-[state=open]:bg-accent data-[state=open]:text-muted-foreground absolute top-4 right-4 rounded-xs opacity-70 transition-opacity hover:opacity-100 focus:ring-2 focus:ring-offset-2 focus:outline-hidden disabled:pointer-events-none [&_svg]:pointer-events-none [&_svg]:shrink-0 [&_svg:not([class*='size-'])]:size-4`},{default:P(()=>[U(N(K_)),r[0]||=H(`span`,{class:`sr-only`},`Close`,-1)]),_:1})):gs(``,!0)]),_:3},16,[`class`])]),_:3}))}}),pv=F({__name:`DialogDescription`,props:{asChild:{type:Boolean},as:{},class:{}},setup(e){let t=e,n=_d(_u(t,`class`));return(e,r)=>(z(),V(N(Af),W({"data-slot":`dialog-description`},N(n),{class:N(Z)(`text-muted-foreground text-sm`,t.class)}),{default:P(()=>[I(e.$slots,`default`)]),_:3},16,[`class`]))}}),mv=F({__name:`DialogFooter`,props:{class:{}},setup(e){let t=e;return(e,n)=>(z(),B(`div`,{"data-slot":`dialog-footer`,class:A(N(Z)(`flex flex-col-reverse gap-2 sm:flex-row sm:justify-end`,t.class))},[I(e.$slots,`default`)],2))}}),hv=F({__name:`DialogHeader`,props:{class:{}},setup(e){let t=e;return(e,n)=>(z(),B(`div`,{"data-slot":`dialog-header`,class:A(N(Z)(`flex flex-col gap-2 text-center sm:text-left`,t.class))},[I(e.$slots,`default`)],2))}}),gv=F({__name:`DialogTitle`,props:{asChild:{type:Boolean},as:{},class:{}},setup(e){let t=e,n=_d(_u(t,`class`));return(e,r)=>(z(),V(N(Ff),W({"data-slot":`dialog-title`},N(n),{class:N(Z)(`text-lg leading-none font-semibold`,t.class)}),{default:P(()=>[I(e.$slots,`default`)]),_:3},16,[`class`]))}}),_v=F({__name:`DialogTrigger`,props:{asChild:{type:Boolean},as:{}},setup(e){let t=e;return(e,n)=>(z(),V(N(If),W({"data-slot":`dialog-trigger`},t),{default:P(()=>[I(e.$slots,`default`)]),_:3},16))}}),vv=F({__name:`Input`,props:{defaultValue:{},modelValue:{},class:{}},emits:[`update:modelValue`],setup(e,{emit:t}){let n=e,r=yu(n,`modelValue`,t,{passive:!0,defaultValue:n.defaultValue});return(e,t)=>_r((z(),B(`input`,{"onUpdate:modelValue":t[0]||=e=>un(r)?r.value=e:null,"data-slot":`input`,class:A(N(Z)(`file:text-foreground placeholder:text-muted-foreground selection:bg-primary selection:text-primary-foreground dark:bg-input/30 border-input h-9 w-full min-w-0 rounded-md border bg-transparent px-3 py-1 text-base shadow-xs transition-[color,box-shadow] outline-none file:inline-flex file:h-7 file:border-0 file:bg-transparent file:text-sm file:font-medium disabled:pointer-events-none disabled:cursor-not-allowed disabled:opacity-50 md:text-sm`,`focus-visible:border-ring focus-visible:ring-ring/50 focus-visible:ring-[3px]`,`aria-invalid:ring-destructive/20 dark:aria-invalid:ring-destructive/40 aria-invalid:border-destructive`,n.class))},null,2)),[[Al,N(r)]])}}),yv=F({__name:`Label`,props:{for:{},asChild:{type:Boolean},as:{},class:{}},setup(e){let t=e,n=_u(t,`class`);return(e,r)=>(z(),V(N(Ih),W({"data-slot":`label`},N(n),{class:N(Z)(`flex items-center gap-2 text-sm leading-none font-medium select-none group-data-[disabled=true]:pointer-events-none group-data-[disabled=true]:opacity-50 peer-disabled:cursor-not-allowed peer-disabled:opacity-50`,t.class)}),{default:P(()=>[I(e.$slots,`default`)]),_:3},16,[`class`]))}}),bv=1,xv=new class{subscribers;toasts;dismissedToasts;constructor(){this.subscribers=[],this.toasts=[],this.dismissedToasts=new Set}subscribe=e=>(this.subscribers.push(e),()=>{let t=this.subscribers.indexOf(e);this.subscribers.splice(t,1)});publish=e=>{this.subscribers.forEach(t=>t(e))};addToast=e=>{this.publish(e),this.toasts=[...this.toasts,e]};create=e=>{let{message:t,...n}=e,r=typeof e.id==`number`||e.id&&e.id?.length>0?e.id:bv++,i=this.toasts.find(e=>e.id===r),a=e.dismissible===void 0?!0:e.dismissible;return this.dismissedToasts.has(r)&&this.dismissedToasts.delete(r),i?this.toasts=this.toasts.map(n=>n.id===r?(this.publish({...n,...e,id:r,title:t}),{...n,...e,id:r,dismissible:a,title:t}):n):this.addToast({title:t,...n,dismissible:a,id:r}),r};dismiss=e=>(e?(this.dismissedToasts.add(e),requestAnimationFrame(()=>this.subscribers.forEach(t=>t({id:e,dismiss:!0})))):this.toasts.forEach(e=>{this.subscribers.forEach(t=>t({id:e.id,dismiss:!0}))}),e);message=(e,t)=>this.create({...t,message:e,type:`default`});error=(e,t)=>this.create({...t,type:`error`,message:e});success=(e,t)=>this.create({...t,type:`success`,message:e});info=(e,t)=>this.create({...t,type:`info`,message:e});warning=(e,t)=>this.create({...t,type:`warning`,message:e});loading=(e,t)=>this.create({...t,type:`loading`,message:e});promise=(e,t)=>{if(!t)return;let n;t.loading!==void 0&&(n=this.create({...t,promise:e,type:`loading`,message:t.loading,description:typeof t.description==`function`?void 0:t.description}));let r=Promise.resolve(e instanceof Function?e():e),i=n!==void 0,a,o=r.then(async e=>{if(a=[`resolve`,e],os(e))i=!1,this.create({id:n,type:`default`,message:e});else if(Cv(e)&&!e.ok){i=!1;let r=typeof t.error==`function`?await t.error(`HTTP error! status: ${e.status}`):t.error,a=typeof t.description==`function`?await t.description(`HTTP error! status: ${e.status}`):t.description,o=typeof r==`object`&&!os(r)?r:{message:r||``,id:n||``};this.create({id:n,type:`error`,description:a,...o})}else if(e instanceof Error){i=!1;let r=typeof t.error==`function`?await t.error(e):t.error,a=typeof t.description==`function`?await t.description(e):t.description,o=typeof r==`object`&&!os(r)?r:{message:r||``,id:n||``};this.create({id:n,type:`error`,description:a,...o})}else if(t.success!==void 0){i=!1;let r=typeof t.success==`function`?await t.success(e):t.success,a=typeof t.description==`function`?await t.description(e):t.description,o=typeof r==`object`&&!os(r)?r:{message:r||``,id:n||``};this.create({id:n,type:`success`,description:a,...o})}}).catch(async e=>{if(a=[`reject`,e],t.error!==void 0){i=!1;let r=typeof t.error==`function`?await t.error(e):t.error,a=typeof t.description==`function`?await t.description(e):t.description,o=typeof r==`object`&&!os(r)?r:{message:r||``,id:n||``};this.create({id:n,type:`error`,description:a,...o})}}).finally(()=>{i&&(this.dismiss(n),n=void 0),t.finally?.()}),s=()=>new Promise((e,t)=>o.then(()=>a[0]===`reject`?t(a[1]):e(a[1])).catch(t));return typeof n!=`string`&&typeof n!=`number`?{unwrap:s}:Object.assign(n,{unwrap:s})};custom=(e,t)=>{let n=t?.id||bv++,r=this.toasts.find(e=>e.id===n),i=t?.dismissible===void 0?!0:t.dismissible;return this.dismissedToasts.has(n)&&this.dismissedToasts.delete(n),r?this.toasts=this.toasts.map(r=>r.id===n?(this.publish({...r,component:e,dismissible:i,id:n,...t}),{...r,component:e,dismissible:i,id:n,...t}):r):this.addToast({component:e,dismissible:i,id:n,...t}),n};getActiveToasts=()=>this.toasts.filter(e=>!this.dismissedToasts.has(e.id))};function Sv(e,t){let n=t?.id||bv++;return xv.create({message:e,id:n,type:`default`,...t}),n}var Cv=e=>e&&typeof e==`object`&&`ok`in e&&typeof e.ok==`boolean`&&`status`in e&&typeof e.status==`number`,wv=Sv,Tv=Object.assign(wv,{success:xv.success,info:xv.info,warning:xv.warning,error:xv.error,custom:xv.custom,message:xv.message,promise:xv.promise,dismiss:xv.dismiss,loading:xv.loading},{getHistory:()=>xv.toasts,getToasts:()=>xv.getActiveToasts()});function Ev(e){return e.label!==void 0}var Dv=3,Ov=`24px`,kv=`16px`,Av=4e3,jv=356,Mv=14,Nv=45,Pv=200;function Fv(){let e=M(!1);return Ua(()=>{let t=()=>{e.value=document.hidden};return document.addEventListener(`visibilitychange`,t),()=>window.removeEventListener(`visibilitychange`,t)}),{isDocumentHidden:e}}function Iv(...e){return e.filter(Boolean).join(` `)}function Lv(e){let[t,n]=e.split(`-`),r=[];return t&&r.push(t),n&&r.push(n),r}function Rv(e,t){let n={};return[e,t].forEach((e,t)=>{let r=t===1,i=r?`--mobile-offset`:`--offset`,a=r?kv:Ov;function o(e){[`top`,`right`,`bottom`,`left`].forEach(t=>{n[`${i}-${t}`]=typeof e==`number`?`${e}px`:e})}typeof e==`number`||typeof e==`string`?o(e):typeof e==`object`?[`top`,`right`,`bottom`,`left`].forEach(t=>{e[t]===void 0?n[`${i}-${t}`]=a:n[`${i}-${t}`]=typeof e[t]==`number`?`${e[t]}px`:e[t]}):o(a)}),n}var zv=[`data-rich-colors`,`data-styled`,`data-mounted`,`data-promise`,`data-swiped`,`data-removed`,`data-visible`,`data-y-position`,`data-x-position`,`data-index`,`data-front`,`data-swiping`,`data-dismissible`,`data-type`,`data-invert`,`data-swipe-out`,`data-swipe-direction`,`data-expanded`,`data-testid`],Bv=[`aria-label`,`data-disabled`,`data-close-button-position`],Vv=F({__name:`Toast`,props:{toast:{},toasts:{},index:{},swipeDirections:{},expanded:{type:Boolean},invert:{type:Boolean},heights:{},gap:{},position:{},closeButtonPosition:{},visibleToasts:{},expandByDefault:{type:Boolean},closeButton:{type:Boolean},interacting:{type:Boolean},style:{},cancelButtonStyle:{},actionButtonStyle:{},duration:{},class:{},unstyled:{type:Boolean},descriptionClass:{},loadingIcon:{},classes:{},icons:{},closeButtonAriaLabel:{},defaultRichColors:{type:Boolean}},emits:[`update:heights`,`update:height`,`removeToast`],setup(e,{emit:t}){let n=e,r=t,i=M(null),a=M(null),o=M(!1),s=M(!1),c=M(!1),l=M(!1),u=M(!1),d=M(0),f=M(0),p=M(n.toast.duration||n.duration||Av),m=M(null),h=M(null),g=G(()=>n.index===0),_=G(()=>n.index+1<=n.visibleToasts),v=G(()=>n.toast.type),y=G(()=>n.toast.dismissible!==!1),b=G(()=>n.toast.class||``),x=G(()=>n.descriptionClass||``),S=G(()=>{let e=n.toast.position||n.position,t=n.heights.filter(t=>t.position===e).findIndex(e=>e.toastId===n.toast.id);return t>=0?t:0}),C=G(()=>{let e=n.toast.position||n.position;return n.heights.filter(t=>t.position===e).reduce((e,t,n)=>n>=S.value?e:e+t.height,0)}),w=G(()=>S.value*n.gap+C.value||0),T=G(()=>n.toast.closeButton??n.closeButton),ee=G(()=>n.toast.duration||n.duration||Av),E=M(0),te=M(0),ne=M(null),re=G(()=>n.position.split(`-`)),D=G(()=>re.value[0]),ie=G(()=>re.value[1]),O=G(()=>typeof n.toast.title!=`string`),k=G(()=>typeof n.toast.description!=`string`),{isDocumentHidden:ae}=Fv(),oe=G(()=>v.value&&v.value===`loading`);Mi(()=>{o.value=!0,p.value=ee.value}),Ua(async()=>{if(!o.value||!h.value)return;await $n();let e=h.value,t=e.style.height;e.style.height=`auto`;let i=e.getBoundingClientRect().height;e.style.height=t,f.value=i,r(`update:height`,{toastId:n.toast.id,height:i,position:n.toast.position||n.position})});function se(){s.value=!0,d.value=w.value,setTimeout(()=>{r(`removeToast`,n.toast)},Pv)}function ce(){if(oe.value||!y.value)return{};se(),n.toast.onDismiss?.(n.toast)}function le(e){e.button!==2&&(oe.value||!y.value||(m.value=new Date,d.value=w.value,e.target.setPointerCapture(e.pointerId),e.target.tagName!==`BUTTON`&&(c.value=!0,ne.value={x:e.clientX,y:e.clientY})))}function ue(){if(l.value||!y.value)return;ne.value=null;let e=Number(h.value?.style.getPropertyValue(`--swipe-amount-x`).replace(`px`,``)||0),t=Number(h.value?.style.getPropertyValue(`--swipe-amount-y`).replace(`px`,``)||0),r=new Date().getTime()-(m.value?.getTime()||0),o=i.value===`x`?e:t,s=Math.abs(o)/r;if(Math.abs(o)>=Nv||s>.11){d.value=w.value,n.toast.onDismiss?.(n.toast),i.value===`x`?a.value=e>0?`right`:`left`:a.value=t>0?`down`:`up`,se(),l.value=!0;return}else h.value?.style.setProperty(`--swipe-amount-x`,`0px`),h.value?.style.setProperty(`--swipe-amount-y`,`0px`);u.value=!1,c.value=!1,i.value=null}function de(e){if(!ne.value||!y.value||(window?.getSelection()?.toString()?.length??!1))return;let t=e.clientY-ne.value.y,r=e.clientX-ne.value.x,a=n.swipeDirections??Lv(n.position);!i.value&&(Math.abs(r)>1||Math.abs(t)>1)&&(i.value=Math.abs(r)>Math.abs(t)?`x`:`y`);let o={x:0,y:0},s=e=>1/(1.5+Math.abs(e)/20);if(i.value===`y`){if(a.includes(`top`)||a.includes(`bottom`))if(a.includes(`top`)&&t<0||a.includes(`bottom`)&&t>0)o.y=t;else{let e=t*s(t);o.y=Math.abs(e)<Math.abs(t)?e:t}}else if(i.value===`x`&&(a.includes(`left`)||a.includes(`right`)))if(a.includes(`left`)&&r<0||a.includes(`right`)&&r>0)o.x=r;else{let e=r*s(r);o.x=Math.abs(e)<Math.abs(r)?e:r}(Math.abs(o.x)>0||Math.abs(o.y)>0)&&(u.value=!0),h.value?.style.setProperty(`--swipe-amount-x`,`${o.x}px`),h.value?.style.setProperty(`--swipe-amount-y`,`${o.y}px`)}Mi(()=>{if(o.value=!0,!h.value)return;let e=h.value.getBoundingClientRect().height;f.value=e,r(`update:heights`,[{toastId:n.toast.id,height:e,position:n.toast.position},...n.heights])}),Fi(()=>{h.value&&r(`removeToast`,n.toast)}),Ua(e=>{if(n.toast.promise&&v.value===`loading`||n.toast.duration===1/0||n.toast.type===`loading`)return;let t;n.expanded||n.interacting||ae.value?(()=>{if(te.value<E.value){let e=new Date().getTime()-E.value;p.value-=e}te.value=new Date().getTime()})():p.value!==1/0&&(E.value=new Date().getTime(),t=setTimeout(()=>{n.toast.onAutoClose?.(n.toast),se()},p.value)),e(()=>{clearTimeout(t)})}),L(()=>n.toast.delete,e=>{e!==void 0&&e&&(se(),n.toast.onDismiss?.(n.toast))},{deep:!0});function fe(){c.value=!1,i.value=null,ne.value=null}return(e,t)=>(z(),B(`li`,{tabindex:`0`,ref_key:`toastRef`,ref:h,class:A(N(Iv)(n.class,b.value,e.classes?.toast,e.toast.classes?.toast,e.classes?.[v.value],e.toast?.classes?.[v.value])),"data-sonner-toast":``,"data-rich-colors":e.toast.richColors??e.defaultRichColors,"data-styled":!(e.toast.component||e.toast?.unstyled||e.unstyled),"data-mounted":o.value,"data-promise":!!e.toast.promise,"data-swiped":u.value,"data-removed":s.value,"data-visible":_.value,"data-y-position":D.value,"data-x-position":ie.value,"data-index":e.index,"data-front":g.value,"data-swiping":c.value,"data-dismissible":y.value,"data-type":v.value,"data-invert":e.toast.invert||e.invert,"data-swipe-out":l.value,"data-swipe-direction":a.value,"data-expanded":!!(e.expanded||e.expandByDefault&&o.value),"data-testid":e.toast.testId,style:me({"--index":e.index,"--toasts-before":e.index,"--z-index":e.toasts.length-e.index,"--offset":`${s.value?d.value:w.value}px`,"--initial-height":e.expandByDefault?`auto`:`${f.value}px`,...e.style,...n.toast.style}),onDragend:fe,onPointerdown:le,onPointerup:ue,onPointermove:de},[T.value&&!e.toast.component&&v.value!==`loading`?(z(),B(`button`,{key:0,"aria-label":e.closeButtonAriaLabel||`Close toast`,"data-disabled":oe.value,"data-close-button":`true`,"data-close-button-position":e.closeButtonPosition,class:A(N(Iv)(e.classes?.closeButton,e.toast?.classes?.closeButton)),onClick:ce},[e.icons?.close?(z(),V(Gi(e.icons?.close),{key:0})):I(e.$slots,`close-icon`,{key:1})],10,Bv)):gs(`v-if`,!0),e.toast.component?(z(),V(Gi(e.toast.component),W({key:1},e.toast.componentProps,{onCloseToast:ce,isPaused:e.$props.expanded||e.$props.interacting||N(ae)}),null,16,[`isPaused`])):(z(),B(R,{key:2},[v.value!==`default`||e.toast.icon||e.toast.promise?(z(),B(`div`,{key:0,"data-icon":``,class:A(N(Iv)(e.classes?.icon,e.toast?.classes?.icon))},[e.toast.icon?(z(),V(Gi(e.toast.icon),{key:0})):(z(),B(R,{key:1},[v.value===`loading`?I(e.$slots,`loading-icon`,{key:0}):v.value===`success`?I(e.$slots,`success-icon`,{key:1}):v.value===`error`?I(e.$slots,`error-icon`,{key:2}):v.value===`warning`?I(e.$slots,`warning-icon`,{key:3}):v.value===`info`?I(e.$slots,`info-icon`,{key:4}):gs(`v-if`,!0)],64))],2)):gs(`v-if`,!0),H(`div`,{"data-content":``,class:A(N(Iv)(e.classes?.content,e.toast?.classes?.content))},[H(`div`,{"data-title":``,class:A(N(Iv)(e.classes?.title,e.toast.classes?.title))},[O.value?(z(),V(Gi(e.toast.title),ye(W({key:0},e.toast.componentProps)),null,16)):(z(),B(R,{key:1},[ms(De(e.toast.title),1)],64))],2),e.toast.description?(z(),B(`div`,{key:0,"data-description":``,class:A(N(Iv)(e.descriptionClass,x.value,e.classes?.description,e.toast.classes?.description))},[k.value?(z(),V(Gi(e.toast.description),ye(W({key:0},e.toast.componentProps)),null,16)):(z(),B(R,{key:1},[ms(De(e.toast.description),1)],64))],2)):gs(`v-if`,!0)],2),e.toast.cancel?(z(),B(`button`,{key:1,style:me(e.toast.cancelButtonStyle||e.cancelButtonStyle),class:A(N(Iv)(e.classes?.cancelButton,e.toast.classes?.cancelButton)),"data-button":``,"data-cancel":``,onClick:t[0]||=t=>{N(Ev)(e.toast.cancel)&&y.value&&(e.toast.cancel.onClick?.(t),se())}},De(N(Ev)(e.toast.cancel)?e.toast.cancel?.label:e.toast.cancel),7)):gs(`v-if`,!0),e.toast.action?(z(),B(`button`,{key:2,style:me(e.toast.actionButtonStyle||e.actionButtonStyle),class:A(N(Iv)(e.classes?.actionButton,e.toast.classes?.actionButton)),"data-button":``,"data-action":``,onClick:t[1]||=t=>{N(Ev)(e.toast.action)&&(e.toast.action.onClick?.(t),!t.defaultPrevented&&se())}},De(N(Ev)(e.toast.action)?e.toast.action?.label:e.toast.action),7)):gs(`v-if`,!0)],64))],46,zv))}}),Hv=(e,t)=>{let n=e.__vccOpts||e;for(let[e,r]of t)n[e]=r;return n},Uv={},Wv={xmlns:`http://www.w3.org/2000/svg`,width:`12`,height:`12`,viewBox:`0 0 24 24`,fill:`none`,stroke:`currentColor`,"stoke-width":`1.5`,"stroke-linecap":`round`,"stroke-linejoin":`round`};function Gv(e,t){return z(),B(`svg`,Wv,t[0]||=[H(`line`,{x1:`18`,y1:`6`,x2:`6`,y2:`18`},null,-1),H(`line`,{x1:`6`,y1:`6`,x2:`18`,y2:`18`},null,-1)])}var Kv=Hv(Uv,[[`render`,Gv]]),qv=[`data-visible`],Jv={class:`sonner-spinner`},Yv=F({__name:`Loader`,props:{visible:{type:Boolean}},setup(e){let t=Array(12).fill(0);return(e,n)=>(z(),B(`div`,{class:`sonner-loading-wrapper`,"data-visible":e.visible},[H(`div`,Jv,[(z(!0),B(R,null,Yi(N(t),e=>(z(),B(`div`,{key:`spinner-bar-${e}`,class:`sonner-loading-bar`}))),128))])],8,qv))}}),Xv={},Zv={xmlns:`http://www.w3.org/2000/svg`,viewBox:`0 0 20 20`,fill:`currentColor`,height:`20`,width:`20`};function Qv(e,t){return z(),B(`svg`,Zv,t[0]||=[H(`path`,{"fill-rule":`evenodd`,d:`M10 18a8 8 0 100-16 8 8 0 000 16zm3.857-9.809a.75.75 0 00-1.214-.882l-3.483 4.79-1.88-1.88a.75.75 0 10-1.06 1.061l2.5 2.5a.75.75 0 001.137-.089l4-5.5z`,"clip-rule":`evenodd`},null,-1)])}var $v=Hv(Xv,[[`render`,Qv]]),ey={},ty={xmlns:`http://www.w3.org/2000/svg`,viewBox:`0 0 20 20`,fill:`currentColor`,height:`20`,width:`20`};function ny(e,t){return z(),B(`svg`,ty,t[0]||=[H(`path`,{"fill-rule":`evenodd`,d:`M18 10a8 8 0 11-16 0 8 8 0 0116 0zm-7-4a1 1 0 11-2 0 1 1 0 012 0zM9 9a.75.75 0 000 1.5h.253a.25.25 0 01.244.304l-.459 2.066A1.75 1.75 0 0010.747 15H11a.75.75 0 000-1.5h-.253a.25.25 0 01-.244-.304l.459-2.066A1.75 1.75 0 009.253 9H9z`,"clip-rule":`evenodd`},null,-1)])}var ry=Hv(ey,[[`render`,ny]]),iy={},ay={xmlns:`http://www.w3.org/2000/svg`,viewBox:`0 0 24 24`,fill:`currentColor`,height:`20`,width:`20`};function oy(e,t){return z(),B(`svg`,ay,t[0]||=[H(`path`,{"fill-rule":`evenodd`,d:`M9.401 3.003c1.155-2 4.043-2 5.197 0l7.355 12.748c1.154 2-.29 4.5-2.599 4.5H4.645c-2.309 0-3.752-2.5-2.598-4.5L9.4 3.003zM12 8.25a.75.75 0 01.75.75v3.75a.75.75 0 01-1.5 0V9a.75.75 0 01.75-.75zm0 8.25a.75.75 0 100-1.5.75.75 0 000 1.5z`,"clip-rule":`evenodd`},null,-1)])}var sy=Hv(iy,[[`render`,oy]]),cy={},ly={xmlns:`http://www.w3.org/2000/svg`,viewBox:`0 0 20 20`,fill:`currentColor`,height:`20`,width:`20`};function uy(e,t){return z(),B(`svg`,ly,t[0]||=[H(`path`,{"fill-rule":`evenodd`,d:`M18 10a8 8 0 11-16 0 8 8 0 0116 0zm-8-5a.75.75 0 01.75.75v4.5a.75.75 0 01-1.5 0v-4.5A.75.75 0 0110 5zm0 10a1 1 0 100-2 1 1 0 000 2z`,"clip-rule":`evenodd`},null,-1)])}var dy=Hv(cy,[[`render`,uy]]),fy=[`aria-label`],py=[`data-sonner-theme`,`dir`,`data-theme`,`data-rich-colors`,`data-y-position`,`data-x-position`],my=typeof window<`u`&&typeof document<`u`;function hy(){if(typeof window>`u`||typeof document>`u`)return`ltr`;let e=document.documentElement.getAttribute(`dir`);return e===`auto`||!e?window.getComputedStyle(document.documentElement).direction:e}var gy=F({name:`Toaster`,inheritAttrs:!1,__name:`Toaster`,props:{id:{},invert:{type:Boolean,default:!1},theme:{default:`light`},position:{default:`bottom-right`},closeButtonPosition:{default:`top-left`},hotkey:{default:()=>[`altKey`,`KeyT`]},richColors:{type:Boolean,default:!1},expand:{type:Boolean,default:!1},duration:{},gap:{default:Mv},visibleToasts:{default:Dv},closeButton:{type:Boolean,default:!1},toastOptions:{default:()=>({})},class:{default:``},style:{},offset:{default:Ov},mobileOffset:{default:kv},dir:{default:`auto`},swipeDirections:{},icons:{},containerAriaLabel:{default:`Notifications`}},setup(e){let t=e,n=fa(),r=M([]),i=G(()=>t.id?r.value.filter(e=>e.toasterId===t.id):r.value.filter(e=>!e.toasterId));function a(e,t){return i.value.filter(n=>!n.position&&t===0||n.position===e)}let o=G(()=>{let e=i.value.filter(e=>e.position).map(e=>e.position);return e.length>0?Array.from(new Set([t.position].concat(e))):[t.position]}),s=G(()=>{let e={};return o.value.forEach(t=>{e[t]=r.value.filter(e=>e.position===t)}),e}),c=M([]),l=M({}),u=M(!1);Ua(()=>{o.value.forEach(e=>{e in l.value||(l.value[e]=!1)})});let d=M(t.theme===`system`?typeof window<`u`&&window.matchMedia&&window.matchMedia(`(prefers-color-scheme: dark)`).matches?`dark`:`light`:t.theme),f=M(null),p=M(null),m=M(!1),h=t.hotkey.join(`+`).replace(/Key/g,``).replace(/Digit/g,``);function g(e){r.value.find(t=>t.id===e.id)?.delete||xv.dismiss(e.id),r.value=r.value.filter(({id:t})=>t!==e.id),setTimeout(()=>{r.value.find(t=>t.id===e.id)||(c.value=c.value.filter(t=>t.toastId!==e.id))},Pv+50)}function _(e){m.value&&!e.currentTarget?.contains?.(e.relatedTarget)&&(m.value=!1,p.value&&=(p.value.focus({preventScroll:!0}),null))}function v(e){e.target instanceof HTMLElement&&e.target.dataset.dismissible===`false`||m.value||(m.value=!0,p.value=e.relatedTarget)}function y(e){e.target&&e.target instanceof HTMLElement&&e.target.dataset.dismissible===`false`||(u.value=!0)}Ua(e=>{e(xv.subscribe(e=>{if(e.dismiss){requestAnimationFrame(()=>{r.value=r.value.map(t=>t.id===e.id?{...t,delete:!0}:t)});return}$n(()=>{let t=r.value.findIndex(t=>t.id===e.id);t===-1?r.value=[e,...r.value]:r.value=[...r.value.slice(0,t),{...r.value[t],...e},...r.value.slice(t+1)]})}))}),Ua(e=>{if(typeof window>`u`)return;if(t.theme!==`system`){d.value=t.theme;return}let n=window.matchMedia(`(prefers-color-scheme: dark)`),r=e=>{d.value=e?`dark`:`light`};r(n.matches);let i=e=>{r(e.matches)};try{n.addEventListener(`change`,i)}catch{n.addListener(i)}e(()=>{try{n.removeEventListener(`change`,i)}catch{n.removeListener(i)}})}),Ua(()=>{f.value&&p.value&&(p.value.focus({preventScroll:!0}),p.value=null,m.value=!1)}),Ua(()=>{r.value.length<=1&&Object.keys(l.value).forEach(e=>{l.value[e]=!1})}),Ua(e=>{function n(e){let n=t.hotkey.every(t=>e[t]||e.code===t),r=Array.isArray(f.value)?f.value[0]:f.value;n&&(o.value.forEach(e=>{l.value[e]=!0}),r?.focus());let i=document.activeElement===f.value||r?.contains(document.activeElement);e.code===`Escape`&&i&&o.value.forEach(e=>{l.value[e]=!1})}my&&(document.addEventListener(`keydown`,n),e(()=>{document.removeEventListener(`keydown`,n)}))});function b(e){let t=e.currentTarget,n=t.getAttribute(`data-y-position`)+`-`+t.getAttribute(`data-x-position`);l.value[n]=!0}function x(e){if(!u.value){let t=e.currentTarget,n=t.getAttribute(`data-y-position`)+`-`+t.getAttribute(`data-x-position`);l.value[n]=!1}}function S(){Object.keys(l.value).forEach(e=>{l.value[e]=!1})}function C(){u.value=!1}function w(e){c.value=e}function T(e){let t=c.value.findIndex(t=>t.toastId===e.toastId);if(t!==-1)c.value[t]=e;else{let t=c.value.findIndex(t=>t.position===e.position);t===-1?c.value.unshift(e):c.value.splice(t,0,e)}}return(e,r)=>(z(),B(R,null,[gs(` Remove item from normal navigation flow, only available via hotkey `),H(`section`,{"aria-label":`${e.containerAriaLabel} ${N(h)}`,tabIndex:-1,"aria-live":`polite`,"aria-relevant":`additions text`,"aria-atomic":`false`},[(z(!0),B(R,null,Yi(o.value,(r,i)=>(z(),B(`ol`,W({key:r,ref_for:!0,ref_key:`listRef`,ref:f,"data-sonner-toaster":``,"data-sonner-theme":d.value,class:t.class,dir:e.dir===`auto`?hy():e.dir,tabIndex:-1,"data-theme":e.theme,"data-rich-colors":e.richColors,"data-y-position":r.split(`-`)[0],"data-x-position":r.split(`-`)[1],style:{"--front-toast-height":`${c.value[0]?.height||0}px`,"--width":`${N(jv)}px`,"--gap":`${e.gap}px`,...e.style,...N(n).style,...N(Rv)(e.offset,e.mobileOffset)}},{ref_for:!0},e.$attrs,{onBlur:_,onFocus:v,onMouseenter:b,onMousemove:b,onMouseleave:x,onDragend:S,onPointerdown:y,onPointerup:C}),[(z(!0),B(R,null,Yi(a(r,i),(n,i)=>(z(),V(Vv,{key:n.id,heights:c.value,icons:e.icons,index:i,toast:n,defaultRichColors:e.richColors,duration:e.toastOptions?.duration??e.duration,class:A(e.toastOptions?.class??``),descriptionClass:e.toastOptions?.descriptionClass,invert:e.invert,visibleToasts:e.visibleToasts,closeButton:e.toastOptions?.closeButton??e.closeButton,interacting:u.value,position:r,closeButtonPosition:e.toastOptions?.closeButtonPosition??e.closeButtonPosition,style:me(e.toastOptions?.style),unstyled:e.toastOptions?.unstyled,classes:e.toastOptions?.classes,cancelButtonStyle:e.toastOptions?.cancelButtonStyle,actionButtonStyle:e.toastOptions?.actionButtonStyle,"close-button-aria-label":e.toastOptions?.closeButtonAriaLabel,toasts:s.value[r],expandByDefault:e.expand,gap:e.gap,expanded:l.value[r]||!1,swipeDirections:t.swipeDirections,"onUpdate:heights":w,"onUpdate:height":T,onRemoveToast:g},{"close-icon":P(()=>[I(e.$slots,`close-icon`,{},()=>[U(Kv)])]),"loading-icon":P(()=>[I(e.$slots,`loading-icon`,{},()=>[U(Yv,{visible:n.type===`loading`},null,8,[`visible`])])]),"success-icon":P(()=>[I(e.$slots,`success-icon`,{},()=>[U($v)])]),"error-icon":P(()=>[I(e.$slots,`error-icon`,{},()=>[U(dy)])]),"warning-icon":P(()=>[I(e.$slots,`warning-icon`,{},()=>[U(sy)])]),"info-icon":P(()=>[I(e.$slots,`info-icon`,{},()=>[U(ry)])]),_:2},1032,[`heights`,`icons`,`index`,`toast`,`defaultRichColors`,`duration`,`class`,`descriptionClass`,`invert`,`visibleToasts`,`closeButton`,`interacting`,`position`,`closeButtonPosition`,`style`,`unstyled`,`classes`,`cancelButtonStyle`,`actionButtonStyle`,`close-button-aria-label`,`toasts`,`expandByDefault`,`gap`,`expanded`,`swipeDirections`]))),128))],16,py))),128))],8,fy)],2112))}}),_y={class:`grid gap-4`},vy={class:`grid gap-3`},yy=F({__name:`AppCreateFolder`,props:{rootpath:{},refreshEntryInfo:{type:Function}},setup(e){let t=e,n=M(!1),r=M(``);function i(){n.value&&=!1;let e=`/create/${t.rootpath?.trim()?t.rootpath.trim()+`/`:``}${r.value.trim()}`;fetch(e,{method:`POST`}).then(e=>e.json()).then(e=>{e.code===200&&Tv.success(e.message)}).catch(e=>Tv.error(e)),r.value=``,t.refreshEntryInfo(t.rootpath)}return(e,t)=>(z(),V(N(lv),{open:n.value,"onUpdate:open":t[1]||=e=>n.value=e},{default:P(()=>[H(`form`,null,[U(N(_v),{"as-child":``},{default:P(()=>[U(N(sv),{variant:`outline`},{default:P(()=>[U(N(B_)),t[2]||=ms(`new folder `,-1)]),_:1})]),_:1}),U(N(fv),{class:`sm:max-w-[425px]`},{default:P(()=>[U(N(hv),null,{default:P(()=>[U(N(gv),null,{default:P(()=>[...t[3]||=[ms(`Create Folder`,-1)]]),_:1}),U(N(pv),null,{default:P(()=>[...t[4]||=[ms(` Create a new folder in the current location. `,-1)]]),_:1})]),_:1}),H(`div`,_y,[H(`div`,vy,[U(N(yv),{for:`fname`},{default:P(()=>[...t[5]||=[ms(`folder name`,-1)]]),_:1}),U(N(vv),{id:`fname`,modelValue:r.value,"onUpdate:modelValue":t[0]||=e=>r.value=e,"default-value":``,placeholder:`please input folder name`},null,8,[`modelValue`])])]),U(N(mv),null,{default:P(()=>[U(N(uv),{"as-child":``},{default:P(()=>[U(N(sv),{variant:`outline`},{default:P(()=>[...t[6]||=[ms(` Cancel `,-1)]]),_:1})]),_:1}),U(N(sv),{type:`submit`,onClick:i},{default:P(()=>[...t[7]||=[ms(` Save `,-1)]]),_:1})]),_:1})]),_:1})])]),_:1},8,[`open`]))}}),by={class:`grid gap-4`},xy={class:`grid gap-3`},Sy={key:0,class:`max-h-[200px] overflow-y-auto space-y-2`},Cy={class:`flex items-center gap-2 overflow-hidden`},wy={class:`flex flex-col truncate`},Ty={class:`truncate font-medium`},Ey={class:`text-xs text-gray-500`},Dy={key:1,class:`text-center py-4 text-sm text-gray-500 border-dashed border-2 rounded-md`},Oy=F({__name:`AppUploadFiles`,props:{rootpath:{},refreshEntryInfo:{type:Function}},setup(e){let t=e,n=M(!1),r=M(!1),i=M([]),a=e=>{let t=e.target;t.files&&t.files.length>0&&(i.value=[...i.value,...Array.from(t.files)],t.value=``)},o=e=>{i.value.splice(e,1)},s=e=>{if(e===0)return`0B`;let t=1024,n=[`B`,`K`,`M`,`G`],r=Math.floor(Math.log(e)/Math.log(t));return(e/t**+r).toFixed(2)+n[r]},c=async()=>{if(i.value.length!==0){try{r.value=!0,console.log(`Start uploading files...`);let e=new FormData;i.value.forEach(t=>{e.append(`files`,t)}),console.log(`Uploading:`,i.value.map(e=>e.name));let a=`/upload/${t.rootpath?.trim()?t.rootpath.trim()+`/`:``}`;console.log(`AppUploadFiles url:`,a),await fetch(a,{method:`POST`,body:e}).then(e=>e.json()).then(e=>{Tv.success(e.message)}).catch(e=>Tv.error(e)),n.value=!1,i.value=[]}catch(e){console.error(`Upload failed:`,e)}finally{r.value=!1}t.refreshEntryInfo(t.rootpath)}};return(e,t)=>(z(),V(N(lv),{open:n.value,"onUpdate:open":t[0]||=e=>n.value=e},{default:P(()=>[H(`form`,null,[U(N(_v),{"as-child":``},{default:P(()=>[U(N(sv),{variant:`outline`},{default:P(()=>[U(N(R_)),t[1]||=ms(` Upload Files `,-1)]),_:1})]),_:1}),U(N(fv),{class:`sm:max-w-[425px]`},{default:P(()=>[U(N(hv),null,{default:P(()=>[U(N(gv),null,{default:P(()=>[...t[2]||=[ms(`Upload Files`,-1)]]),_:1}),U(N(pv),null,{default:P(()=>[...t[3]||=[ms(` upload files in the current location. `,-1)]]),_:1})]),_:1}),H(`div`,by,[H(`div`,xy,[U(N(yv),{for:`file-upload`},{default:P(()=>[...t[4]||=[ms(`select files`,-1)]]),_:1}),U(N(vv),{id:`file-upload`,type:`file`,multiple:``,onChange:a})]),i.value.length>0?(z(),B(`div`,Sy,[(z(!0),B(R,null,Yi(i.value,(e,t)=>(z(),B(`div`,{key:t+e.name,class:`flex items-center justify-between p-2 border rounded-md text-sm`},[H(`div`,Cy,[U(N(L_),{class:`h-4 w-4 text-blue-500 flex-shrink-0`}),H(`div`,wy,[H(`span`,Ty,De(e.name),1),H(`span`,Ey,De(s(e.size)),1)])]),U(N(sv),{variant:`ghost`,size:`icon`,class:`h-6 w-6 text-gray-500 hover:text-red-500`,onClick:e=>o(t)},{default:P(()=>[U(N(K_),{class:`h-4 w-4`})]),_:1},8,[`onClick`])]))),128))])):(z(),B(`div`,Dy,` No files selected `))]),U(N(mv),null,{default:P(()=>[U(N(uv),{"as-child":``},{default:P(()=>[U(N(sv),{variant:`outline`},{default:P(()=>[...t[5]||=[ms(` Cancel `,-1)]]),_:1})]),_:1}),U(N(sv),{type:`submit`,onClick:c,disabled:r.value||i.value.length===0},{default:P(()=>[r.value?gs(``,!0):(z(),V(N(G_),{key:0,class:`mr-2 h-4 w-4`})),ms(` `+De(r.value?`Uploading...`:`Upload`),1)]),_:1},8,[`disabled`])]),_:1})]),_:1})])]),_:1},8,[`open`]))}});function ky(e,t){return typeof e==`function`?e(t):e}function Ay(e,t){return n=>{t.setState(t=>({...t,[e]:ky(n,t[e])}))}}function jy(e){return e instanceof Function}function My(e){return Array.isArray(e)&&e.every(e=>typeof e==`number`)}function Ny(e,t){let n=[],r=e=>{e.forEach(e=>{n.push(e);let i=t(e);i!=null&&i.length&&r(i)})};return r(e),n}function Q(e,t,n){let r=[],i;return a=>{let o;n.key&&n.debug&&(o=Date.now());let s=e(a);if(!(s.length!==r.length||s.some((e,t)=>r[t]!==e)))return i;r=s;let c;if(n.key&&n.debug&&(c=Date.now()),i=t(...s),n==null||n.onChange==null||n.onChange(i),n.key&&n.debug&&n!=null&&n.debug()){let e=Math.round((Date.now()-o)*100)/100,t=Math.round((Date.now()-c)*100)/100,r=t/16,i=(e,t)=>{for(e=String(e);e.length<t;)e=` `+e;return e};console.info(`%c⏱ ${i(t,5)} /${i(e,5)} ms`,`
             font-size: .6rem;
             font-weight: bold;
             color: hsl(${Math.max(0,Math.min(120-120*r,120))}deg 100% 31%);`,n?.key)}return i}}function $(e,t,n,r){return{debug:()=>e?.debugAll??e[t],key:!1,onChange:r}}function Py(e,t,n,r){let i={id:`${t.id}_${n.id}`,row:t,column:n,getValue:()=>t.getValue(r),renderValue:()=>i.getValue()??e.options.renderFallbackValue,getContext:Q(()=>[e,n,t,i],(e,t,n,r)=>({table:e,column:t,row:n,cell:r,getValue:r.getValue,renderValue:r.renderValue}),$(e.options,`debugCells`,`cell.getContext`))};return e._features.forEach(r=>{r.createCell==null||r.createCell(i,n,t,e)},{}),i}function Fy(e,t,n,r){let i={...e._getDefaultColumnDef(),...t},a=i.accessorKey,o=i.id??(a?typeof String.prototype.replaceAll==`function`?a.replaceAll(`.`,`_`):a.replace(/\./g,`_`):void 0)??(typeof i.header==`string`?i.header:void 0),s;if(i.accessorFn?s=i.accessorFn:a&&(s=a.includes(`.`)?e=>{let t=e;for(let e of a.split(`.`))t=t?.[e];return t}:e=>e[i.accessorKey]),!o)throw Error();let c={id:`${String(o)}`,accessorFn:s,parent:r,depth:n,columnDef:i,columns:[],getFlatColumns:Q(()=>[!0],()=>[c,...c.columns?.flatMap(e=>e.getFlatColumns())],$(e.options,`debugColumns`,`column.getFlatColumns`)),getLeafColumns:Q(()=>[e._getOrderColumnsFn()],e=>{var t;return(t=c.columns)!=null&&t.length?e(c.columns.flatMap(e=>e.getLeafColumns())):[c]},$(e.options,`debugColumns`,`column.getLeafColumns`))};for(let t of e._features)t.createColumn==null||t.createColumn(c,e);return c}var Iy=`debugHeaders`;function Ly(e,t,n){let r={id:n.id??t.id,column:t,index:n.index,isPlaceholder:!!n.isPlaceholder,placeholderId:n.placeholderId,depth:n.depth,subHeaders:[],colSpan:0,rowSpan:0,headerGroup:null,getLeafHeaders:()=>{let e=[],t=n=>{n.subHeaders&&n.subHeaders.length&&n.subHeaders.map(t),e.push(n)};return t(r),e},getContext:()=>({table:e,header:r,column:t})};return e._features.forEach(t=>{t.createHeader==null||t.createHeader(r,e)}),r}var Ry={createTable:e=>{e.getHeaderGroups=Q(()=>[e.getAllColumns(),e.getVisibleLeafColumns(),e.getState().columnPinning.left,e.getState().columnPinning.right],(t,n,r,i)=>{let a=r?.map(e=>n.find(t=>t.id===e)).filter(Boolean)??[],o=i?.map(e=>n.find(t=>t.id===e)).filter(Boolean)??[],s=n.filter(e=>!(r!=null&&r.includes(e.id))&&!(i!=null&&i.includes(e.id)));return zy(t,[...a,...s,...o],e)},$(e.options,Iy,`getHeaderGroups`)),e.getCenterHeaderGroups=Q(()=>[e.getAllColumns(),e.getVisibleLeafColumns(),e.getState().columnPinning.left,e.getState().columnPinning.right],(t,n,r,i)=>(n=n.filter(e=>!(r!=null&&r.includes(e.id))&&!(i!=null&&i.includes(e.id))),zy(t,n,e,`center`)),$(e.options,Iy,`getCenterHeaderGroups`)),e.getLeftHeaderGroups=Q(()=>[e.getAllColumns(),e.getVisibleLeafColumns(),e.getState().columnPinning.left],(t,n,r)=>zy(t,r?.map(e=>n.find(t=>t.id===e)).filter(Boolean)??[],e,`left`),$(e.options,Iy,`getLeftHeaderGroups`)),e.getRightHeaderGroups=Q(()=>[e.getAllColumns(),e.getVisibleLeafColumns(),e.getState().columnPinning.right],(t,n,r)=>zy(t,r?.map(e=>n.find(t=>t.id===e)).filter(Boolean)??[],e,`right`),$(e.options,Iy,`getRightHeaderGroups`)),e.getFooterGroups=Q(()=>[e.getHeaderGroups()],e=>[...e].reverse(),$(e.options,Iy,`getFooterGroups`)),e.getLeftFooterGroups=Q(()=>[e.getLeftHeaderGroups()],e=>[...e].reverse(),$(e.options,Iy,`getLeftFooterGroups`)),e.getCenterFooterGroups=Q(()=>[e.getCenterHeaderGroups()],e=>[...e].reverse(),$(e.options,Iy,`getCenterFooterGroups`)),e.getRightFooterGroups=Q(()=>[e.getRightHeaderGroups()],e=>[...e].reverse(),$(e.options,Iy,`getRightFooterGroups`)),e.getFlatHeaders=Q(()=>[e.getHeaderGroups()],e=>e.map(e=>e.headers).flat(),$(e.options,Iy,`getFlatHeaders`)),e.getLeftFlatHeaders=Q(()=>[e.getLeftHeaderGroups()],e=>e.map(e=>e.headers).flat(),$(e.options,Iy,`getLeftFlatHeaders`)),e.getCenterFlatHeaders=Q(()=>[e.getCenterHeaderGroups()],e=>e.map(e=>e.headers).flat(),$(e.options,Iy,`getCenterFlatHeaders`)),e.getRightFlatHeaders=Q(()=>[e.getRightHeaderGroups()],e=>e.map(e=>e.headers).flat(),$(e.options,Iy,`getRightFlatHeaders`)),e.getCenterLeafHeaders=Q(()=>[e.getCenterFlatHeaders()],e=>e.filter(e=>{var t;return!((t=e.subHeaders)!=null&&t.length)}),$(e.options,Iy,`getCenterLeafHeaders`)),e.getLeftLeafHeaders=Q(()=>[e.getLeftFlatHeaders()],e=>e.filter(e=>{var t;return!((t=e.subHeaders)!=null&&t.length)}),$(e.options,Iy,`getLeftLeafHeaders`)),e.getRightLeafHeaders=Q(()=>[e.getRightFlatHeaders()],e=>e.filter(e=>{var t;return!((t=e.subHeaders)!=null&&t.length)}),$(e.options,Iy,`getRightLeafHeaders`)),e.getLeafHeaders=Q(()=>[e.getLeftHeaderGroups(),e.getCenterHeaderGroups(),e.getRightHeaderGroups()],(e,t,n)=>[...e[0]?.headers??[],...t[0]?.headers??[],...n[0]?.headers??[]].map(e=>e.getLeafHeaders()).flat(),$(e.options,Iy,`getLeafHeaders`))}};function zy(e,t,n,r){let i=0,a=function(e,t){t===void 0&&(t=1),i=Math.max(i,t),e.filter(e=>e.getIsVisible()).forEach(e=>{var n;(n=e.columns)!=null&&n.length&&a(e.columns,t+1)},0)};a(e);let o=[],s=(e,t)=>{let i={depth:t,id:[r,`${t}`].filter(Boolean).join(`_`),headers:[]},a=[];e.forEach(e=>{let o=[...a].reverse()[0],s=e.column.depth===i.depth,c,l=!1;if(s&&e.column.parent?c=e.column.parent:(c=e.column,l=!0),o&&o?.column===c)o.subHeaders.push(e);else{let i=Ly(n,c,{id:[r,t,c.id,e?.id].filter(Boolean).join(`_`),isPlaceholder:l,placeholderId:l?`${a.filter(e=>e.column===c).length}`:void 0,depth:t,index:a.length});i.subHeaders.push(e),a.push(i)}i.headers.push(e),e.headerGroup=i}),o.push(i),t>0&&s(a,t-1)};s(t.map((e,t)=>Ly(n,e,{depth:i,index:t})),i-1),o.reverse();let c=e=>e.filter(e=>e.column.getIsVisible()).map(e=>{let t=0,n=0,r=[0];e.subHeaders&&e.subHeaders.length?(r=[],c(e.subHeaders).forEach(e=>{let{colSpan:n,rowSpan:i}=e;t+=n,r.push(i)})):t=1;let i=Math.min(...r);return n+=i,e.colSpan=t,e.rowSpan=n,{colSpan:t,rowSpan:n}});return c(o[0]?.headers??[]),o}var By=(e,t,n,r,i,a,o)=>{let s={id:t,index:r,original:n,depth:i,parentId:o,_valuesCache:{},_uniqueValuesCache:{},getValue:t=>{if(s._valuesCache.hasOwnProperty(t))return s._valuesCache[t];let n=e.getColumn(t);if(n!=null&&n.accessorFn)return s._valuesCache[t]=n.accessorFn(s.original,r),s._valuesCache[t]},getUniqueValues:t=>{if(s._uniqueValuesCache.hasOwnProperty(t))return s._uniqueValuesCache[t];let n=e.getColumn(t);if(n!=null&&n.accessorFn)return n.columnDef.getUniqueValues?(s._uniqueValuesCache[t]=n.columnDef.getUniqueValues(s.original,r),s._uniqueValuesCache[t]):(s._uniqueValuesCache[t]=[s.getValue(t)],s._uniqueValuesCache[t])},renderValue:t=>s.getValue(t)??e.options.renderFallbackValue,subRows:a??[],getLeafRows:()=>Ny(s.subRows,e=>e.subRows),getParentRow:()=>s.parentId?e.getRow(s.parentId,!0):void 0,getParentRows:()=>{let e=[],t=s;for(;;){let n=t.getParentRow();if(!n)break;e.push(n),t=n}return e.reverse()},getAllCells:Q(()=>[e.getAllLeafColumns()],t=>t.map(t=>Py(e,s,t,t.id)),$(e.options,`debugRows`,`getAllCells`)),_getAllCellsByColumnId:Q(()=>[s.getAllCells()],e=>e.reduce((e,t)=>(e[t.column.id]=t,e),{}),$(e.options,`debugRows`,`getAllCellsByColumnId`))};for(let t=0;t<e._features.length;t++){let n=e._features[t];n==null||n.createRow==null||n.createRow(s,e)}return s},Vy={createColumn:(e,t)=>{e._getFacetedRowModel=t.options.getFacetedRowModel&&t.options.getFacetedRowModel(t,e.id),e.getFacetedRowModel=()=>e._getFacetedRowModel?e._getFacetedRowModel():t.getPreFilteredRowModel(),e._getFacetedUniqueValues=t.options.getFacetedUniqueValues&&t.options.getFacetedUniqueValues(t,e.id),e.getFacetedUniqueValues=()=>e._getFacetedUniqueValues?e._getFacetedUniqueValues():new Map,e._getFacetedMinMaxValues=t.options.getFacetedMinMaxValues&&t.options.getFacetedMinMaxValues(t,e.id),e.getFacetedMinMaxValues=()=>{if(e._getFacetedMinMaxValues)return e._getFacetedMinMaxValues()}}},Hy=(e,t,n)=>{var r,i;let a=n==null||(r=n.toString())==null?void 0:r.toLowerCase();return!!(!((i=e.getValue(t))==null||(i=i.toString())==null||(i=i.toLowerCase())==null)&&i.includes(a))};Hy.autoRemove=e=>Qy(e);var Uy=(e,t,n)=>{var r;return!!(!((r=e.getValue(t))==null||(r=r.toString())==null)&&r.includes(n))};Uy.autoRemove=e=>Qy(e);var Wy=(e,t,n)=>{var r;return((r=e.getValue(t))==null||(r=r.toString())==null?void 0:r.toLowerCase())===n?.toLowerCase()};Wy.autoRemove=e=>Qy(e);var Gy=(e,t,n)=>e.getValue(t)?.includes(n);Gy.autoRemove=e=>Qy(e);var Ky=(e,t,n)=>!n.some(n=>{var r;return!((r=e.getValue(t))!=null&&r.includes(n))});Ky.autoRemove=e=>Qy(e)||!(e!=null&&e.length);var qy=(e,t,n)=>n.some(n=>e.getValue(t)?.includes(n));qy.autoRemove=e=>Qy(e)||!(e!=null&&e.length);var Jy=(e,t,n)=>e.getValue(t)===n;Jy.autoRemove=e=>Qy(e);var Yy=(e,t,n)=>e.getValue(t)==n;Yy.autoRemove=e=>Qy(e);var Xy=(e,t,n)=>{let[r,i]=n,a=e.getValue(t);return a>=r&&a<=i};Xy.resolveFilterValue=e=>{let[t,n]=e,r=typeof t==`number`?t:parseFloat(t),i=typeof n==`number`?n:parseFloat(n),a=t===null||Number.isNaN(r)?-1/0:r,o=n===null||Number.isNaN(i)?1/0:i;if(a>o){let e=a;a=o,o=e}return[a,o]},Xy.autoRemove=e=>Qy(e)||Qy(e[0])&&Qy(e[1]);var Zy={includesString:Hy,includesStringSensitive:Uy,equalsString:Wy,arrIncludes:Gy,arrIncludesAll:Ky,arrIncludesSome:qy,equals:Jy,weakEquals:Yy,inNumberRange:Xy};function Qy(e){return e==null||e===``}var $y={getDefaultColumnDef:()=>({filterFn:`auto`}),getInitialState:e=>({columnFilters:[],...e}),getDefaultOptions:e=>({onColumnFiltersChange:Ay(`columnFilters`,e),filterFromLeafRows:!1,maxLeafRowFilterDepth:100}),createColumn:(e,t)=>{e.getAutoFilterFn=()=>{let n=t.getCoreRowModel().flatRows[0]?.getValue(e.id);return typeof n==`string`?Zy.includesString:typeof n==`number`?Zy.inNumberRange:typeof n==`boolean`||typeof n==`object`&&n?Zy.equals:Array.isArray(n)?Zy.arrIncludes:Zy.weakEquals},e.getFilterFn=()=>jy(e.columnDef.filterFn)?e.columnDef.filterFn:e.columnDef.filterFn===`auto`?e.getAutoFilterFn():t.options.filterFns?.[e.columnDef.filterFn]??Zy[e.columnDef.filterFn],e.getCanFilter=()=>(e.columnDef.enableColumnFilter??!0)&&(t.options.enableColumnFilters??!0)&&(t.options.enableFilters??!0)&&!!e.accessorFn,e.getIsFiltered=()=>e.getFilterIndex()>-1,e.getFilterValue=()=>{var n;return(n=t.getState().columnFilters)==null||(n=n.find(t=>t.id===e.id))==null?void 0:n.value},e.getFilterIndex=()=>t.getState().columnFilters?.findIndex(t=>t.id===e.id)??-1,e.setFilterValue=n=>{t.setColumnFilters(t=>{let r=e.getFilterFn(),i=t?.find(t=>t.id===e.id),a=ky(n,i?i.value:void 0);if(eb(r,a,e))return t?.filter(t=>t.id!==e.id)??[];let o={id:e.id,value:a};return i?t?.map(t=>t.id===e.id?o:t)??[]:t!=null&&t.length?[...t,o]:[o]})}},createRow:(e,t)=>{e.columnFilters={},e.columnFiltersMeta={}},createTable:e=>{e.setColumnFilters=t=>{let n=e.getAllLeafColumns();e.options.onColumnFiltersChange==null||e.options.onColumnFiltersChange(e=>ky(t,e)?.filter(e=>{let t=n.find(t=>t.id===e.id);return!(t&&eb(t.getFilterFn(),e.value,t))}))},e.resetColumnFilters=t=>{e.setColumnFilters(t?[]:e.initialState?.columnFilters??[])},e.getPreFilteredRowModel=()=>e.getCoreRowModel(),e.getFilteredRowModel=()=>(!e._getFilteredRowModel&&e.options.getFilteredRowModel&&(e._getFilteredRowModel=e.options.getFilteredRowModel(e)),e.options.manualFiltering||!e._getFilteredRowModel?e.getPreFilteredRowModel():e._getFilteredRowModel())}};function eb(e,t,n){return(e&&e.autoRemove?e.autoRemove(t,n):!1)||t===void 0||typeof t==`string`&&!t}var tb={sum:(e,t,n)=>n.reduce((t,n)=>{let r=n.getValue(e);return t+(typeof r==`number`?r:0)},0),min:(e,t,n)=>{let r;return n.forEach(t=>{let n=t.getValue(e);n!=null&&(r>n||r===void 0&&n>=n)&&(r=n)}),r},max:(e,t,n)=>{let r;return n.forEach(t=>{let n=t.getValue(e);n!=null&&(r<n||r===void 0&&n>=n)&&(r=n)}),r},extent:(e,t,n)=>{let r,i;return n.forEach(t=>{let n=t.getValue(e);n!=null&&(r===void 0?n>=n&&(r=i=n):(r>n&&(r=n),i<n&&(i=n)))}),[r,i]},mean:(e,t)=>{let n=0,r=0;if(t.forEach(t=>{let i=t.getValue(e);i!=null&&(i=+i)>=i&&(++n,r+=i)}),n)return r/n},median:(e,t)=>{if(!t.length)return;let n=t.map(t=>t.getValue(e));if(!My(n))return;if(n.length===1)return n[0];let r=Math.floor(n.length/2),i=n.sort((e,t)=>e-t);return n.length%2==0?(i[r-1]+i[r])/2:i[r]},unique:(e,t)=>Array.from(new Set(t.map(t=>t.getValue(e))).values()),uniqueCount:(e,t)=>new Set(t.map(t=>t.getValue(e))).size,count:(e,t)=>t.length},nb={getDefaultColumnDef:()=>({aggregatedCell:e=>{var t;return((t=e.getValue())==null||t.toString==null?void 0:t.toString())??null},aggregationFn:`auto`}),getInitialState:e=>({grouping:[],...e}),getDefaultOptions:e=>({onGroupingChange:Ay(`grouping`,e),groupedColumnMode:`reorder`}),createColumn:(e,t)=>{e.toggleGrouping=()=>{t.setGrouping(t=>t!=null&&t.includes(e.id)?t.filter(t=>t!==e.id):[...t??[],e.id])},e.getCanGroup=()=>(e.columnDef.enableGrouping??!0)&&(t.options.enableGrouping??!0)&&(!!e.accessorFn||!!e.columnDef.getGroupingValue),e.getIsGrouped=()=>t.getState().grouping?.includes(e.id),e.getGroupedIndex=()=>t.getState().grouping?.indexOf(e.id),e.getToggleGroupingHandler=()=>{let t=e.getCanGroup();return()=>{t&&e.toggleGrouping()}},e.getAutoAggregationFn=()=>{let n=t.getCoreRowModel().flatRows[0]?.getValue(e.id);if(typeof n==`number`)return tb.sum;if(Object.prototype.toString.call(n)===`[object Date]`)return tb.extent},e.getAggregationFn=()=>{if(!e)throw Error();return jy(e.columnDef.aggregationFn)?e.columnDef.aggregationFn:e.columnDef.aggregationFn===`auto`?e.getAutoAggregationFn():t.options.aggregationFns?.[e.columnDef.aggregationFn]??tb[e.columnDef.aggregationFn]}},createTable:e=>{e.setGrouping=t=>e.options.onGroupingChange==null?void 0:e.options.onGroupingChange(t),e.resetGrouping=t=>{e.setGrouping(t?[]:e.initialState?.grouping??[])},e.getPreGroupedRowModel=()=>e.getFilteredRowModel(),e.getGroupedRowModel=()=>(!e._getGroupedRowModel&&e.options.getGroupedRowModel&&(e._getGroupedRowModel=e.options.getGroupedRowModel(e)),e.options.manualGrouping||!e._getGroupedRowModel?e.getPreGroupedRowModel():e._getGroupedRowModel())},createRow:(e,t)=>{e.getIsGrouped=()=>!!e.groupingColumnId,e.getGroupingValue=n=>{if(e._groupingValuesCache.hasOwnProperty(n))return e._groupingValuesCache[n];let r=t.getColumn(n);return r!=null&&r.columnDef.getGroupingValue?(e._groupingValuesCache[n]=r.columnDef.getGroupingValue(e.original),e._groupingValuesCache[n]):e.getValue(n)},e._groupingValuesCache={}},createCell:(e,t,n,r)=>{e.getIsGrouped=()=>t.getIsGrouped()&&t.id===n.groupingColumnId,e.getIsPlaceholder=()=>!e.getIsGrouped()&&t.getIsGrouped(),e.getIsAggregated=()=>{var t;return!e.getIsGrouped()&&!e.getIsPlaceholder()&&!!((t=n.subRows)!=null&&t.length)}}};function rb(e,t,n){if(!(t!=null&&t.length)||!n)return e;let r=e.filter(e=>!t.includes(e.id));return n===`remove`?r:[...t.map(t=>e.find(e=>e.id===t)).filter(Boolean),...r]}var ib={getInitialState:e=>({columnOrder:[],...e}),getDefaultOptions:e=>({onColumnOrderChange:Ay(`columnOrder`,e)}),createColumn:(e,t)=>{e.getIndex=Q(e=>[hb(t,e)],t=>t.findIndex(t=>t.id===e.id),$(t.options,`debugColumns`,`getIndex`)),e.getIsFirstColumn=n=>hb(t,n)[0]?.id===e.id,e.getIsLastColumn=n=>{let r=hb(t,n);return r[r.length-1]?.id===e.id}},createTable:e=>{e.setColumnOrder=t=>e.options.onColumnOrderChange==null?void 0:e.options.onColumnOrderChange(t),e.resetColumnOrder=t=>{e.setColumnOrder(t?[]:e.initialState.columnOrder??[])},e._getOrderColumnsFn=Q(()=>[e.getState().columnOrder,e.getState().grouping,e.options.groupedColumnMode],(e,t,n)=>r=>{let i=[];if(!(e!=null&&e.length))i=r;else{let t=[...e],n=[...r];for(;n.length&&t.length;){let e=t.shift(),r=n.findIndex(t=>t.id===e);r>-1&&i.push(n.splice(r,1)[0])}i=[...i,...n]}return rb(i,t,n)},$(e.options,`debugTable`,`_getOrderColumnsFn`))}},ab=()=>({left:[],right:[]}),ob={getInitialState:e=>({columnPinning:ab(),...e}),getDefaultOptions:e=>({onColumnPinningChange:Ay(`columnPinning`,e)}),createColumn:(e,t)=>{e.pin=n=>{let r=e.getLeafColumns().map(e=>e.id).filter(Boolean);t.setColumnPinning(e=>n===`right`?{left:(e?.left??[]).filter(e=>!(r!=null&&r.includes(e))),right:[...(e?.right??[]).filter(e=>!(r!=null&&r.includes(e))),...r]}:n===`left`?{left:[...(e?.left??[]).filter(e=>!(r!=null&&r.includes(e))),...r],right:(e?.right??[]).filter(e=>!(r!=null&&r.includes(e)))}:{left:(e?.left??[]).filter(e=>!(r!=null&&r.includes(e))),right:(e?.right??[]).filter(e=>!(r!=null&&r.includes(e)))})},e.getCanPin=()=>e.getLeafColumns().some(e=>(e.columnDef.enablePinning??!0)&&(t.options.enableColumnPinning??t.options.enablePinning??!0)),e.getIsPinned=()=>{let n=e.getLeafColumns().map(e=>e.id),{left:r,right:i}=t.getState().columnPinning,a=n.some(e=>r?.includes(e)),o=n.some(e=>i?.includes(e));return a?`left`:o?`right`:!1},e.getPinnedIndex=()=>{var n;let r=e.getIsPinned();return r?((n=t.getState().columnPinning)==null||(n=n[r])==null?void 0:n.indexOf(e.id))??-1:0}},createRow:(e,t)=>{e.getCenterVisibleCells=Q(()=>[e._getAllVisibleCells(),t.getState().columnPinning.left,t.getState().columnPinning.right],(e,t,n)=>{let r=[...t??[],...n??[]];return e.filter(e=>!r.includes(e.column.id))},$(t.options,`debugRows`,`getCenterVisibleCells`)),e.getLeftVisibleCells=Q(()=>[e._getAllVisibleCells(),t.getState().columnPinning.left],(e,t)=>(t??[]).map(t=>e.find(e=>e.column.id===t)).filter(Boolean).map(e=>({...e,position:`left`})),$(t.options,`debugRows`,`getLeftVisibleCells`)),e.getRightVisibleCells=Q(()=>[e._getAllVisibleCells(),t.getState().columnPinning.right],(e,t)=>(t??[]).map(t=>e.find(e=>e.column.id===t)).filter(Boolean).map(e=>({...e,position:`right`})),$(t.options,`debugRows`,`getRightVisibleCells`))},createTable:e=>{e.setColumnPinning=t=>e.options.onColumnPinningChange==null?void 0:e.options.onColumnPinningChange(t),e.resetColumnPinning=t=>e.setColumnPinning(t?ab():e.initialState?.columnPinning??ab()),e.getIsSomeColumnsPinned=t=>{let n=e.getState().columnPinning;return t?!!n[t]?.length:!!(n.left?.length||n.right?.length)},e.getLeftLeafColumns=Q(()=>[e.getAllLeafColumns(),e.getState().columnPinning.left],(e,t)=>(t??[]).map(t=>e.find(e=>e.id===t)).filter(Boolean),$(e.options,`debugColumns`,`getLeftLeafColumns`)),e.getRightLeafColumns=Q(()=>[e.getAllLeafColumns(),e.getState().columnPinning.right],(e,t)=>(t??[]).map(t=>e.find(e=>e.id===t)).filter(Boolean),$(e.options,`debugColumns`,`getRightLeafColumns`)),e.getCenterLeafColumns=Q(()=>[e.getAllLeafColumns(),e.getState().columnPinning.left,e.getState().columnPinning.right],(e,t,n)=>{let r=[...t??[],...n??[]];return e.filter(e=>!r.includes(e.id))},$(e.options,`debugColumns`,`getCenterLeafColumns`))}};function sb(e){return e||(typeof document<`u`?document:null)}var cb={size:150,minSize:20,maxSize:2**53-1},lb=()=>({startOffset:null,startSize:null,deltaOffset:null,deltaPercentage:null,isResizingColumn:!1,columnSizingStart:[]}),ub={getDefaultColumnDef:()=>cb,getInitialState:e=>({columnSizing:{},columnSizingInfo:lb(),...e}),getDefaultOptions:e=>({columnResizeMode:`onEnd`,columnResizeDirection:`ltr`,onColumnSizingChange:Ay(`columnSizing`,e),onColumnSizingInfoChange:Ay(`columnSizingInfo`,e)}),createColumn:(e,t)=>{e.getSize=()=>{let n=t.getState().columnSizing[e.id];return Math.min(Math.max(e.columnDef.minSize??cb.minSize,n??e.columnDef.size??cb.size),e.columnDef.maxSize??cb.maxSize)},e.getStart=Q(e=>[e,hb(t,e),t.getState().columnSizing],(t,n)=>n.slice(0,e.getIndex(t)).reduce((e,t)=>e+t.getSize(),0),$(t.options,`debugColumns`,`getStart`)),e.getAfter=Q(e=>[e,hb(t,e),t.getState().columnSizing],(t,n)=>n.slice(e.getIndex(t)+1).reduce((e,t)=>e+t.getSize(),0),$(t.options,`debugColumns`,`getAfter`)),e.resetSize=()=>{t.setColumnSizing(t=>{let{[e.id]:n,...r}=t;return r})},e.getCanResize=()=>(e.columnDef.enableResizing??!0)&&(t.options.enableColumnResizing??!0),e.getIsResizing=()=>t.getState().columnSizingInfo.isResizingColumn===e.id},createHeader:(e,t)=>{e.getSize=()=>{let t=0,n=e=>{e.subHeaders.length?e.subHeaders.forEach(n):t+=e.column.getSize()??0};return n(e),t},e.getStart=()=>{if(e.index>0){let t=e.headerGroup.headers[e.index-1];return t.getStart()+t.getSize()}return 0},e.getResizeHandler=n=>{let r=t.getColumn(e.column.id),i=r?.getCanResize();return a=>{if(!r||!i||(a.persist==null||a.persist(),pb(a)&&a.touches&&a.touches.length>1))return;let o=e.getSize(),s=e?e.getLeafHeaders().map(e=>[e.column.id,e.column.getSize()]):[[r.id,r.getSize()]],c=pb(a)?Math.round(a.touches[0].clientX):a.clientX,l={},u=(e,n)=>{typeof n==`number`&&(t.setColumnSizingInfo(e=>{let r=t.options.columnResizeDirection===`rtl`?-1:1,i=(n-(e?.startOffset??0))*r,a=Math.max(i/(e?.startSize??0),-.999999);return e.columnSizingStart.forEach(e=>{let[t,n]=e;l[t]=Math.round(Math.max(n+n*a,0)*100)/100}),{...e,deltaOffset:i,deltaPercentage:a}}),(t.options.columnResizeMode===`onChange`||e===`end`)&&t.setColumnSizing(e=>({...e,...l})))},d=e=>u(`move`,e),f=e=>{u(`end`,e),t.setColumnSizingInfo(e=>({...e,isResizingColumn:!1,startOffset:null,startSize:null,deltaOffset:null,deltaPercentage:null,columnSizingStart:[]}))},p=sb(n),m={moveHandler:e=>d(e.clientX),upHandler:e=>{p?.removeEventListener(`mousemove`,m.moveHandler),p?.removeEventListener(`mouseup`,m.upHandler),f(e.clientX)}},h={moveHandler:e=>(e.cancelable&&(e.preventDefault(),e.stopPropagation()),d(e.touches[0].clientX),!1),upHandler:e=>{p?.removeEventListener(`touchmove`,h.moveHandler),p?.removeEventListener(`touchend`,h.upHandler),e.cancelable&&(e.preventDefault(),e.stopPropagation()),f(e.touches[0]?.clientX)}},g=fb()?{passive:!1}:!1;pb(a)?(p?.addEventListener(`touchmove`,h.moveHandler,g),p?.addEventListener(`touchend`,h.upHandler,g)):(p?.addEventListener(`mousemove`,m.moveHandler,g),p?.addEventListener(`mouseup`,m.upHandler,g)),t.setColumnSizingInfo(e=>({...e,startOffset:c,startSize:o,deltaOffset:0,deltaPercentage:0,columnSizingStart:s,isResizingColumn:r.id}))}}},createTable:e=>{e.setColumnSizing=t=>e.options.onColumnSizingChange==null?void 0:e.options.onColumnSizingChange(t),e.setColumnSizingInfo=t=>e.options.onColumnSizingInfoChange==null?void 0:e.options.onColumnSizingInfoChange(t),e.resetColumnSizing=t=>{e.setColumnSizing(t?{}:e.initialState.columnSizing??{})},e.resetHeaderSizeInfo=t=>{e.setColumnSizingInfo(t?lb():e.initialState.columnSizingInfo??lb())},e.getTotalSize=()=>e.getHeaderGroups()[0]?.headers.reduce((e,t)=>e+t.getSize(),0)??0,e.getLeftTotalSize=()=>e.getLeftHeaderGroups()[0]?.headers.reduce((e,t)=>e+t.getSize(),0)??0,e.getCenterTotalSize=()=>e.getCenterHeaderGroups()[0]?.headers.reduce((e,t)=>e+t.getSize(),0)??0,e.getRightTotalSize=()=>e.getRightHeaderGroups()[0]?.headers.reduce((e,t)=>e+t.getSize(),0)??0}},db=null;function fb(){if(typeof db==`boolean`)return db;let e=!1;try{let t={get passive(){return e=!0,!1}},n=()=>{};window.addEventListener(`test`,n,t),window.removeEventListener(`test`,n)}catch{e=!1}return db=e,db}function pb(e){return e.type===`touchstart`}var mb={getInitialState:e=>({columnVisibility:{},...e}),getDefaultOptions:e=>({onColumnVisibilityChange:Ay(`columnVisibility`,e)}),createColumn:(e,t)=>{e.toggleVisibility=n=>{e.getCanHide()&&t.setColumnVisibility(t=>({...t,[e.id]:n??!e.getIsVisible()}))},e.getIsVisible=()=>{let n=e.columns;return(n.length?n.some(e=>e.getIsVisible()):t.getState().columnVisibility?.[e.id])??!0},e.getCanHide=()=>(e.columnDef.enableHiding??!0)&&(t.options.enableHiding??!0),e.getToggleVisibilityHandler=()=>t=>{e.toggleVisibility==null||e.toggleVisibility(t.target.checked)}},createRow:(e,t)=>{e._getAllVisibleCells=Q(()=>[e.getAllCells(),t.getState().columnVisibility],e=>e.filter(e=>e.column.getIsVisible()),$(t.options,`debugRows`,`_getAllVisibleCells`)),e.getVisibleCells=Q(()=>[e.getLeftVisibleCells(),e.getCenterVisibleCells(),e.getRightVisibleCells()],(e,t,n)=>[...e,...t,...n],$(t.options,`debugRows`,`getVisibleCells`))},createTable:e=>{let t=(t,n)=>Q(()=>[n(),n().filter(e=>e.getIsVisible()).map(e=>e.id).join(`_`)],e=>e.filter(e=>e.getIsVisible==null?void 0:e.getIsVisible()),$(e.options,`debugColumns`,t));e.getVisibleFlatColumns=t(`getVisibleFlatColumns`,()=>e.getAllFlatColumns()),e.getVisibleLeafColumns=t(`getVisibleLeafColumns`,()=>e.getAllLeafColumns()),e.getLeftVisibleLeafColumns=t(`getLeftVisibleLeafColumns`,()=>e.getLeftLeafColumns()),e.getRightVisibleLeafColumns=t(`getRightVisibleLeafColumns`,()=>e.getRightLeafColumns()),e.getCenterVisibleLeafColumns=t(`getCenterVisibleLeafColumns`,()=>e.getCenterLeafColumns()),e.setColumnVisibility=t=>e.options.onColumnVisibilityChange==null?void 0:e.options.onColumnVisibilityChange(t),e.resetColumnVisibility=t=>{e.setColumnVisibility(t?{}:e.initialState.columnVisibility??{})},e.toggleAllColumnsVisible=t=>{t??=!e.getIsAllColumnsVisible(),e.setColumnVisibility(e.getAllLeafColumns().reduce((e,n)=>({...e,[n.id]:t||!(n.getCanHide!=null&&n.getCanHide())}),{}))},e.getIsAllColumnsVisible=()=>!e.getAllLeafColumns().some(e=>!(e.getIsVisible!=null&&e.getIsVisible())),e.getIsSomeColumnsVisible=()=>e.getAllLeafColumns().some(e=>e.getIsVisible==null?void 0:e.getIsVisible()),e.getToggleAllColumnsVisibilityHandler=()=>t=>{e.toggleAllColumnsVisible(t.target?.checked)}}};function hb(e,t){return t?t===`center`?e.getCenterVisibleLeafColumns():t===`left`?e.getLeftVisibleLeafColumns():e.getRightVisibleLeafColumns():e.getVisibleLeafColumns()}var gb={createTable:e=>{e._getGlobalFacetedRowModel=e.options.getFacetedRowModel&&e.options.getFacetedRowModel(e,`__global__`),e.getGlobalFacetedRowModel=()=>e.options.manualFiltering||!e._getGlobalFacetedRowModel?e.getPreFilteredRowModel():e._getGlobalFacetedRowModel(),e._getGlobalFacetedUniqueValues=e.options.getFacetedUniqueValues&&e.options.getFacetedUniqueValues(e,`__global__`),e.getGlobalFacetedUniqueValues=()=>e._getGlobalFacetedUniqueValues?e._getGlobalFacetedUniqueValues():new Map,e._getGlobalFacetedMinMaxValues=e.options.getFacetedMinMaxValues&&e.options.getFacetedMinMaxValues(e,`__global__`),e.getGlobalFacetedMinMaxValues=()=>{if(e._getGlobalFacetedMinMaxValues)return e._getGlobalFacetedMinMaxValues()}}},_b={getInitialState:e=>({globalFilter:void 0,...e}),getDefaultOptions:e=>({onGlobalFilterChange:Ay(`globalFilter`,e),globalFilterFn:`auto`,getColumnCanGlobalFilter:t=>{var n;let r=(n=e.getCoreRowModel().flatRows[0])==null||(n=n._getAllCellsByColumnId()[t.id])==null?void 0:n.getValue();return typeof r==`string`||typeof r==`number`}}),createColumn:(e,t)=>{e.getCanGlobalFilter=()=>(e.columnDef.enableGlobalFilter??!0)&&(t.options.enableGlobalFilter??!0)&&(t.options.enableFilters??!0)&&((t.options.getColumnCanGlobalFilter==null?void 0:t.options.getColumnCanGlobalFilter(e))??!0)&&!!e.accessorFn},createTable:e=>{e.getGlobalAutoFilterFn=()=>Zy.includesString,e.getGlobalFilterFn=()=>{let{globalFilterFn:t}=e.options;return jy(t)?t:t===`auto`?e.getGlobalAutoFilterFn():e.options.filterFns?.[t]??Zy[t]},e.setGlobalFilter=t=>{e.options.onGlobalFilterChange==null||e.options.onGlobalFilterChange(t)},e.resetGlobalFilter=t=>{e.setGlobalFilter(t?void 0:e.initialState.globalFilter)}}},vb={getInitialState:e=>({expanded:{},...e}),getDefaultOptions:e=>({onExpandedChange:Ay(`expanded`,e),paginateExpandedRows:!0}),createTable:e=>{let t=!1,n=!1;e._autoResetExpanded=()=>{if(!t){e._queue(()=>{t=!0});return}if(e.options.autoResetAll??e.options.autoResetExpanded??!e.options.manualExpanding){if(n)return;n=!0,e._queue(()=>{e.resetExpanded(),n=!1})}},e.setExpanded=t=>e.options.onExpandedChange==null?void 0:e.options.onExpandedChange(t),e.toggleAllRowsExpanded=t=>{t??!e.getIsAllRowsExpanded()?e.setExpanded(!0):e.setExpanded({})},e.resetExpanded=t=>{e.setExpanded(t?{}:e.initialState?.expanded??{})},e.getCanSomeRowsExpand=()=>e.getPrePaginationRowModel().flatRows.some(e=>e.getCanExpand()),e.getToggleAllRowsExpandedHandler=()=>t=>{t.persist==null||t.persist(),e.toggleAllRowsExpanded()},e.getIsSomeRowsExpanded=()=>{let t=e.getState().expanded;return t===!0||Object.values(t).some(Boolean)},e.getIsAllRowsExpanded=()=>{let t=e.getState().expanded;return typeof t==`boolean`?t===!0:!(!Object.keys(t).length||e.getRowModel().flatRows.some(e=>!e.getIsExpanded()))},e.getExpandedDepth=()=>{let t=0;return(e.getState().expanded===!0?Object.keys(e.getRowModel().rowsById):Object.keys(e.getState().expanded)).forEach(e=>{let n=e.split(`.`);t=Math.max(t,n.length)}),t},e.getPreExpandedRowModel=()=>e.getSortedRowModel(),e.getExpandedRowModel=()=>(!e._getExpandedRowModel&&e.options.getExpandedRowModel&&(e._getExpandedRowModel=e.options.getExpandedRowModel(e)),e.options.manualExpanding||!e._getExpandedRowModel?e.getPreExpandedRowModel():e._getExpandedRowModel())},createRow:(e,t)=>{e.toggleExpanded=n=>{t.setExpanded(r=>{let i=r===!0?!0:!!(r!=null&&r[e.id]),a={};if(r===!0?Object.keys(t.getRowModel().rowsById).forEach(e=>{a[e]=!0}):a=r,n??=!i,!i&&n)return{...a,[e.id]:!0};if(i&&!n){let{[e.id]:t,...n}=a;return n}return r})},e.getIsExpanded=()=>{let n=t.getState().expanded;return!!((t.options.getIsRowExpanded==null?void 0:t.options.getIsRowExpanded(e))??(n===!0||n?.[e.id]))},e.getCanExpand=()=>{var n;return(t.options.getRowCanExpand==null?void 0:t.options.getRowCanExpand(e))??((t.options.enableExpanding??!0)&&!!((n=e.subRows)!=null&&n.length))},e.getIsAllParentsExpanded=()=>{let n=!0,r=e;for(;n&&r.parentId;)r=t.getRow(r.parentId,!0),n=r.getIsExpanded();return n},e.getToggleExpandedHandler=()=>{let t=e.getCanExpand();return()=>{t&&e.toggleExpanded()}}}},yb=0,bb=10,xb=()=>({pageIndex:yb,pageSize:bb}),Sb={getInitialState:e=>({...e,pagination:{...xb(),...e?.pagination}}),getDefaultOptions:e=>({onPaginationChange:Ay(`pagination`,e)}),createTable:e=>{let t=!1,n=!1;e._autoResetPageIndex=()=>{if(!t){e._queue(()=>{t=!0});return}if(e.options.autoResetAll??e.options.autoResetPageIndex??!e.options.manualPagination){if(n)return;n=!0,e._queue(()=>{e.resetPageIndex(),n=!1})}},e.setPagination=t=>e.options.onPaginationChange==null?void 0:e.options.onPaginationChange(e=>ky(t,e)),e.resetPagination=t=>{e.setPagination(t?xb():e.initialState.pagination??xb())},e.setPageIndex=t=>{e.setPagination(n=>{let r=ky(t,n.pageIndex),i=e.options.pageCount===void 0||e.options.pageCount===-1?2**53-1:e.options.pageCount-1;return r=Math.max(0,Math.min(r,i)),{...n,pageIndex:r}})},e.resetPageIndex=t=>{var n;e.setPageIndex(t?yb:((n=e.initialState)==null||(n=n.pagination)==null?void 0:n.pageIndex)??yb)},e.resetPageSize=t=>{var n;e.setPageSize(t?bb:((n=e.initialState)==null||(n=n.pagination)==null?void 0:n.pageSize)??bb)},e.setPageSize=t=>{e.setPagination(e=>{let n=Math.max(1,ky(t,e.pageSize)),r=e.pageSize*e.pageIndex,i=Math.floor(r/n);return{...e,pageIndex:i,pageSize:n}})},e.setPageCount=t=>e.setPagination(n=>{let r=ky(t,e.options.pageCount??-1);return typeof r==`number`&&(r=Math.max(-1,r)),{...n,pageCount:r}}),e.getPageOptions=Q(()=>[e.getPageCount()],e=>{let t=[];return e&&e>0&&(t=[...Array(e)].fill(null).map((e,t)=>t)),t},$(e.options,`debugTable`,`getPageOptions`)),e.getCanPreviousPage=()=>e.getState().pagination.pageIndex>0,e.getCanNextPage=()=>{let{pageIndex:t}=e.getState().pagination,n=e.getPageCount();return n===-1?!0:n===0?!1:t<n-1},e.previousPage=()=>e.setPageIndex(e=>e-1),e.nextPage=()=>e.setPageIndex(e=>e+1),e.firstPage=()=>e.setPageIndex(0),e.lastPage=()=>e.setPageIndex(e.getPageCount()-1),e.getPrePaginationRowModel=()=>e.getExpandedRowModel(),e.getPaginationRowModel=()=>(!e._getPaginationRowModel&&e.options.getPaginationRowModel&&(e._getPaginationRowModel=e.options.getPaginationRowModel(e)),e.options.manualPagination||!e._getPaginationRowModel?e.getPrePaginationRowModel():e._getPaginationRowModel()),e.getPageCount=()=>e.options.pageCount??Math.ceil(e.getRowCount()/e.getState().pagination.pageSize),e.getRowCount=()=>e.options.rowCount??e.getPrePaginationRowModel().rows.length}},Cb=()=>({top:[],bottom:[]}),wb={getInitialState:e=>({rowPinning:Cb(),...e}),getDefaultOptions:e=>({onRowPinningChange:Ay(`rowPinning`,e)}),createRow:(e,t)=>{e.pin=(n,r,i)=>{let a=r?e.getLeafRows().map(e=>{let{id:t}=e;return t}):[],o=i?e.getParentRows().map(e=>{let{id:t}=e;return t}):[],s=new Set([...o,e.id,...a]);t.setRowPinning(e=>n===`bottom`?{top:(e?.top??[]).filter(e=>!(s!=null&&s.has(e))),bottom:[...(e?.bottom??[]).filter(e=>!(s!=null&&s.has(e))),...Array.from(s)]}:n===`top`?{top:[...(e?.top??[]).filter(e=>!(s!=null&&s.has(e))),...Array.from(s)],bottom:(e?.bottom??[]).filter(e=>!(s!=null&&s.has(e)))}:{top:(e?.top??[]).filter(e=>!(s!=null&&s.has(e))),bottom:(e?.bottom??[]).filter(e=>!(s!=null&&s.has(e)))})},e.getCanPin=()=>{let{enableRowPinning:n,enablePinning:r}=t.options;return typeof n==`function`?n(e):n??r??!0},e.getIsPinned=()=>{let n=[e.id],{top:r,bottom:i}=t.getState().rowPinning,a=n.some(e=>r?.includes(e)),o=n.some(e=>i?.includes(e));return a?`top`:o?`bottom`:!1},e.getPinnedIndex=()=>{let n=e.getIsPinned();return n?((n===`top`?t.getTopRows():t.getBottomRows())?.map(e=>{let{id:t}=e;return t}))?.indexOf(e.id)??-1:-1}},createTable:e=>{e.setRowPinning=t=>e.options.onRowPinningChange==null?void 0:e.options.onRowPinningChange(t),e.resetRowPinning=t=>e.setRowPinning(t?Cb():e.initialState?.rowPinning??Cb()),e.getIsSomeRowsPinned=t=>{let n=e.getState().rowPinning;return t?!!n[t]?.length:!!(n.top?.length||n.bottom?.length)},e._getPinnedRows=(t,n,r)=>(e.options.keepPinnedRows??!0?(n??[]).map(t=>{let n=e.getRow(t,!0);return n.getIsAllParentsExpanded()?n:null}):(n??[]).map(e=>t.find(t=>t.id===e))).filter(Boolean).map(e=>({...e,position:r})),e.getTopRows=Q(()=>[e.getRowModel().rows,e.getState().rowPinning.top],(t,n)=>e._getPinnedRows(t,n,`top`),$(e.options,`debugRows`,`getTopRows`)),e.getBottomRows=Q(()=>[e.getRowModel().rows,e.getState().rowPinning.bottom],(t,n)=>e._getPinnedRows(t,n,`bottom`),$(e.options,`debugRows`,`getBottomRows`)),e.getCenterRows=Q(()=>[e.getRowModel().rows,e.getState().rowPinning.top,e.getState().rowPinning.bottom],(e,t,n)=>{let r=new Set([...t??[],...n??[]]);return e.filter(e=>!r.has(e.id))},$(e.options,`debugRows`,`getCenterRows`))}},Tb={getInitialState:e=>({rowSelection:{},...e}),getDefaultOptions:e=>({onRowSelectionChange:Ay(`rowSelection`,e),enableRowSelection:!0,enableMultiRowSelection:!0,enableSubRowSelection:!0}),createTable:e=>{e.setRowSelection=t=>e.options.onRowSelectionChange==null?void 0:e.options.onRowSelectionChange(t),e.resetRowSelection=t=>e.setRowSelection(t?{}:e.initialState.rowSelection??{}),e.toggleAllRowsSelected=t=>{e.setRowSelection(n=>{t=t===void 0?!e.getIsAllRowsSelected():t;let r={...n},i=e.getPreGroupedRowModel().flatRows;return t?i.forEach(e=>{e.getCanSelect()&&(r[e.id]=!0)}):i.forEach(e=>{delete r[e.id]}),r})},e.toggleAllPageRowsSelected=t=>e.setRowSelection(n=>{let r=t===void 0?!e.getIsAllPageRowsSelected():t,i={...n};return e.getRowModel().rows.forEach(t=>{Eb(i,t.id,r,!0,e)}),i}),e.getPreSelectedRowModel=()=>e.getCoreRowModel(),e.getSelectedRowModel=Q(()=>[e.getState().rowSelection,e.getCoreRowModel()],(t,n)=>Object.keys(t).length?Db(e,n):{rows:[],flatRows:[],rowsById:{}},$(e.options,`debugTable`,`getSelectedRowModel`)),e.getFilteredSelectedRowModel=Q(()=>[e.getState().rowSelection,e.getFilteredRowModel()],(t,n)=>Object.keys(t).length?Db(e,n):{rows:[],flatRows:[],rowsById:{}},$(e.options,`debugTable`,`getFilteredSelectedRowModel`)),e.getGroupedSelectedRowModel=Q(()=>[e.getState().rowSelection,e.getSortedRowModel()],(t,n)=>Object.keys(t).length?Db(e,n):{rows:[],flatRows:[],rowsById:{}},$(e.options,`debugTable`,`getGroupedSelectedRowModel`)),e.getIsAllRowsSelected=()=>{let t=e.getFilteredRowModel().flatRows,{rowSelection:n}=e.getState(),r=!!(t.length&&Object.keys(n).length);return r&&t.some(e=>e.getCanSelect()&&!n[e.id])&&(r=!1),r},e.getIsAllPageRowsSelected=()=>{let t=e.getPaginationRowModel().flatRows.filter(e=>e.getCanSelect()),{rowSelection:n}=e.getState(),r=!!t.length;return r&&t.some(e=>!n[e.id])&&(r=!1),r},e.getIsSomeRowsSelected=()=>{let t=Object.keys(e.getState().rowSelection??{}).length;return t>0&&t<e.getFilteredRowModel().flatRows.length},e.getIsSomePageRowsSelected=()=>{let t=e.getPaginationRowModel().flatRows;return e.getIsAllPageRowsSelected()?!1:t.filter(e=>e.getCanSelect()).some(e=>e.getIsSelected()||e.getIsSomeSelected())},e.getToggleAllRowsSelectedHandler=()=>t=>{e.toggleAllRowsSelected(t.target.checked)},e.getToggleAllPageRowsSelectedHandler=()=>t=>{e.toggleAllPageRowsSelected(t.target.checked)}},createRow:(e,t)=>{e.toggleSelected=(n,r)=>{let i=e.getIsSelected();t.setRowSelection(a=>{if(n=n===void 0?!i:n,e.getCanSelect()&&i===n)return a;let o={...a};return Eb(o,e.id,n,r?.selectChildren??!0,t),o})},e.getIsSelected=()=>{let{rowSelection:n}=t.getState();return Ob(e,n)},e.getIsSomeSelected=()=>{let{rowSelection:n}=t.getState();return kb(e,n)===`some`},e.getIsAllSubRowsSelected=()=>{let{rowSelection:n}=t.getState();return kb(e,n)===`all`},e.getCanSelect=()=>typeof t.options.enableRowSelection==`function`?t.options.enableRowSelection(e):t.options.enableRowSelection??!0,e.getCanSelectSubRows=()=>typeof t.options.enableSubRowSelection==`function`?t.options.enableSubRowSelection(e):t.options.enableSubRowSelection??!0,e.getCanMultiSelect=()=>typeof t.options.enableMultiRowSelection==`function`?t.options.enableMultiRowSelection(e):t.options.enableMultiRowSelection??!0,e.getToggleSelectedHandler=()=>{let t=e.getCanSelect();return n=>{t&&e.toggleSelected(n.target?.checked)}}}},Eb=(e,t,n,r,i)=>{var a;let o=i.getRow(t,!0);n?(o.getCanMultiSelect()||Object.keys(e).forEach(t=>delete e[t]),o.getCanSelect()&&(e[t]=!0)):delete e[t],r&&(a=o.subRows)!=null&&a.length&&o.getCanSelectSubRows()&&o.subRows.forEach(t=>Eb(e,t.id,n,r,i))};function Db(e,t){let n=e.getState().rowSelection,r=[],i={},a=function(e,t){return e.map(e=>{var t;let o=Ob(e,n);if(o&&(r.push(e),i[e.id]=e),(t=e.subRows)!=null&&t.length&&(e={...e,subRows:a(e.subRows)}),o)return e}).filter(Boolean)};return{rows:a(t.rows),flatRows:r,rowsById:i}}function Ob(e,t){return t[e.id]??!1}function kb(e,t,n){var r;if(!((r=e.subRows)!=null&&r.length))return!1;let i=!0,a=!1;return e.subRows.forEach(e=>{if(!(a&&!i)&&(e.getCanSelect()&&(Ob(e,t)?a=!0:i=!1),e.subRows&&e.subRows.length)){let n=kb(e,t);n===`all`?a=!0:(n===`some`&&(a=!0),i=!1)}}),i?`all`:a?`some`:!1}var Ab=/([0-9]+)/gm,jb=(e,t,n)=>zb(Rb(e.getValue(n)).toLowerCase(),Rb(t.getValue(n)).toLowerCase()),Mb=(e,t,n)=>zb(Rb(e.getValue(n)),Rb(t.getValue(n))),Nb=(e,t,n)=>Lb(Rb(e.getValue(n)).toLowerCase(),Rb(t.getValue(n)).toLowerCase()),Pb=(e,t,n)=>Lb(Rb(e.getValue(n)),Rb(t.getValue(n))),Fb=(e,t,n)=>{let r=e.getValue(n),i=t.getValue(n);return r>i?1:r<i?-1:0},Ib=(e,t,n)=>Lb(e.getValue(n),t.getValue(n));function Lb(e,t){return e===t?0:e>t?1:-1}function Rb(e){return typeof e==`number`?isNaN(e)||e===1/0||e===-1/0?``:String(e):typeof e==`string`?e:``}function zb(e,t){let n=e.split(Ab).filter(Boolean),r=t.split(Ab).filter(Boolean);for(;n.length&&r.length;){let e=n.shift(),t=r.shift(),i=parseInt(e,10),a=parseInt(t,10),o=[i,a].sort();if(isNaN(o[0])){if(e>t)return 1;if(t>e)return-1;continue}if(isNaN(o[1]))return isNaN(i)?-1:1;if(i>a)return 1;if(a>i)return-1}return n.length-r.length}var Bb={alphanumeric:jb,alphanumericCaseSensitive:Mb,text:Nb,textCaseSensitive:Pb,datetime:Fb,basic:Ib},Vb=[Ry,mb,ib,ob,Vy,$y,gb,_b,{getInitialState:e=>({sorting:[],...e}),getDefaultColumnDef:()=>({sortingFn:`auto`,sortUndefined:1}),getDefaultOptions:e=>({onSortingChange:Ay(`sorting`,e),isMultiSortEvent:e=>e.shiftKey}),createColumn:(e,t)=>{e.getAutoSortingFn=()=>{let n=t.getFilteredRowModel().flatRows.slice(10),r=!1;for(let t of n){let n=t?.getValue(e.id);if(Object.prototype.toString.call(n)===`[object Date]`)return Bb.datetime;if(typeof n==`string`&&(r=!0,n.split(Ab).length>1))return Bb.alphanumeric}return r?Bb.text:Bb.basic},e.getAutoSortDir=()=>typeof t.getFilteredRowModel().flatRows[0]?.getValue(e.id)==`string`?`asc`:`desc`,e.getSortingFn=()=>{if(!e)throw Error();return jy(e.columnDef.sortingFn)?e.columnDef.sortingFn:e.columnDef.sortingFn===`auto`?e.getAutoSortingFn():t.options.sortingFns?.[e.columnDef.sortingFn]??Bb[e.columnDef.sortingFn]},e.toggleSorting=(n,r)=>{let i=e.getNextSortingOrder(),a=n!=null;t.setSorting(o=>{let s=o?.find(t=>t.id===e.id),c=o?.findIndex(t=>t.id===e.id),l=[],u,d=a?n:i===`desc`;return u=o!=null&&o.length&&e.getCanMultiSort()&&r?s?`toggle`:`add`:o!=null&&o.length&&c!==o.length-1?`replace`:s?`toggle`:`replace`,u===`toggle`&&(a||i||(u=`remove`)),u===`add`?(l=[...o,{id:e.id,desc:d}],l.splice(0,l.length-(t.options.maxMultiSortColCount??2**53-1))):l=u===`toggle`?o.map(t=>t.id===e.id?{...t,desc:d}:t):u===`remove`?o.filter(t=>t.id!==e.id):[{id:e.id,desc:d}],l})},e.getFirstSortDir=()=>e.columnDef.sortDescFirst??t.options.sortDescFirst??e.getAutoSortDir()===`desc`?`desc`:`asc`,e.getNextSortingOrder=n=>{let r=e.getFirstSortDir(),i=e.getIsSorted();return i?i!==r&&(t.options.enableSortingRemoval??!0)&&(!n||(t.options.enableMultiRemove??!0))?!1:i===`desc`?`asc`:`desc`:r},e.getCanSort=()=>(e.columnDef.enableSorting??!0)&&(t.options.enableSorting??!0)&&!!e.accessorFn,e.getCanMultiSort=()=>e.columnDef.enableMultiSort??t.options.enableMultiSort??!!e.accessorFn,e.getIsSorted=()=>{let n=t.getState().sorting?.find(t=>t.id===e.id);return n?n.desc?`desc`:`asc`:!1},e.getSortIndex=()=>t.getState().sorting?.findIndex(t=>t.id===e.id)??-1,e.clearSorting=()=>{t.setSorting(t=>t!=null&&t.length?t.filter(t=>t.id!==e.id):[])},e.getToggleSortingHandler=()=>{let n=e.getCanSort();return r=>{n&&(r.persist==null||r.persist(),e.toggleSorting==null||e.toggleSorting(void 0,e.getCanMultiSort()?t.options.isMultiSortEvent==null?void 0:t.options.isMultiSortEvent(r):!1))}}},createTable:e=>{e.setSorting=t=>e.options.onSortingChange==null?void 0:e.options.onSortingChange(t),e.resetSorting=t=>{e.setSorting(t?[]:e.initialState?.sorting??[])},e.getPreSortedRowModel=()=>e.getGroupedRowModel(),e.getSortedRowModel=()=>(!e._getSortedRowModel&&e.options.getSortedRowModel&&(e._getSortedRowModel=e.options.getSortedRowModel(e)),e.options.manualSorting||!e._getSortedRowModel?e.getPreSortedRowModel():e._getSortedRowModel())}},nb,vb,Sb,wb,Tb,ub];function Hb(e){let t=[...Vb,...e._features??[]],n={_features:t},r=n._features.reduce((e,t)=>Object.assign(e,t.getDefaultOptions==null?void 0:t.getDefaultOptions(n)),{}),i=e=>n.options.mergeOptions?n.options.mergeOptions(r,e):{...r,...e},a={...e.initialState??{}};n._features.forEach(e=>{a=(e.getInitialState==null?void 0:e.getInitialState(a))??a});let o=[],s=!1,c={_features:t,options:{...r,...e},initialState:a,_queue:e=>{o.push(e),s||(s=!0,Promise.resolve().then(()=>{for(;o.length;)o.shift()();s=!1}).catch(e=>setTimeout(()=>{throw e})))},reset:()=>{n.setState(n.initialState)},setOptions:e=>{n.options=i(ky(e,n.options))},getState:()=>n.options.state,setState:e=>{n.options.onStateChange==null||n.options.onStateChange(e)},_getRowId:(e,t,r)=>(n.options.getRowId==null?void 0:n.options.getRowId(e,t,r))??`${r?[r.id,t].join(`.`):t}`,getCoreRowModel:()=>(n._getCoreRowModel||=n.options.getCoreRowModel(n),n._getCoreRowModel()),getRowModel:()=>n.getPaginationRowModel(),getRow:(e,t)=>{let r=(t?n.getPrePaginationRowModel():n.getRowModel()).rowsById[e];if(!r&&(r=n.getCoreRowModel().rowsById[e],!r))throw Error();return r},_getDefaultColumnDef:Q(()=>[n.options.defaultColumn],e=>(e??={},{header:e=>{let t=e.header.column.columnDef;return t.accessorKey?t.accessorKey:t.accessorFn?t.id:null},cell:e=>{var t;return((t=e.renderValue())==null||t.toString==null?void 0:t.toString())??null},...n._features.reduce((e,t)=>Object.assign(e,t.getDefaultColumnDef==null?void 0:t.getDefaultColumnDef()),{}),...e}),$(e,`debugColumns`,`_getDefaultColumnDef`)),_getColumnDefs:()=>n.options.columns,getAllColumns:Q(()=>[n._getColumnDefs()],e=>{let t=function(e,r,i){return i===void 0&&(i=0),e.map(e=>{let a=Fy(n,e,i,r),o=e;return a.columns=o.columns?t(o.columns,a,i+1):[],a})};return t(e)},$(e,`debugColumns`,`getAllColumns`)),getAllFlatColumns:Q(()=>[n.getAllColumns()],e=>e.flatMap(e=>e.getFlatColumns()),$(e,`debugColumns`,`getAllFlatColumns`)),_getAllFlatColumnsById:Q(()=>[n.getAllFlatColumns()],e=>e.reduce((e,t)=>(e[t.id]=t,e),{}),$(e,`debugColumns`,`getAllFlatColumnsById`)),getAllLeafColumns:Q(()=>[n.getAllColumns(),n._getOrderColumnsFn()],(e,t)=>t(e.flatMap(e=>e.getLeafColumns())),$(e,`debugColumns`,`getAllLeafColumns`)),getColumn:e=>n._getAllFlatColumnsById()[e]};Object.assign(n,c);for(let e=0;e<n._features.length;e++){let t=n._features[e];t==null||t.createTable==null||t.createTable(n)}return n}function Ub(){return e=>Q(()=>[e.options.data],t=>{let n={rows:[],flatRows:[],rowsById:{}},r=function(t,i,a){i===void 0&&(i=0);let o=[];for(let c=0;c<t.length;c++){let l=By(e,e._getRowId(t[c],c,a),t[c],c,i,void 0,a?.id);if(n.flatRows.push(l),n.rowsById[l.id]=l,o.push(l),e.options.getSubRows){var s;l.originalSubRows=e.options.getSubRows(t[c],c),(s=l.originalSubRows)!=null&&s.length&&(l.subRows=r(l.originalSubRows,i+1,l))}}return o};return n.rows=r(t),n},$(e.options,`debugTable`,`getRowModel`,()=>e._autoResetPageIndex()))}function Wb(){return!0}var Gb=Symbol(`merge-proxy`),Kb={get(e,t,n){return t===Gb?n:e.get(t)},has(e,t){return e.has(t)},set:Wb,deleteProperty:Wb,getOwnPropertyDescriptor(e,t){return{configurable:!0,enumerable:!0,get(){return e.get(t)},set:Wb,deleteProperty:Wb}},ownKeys(e){return e.keys()}};function qb(e){return`value`in e?e.value:e}function Jb(){var e=[...arguments];return new Proxy({get(t){for(let n=e.length-1;n>=0;n--){let r=qb(e[n])[t];if(r!==void 0)return r}},has(t){for(let n=e.length-1;n>=0;n--)if(t in qb(e[n]))return!0;return!1},keys(){let t=[];for(let n=0;n<e.length;n++)t.push(...Object.keys(qb(e[n])));return[...Array.from(new Set(t))]}},Kb)}var Yb=F({props:[`render`,`props`],setup:e=>()=>typeof e.render==`function`||typeof e.render==`object`?K(e.render,e.props):e.render});function Xb(e){return Jb(e,{data:N(e.data)})}function Zb(e){let t=un(e.data),n=Hb(Jb({state:{},onStateChange:()=>{},renderFallbackValue:null,mergeOptions(e,n){return t?{...e,...n}:Jb(e,n)}},t?Xb(e):e));if(t){let t=dn(e.data);L(t,()=>{n.setState(e=>({...e,data:t.value}))},{immediate:!0})}let r=M(n.initialState);return Ua(()=>{n.setOptions(n=>{let i=new Proxy({},{get:(e,t)=>r.value[t]});return Jb(n,t?Xb(e):e,{state:Jb(i,e.state??{}),onStateChange:t=>{t instanceof Function?r.value=t(r.value):r.value=t,e.onStateChange==null||e.onStateChange(t)}})})}),n}var Qb={"data-slot":`table-container`,class:`relative w-full overflow-auto`},$b=F({__name:`Table`,props:{class:{}},setup(e){let t=e;return(e,n)=>(z(),B(`div`,Qb,[H(`table`,{"data-slot":`table`,class:A(N(Z)(`w-full caption-bottom text-sm`,t.class))},[I(e.$slots,`default`)],2)]))}}),ex=F({__name:`TableBody`,props:{class:{}},setup(e){let t=e;return(e,n)=>(z(),B(`tbody`,{"data-slot":`table-body`,class:A(N(Z)(`[&_tr:last-child]:border-0`,t.class))},[I(e.$slots,`default`)],2))}}),tx=F({__name:`TableCell`,props:{class:{}},setup(e){let t=e;return(e,n)=>(z(),B(`td`,{"data-slot":`table-cell`,class:A(N(Z)(`p-2 align-middle whitespace-nowrap [&:has([role=checkbox])]:pr-0 [&>[role=checkbox]]:translate-y-[2px]`,t.class))},[I(e.$slots,`default`)],2))}}),nx=F({__name:`TableRow`,props:{class:{}},setup(e){let t=e;return(e,n)=>(z(),B(`tr`,{"data-slot":`table-row`,class:A(N(Z)(`hover:bg-muted/50 data-[state=selected]:bg-muted border-b transition-colors`,t.class))},[I(e.$slots,`default`)],2))}}),rx=F({__name:`TableHead`,props:{class:{}},setup(e){let t=e;return(e,n)=>(z(),B(`th`,{"data-slot":`table-head`,class:A(N(Z)(`text-foreground h-10 px-2 text-left align-middle font-medium whitespace-nowrap [&:has([role=checkbox])]:pr-0 [&>[role=checkbox]]:translate-y-[2px]`,t.class))},[I(e.$slots,`default`)],2))}}),ix=F({__name:`TableHeader`,props:{class:{}},setup(e){let t=e;return(e,n)=>(z(),B(`thead`,{"data-slot":`table-header`,class:A(N(Z)(`[&_tr]:border-b`,t.class))},[I(e.$slots,`default`)],2))}}),ax={class:`border rounded-md`},ox=F({__name:`AppFileDataTable`,props:{data:{},rootpath:{},refreshEntryInfo:{type:Function}},emits:[`update:data`,`update:rootpath`],setup(e,{emit:t}){let n=e,r=t;function i(e,t){if(t===``||t===void 0||t===`/`){console.warn(`handleDelete `,t,` is missing`);return}let i=`/delete/${t.trim()?t.trim():``}`;fetch(i,{method:`DELETE`}).then(e=>e.json()).then(e=>{Tv.success(e.message)}).catch(e=>Tv.error(e)),r(`update:data`,n.data?.toSpliced(e,1))}function a(e){r(`update:rootpath`,e),n.refreshEntryInfo(e)}let o=[{id:`row-index`,header:``,cell:({row:e})=>K(`div`,{class:`text-center font-medium text-muted-foreground tabular-nums`},e.index)},{accessorKey:`ename`,header:`Name`,meta:{fixed:!0},cell:({row:e})=>{let t=e.getValue(`ename`),n=e.getValue(`etype`),r=e.getValue(`epath`);return n===`d`?K(`div`,{class:`text-left font-medium flex flex-row gap-1 hover:underline`,onClick:()=>{a(r)}},[K(H_,{class:`size-6`}),t]):n===`f`?K(`a`,{class:`text-left font-medium flex flex-row gap-1 hover:underline`,href:`/download/${r}`,download:t,onClick:e=>{e.stopPropagation()}},[K(z_,{class:`size-6`}),t]):n===`l`?K(`div`,{class:`text-left font-medium flex flex-row gap-1 hover:underline`,onClick:()=>{a(r)}},[K(V_,{class:`size-6`}),t]):K(`div`,{class:`text-left font-medium flex flex-row gap-1 `},t)}},{accessorKey:`etype`,header:()=>K(`div`,{class:`text-center font-medium`},`Type`),cell:({row:e})=>{if(e.getValue(`etype`)===`f`)return K(`div`,{class:`text-center font-medium`},`F`);if(e.getValue(`etype`)===`d`)return K(`div`,{class:`text-center font-medium`},`D`);if(e.getValue(`etype`)===`l`)return K(`div`,{class:`text-center font-medium`},`L`)}},{accessorKey:`esize`,header:()=>K(`div`,{class:`text-center font-medium`},`Size`),cell:({row:e})=>K(`div`,{class:`text-center font-medium`},e.getValue(`esize`))},{accessorKey:`emodified`,header:`Modified Time`,cell:({row:e})=>K(`div`,{class:`text-left font-medium`},b_(e.getValue(`emodified`)))},{accessorKey:`ecreated`,header:`Created Time`,cell:({row:e})=>K(`div`,{class:`text-left font-medium`},b_(e.getValue(`ecreated`)))},{accessorKey:`eaccessed`,header:`Accessed Time`,cell:({row:e})=>K(`div`,{class:`text-left font-medium`},b_(e.getValue(`eaccessed`)))},{accessorKey:`epath`,header:`Path`,cell:({row:e})=>K(`div`,{class:`text-left font-medium`},e.getValue(`epath`))},{accessorKey:``,header:`Actions`,cell:({row:e})=>{let t=e.getValue(`epath`),n=e.index;return K(sv,{variant:`destructive`,onClick:()=>i(n,t)},`Del`)}}],s=Zb({get data(){return n.data},get columns(){return o},getCoreRowModel:Ub()});return(e,t)=>(z(),B(`div`,ax,[U(N($b),null,{default:P(()=>[U(N(ix),null,{default:P(()=>[(z(!0),B(R,null,Yi(N(s).getHeaderGroups(),e=>(z(),V(N(nx),{key:e.id},{default:P(()=>[(z(!0),B(R,null,Yi(e.headers,e=>(z(),V(N(rx),{key:e.id,class:A([e.column.columnDef.meta?.fixed?`sticky left-0 z-20 bg-background hover:bg-gray-50`:``])},{default:P(()=>[e.isPlaceholder?gs(``,!0):(z(),V(N(Yb),{key:0,render:e.column.columnDef.header,props:e.getContext()},null,8,[`render`,`props`]))]),_:2},1032,[`class`]))),128))]),_:2},1024))),128))]),_:1}),U(N(ex),null,{default:P(()=>[N(s).getRowModel().rows?.length?(z(!0),B(R,{key:0},Yi(N(s).getRowModel().rows,e=>(z(),V(N(nx),{key:e.id,"data-state":e.getIsSelected()?`selected`:void 0},{default:P(()=>[(z(!0),B(R,null,Yi(e.getVisibleCells(),e=>(z(),V(N(tx),{key:e.id,class:A([e.column.columnDef.meta?.fixed?`sticky left-0 z-10 bg-background hover:bg-gray-50`:``])},{default:P(()=>[U(N(Yb),{render:e.column.columnDef.cell,props:e.getContext()},null,8,[`render`,`props`])]),_:2},1032,[`class`]))),128))]),_:2},1032,[`data-state`]))),128)):(z(),V(N(nx),{key:1},{default:P(()=>[U(N(tx),{colspan:o.length,class:`h-24 text-center`},{default:P(()=>[...t[0]||=[ms(` No results. `,-1)]]),_:1},8,[`colspan`])]),_:1}))]),_:1})]),_:1})]))}}),sx={class:`main`},cx={class:`maincontainer`},lx={class:`w-full h-20 flex items-center shadow-md rounded-md px-1`},ux={class:`w-full h-12 flex flex-row items-center shadow-md rounded-md px-1`},dx={class:`flex-2`},fx={class:`flex-1 flex flex-row justify-end gap-3`},px={class:`w-full h-[calc(100vh-5rem-3rem)]`};eu(((e,t)=>{let n=e.__vccOpts||e;for(let[e,r]of t)n[e]=r;return n})(F({__name:`App`,setup(e){let t=M(``),n=M([]);function r(e){console.log(e);let r=`/info/${t.value?.trim()?t.value.trim()+`/`:``}`;fetch(r,{method:`GET`}).then(e=>e.json()).then(e=>{n.value=e.data.map((e,t)=>({id:t+1,ename:e.ename,eaccessed:e.eaccessed,ecreated:e.ecreated,emodified:e.emodified,epath:e.epath,esize:y_(e.esize),eppath:e.eppath,etype:e.etype}))}).catch(e=>{Tv.error(e.message)}),console.log(`success refresh entries info`)}return L(()=>t,e=>{r(e.value)},{immediate:!0}),(e,i)=>(z(),B(`div`,sx,[U(N(gy),{position:`top-right`,richColors:``}),H(`div`,cx,[H(`div`,lx,[U(J_)]),H(`div`,ux,[H(`div`,dx,[U(ov,{rootpath:t.value,"onUpdate:rootpath":i[0]||=e=>t.value=e,refreshEntryInfo:r},null,8,[`rootpath`])]),H(`div`,fx,[U(yy,{rootpath:t.value,"onUpdate:rootpath":i[1]||=e=>t.value=e,refreshEntryInfo:r},null,8,[`rootpath`]),U(Oy,{rootpath:t.value,"onUpdate:rootpath":i[2]||=e=>t.value=e,refreshEntryInfo:r},null,8,[`rootpath`])])]),H(`div`,px,[U(ox,{data:n.value,"onUpdate:data":i[3]||=e=>n.value=e,rootpath:t.value,"onUpdate:rootpath":i[4]||=e=>t.value=e,refreshEntryInfo:r},null,8,[`data`,`rootpath`])])])]))}}),[[`__scopeId`,`data-v-6fd9fa6b`]])).mount(`#app`);
\ No newline at end of file
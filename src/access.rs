@@ -0,0 +1,265 @@
+use crate::auth::CurrentUser;
+use crate::error::AppError;
+use globset::{Glob, GlobBuilder, GlobMatcher};
+use std::path::{Component, Path};
+use std::str::FromStr;
+
+/// 受访问规则控制的操作
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Op {
+    List,
+    Download,
+    Upload,
+    Rename,
+    Create,
+    Delete,
+}
+
+impl std::fmt::Display for Op {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Op::List => write!(f, "list"),
+            Op::Download => write!(f, "download"),
+            Op::Upload => write!(f, "upload"),
+            Op::Rename => write!(f, "rename"),
+            Op::Create => write!(f, "create"),
+            Op::Delete => write!(f, "delete"),
+        }
+    }
+}
+
+/// 一条按路径匹配的访问规则，格式为 `<glob>=<policy>[,<policy>...]`，例如
+/// `/releases/**=read-only`、`/incoming/**=no-delete,no-rename`、`/private/**=private`。
+///
+/// glob 以服务根目录为 `/`，`*` 不跨越目录，以 `/**` 结尾的规则同时匹配目录本身。
+/// 一个路径匹配到的所有规则会叠加生效，对所有用户（包括 admin）都适用。
+#[derive(Debug, Clone)]
+pub(crate) struct AccessRule {
+    spec: String,
+    pattern: String,
+    matchers: Vec<GlobMatcher>,
+    denied: Vec<Op>,
+    private: bool,
+}
+
+impl FromStr for AccessRule {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (pattern, policies) = s
+            .rsplit_once('=')
+            .ok_or_else(|| format!("invalid rule {}, expect <glob>=<policy>", s))?;
+        let pattern = if pattern.starts_with('/') {
+            pattern.to_string()
+        } else {
+            format!("/{}", pattern)
+        };
+        // 匹配前请求路径里的 `.` 和 `..` 已经按字面处理掉了，规则里带这些片段永远不会匹配
+        if pattern.split('/').any(|p| p == "." || p == "..") {
+            return Err(format!("invalid rule {}, globs can not contain . or .. segments", s));
+        }
+
+        let mut globs = vec![pattern.clone()];
+        if let Some(base) = pattern.strip_suffix("/**") {
+            globs.push(if base.is_empty() { "/".to_string() } else { base.to_string() });
+        }
+        let matchers = globs
+            .iter()
+            .map(|g| {
+                GlobBuilder::new(g)
+                    .literal_separator(true)
+                    .build()
+                    .map(|g: Glob| g.compile_matcher())
+                    .map_err(|e| format!("invalid glob {}: {}", g, e))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut denied = vec![];
+        let mut private = false;
+        for policy in policies.split(',').map(str::trim) {
+            match policy {
+                "read-only" => denied.extend([Op::Upload, Op::Rename, Op::Create, Op::Delete]),
+                "deny" => denied.extend([Op::List, Op::Download, Op::Upload, Op::Rename, Op::Create, Op::Delete]),
+                "private" => private = true,
+                "no-list" => denied.push(Op::List),
+                "no-download" => denied.push(Op::Download),
+                "no-upload" => denied.push(Op::Upload),
+                "no-rename" => denied.push(Op::Rename),
+                "no-create" => denied.push(Op::Create),
+                "no-delete" => denied.push(Op::Delete),
+                _ => {
+                    return Err(format!(
+                        "unknown policy {}, expect read-only, deny, private or no-<list|download|upload|rename|create|delete>",
+                        policy
+                    ));
+                }
+            }
+        }
+
+        Ok(AccessRule {
+            spec: s.to_string(),
+            pattern,
+            matchers,
+            denied,
+            private,
+        })
+    }
+}
+
+// 输出原始的规则写法，用于打印配置
+impl std::fmt::Display for AccessRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.spec)
+    }
+}
+
+impl AccessRule {
+    fn matches(&self, rpath: &str) -> bool {
+        self.matchers.iter().any(|m| m.is_match(rpath))
+    }
+}
+
+/// `private` 规则只对未登录的请求隐藏路径，没有配置用户时所有请求都未登录，这样的规则会对所有人隐藏，按配置错误处理
+pub(crate) fn validate_rules(rules: &[AccessRule], has_users: bool) -> Result<(), String> {
+    match rules.iter().find(|r| r.private) {
+        Some(rule) if !has_users => Err(format!("rule {} is private, which requires users to be configured", rule)),
+        _ => Ok(()),
+    }
+}
+
+/// 把根目录下的绝对路径转换为规则使用的 `/a/b` 形式
+pub(crate) fn rule_path(root: &Path, a_path: &Path) -> String {
+    let rel = a_path.strip_prefix(root).unwrap_or(a_path);
+    // 按字面处理 `.` 和 `..`，避免 `/public/../private` 绕过规则
+    let mut parts = vec![];
+    for c in rel.components() {
+        match c {
+            Component::Normal(p) => parts.push(p.to_string_lossy().to_string()),
+            Component::ParentDir => {
+                parts.pop();
+            }
+            _ => {}
+        }
+    }
+    format!("/{}", parts.join("/"))
+}
+
+/// `private` 的路径只对已登录用户可见
+pub(crate) fn is_hidden(rules: &[AccessRule], user: &CurrentUser, rpath: &str) -> bool {
+    user.name.is_none() && rules.iter().any(|r| r.private && r.matches(rpath))
+}
+
+/// 检查规则是否允许在该路径上执行操作，隐藏的路径按不存在处理
+pub(crate) fn check(rules: &[AccessRule], user: &CurrentUser, rpath: &str, op: Op) -> Result<(), AppError> {
+    if is_hidden(rules, user, rpath) {
+        return Err(AppError::NotFound(format!("{} not found", rpath)));
+    }
+    if let Some(rule) = rules.iter().find(|r| r.denied.contains(&op) && r.matches(rpath)) {
+        return Err(AppError::Forbidden(format!("{} is not allowed on {} by rule {}", op, rpath, &rule.pattern)));
+    }
+    Ok(())
+}
+
+/// 只判断是否允许，用于列表过滤和打包下载时跳过条目
+pub(crate) fn allows(rules: &[AccessRule], user: &CurrentUser, rpath: &str, op: Op) -> bool {
+    !is_hidden(rules, user, rpath) && !rules.iter().any(|r| r.denied.contains(&op) && r.matches(rpath))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::auth::Role;
+    use axum::http::StatusCode;
+
+    fn rules(specs: &[&str]) -> Vec<AccessRule> {
+        specs.iter().map(|s| s.parse().unwrap()).collect()
+    }
+
+    fn user(name: Option<&str>) -> CurrentUser {
+        CurrentUser {
+            name: name.map(str::to_string),
+            role: name.map(|_| Role::Admin),
+        }
+    }
+
+    #[test]
+    fn matches_globs() {
+        let cases = [
+            ("/releases/**", "/releases", true),
+            ("/releases/**", "/releases/a", true),
+            ("/releases/**", "/releases/a/b.zip", true),
+            ("/releases/**", "/releases-old", false),
+            ("/releases/**", "/other/releases", false),
+            ("/docs/*", "/docs/a", true),
+            ("/docs/*", "/docs/a/b", false),
+            ("/docs/*", "/docs", false),
+            ("/*.log", "/a.log", true),
+            ("/*.log", "/logs/a.log", false),
+            ("/**/*.log", "/logs/a.log", true),
+            ("logs/*.txt", "/logs/a.txt", true),
+            ("/**", "/", true),
+            ("/**", "/a/b", true),
+        ];
+        for (glob, rpath, expected) in cases {
+            let rule: AccessRule = format!("{}=deny", glob).parse().unwrap();
+            assert_eq!(rule.matches(rpath), expected, "{} {}", glob, rpath);
+        }
+    }
+
+    #[test]
+    fn parses_rules() {
+        let rule: AccessRule = "/incoming/**=no-delete, no-rename".parse().unwrap();
+        assert_eq!((rule.denied.as_slice(), rule.private), ([Op::Delete, Op::Rename].as_slice(), false));
+        assert_eq!(rule.to_string(), "/incoming/**=no-delete, no-rename");
+        for invalid in ["/a", "/a=unknown", "/a/[=deny", "/public/../private/**=deny", "../**=deny", "/a/./b=deny"] {
+            assert!(invalid.parse::<AccessRule>().is_err(), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn normalizes_request_paths() {
+        let root = Path::new("/srv");
+        let cases = [
+            ("/srv", "/"),
+            ("/srv/a/b", "/a/b"),
+            ("/srv/public/../private/x", "/private/x"),
+            ("/srv/./a/.", "/a"),
+            ("/srv/../../etc", "/etc"),
+        ];
+        for (a_path, expected) in cases {
+            assert_eq!(rule_path(root, Path::new(a_path)), expected, "{}", a_path);
+        }
+        // `..` 不能绕过规则
+        let rules = rules(&["/private/**=deny"]);
+        let rpath = rule_path(root, Path::new("/srv/public/../private/x"));
+        assert!(!allows(&rules, &user(None), &rpath, Op::Download));
+    }
+
+    #[test]
+    fn applies_policies() {
+        let rules = rules(&["/private/**=private", "/releases/**=read-only", "/releases/latest=no-download"]);
+        let (anonymous, alice) = (user(None), user(Some("alice")));
+        let status = |user: &CurrentUser, rpath: &str, op: Op| check(&rules, user, rpath, op).err().map(|e| e.status());
+
+        assert!(is_hidden(&rules, &anonymous, "/private") && is_hidden(&rules, &anonymous, "/private/a"));
+        assert!(!is_hidden(&rules, &alice, "/private/a") && !is_hidden(&rules, &anonymous, "/privately"));
+        // 隐藏的路径按不存在处理，规则禁止的操作返回 403，对 admin 也一样
+        assert_eq!(status(&anonymous, "/private/a", Op::Download), Some(StatusCode::NOT_FOUND));
+        assert_eq!(status(&alice, "/private/a", Op::Download), None);
+        assert_eq!(status(&alice, "/releases/a", Op::Delete), Some(StatusCode::FORBIDDEN));
+        assert_eq!(status(&alice, "/releases/a", Op::Download), None);
+        assert_eq!(status(&alice, "/releases/latest", Op::Download), Some(StatusCode::FORBIDDEN));
+        assert_eq!(status(&alice, "/releases/latest", Op::Upload), Some(StatusCode::FORBIDDEN));
+        assert!(!allows(&rules, &anonymous, "/private/a", Op::List));
+        assert!(allows(&rules, &alice, "/private/a", Op::Delete));
+    }
+
+    #[test]
+    fn private_requires_users() {
+        let private = rules(&["/private/**=private"]);
+        assert!(validate_rules(&private, false).is_err());
+        assert!(validate_rules(&private, true).is_ok());
+        assert!(validate_rules(&rules(&["/releases/**=read-only"]), false).is_ok());
+        assert!(validate_rules(&[], false).is_ok());
+    }
+}
@@ -0,0 +1,341 @@
+use axum::body::Body;
+use bytes::Bytes;
+use std::fs::{File, Metadata};
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+use walkdir::WalkDir;
+use zip::write::SimpleFileOptions;
+
+// 每次推送给客户端的数据块大小
+const CHUNK_SIZE: usize = 64 * 1024;
+// 无法读取的条目会被记录到归档末尾的这个文件里
+const ERRORS_ENTRY: &str = "ARCHIVE_ERRORS.txt";
+
+/// 目录打包下载支持的格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ArchiveFormat {
+    Zip,
+    Tar,
+    TarGz,
+    TarZst,
+}
+
+impl ArchiveFormat {
+    /// 解析 `?format=`，缺省为 zip
+    pub(crate) fn from_query(format: Option<&str>) -> Option<ArchiveFormat> {
+        match format.unwrap_or("zip") {
+            "zip" => Some(ArchiveFormat::Zip),
+            "tar" => Some(ArchiveFormat::Tar),
+            "tar.gz" | "tgz" => Some(ArchiveFormat::TarGz),
+            "tar.zst" | "tzst" => Some(ArchiveFormat::TarZst),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn extension(&self) -> &'static str {
+        match self {
+            ArchiveFormat::Zip => "zip",
+            ArchiveFormat::Tar => "tar",
+            ArchiveFormat::TarGz => "tar.gz",
+            ArchiveFormat::TarZst => "tar.zst",
+        }
+    }
+
+    pub(crate) fn content_type(&self) -> &'static str {
+        match self {
+            ArchiveFormat::Zip => "application/zip",
+            ArchiveFormat::Tar => "application/x-tar",
+            ArchiveFormat::TarGz => "application/gzip",
+            ArchiveFormat::TarZst => "application/zstd",
+        }
+    }
+}
+
+/// 打包时用来跳过条目的过滤器，返回 false 的文件或目录（连同其子项）不会出现在归档里
+pub(crate) type EntryFilter = Box<dyn Fn(&Path) -> bool + Send>;
+
+/// 边打包边发送：归档在阻塞线程中生成，通过 channel 流式写入响应体，不落盘
+pub(crate) fn stream_dir(dir: PathBuf, format: ArchiveFormat, filter: EntryFilter) -> Body {
+    let (tx, rx) = mpsc::channel::<io::Result<Bytes>>(8);
+    tokio::task::spawn_blocking(move || {
+        let writer = ChannelWriter::new(tx.clone());
+        match write_archive(&dir, format, &filter, writer) {
+            Ok(()) => tracing::info!(">>> archive {:?} as {} done", &dir, format.extension()),
+            Err(e) => {
+                // 中途出错时让响应体以错误结束，客户端会看到下载失败而不是一个残缺的归档
+                tracing::error!(">>> archive {:?} aborted: {}", &dir, e);
+                let _ = tx.blocking_send(Err(e));
+            }
+        }
+    });
+    Body::from_stream(ReceiverStream::new(rx))
+}
+
+fn write_archive(dir: &Path, format: ArchiveFormat, filter: &EntryFilter, writer: ChannelWriter) -> io::Result<()> {
+    match format {
+        ArchiveFormat::Zip => {
+            let mut sink = ZipSink(zip::ZipWriter::new_stream(writer));
+            walk_dir(dir, filter, &mut sink)?;
+            sink.0.finish().map_err(io::Error::other)?.into_inner().flush()
+        }
+        ArchiveFormat::Tar => {
+            let mut sink = TarSink(tar::Builder::new(writer));
+            walk_dir(dir, filter, &mut sink)?;
+            sink.0.into_inner()?.flush()
+        }
+        ArchiveFormat::TarGz => {
+            let encoder = flate2::write::GzEncoder::new(writer, flate2::Compression::default());
+            let mut sink = TarSink(tar::Builder::new(encoder));
+            walk_dir(dir, filter, &mut sink)?;
+            sink.0.into_inner()?.finish()?.flush()
+        }
+        ArchiveFormat::TarZst => {
+            let encoder = zstd::Encoder::new(writer, 0)?;
+            let mut sink = TarSink(tar::Builder::new(encoder));
+            walk_dir(dir, filter, &mut sink)?;
+            sink.0.into_inner()?.finish()?.flush()
+        }
+    }
+}
+
+fn walk_dir(dir: &Path, filter: &EntryFilter, sink: &mut dyn ArchiveSink) -> io::Result<()> {
+    // 归档内所有条目都放在以目录名命名的顶层文件夹下
+    let prefix = dir
+        .file_name()
+        .map_or_else(|| "archive".to_string(), |f| f.to_string_lossy().to_string());
+    let mut skipped = vec![];
+
+    let walker = WalkDir::new(dir)
+        .follow_links(false)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_entry(|e| e.depth() == 0 || filter(e.path()));
+    for entry in walker {
+        let entry = match entry {
+            Ok(entry) => entry,
+            Err(e) => {
+                let path = e.path().map_or_else(|| dir.to_path_buf(), |p| p.to_path_buf());
+                skipped.push(format!("{}: {}", entry_name(dir, &prefix, &path), e));
+                continue;
+            }
+        };
+        let name = entry_name(dir, &prefix, entry.path());
+        // 符号链接按目标处理，这里的 metadata 会跟随链接
+        let meta = match std::fs::metadata(entry.path()) {
+            Ok(m) => m,
+            Err(e) => {
+                skipped.push(format!("{}: {}", &name, e));
+                continue;
+            }
+        };
+
+        if meta.is_dir() {
+            if entry.path_is_symlink() {
+                skipped.push(format!("{}: symlink to directory not followed", &name));
+                continue;
+            }
+            sink.add_dir(&name, &EntryMeta::from(&meta))?;
+        } else if meta.is_file() {
+            match File::open(entry.path()) {
+                Ok(file) => {
+                    let mut reader = ExactReader { inner: file, remaining: meta.len() };
+                    sink.add_file(&name, &EntryMeta::from(&meta), &mut reader)?;
+                }
+                Err(e) => skipped.push(format!("{}: {}", &name, e)),
+            }
+        } else {
+            skipped.push(format!("{}: unsupported file type", &name));
+        }
+    }
+
+    if !skipped.is_empty() {
+        for s in &skipped {
+            tracing::warn!(">>> archive skip {}", s);
+        }
+        let report = skipped.join("\n") + "\n";
+        let meta = EntryMeta {
+            size: report.len() as u64,
+            modified: SystemTime::now(),
+            mode: 0o644,
+        };
+        sink.add_file(&format!("{}/{}", &prefix, errors_entry(dir)), &meta, &mut report.as_bytes())?;
+    }
+    Ok(())
+}
+
+// 目录里已经有同名文件时依次尝试 `ARCHIVE_ERRORS (1).txt`、`ARCHIVE_ERRORS (2).txt`，避免归档里出现重复条目
+fn errors_entry(dir: &Path) -> String {
+    let (stem, ext) = ERRORS_ENTRY.rsplit_once('.').unwrap_or((ERRORS_ENTRY, ""));
+    let mut name = ERRORS_ENTRY.to_string();
+    let mut n = 0;
+    while dir.join(&name).symlink_metadata().is_ok() {
+        n += 1;
+        name = format!("{} ({}).{}", stem, n, ext);
+    }
+    name
+}
+
+// 归档内的路径统一使用 `/` 分隔
+fn entry_name(dir: &Path, prefix: &str, path: &Path) -> String {
+    let rel = path.strip_prefix(dir).unwrap_or(path);
+    let mut name = prefix.to_string();
+    for c in rel.components() {
+        name.push('/');
+        name.push_str(&c.as_os_str().to_string_lossy());
+    }
+    name
+}
+
+struct EntryMeta {
+    size: u64,
+    modified: SystemTime,
+    mode: u32,
+}
+
+impl From<&Metadata> for EntryMeta {
+    fn from(meta: &Metadata) -> Self {
+        #[cfg(unix)]
+        let mode = std::os::unix::fs::PermissionsExt::mode(&meta.permissions()) & 0o7777;
+        #[cfg(not(unix))]
+        let mode = if meta.is_dir() { 0o755 } else { 0o644 };
+        EntryMeta {
+            size: meta.len(),
+            modified: meta.modified().unwrap_or(SystemTime::UNIX_EPOCH),
+            mode,
+        }
+    }
+}
+
+trait ArchiveSink {
+    fn add_dir(&mut self, name: &str, meta: &EntryMeta) -> io::Result<()>;
+    fn add_file(&mut self, name: &str, meta: &EntryMeta, reader: &mut dyn Read) -> io::Result<()>;
+}
+
+struct ZipSink<W: Write>(zip::ZipWriter<zip::write::StreamWriter<W>>);
+
+impl<W: Write> ZipSink<W> {
+    fn options(meta: &EntryMeta) -> SimpleFileOptions {
+        let modified = time::OffsetDateTime::from(meta.modified);
+        let modified = zip::DateTime::try_from(time::PrimitiveDateTime::new(modified.date(), modified.time()))
+            .unwrap_or_default();
+        SimpleFileOptions::default()
+            .compression_method(zip::CompressionMethod::Deflated)
+            .last_modified_time(modified)
+            .unix_permissions(meta.mode)
+            // 流式写入无法回填头部，超过 4G 的文件需要提前声明 zip64
+            .large_file(meta.size >= u32::MAX as u64)
+    }
+}
+
+impl<W: Write> ArchiveSink for ZipSink<W> {
+    fn add_dir(&mut self, name: &str, meta: &EntryMeta) -> io::Result<()> {
+        // 流式模式下 add_directory 不写数据描述符，部分解压工具会报错，这里用空文件条目代替
+        let options = ZipSink::<W>::options(meta).compression_method(zip::CompressionMethod::Stored);
+        self.0
+            .start_file(format!("{}/", name), options)
+            .map_err(io::Error::other)
+    }
+
+    fn add_file(&mut self, name: &str, meta: &EntryMeta, reader: &mut dyn Read) -> io::Result<()> {
+        self.0
+            .start_file(name, ZipSink::<W>::options(meta))
+            .map_err(io::Error::other)?;
+        io::copy(reader, &mut self.0)?;
+        Ok(())
+    }
+}
+
+struct TarSink<W: Write>(tar::Builder<W>);
+
+impl<W: Write> TarSink<W> {
+    fn header(meta: &EntryMeta, entry_type: tar::EntryType) -> tar::Header {
+        let mut header = tar::Header::new_gnu();
+        header.set_entry_type(entry_type);
+        header.set_size(if entry_type.is_dir() { 0 } else { meta.size });
+        header.set_mode(meta.mode);
+        header.set_mtime(
+            meta.modified
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .map_or(0, |d| d.as_secs()),
+        );
+        header
+    }
+}
+
+impl<W: Write> ArchiveSink for TarSink<W> {
+    fn add_dir(&mut self, name: &str, meta: &EntryMeta) -> io::Result<()> {
+        let mut header = TarSink::<W>::header(meta, tar::EntryType::Directory);
+        self.0.append_data(&mut header, format!("{}/", name), io::empty())
+    }
+
+    fn add_file(&mut self, name: &str, meta: &EntryMeta, reader: &mut dyn Read) -> io::Result<()> {
+        let mut header = TarSink::<W>::header(meta, tar::EntryType::Regular);
+        self.0.append_data(&mut header, name, reader)
+    }
+}
+
+// 保证读出的字节数与打包前 metadata 中的大小一致，文件在打包过程中被截断时直接报错
+struct ExactReader {
+    inner: File,
+    remaining: u64,
+}
+
+impl Read for ExactReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.remaining == 0 {
+            return Ok(0);
+        }
+        let max = buf.len().min(self.remaining.min(usize::MAX as u64) as usize);
+        let n = self.inner.read(&mut buf[..max])?;
+        if n == 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "file shrank while archiving",
+            ));
+        }
+        self.remaining -= n as u64;
+        Ok(n)
+    }
+}
+
+// 把同步的 Write 桥接到异步响应体
+struct ChannelWriter {
+    tx: mpsc::Sender<io::Result<Bytes>>,
+    buf: Vec<u8>,
+}
+
+impl ChannelWriter {
+    fn new(tx: mpsc::Sender<io::Result<Bytes>>) -> Self {
+        ChannelWriter {
+            tx,
+            buf: Vec::with_capacity(CHUNK_SIZE),
+        }
+    }
+
+    fn send_buf(&mut self) -> io::Result<()> {
+        if self.buf.is_empty() {
+            return Ok(());
+        }
+        let chunk = std::mem::replace(&mut self.buf, Vec::with_capacity(CHUNK_SIZE));
+        self.tx
+            .blocking_send(Ok(Bytes::from(chunk)))
+            .map_err(|_| io::Error::new(io::ErrorKind::BrokenPipe, "client disconnected"))
+    }
+}
+
+impl Write for ChannelWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.buf.extend_from_slice(buf);
+        if self.buf.len() >= CHUNK_SIZE {
+            self.send_buf()?;
+        }
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.send_buf()
+    }
+}
@@ -0,0 +1,475 @@
+use crate::error::AppError;
+use crate::handlers::ApiResponse;
+use crate::state::AppState;
+use crate::utils::random_hex;
+use argon2::password_hash::rand_core::OsRng;
+use argon2::password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString};
+use argon2::Argon2;
+use axum::Json;
+use axum::extract::{ConnectInfo, Request, State};
+use axum::http::{HeaderMap, HeaderValue, StatusCode, header};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::str::FromStr;
+use std::sync::{LazyLock, RwLock};
+use std::time::{Duration, Instant};
+
+const SESSION_COOKIE: &str = "rshttp_session";
+const SESSION_TTL: Duration = Duration::from_secs(12 * 60 * 60);
+// Basic 认证每个请求都会带上密码，校验过的凭据缓存一段时间，避免每次都跑 argon2
+const BASIC_CACHE_TTL: Duration = Duration::from_secs(5 * 60);
+
+/// 用户角色，权限从低到高：viewer 只能浏览下载，uploader 可以上传和新建目录，admin 可以重命名和删除
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum Role {
+    Viewer,
+    Uploader,
+    Admin,
+}
+
+impl std::fmt::Display for Role {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Role::Viewer => write!(f, "viewer"),
+            Role::Uploader => write!(f, "uploader"),
+            Role::Admin => write!(f, "admin"),
+        }
+    }
+}
+
+impl FromStr for Role {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "viewer" => Ok(Role::Viewer),
+            "uploader" => Ok(Role::Uploader),
+            "admin" => Ok(Role::Admin),
+            _ => Err(format!("unknown role {}, expect viewer, uploader or admin", s)),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct User {
+    pub(crate) name: String,
+    pub(crate) role: Role,
+    pub(crate) password_hash: String,
+}
+
+// 启动时会打印配置，不要把密码哈希写进日志
+impl std::fmt::Debug for User {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("User")
+            .field("name", &self.name)
+            .field("role", &self.role)
+            .finish_non_exhaustive()
+    }
+}
+
+impl FromStr for User {
+    type Err = String;
+
+    /// 格式为 `name:role:hash`，hash 是 `--hash-password` 生成的 argon2 PHC 字符串
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.splitn(3, ':');
+        let (Some(name), Some(role), Some(password_hash)) = (parts.next(), parts.next(), parts.next()) else {
+            return Err(format!("invalid user {}, expect name:role:hash", s));
+        };
+        if name.is_empty() {
+            return Err("user name must not be empty".to_string());
+        }
+        PasswordHash::new(password_hash).map_err(|e| format!("invalid password hash for {}: {}", name, e))?;
+        Ok(User {
+            name: name.to_string(),
+            role: role.parse()?,
+            password_hash: password_hash.to_string(),
+        })
+    }
+}
+
+pub(crate) fn hash_password(password: &str) -> String {
+    let salt = SaltString::generate(&mut OsRng);
+    Argon2::default()
+        .hash_password(password.as_bytes(), &salt)
+        .expect("Failed to hash password")
+        .to_string()
+}
+
+// 用户名不存在时用来校验的哈希，保证无论用户是否存在都要跑一次 argon2，响应时间不会暴露用户名
+static DUMMY_HASH: LazyLock<String> = LazyLock::new(|| hash_password("rshttpserver"));
+
+fn verify_password(password_hash: &str, password: &str) -> bool {
+    PasswordHash::new(password_hash)
+        .map(|h| Argon2::default().verify_password(password.as_bytes(), &h).is_ok())
+        .unwrap_or(false)
+}
+
+/// 当前请求的身份，由 [`auth_middleware`] 放进 request extensions
+#[derive(Debug, Clone)]
+pub(crate) struct CurrentUser {
+    pub(crate) name: Option<String>,
+    pub(crate) role: Option<Role>,
+}
+
+impl CurrentUser {
+    /// 角色不够时返回 401（未登录）或 403（已登录但权限不足）
+    pub(crate) fn require(&self, role: Role) -> Result<(), AppError> {
+        match (&self.name, self.role) {
+            (_, Some(r)) if r >= role => Ok(()),
+            (None, _) => Err(AppError::Unauthorized("login required".to_string())),
+            (Some(name), _) => Err(AppError::Forbidden(format!("{} is not allowed, {} role required", name, role))),
+        }
+    }
+}
+
+struct Session {
+    name: String,
+    role: Role,
+    expires: Instant,
+}
+
+/// 内存中的登录会话，服务重启后需要重新登录
+#[derive(Default)]
+pub(crate) struct SessionStore {
+    sessions: RwLock<HashMap<String, Session>>,
+    basic_cache: RwLock<HashMap<[u8; 32], Session>>,
+}
+
+impl SessionStore {
+    fn create(&self, name: &str, role: Role) -> String {
+        let token = random_hex(32);
+        let now = Instant::now();
+        let mut sessions = self.sessions.write().unwrap();
+        sessions.retain(|_, s| s.expires > now);
+        sessions.insert(
+            token.clone(),
+            Session {
+                name: name.to_string(),
+                role,
+                expires: now + SESSION_TTL,
+            },
+        );
+        token
+    }
+
+    fn get(&self, token: &str) -> Option<(String, Role)> {
+        let sessions = self.sessions.read().unwrap();
+        sessions
+            .get(token)
+            .filter(|s| s.expires > Instant::now())
+            .map(|s| (s.name.clone(), s.role))
+    }
+
+    fn remove(&self, token: &str) {
+        self.sessions.write().unwrap().remove(token);
+    }
+}
+
+fn session_token(headers: &HeaderMap) -> Option<String> {
+    headers
+        .get_all(header::COOKIE)
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .flat_map(|v| v.split(';'))
+        .filter_map(|c| c.trim().split_once('='))
+        .find(|(k, _)| *k == SESSION_COOKIE)
+        .map(|(_, v)| v.to_string())
+}
+
+fn basic_credentials(headers: &HeaderMap) -> Option<(String, String)> {
+    let value = headers.get(header::AUTHORIZATION)?.to_str().ok()?;
+    let encoded = value.strip_prefix("Basic ").or_else(|| value.strip_prefix("basic "))?;
+    let decoded = base64::engine::general_purpose::STANDARD.decode(encoded.trim()).ok()?;
+    let decoded = String::from_utf8(decoded).ok()?;
+    let (name, password) = decoded.split_once(':')?;
+    Some((name.to_string(), password.to_string()))
+}
+
+async fn check_credentials(state: &AppState, name: &str, password: &str) -> Option<Role> {
+    let user = state.config.users.iter().find(|u| u.name == name).cloned();
+    let password = password.to_string();
+    // argon2 比较耗 CPU，放到阻塞线程池里
+    tokio::task::spawn_blocking(move || match user {
+        Some(user) => verify_password(&user.password_hash, &password).then_some(user.role),
+        None => {
+            verify_password(&DUMMY_HASH, &password);
+            None
+        }
+    })
+        .await
+        .ok()
+        .flatten()
+}
+
+async fn check_basic(state: &AppState, name: &str, password: &str) -> Option<Role> {
+    let key: [u8; 32] = Sha256::new()
+        .chain_update(name.as_bytes())
+        .chain_update([0u8])
+        .chain_update(password.as_bytes())
+        .finalize()
+        .into();
+    let now = Instant::now();
+    if let Some(s) = state.sessions.basic_cache.read().unwrap().get(&key)
+        && s.expires > now
+        && s.name == name
+    {
+        return Some(s.role);
+    }
+
+    let role = check_credentials(state, name, password).await?;
+    let mut cache = state.sessions.basic_cache.write().unwrap();
+    cache.retain(|_, s| s.expires > now);
+    cache.insert(
+        key,
+        Session {
+            name: name.to_string(),
+            role,
+            expires: now + BASIC_CACHE_TTL,
+        },
+    );
+    Some(role)
+}
+
+fn client_addr(request: &Request) -> String {
+    request
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map_or_else(|| "unknown".to_string(), |c| c.0.to_string())
+}
+
+/// 识别请求身份：Basic 认证优先，其次是会话 cookie，都没有时使用匿名角色。
+/// 未配置任何用户时认证关闭，所有请求都按 admin 处理。
+pub(crate) async fn auth_middleware(State(state): State<AppState>, mut request: Request, next: Next) -> Response {
+    let current_user = if state.config.users.is_empty() {
+        CurrentUser {
+            name: None,
+            role: Some(Role::Admin),
+        }
+    } else if let Some((name, password)) = basic_credentials(request.headers()) {
+        match check_basic(&state, &name, &password).await {
+            Some(role) => CurrentUser {
+                name: Some(name),
+                role: Some(role),
+            },
+            None => {
+                tracing::warn!(">>> basic auth failed for user {} from {}", &name, client_addr(&request));
+                let mut response = AppError::Unauthorized("invalid username or password".to_string()).into_response();
+                response.headers_mut().insert(
+                    header::WWW_AUTHENTICATE,
+                    HeaderValue::from_static("Basic realm=\"rshttpserver\", charset=\"UTF-8\""),
+                );
+                return response;
+            }
+        }
+    } else if let Some((name, role)) = session_token(request.headers()).and_then(|t| state.sessions.get(&t)) {
+        CurrentUser {
+            name: Some(name),
+            role: Some(role),
+        }
+    } else {
+        CurrentUser {
+            name: None,
+            role: state.config.anonymous_role,
+        }
+    };
+
+    request.extensions_mut().insert(current_user);
+    let mut response = next.run(request).await;
+    // 让浏览器在需要登录时弹出认证框
+    if response.status() == StatusCode::UNAUTHORIZED && !response.headers().contains_key(header::WWW_AUTHENTICATE) {
+        response.headers_mut().insert(
+            header::WWW_AUTHENTICATE,
+            HeaderValue::from_static("Basic realm=\"rshttpserver\", charset=\"UTF-8\""),
+        );
+    }
+    response
+}
+
+#[derive(Deserialize)]
+pub(crate) struct LoginBody {
+    username: String,
+    password: String,
+}
+
+pub(crate) async fn login_handler(
+    State(state): State<AppState>,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    Json(body): Json<LoginBody>,
+) -> Result<Response, AppError> {
+    let Some(role) = check_credentials(&state, &body.username, &body.password).await else {
+        tracing::warn!(">>> login failed for user {} from {}", &body.username, addr);
+        return Err(AppError::Unauthorized("invalid username or password".to_string()));
+    };
+
+    tracing::info!(">>> user {} login from {}", &body.username, addr);
+    let token = state.sessions.create(&body.username, role);
+    let mut cookie = format!(
+        "{}={}; Path=/; HttpOnly; SameSite=Lax; Max-Age={}",
+        SESSION_COOKIE,
+        token,
+        SESSION_TTL.as_secs()
+    );
+    if state.config.tls_enabled() {
+        cookie.push_str("; Secure");
+    }
+    let mut headers = HeaderMap::new();
+    let cookie = HeaderValue::from_str(&cookie).map_err(|e| AppError::Internal(format!("invalid session cookie: {}", e)))?;
+    headers.insert(header::SET_COOKIE, cookie);
+    Ok((
+        StatusCode::OK,
+        headers,
+        Json(ApiResponse {
+            code: 200,
+            message: format!("welcome {}", &body.username),
+            data: Some(json!({"username": body.username, "role": role})),
+        }),
+    )
+        .into_response())
+}
+
+pub(crate) async fn logout_handler(State(state): State<AppState>, headers: HeaderMap) -> Result<(StatusCode, HeaderMap, Json<ApiResponse>), AppError> {
+    if let Some(token) = session_token(&headers) {
+        state.sessions.remove(&token);
+    }
+    let mut response_headers = HeaderMap::new();
+    response_headers.insert(
+        header::SET_COOKIE,
+        HeaderValue::from_static("rshttp_session=; Path=/; HttpOnly; SameSite=Lax; Max-Age=0"),
+    );
+    Ok((
+        StatusCode::OK,
+        response_headers,
+        Json(ApiResponse {
+            code: 200,
+            message: "success logout".to_string(),
+            data: None,
+        }),
+    ))
+}
+
+pub(crate) async fn whoami_handler(axum::Extension(user): axum::Extension<CurrentUser>) -> Result<(StatusCode, Json<ApiResponse>), AppError> {
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse {
+            code: 200,
+            message: "OK".to_string(),
+            data: Some(json!({"username": user.name, "role": user.role})),
+        }),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user(name: Option<&str>, role: Option<Role>) -> CurrentUser {
+        CurrentUser {
+            name: name.map(str::to_string),
+            role,
+        }
+    }
+
+    fn headers(name: header::HeaderName, values: &[&str]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for value in values {
+            headers.append(name.clone(), HeaderValue::from_str(value).unwrap());
+        }
+        headers
+    }
+
+    #[test]
+    fn orders_roles() {
+        assert!(Role::Viewer < Role::Uploader && Role::Uploader < Role::Admin);
+        for role in [Role::Viewer, Role::Uploader, Role::Admin] {
+            assert_eq!(role.to_string().parse::<Role>(), Ok(role));
+        }
+        assert!("root".parse::<Role>().is_err());
+        assert!("Admin".parse::<Role>().is_err());
+    }
+
+    #[test]
+    fn requires_role() {
+        let cases = [
+            (user(None, None), Role::Viewer, None),
+            (user(None, Some(Role::Viewer)), Role::Viewer, Some(StatusCode::OK)),
+            // 匿名用户权限不够时要求登录，而不是直接拒绝
+            (user(None, Some(Role::Viewer)), Role::Uploader, None),
+            (user(Some("bob"), Some(Role::Viewer)), Role::Uploader, Some(StatusCode::FORBIDDEN)),
+            (user(Some("bob"), Some(Role::Uploader)), Role::Uploader, Some(StatusCode::OK)),
+            (user(Some("alice"), Some(Role::Admin)), Role::Uploader, Some(StatusCode::OK)),
+        ];
+        for (user, role, expected) in cases {
+            let status = match user.require(role) {
+                Ok(()) => StatusCode::OK,
+                Err(e) => e.status(),
+            };
+            assert_eq!(status, expected.unwrap_or(StatusCode::UNAUTHORIZED), "{:?} requires {}", user, role);
+        }
+    }
+
+    #[test]
+    fn parses_basic_credentials() {
+        let basic = |s: &str| base64::engine::general_purpose::STANDARD.encode(s);
+        let cases = [
+            (format!("Basic {}", basic("alice:secret")), Some(("alice", "secret"))),
+            // 密码里可以有冒号
+            (format!("basic {}", basic("alice:a:b")), Some(("alice", "a:b"))),
+            (format!("Basic {}", basic("alice:")), Some(("alice", ""))),
+            (format!("Basic {}", basic("alice")), None),
+            ("Basic !!!".to_string(), None),
+            (format!("Bearer {}", basic("alice:secret")), None),
+        ];
+        for (value, expected) in cases {
+            let credentials = basic_credentials(&headers(header::AUTHORIZATION, &[&value]));
+            assert_eq!(credentials, expected.map(|(n, p)| (n.to_string(), p.to_string())), "{}", value);
+        }
+        assert_eq!(basic_credentials(&HeaderMap::new()), None);
+    }
+
+    #[test]
+    fn parses_session_cookie() {
+        let cookie = |v: &str| format!("{}={}", SESSION_COOKIE, v);
+        let cases = [
+            (vec![cookie("abc")], Some("abc")),
+            (vec![format!("theme=dark; {}; lang=zh", cookie("abc"))], Some("abc")),
+            (vec!["theme=dark".to_string(), cookie("abc")], Some("abc")),
+            (vec![format!("x{}", cookie("abc"))], None),
+            (vec!["theme=dark".to_string()], None),
+        ];
+        for (values, expected) in cases {
+            let values: Vec<&str> = values.iter().map(String::as_str).collect();
+            assert_eq!(session_token(&headers(header::COOKIE, &values)).as_deref(), expected, "{:?}", values);
+        }
+    }
+
+    #[test]
+    fn stores_sessions() {
+        let store = SessionStore::default();
+        let token = store.create("alice", Role::Uploader);
+        assert_eq!(token.len(), 64);
+        assert_eq!(store.get(&token), Some(("alice".to_string(), Role::Uploader)));
+        assert_eq!(store.get("unknown"), None);
+        store.remove(&token);
+        assert_eq!(store.get(&token), None);
+    }
+
+    #[test]
+    fn parses_users() {
+        let hash = hash_password("secret");
+        let user: User = format!("alice:admin:{}", hash).parse().unwrap();
+        assert_eq!((user.name.as_str(), user.role), ("alice", Role::Admin));
+        assert!(verify_password(&user.password_hash, "secret"));
+        assert!(!verify_password(&user.password_hash, "Secret"));
+        for invalid in [format!(":admin:{}", hash), format!("alice:root:{}", hash), "alice:admin".to_string(), "alice:admin:plain".to_string()] {
+            assert!(invalid.parse::<User>().is_err(), "{}", invalid);
+        }
+    }
+}
@@ -1,39 +1,420 @@
-use std::path::PathBuf;
-use clap::Parser;
+use std::io::IsTerminal;
+use std::path::{Path, PathBuf};
+use clap::{CommandFactory, Parser, ValueEnum};
+use clap::error::ErrorKind;
+use serde::{Deserialize, Serialize};
+use crate::access::{self, AccessRule};
+use crate::auth::{self, Role, User};
+use crate::paths::SymlinkPolicy;
 
 #[derive(Debug,Clone)]
 pub(crate) struct AppConfig {
     pub(crate) host: String,
     pub(crate) port: u16,
     pub(crate) root_dirpath: PathBuf,
+    pub(crate) symlink_policy: SymlinkPolicy,
+    pub(crate) max_upload_size: u64,
+    pub(crate) users: Vec<User>,
+    pub(crate) anonymous_role: Option<Role>,
+    pub(crate) access_rules: Vec<AccessRule>,
+    pub(crate) read_only: bool,
+    pub(crate) upload_only: bool,
+    pub(crate) tls_cert: Option<PathBuf>,
+    pub(crate) tls_key: Option<PathBuf>,
+    pub(crate) tls_self_signed: bool,
+    pub(crate) tls_redirect_port: Option<u16>,
+    pub(crate) dav_prefix: Option<String>,
+    pub(crate) state_dir: PathBuf,
+    pub(crate) tus_expiry: u64,
+    pub(crate) content_index: bool,
+    pub(crate) watch: bool,
+    pub(crate) trash: bool,
+    pub(crate) trash_max_age: u64,
+    pub(crate) trash_max_size: u64,
 }
 
 
+/// 命令行参数，每个参数也可以通过 `RSHTTP_*` 环境变量设置，命令行优先
 #[derive(Parser, Debug)]
 #[command(version="0.1")]
 struct AppArgs {
-    /// port
+    /// TOML config file, relative paths inside it are resolved against the file's directory
+    #[arg(short='c', long, env="RSHTTP_CONFIG")]
+    config:Option<PathBuf>,
+
+    /// print the effective config merged from defaults, config file, environment and command line, then exit
     #[arg(long)]
+    print_config:bool,
+
+    /// host [default: 0.0.0.0]
+    #[arg(long, env="RSHTTP_HOST")]
+    host:Option<String>,
+
+    /// port [default: 3000]
+    #[arg(long, env="RSHTTP_PORT")]
     port:Option<u16>,
 
-    #[arg(short='d', long)]
+    /// root directory to serve [default: current directory]
+    #[arg(short='d', long, env="RSHTTP_ROOT")]
     root:Option<PathBuf>,
+
+    /// how symlinks inside the root are handled [default: follow-within-root]
+    #[arg(long, value_enum, env="RSHTTP_SYMLINKS")]
+    symlinks:Option<SymlinkPolicy>,
+
+    /// max request body size in bytes [default: 1 TiB]
+    #[arg(long, env="RSHTTP_MAX_UPLOAD_SIZE")]
+    max_upload_size:Option<u64>,
+
+    /// user in the form name:role:hash, role is viewer, uploader or admin; repeat for more users
+    #[arg(long="user", value_name="NAME:ROLE:HASH", env="RSHTTP_USERS", value_delimiter=';')]
+    users:Vec<User>,
+
+    /// role granted to requests without login when users are configured
+    #[arg(long, env="RSHTTP_ANONYMOUS_ROLE")]
+    anonymous_role:Option<Role>,
+
+    /// access rule in the form <glob>=<policy>[,<policy>...], e.g. '/releases/**=read-only'; repeat for more rules
+    #[arg(long="rule", value_name="GLOB=POLICY", env="RSHTTP_RULES", value_delimiter=';')]
+    rules:Vec<AccessRule>,
+
+    /// serve files without delete, rename, create and upload
+    #[arg(long, env="RSHTTP_READ_ONLY", num_args=0..=1, default_missing_value="true")]
+    read_only:Option<bool>,
+
+    /// drop box mode, accept uploads but never list or serve files back
+    #[arg(long, env="RSHTTP_UPLOAD_ONLY", num_args=0..=1, default_missing_value="true")]
+    upload_only:Option<bool>,
+
+    /// PEM certificate chain to serve HTTPS, reloaded on SIGHUP
+    #[arg(long, env="RSHTTP_TLS_CERT")]
+    tls_cert:Option<PathBuf>,
+
+    /// PEM private key of --tls-cert
+    #[arg(long, env="RSHTTP_TLS_KEY")]
+    tls_key:Option<PathBuf>,
+
+    /// serve HTTPS with an in-memory self-signed certificate
+    #[arg(long, env="RSHTTP_TLS_SELF_SIGNED", num_args=0..=1, default_missing_value="true")]
+    tls_self_signed:Option<bool>,
+
+    /// also listen for plain HTTP on this port and redirect to HTTPS
+    #[arg(long, env="RSHTTP_TLS_REDIRECT_PORT")]
+    tls_redirect_port:Option<u16>,
+
+    /// serve the root over WebDAV under this path prefix, e.g. /dav [default: disabled]
+    #[arg(long, env="RSHTTP_DAV_PREFIX")]
+    dav_prefix:Option<String>,
+
+    /// directory for server state such as unfinished uploads, hidden from clients when inside the root [default: <root>/.rshttpserver]
+    #[arg(long, env="RSHTTP_STATE_DIR")]
+    state_dir:Option<PathBuf>,
+
+    /// seconds after the last write when an unfinished resumable upload is removed [default: 86400]
+    #[arg(long, env="RSHTTP_TUS_EXPIRY")]
+    tus_expiry:Option<u64>,
+
+    /// index the content of text files in the state directory for full-text search
+    #[arg(long, env="RSHTTP_CONTENT_INDEX", num_args=0..=1, default_missing_value="true")]
+    content_index:Option<bool>,
+
+    /// watch the root for changes and push them to clients at /events, uses one inotify watch per folder [default: false]
+    #[arg(long, env="RSHTTP_WATCH", num_args=0..=1, default_missing_value="true")]
+    watch:Option<bool>,
+
+    /// move deleted entries to a trash in the state directory instead of removing them [default: true]
+    #[arg(long, env="RSHTTP_TRASH", num_args=0..=1, default_missing_value="true")]
+    trash:Option<bool>,
+
+    /// seconds a deleted entry is kept in the trash, 0 keeps it until purged [default: 2592000]
+    #[arg(long, env="RSHTTP_TRASH_MAX_AGE")]
+    trash_max_age:Option<u64>,
+
+    /// max total size of the trash in bytes, the oldest entries are purged first, 0 means no limit [default: 0]
+    #[arg(long, env="RSHTTP_TRASH_MAX_SIZE")]
+    trash_max_size:Option<u64>,
+
+    /// read a password from a prompt (or stdin when piped), print its argon2 hash and exit
+    #[arg(long)]
+    hash_password:bool,
+}
+
+/// 配置文件的内容，所有字段都可以省略；`--print-config` 也用它输出合并后的配置
+#[derive(Debug, Default, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+struct FileConfig {
+    host: Option<String>,
+    port: Option<u16>,
+    root: Option<PathBuf>,
+    symlinks: Option<String>,
+    max_upload_size: Option<u64>,
+    read_only: Option<bool>,
+    upload_only: Option<bool>,
+    anonymous_role: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    rules: Vec<String>,
+    tls_cert: Option<PathBuf>,
+    tls_key: Option<PathBuf>,
+    tls_self_signed: Option<bool>,
+    tls_redirect_port: Option<u16>,
+    dav_prefix: Option<String>,
+    state_dir: Option<PathBuf>,
+    tus_expiry: Option<u64>,
+    content_index: Option<bool>,
+    watch: Option<bool>,
+    trash: Option<bool>,
+    trash_max_age: Option<u64>,
+    trash_max_size: Option<u64>,
+    // 表数组在 TOML 里必须放在最后
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    users: Vec<FileUser>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+struct FileUser {
+    name: String,
+    role: String,
+    password_hash: String,
+}
+
+impl FileConfig {
+    fn load(path: &Path) -> Result<FileConfig, String> {
+        let content = std::fs::read_to_string(path).map_err(|e| format!("read {:?} error: {}", path, e))?;
+        toml::from_str(&content).map_err(|e| format!("parse {:?} error: {}", path, e))
+    }
+}
+
+fn fail(kind: ErrorKind, message: String) -> ! {
+    AppArgs::command().error(kind, message).exit()
+}
+
+/// 密码不能出现在命令行参数里（`ps` 和 shell 历史都看得到），终端上不回显地输入两次，管道输入时读取第一行
+fn read_password() -> Result<String, String> {
+    let password = if std::io::stdin().is_terminal() {
+        let password = rpassword::prompt_password("Password: ").map_err(|e| e.to_string())?;
+        if rpassword::prompt_password("Repeat password: ").map_err(|e| e.to_string())? != password {
+            return Err("passwords do not match".to_string());
+        }
+        password
+    } else {
+        let mut line = String::new();
+        std::io::stdin().read_line(&mut line).map_err(|e| e.to_string())?;
+        line.trim_end_matches(['\r', '\n']).to_string()
+    };
+    if password.is_empty() {
+        return Err("password must not be empty".to_string());
+    }
+    Ok(password)
+}
+
+// WebDAV 前缀不能占用 JSON API 已有的路由
+const RESERVED_PREFIXES: [&str; 18] = ["", "~", "mode", "login", "logout", "whoami", "info", "download", "upload", "delete", "rename", "create", "tus", "hash", "search", "events", "trash", "view"];
+
+/// 去掉末尾的 `/`；WebDAV 默认关闭，未设置或为空字符串时不提供
+fn dav_prefix(value: Option<String>) -> Option<String> {
+    match value {
+        None => None,
+        Some(v) if v.is_empty() => None,
+        Some(v) if v.trim_end_matches('/').is_empty() => Some(v),
+        Some(v) => Some(v.trim_end_matches('/').to_string()),
+    }
+}
+
+fn absolute(base: &Path, path: PathBuf) -> PathBuf {
+    if path.is_relative() { base.join(path) } else { path }
 }
 
 impl AppConfig {
+    pub(crate) fn tls_enabled(&self) -> bool {
+        self.tls_cert.is_some() || self.tls_self_signed
+    }
+
+    /// 按 默认值 < 配置文件 < 环境变量 < 命令行 的优先级合并配置，环境变量和命令行由 clap 合并
     pub(crate) fn new() -> AppConfig {
         let app_args = AppArgs::parse();
-        let cur_root_dir = std::env::current_dir().expect("Failed to get current directory");
+        if app_args.hash_password {
+            let password = read_password().unwrap_or_else(|e| fail(ErrorKind::Io, e));
+            println!("{}", auth::hash_password(&password));
+            std::process::exit(0);
+        }
+        let cur_dir = std::env::current_dir().expect("Failed to get current directory");
+        let (file, file_dir) = match &app_args.config {
+            Some(path) => {
+                let file = FileConfig::load(path).unwrap_or_else(|e| fail(ErrorKind::InvalidValue, e));
+                let file_dir = absolute(&cur_dir, path.clone()).parent().map_or_else(|| cur_dir.clone(), |p| p.to_path_buf());
+                (file, file_dir)
+            }
+            None => (FileConfig::default(), cur_dir.clone()),
+        };
+
+        let users = if !app_args.users.is_empty() {
+            app_args.users
+        } else {
+            file.users
+                .iter()
+                .map(|u| format!("{}:{}:{}", u.name, u.role, u.password_hash).parse::<User>())
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap_or_else(|e| fail(ErrorKind::InvalidValue, e))
+        };
+        let access_rules = if !app_args.rules.is_empty() {
+            app_args.rules
+        } else {
+            file.rules
+                .iter()
+                .map(|r| r.parse::<AccessRule>())
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap_or_else(|e| fail(ErrorKind::InvalidValue, e))
+        };
+        let symlink_policy = app_args.symlinks.unwrap_or_else(|| match &file.symlinks {
+            Some(s) => SymlinkPolicy::from_str(s, false).unwrap_or_else(|e| fail(ErrorKind::InvalidValue, format!("invalid symlinks {}: {}", s, e))),
+            None => SymlinkPolicy::FollowWithinRoot,
+        });
+        let anonymous_role = app_args.anonymous_role.or_else(|| {
+            file.anonymous_role
+                .as_ref()
+                .map(|r| r.parse::<Role>().unwrap_or_else(|e| fail(ErrorKind::InvalidValue, e)))
+        });
+
+        let root_dirpath = match (app_args.root, file.root) {
+            (Some(root), _) => absolute(&cur_dir, root),
+            (None, Some(root)) => absolute(&file_dir, root),
+            (None, None) => cur_dir.clone(),
+        };
+        let tls_cert = app_args.tls_cert.map(|p| absolute(&cur_dir, p)).or(file.tls_cert.map(|p| absolute(&file_dir, p)));
+        let tls_key = app_args.tls_key.map(|p| absolute(&cur_dir, p)).or(file.tls_key.map(|p| absolute(&file_dir, p)));
+        let state_dir = app_args.state_dir.map(|p| absolute(&cur_dir, p)).or(file.state_dir.map(|p| absolute(&file_dir, p)));
+
+        // 规则按去掉根目录前缀后的路径匹配，根目录需要和 canonicalize 后的路径保持一致
+        let root_dirpath = root_dirpath.canonicalize().unwrap_or(root_dirpath);
+        // 状态目录同样 canonicalize，才能判断它是否在根目录内
+        let state_dir = state_dir.unwrap_or_else(|| root_dirpath.join(".rshttpserver"));
+        let state_dir = state_dir.canonicalize().unwrap_or(state_dir);
+
+        let app_config = AppConfig {
+            host: app_args.host.or(file.host).unwrap_or_else(|| "0.0.0.0".to_string()),
+            port: app_args.port.or(file.port).unwrap_or(3000),
+            root_dirpath,
+            symlink_policy,
+            max_upload_size: app_args.max_upload_size.or(file.max_upload_size).unwrap_or(1024 * 1024 * 1024 * 1024),
+            users,
+            anonymous_role,
+            access_rules,
+            read_only: app_args.read_only.or(file.read_only).unwrap_or(false),
+            upload_only: app_args.upload_only.or(file.upload_only).unwrap_or(false),
+            tls_cert,
+            tls_key,
+            tls_self_signed: app_args.tls_self_signed.or(file.tls_self_signed).unwrap_or(false),
+            tls_redirect_port: app_args.tls_redirect_port.or(file.tls_redirect_port),
+            dav_prefix: dav_prefix(app_args.dav_prefix.or(file.dav_prefix)),
+            state_dir,
+            tus_expiry: app_args.tus_expiry.or(file.tus_expiry).unwrap_or(24 * 60 * 60),
+            content_index: app_args.content_index.or(file.content_index).unwrap_or(false),
+            watch: app_args.watch.or(file.watch).unwrap_or(false),
+            trash: app_args.trash.or(file.trash).unwrap_or(true),
+            trash_max_age: app_args.trash_max_age.or(file.trash_max_age).unwrap_or(30 * 24 * 60 * 60),
+            trash_max_size: app_args.trash_max_size.or(file.trash_max_size).unwrap_or(0),
+        };
+        app_config.validate();
+
+        if app_args.print_config {
+            print!("{}", toml::to_string(&app_config.to_file_config()).expect("Failed to serialize config"));
+            std::process::exit(0);
+        }
+        app_config
+    }
+
+    /// 测试用：只服务 `root`，其它都取默认值，不开启回收站、监听和 WebDAV
+    #[cfg(test)]
+    pub(crate) fn for_root(root: &Path) -> AppConfig {
+        let root_dirpath = root.canonicalize().unwrap_or_else(|_| root.to_path_buf());
         AppConfig {
-            host: "0.0.0.0".to_string(),
-            port: if let Some(port) = app_args.port {port}else { 3000 },
-            root_dirpath: if let Some(root) = app_args.root {
-                if root.is_relative() {
-                    cur_root_dir.join(root)
-                }else {
-                    root
-                }
-            }else { cur_root_dir },
+            host: "127.0.0.1".to_string(),
+            port: 0,
+            state_dir: root_dirpath.join(".rshttpserver"),
+            root_dirpath,
+            symlink_policy: SymlinkPolicy::FollowWithinRoot,
+            max_upload_size: 1024 * 1024,
+            users: vec![],
+            anonymous_role: None,
+            access_rules: vec![],
+            read_only: false,
+            upload_only: false,
+            tls_cert: None,
+            tls_key: None,
+            tls_self_signed: false,
+            tls_redirect_port: None,
+            dav_prefix: None,
+            tus_expiry: 24 * 60 * 60,
+            content_index: false,
+            watch: false,
+            trash: false,
+            trash_max_age: 30 * 24 * 60 * 60,
+            trash_max_size: 0,
+        }
+    }
+
+    // 各层合并之后再检查互斥和依赖关系
+    fn validate(&self) {
+        if self.read_only && self.upload_only {
+            fail(ErrorKind::ArgumentConflict, "read_only and upload_only can not be used together".to_string());
+        }
+        if let Err(e) = access::validate_rules(&self.access_rules, !self.users.is_empty()) {
+            fail(ErrorKind::ArgumentConflict, e);
+        }
+        if self.tls_cert.is_some() != self.tls_key.is_some() {
+            fail(ErrorKind::MissingRequiredArgument, "tls_cert and tls_key must be set together".to_string());
+        }
+        if self.tls_cert.is_some() && self.tls_self_signed {
+            fail(ErrorKind::ArgumentConflict, "tls_cert and tls_self_signed can not be used together".to_string());
+        }
+        if let Some(prefix) = &self.dav_prefix {
+            let first = prefix.trim_start_matches('/').split('/').next().unwrap_or_default();
+            if !prefix.starts_with('/') || RESERVED_PREFIXES.contains(&first) || prefix.contains(['{', '}']) {
+                fail(ErrorKind::InvalidValue, format!("dav_prefix {} must start with / and not clash with the api routes", prefix));
+            }
+        }
+        // 只读模式不往根目录里写任何东西，索引只能放在根目录外的状态目录里
+        if self.read_only && self.content_index && self.state_dir.starts_with(&self.root_dirpath) {
+            fail(ErrorKind::ArgumentConflict, "content_index with read_only requires a state_dir outside the root".to_string());
+        }
+        if self.tls_redirect_port.is_some() && !self.tls_enabled() {
+            fail(ErrorKind::MissingRequiredArgument, "tls_redirect_port requires tls_cert or tls_self_signed".to_string());
+        }
+    }
+
+    fn to_file_config(&self) -> FileConfig {
+        FileConfig {
+            host: Some(self.host.clone()),
+            port: Some(self.port),
+            root: Some(self.root_dirpath.clone()),
+            symlinks: self.symlink_policy.to_possible_value().map(|v| v.get_name().to_string()),
+            max_upload_size: Some(self.max_upload_size),
+            read_only: Some(self.read_only),
+            upload_only: Some(self.upload_only),
+            anonymous_role: self.anonymous_role.map(|r| r.to_string()),
+            rules: self.access_rules.iter().map(|r| r.to_string()).collect(),
+            tls_cert: self.tls_cert.clone(),
+            tls_key: self.tls_key.clone(),
+            tls_self_signed: Some(self.tls_self_signed),
+            tls_redirect_port: self.tls_redirect_port,
+            dav_prefix: self.dav_prefix.clone(),
+            state_dir: Some(self.state_dir.clone()),
+            tus_expiry: Some(self.tus_expiry),
+            content_index: Some(self.content_index),
+            watch: Some(self.watch),
+            trash: Some(self.trash),
+            trash_max_age: Some(self.trash_max_age),
+            trash_max_size: Some(self.trash_max_size),
+            users: self
+                .users
+                .iter()
+                .map(|u| FileUser {
+                    name: u.name.clone(),
+                    role: u.role.to_string(),
+                    password_hash: u.password_hash.clone(),
+                })
+                .collect(),
         }
     }
 }
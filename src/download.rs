@@ -0,0 +1,522 @@
+use crate::error::AppError;
+use crate::utils::random_hex;
+use axum::body::Body;
+use axum::http::{HeaderMap, HeaderValue, Method, StatusCode, header};
+use axum::response::{IntoResponse, Response};
+use std::fs::Metadata;
+use bytes::Bytes;
+use mime_guess::mime;
+use std::io::{self, SeekFrom};
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tokio::fs::File;
+use tokio::io::{AsyncReadExt, AsyncSeekExt, Take};
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+use tokio_util::io::ReaderStream;
+
+/// 文件的校验值，条件请求按它判断文件是否变化
+pub(crate) struct Validators {
+    etag: String,
+    /// 精确到秒，和 HTTP 日期的精度一致
+    last_modified: Option<SystemTime>,
+}
+
+impl Validators {
+    /// ETag 由大小、修改时间和 inode 生成；上传和重命名会替换 inode，同一时刻写入的不同版本也能区分
+    pub(crate) fn new(meta: &Metadata) -> Self {
+        let modified = meta.modified().ok().and_then(|m| m.duration_since(UNIX_EPOCH).ok());
+        #[cfg(unix)]
+        let ino = std::os::unix::fs::MetadataExt::ino(meta);
+        #[cfg(not(unix))]
+        let ino = 0u64;
+        let etag = format!(
+            "\"{:x}-{:x}-{:x}\"",
+            meta.len(),
+            modified.map_or(0, |d| d.as_nanos()),
+            ino
+        );
+        let last_modified = modified.map(|d| UNIX_EPOCH + std::time::Duration::from_secs(d.as_secs()));
+        Validators { etag, last_modified }
+    }
+
+    fn insert(&self, headers: &mut HeaderMap) {
+        if let Ok(v) = HeaderValue::from_str(&self.etag) {
+            headers.insert(header::ETAG, v);
+        }
+        if let Some(v) = self.last_modified.and_then(|m| HeaderValue::from_str(&httpdate::fmt_http_date(m)).ok()) {
+            headers.insert(header::LAST_MODIFIED, v);
+        }
+        // 允许缓存，但每次使用前都要重新验证，文件更新后不会拿到旧内容
+        headers.insert(header::CACHE_CONTROL, HeaderValue::from_static("no-cache"));
+    }
+
+    // 强比较：弱 ETag 永远不匹配
+    fn strong_match(&self, tags: &str) -> bool {
+        tags.split(',').map(str::trim).any(|t| t == "*" || t == self.etag)
+    }
+
+    // 弱比较：忽略 `W/` 前缀
+    fn weak_match(&self, tags: &str) -> bool {
+        tags.split(',')
+            .map(|t| t.trim().trim_start_matches("W/"))
+            .any(|t| t == "*" || t == self.etag)
+    }
+
+    fn modified_after(&self, date: &str) -> Option<bool> {
+        let date = httpdate::parse_http_date(date).ok()?;
+        Some(self.last_modified? > date)
+    }
+}
+
+/// 按 RFC 9110 第 13.2.2 节的顺序检查条件请求头的结果
+enum Precondition {
+    /// 继续处理，`range` 为 false 时忽略 Range 返回完整内容
+    Proceed { range: bool },
+    NotModified,
+    Failed,
+}
+
+fn evaluate(headers: &HeaderMap, method: &Method, validators: &Validators) -> Precondition {
+    let get = |name| headers.get(name).and_then(|v: &HeaderValue| v.to_str().ok());
+    if let Some(tags) = get(header::IF_MATCH) {
+        if !validators.strong_match(tags) {
+            return Precondition::Failed;
+        }
+    } else if let Some(date) = get(header::IF_UNMODIFIED_SINCE)
+        && validators.modified_after(date) == Some(true)
+    {
+        return Precondition::Failed;
+    }
+    let read = method == Method::GET || method == Method::HEAD;
+    if let Some(tags) = get(header::IF_NONE_MATCH) {
+        if validators.weak_match(tags) {
+            return if read { Precondition::NotModified } else { Precondition::Failed };
+        }
+    } else if read
+        && let Some(date) = get(header::IF_MODIFIED_SINCE)
+        && validators.modified_after(date) == Some(false)
+    {
+        return Precondition::NotModified;
+    }
+    // If-Range 不匹配时返回完整的新文件，避免把两个版本的内容拼在一起；日期只在和 Last-Modified 完全相同时才算匹配
+    let range = match get(header::IF_RANGE) {
+        None => true,
+        Some(v) if v.starts_with('"') => v.trim() == validators.etag,
+        Some(v) => httpdate::parse_http_date(v).is_ok_and(|d| Some(d) == validators.last_modified),
+    };
+    Precondition::Proceed { range }
+}
+
+/// 最多接受的区间数，超过时忽略 Range 返回完整内容，避免大量小区间放大请求
+const MAX_RANGES: usize = 64;
+
+/// Range 头的解析结果，区间都是闭区间并且已经按起点排序、合并了重叠和相邻的区间
+#[derive(Debug, PartialEq)]
+enum Ranges {
+    /// 没有 Range、单位不是 bytes 或语法错误，按 RFC 9110 忽略 Range 返回完整内容
+    Full,
+    Partial(Vec<(u64, u64)>),
+    /// 所有区间都超出了文件，返回 416 和 `Content-Range: bytes */size`
+    Unsatisfiable,
+}
+
+// 只接受纯数字，`u64::from_str` 会接受前导的 `+`
+fn position(s: &str) -> Option<u64> {
+    if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    s.parse().ok()
+}
+
+/// 按 RFC 9110 第 14.1.2 节解析 `bytes=0-99,200-`、后缀区间 `bytes=-500` 和多个区间；空文件上的任何区间都不可满足
+fn parse_ranges(header: &str, size: u64) -> Ranges {
+    let Some((unit, specs)) = header.split_once('=') else {
+        return Ranges::Full;
+    };
+    if !unit.trim().eq_ignore_ascii_case("bytes") {
+        return Ranges::Full;
+    }
+    let mut ranges = vec![];
+    let mut count = 0;
+    // 列表里允许出现空元素，例如 `bytes=0-1,,5-6`
+    for spec in specs.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+        count += 1;
+        if count > MAX_RANGES {
+            return Ranges::Full;
+        }
+        let Some((first, last)) = spec.split_once('-') else {
+            return Ranges::Full;
+        };
+        let (first, last) = (first.trim(), last.trim());
+        if first.is_empty() {
+            // 最后 n 个字节，n 大于文件大小时就是整个文件
+            let Some(suffix) = position(last) else {
+                return Ranges::Full;
+            };
+            if suffix > 0 && size > 0 {
+                ranges.push((size.saturating_sub(suffix), size - 1));
+            }
+            continue;
+        }
+        let Some(start) = position(first) else {
+            return Ranges::Full;
+        };
+        let end = if last.is_empty() {
+            None
+        } else {
+            match position(last) {
+                Some(end) if end >= start => Some(end),
+                _ => return Ranges::Full,
+            }
+        };
+        // 终点超出文件时截到文件末尾
+        if start < size {
+            ranges.push((start, end.map_or(size - 1, |e| e.min(size - 1))));
+        }
+    }
+    if count == 0 {
+        return Ranges::Full;
+    }
+    if ranges.is_empty() {
+        return Ranges::Unsatisfiable;
+    }
+    ranges.sort_unstable();
+    let mut merged: Vec<(u64, u64)> = Vec::with_capacity(ranges.len());
+    for (start, end) in ranges {
+        match merged.last_mut() {
+            Some(last) if start <= last.1.saturating_add(1) => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    Ranges::Partial(merged)
+}
+
+/// 按 RFC 6266 生成 Content-Disposition：`filename` 是只含可见 ASCII 的兜底名，`filename*` 是 RFC 5987 编码的 UTF-8 原名，
+/// 例如 `报告 "v2".pdf` 得到 `attachment; filename="__ _v2_.pdf"; filename*=UTF-8''%E6%8A%A5%E5%91%8A%20%22v2%22.pdf`
+pub(crate) fn content_disposition(kind: &str, filename: &str) -> HeaderValue {
+    // 引号、反斜杠、控制字符和非 ASCII 字符都替换掉，兜底名一定是合法的 quoted-string；
+    // 有的客户端会对 filename 做百分号解码，`%` 也替换掉
+    let fallback: String = filename
+        .chars()
+        .map(|c| if (c.is_ascii_graphic() && !matches!(c, '"' | '\\' | '%')) || c == ' ' { c } else { '_' })
+        .collect();
+    // RFC 5987 的 attr-char 之外的字节都按 %XX 编码
+    let encoded: String = filename
+        .bytes()
+        .map(|b| match b {
+            b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'!' | b'#' | b'$' | b'&' | b'+' | b'-' | b'.' | b'^' | b'_' | b'`' | b'|' | b'~' => {
+                (b as char).to_string()
+            }
+            _ => format!("%{:02X}", b),
+        })
+        .collect();
+    let value = if fallback == filename {
+        format!("{}; filename=\"{}\"", kind, fallback)
+    } else {
+        format!("{}; filename=\"{}\"; filename*=UTF-8''{}", kind, fallback, encoded)
+    };
+    // 上面的值只含可见 ASCII，不会失败；保险起见失败时只返回类型
+    HeaderValue::from_str(&value).unwrap_or_else(|_| HeaderValue::from_static("attachment"))
+}
+
+/// 浏览器里预览时的 CSP：不执行脚本、不加载外部资源；PDF 查看器在 sandbox 下无法工作，只对其它类型加 sandbox
+const VIEW_CSP: &str = "default-src 'none'; img-src 'self' data:; media-src 'self'; style-src 'unsafe-inline'";
+
+/// 预览时使用的 Content-Type，返回 None 时按附件下载。
+/// 图片、音视频和 PDF 原样内联；HTML、SVG、XML 等可以执行脚本的类型和其它文本一律按 `text/plain` 显示源码，
+/// 避免上传的文件在本站域名下执行脚本
+fn view_type(mime: &mime_guess::Mime) -> Option<String> {
+    let (ty, sub, suffix) = (mime.type_(), mime.subtype(), mime.suffix());
+    let textual = ty == mime::TEXT
+        || suffix == Some(mime::XML)
+        || suffix == Some(mime::JSON)
+        || (ty == mime::APPLICATION
+            && ["json", "javascript", "ecmascript", "xml", "toml", "yaml", "x-yaml", "x-sh"].contains(&sub.as_str()));
+    if textual {
+        return Some("text/plain; charset=utf-8".to_string());
+    }
+    match ty {
+        mime::IMAGE | mime::AUDIO | mime::VIDEO => Some(mime.essence_str().to_string()),
+        mime::APPLICATION if sub == mime::PDF => Some(mime.essence_str().to_string()),
+        _ => None,
+    }
+}
+
+/// 返回文件内容，支持条件请求、Range（多个区间时返回 `multipart/byteranges`）和 HEAD；`epath` 用于错误信息。
+/// `view` 为 true 时尽量在浏览器里内联显示，见 [`view_type`]
+pub(crate) async fn serve_file(
+    a_path: &Path,
+    epath: &str,
+    meta: &Metadata,
+    method: &Method,
+    headers: &HeaderMap,
+    view: bool,
+) -> Result<Response, AppError> {
+    let validators = Validators::new(meta);
+    let mut response_headers = HeaderMap::new();
+    validators.insert(&mut response_headers);
+    let use_range = match evaluate(headers, method, &validators) {
+        Precondition::NotModified => return Ok((StatusCode::NOT_MODIFIED, response_headers).into_response()),
+        Precondition::Failed => return Err(AppError::PreconditionFailed(format!("{} does not match the preconditions", epath))),
+        Precondition::Proceed { range } => range,
+    };
+
+    let esize = meta.len();
+    let guessed = mime_guess::from_path(a_path).first_or_octet_stream();
+    let inline = if view { view_type(&guessed) } else { None };
+    let mime_type = inline.clone().unwrap_or_else(|| guessed.to_string());
+    let ename = a_path
+        .file_name()
+        .map_or_else(|| "unknow".to_string(), |m| m.to_string_lossy().to_string());
+    let disposition = content_disposition(if inline.is_some() { "inline" } else { "attachment" }, &ename);
+    let ranges = match headers.get(header::RANGE).and_then(|v| v.to_str().ok()) {
+        Some(range) if use_range => parse_ranges(range, esize),
+        _ => Ranges::Full,
+    };
+    response_headers.insert(header::ACCEPT_RANGES, HeaderValue::from_static("bytes"));
+    let ranges = match ranges {
+        Ranges::Full => vec![],
+        Ranges::Partial(ranges) => ranges,
+        Ranges::Unsatisfiable => return Err(AppError::RangeNotSatisfiable(esize)),
+    };
+    response_headers.insert(header::CONTENT_DISPOSITION, disposition);
+    response_headers.insert(header::X_CONTENT_TYPE_OPTIONS, HeaderValue::from_static("nosniff"));
+    if view {
+        let csp = if guessed.subtype() == mime::PDF { VIEW_CSP.to_string() } else { format!("{}; sandbox", VIEW_CSP) };
+        response_headers.insert(header::CONTENT_SECURITY_POLICY, csp.parse().unwrap());
+    }
+
+    // 多个区间：每个区间一段，段头里带各自的 Content-Type 和 Content-Range
+    let mut parts = vec![];
+    let (status, content_length) = match ranges.as_slice() {
+        [] => {
+            response_headers.insert(header::CONTENT_TYPE, mime_type.parse().unwrap());
+            parts.push((Bytes::new(), 0, esize));
+            (StatusCode::OK, esize)
+        }
+        [(start, end)] => {
+            response_headers.insert(header::CONTENT_TYPE, mime_type.parse().unwrap());
+            response_headers.insert(
+                header::CONTENT_RANGE,
+                format!("bytes {}-{}/{}", start, end, esize).parse().unwrap(),
+            );
+            parts.push((Bytes::new(), *start, end - start + 1));
+            (StatusCode::PARTIAL_CONTENT, end - start + 1)
+        }
+        ranges => {
+            let boundary = random_hex(16);
+            response_headers.insert(
+                header::CONTENT_TYPE,
+                format!("multipart/byteranges; boundary={}", boundary).parse().unwrap(),
+            );
+            let mut total = 0;
+            for (start, end) in ranges {
+                let head = format!(
+                    "\r\n--{}\r\nContent-Type: {}\r\nContent-Range: bytes {}-{}/{}\r\n\r\n",
+                    boundary, mime_type, start, end, esize
+                );
+                total += head.len() as u64 + end - start + 1;
+                parts.push((Bytes::from(head), *start, end - start + 1));
+            }
+            let tail = format!("\r\n--{}--\r\n", boundary);
+            total += tail.len() as u64;
+            parts.push((Bytes::from(tail), 0, 0));
+            (StatusCode::PARTIAL_CONTENT, total)
+        }
+    };
+    response_headers.insert(header::CONTENT_LENGTH, content_length.to_string().parse().unwrap());
+    // HEAD 只返回响应头，不打开文件
+    if method == Method::HEAD {
+        return Ok((status, response_headers).into_response());
+    }
+
+    let file = tokio::fs::File::open(a_path).await.map_err(|e| AppError::io(epath, e))?;
+    if let [(_, start, len)] = parts[..] {
+        return Ok((status, response_headers, Body::from_stream(read_range(file, start, len).await?)).into_response());
+    }
+    let (tx, rx) = mpsc::channel::<Result<Bytes, io::Error>>(8);
+    tokio::spawn(async move {
+        let mut file = file;
+        let mut buf = vec![0u8; 64 * 1024];
+        for (head, start, len) in parts {
+            if !head.is_empty() && tx.send(Ok(head)).await.is_err() {
+                return;
+            }
+            if len == 0 {
+                continue;
+            }
+            if let Err(e) = file.seek(SeekFrom::Start(start)).await {
+                let _ = tx.send(Err(e)).await;
+                return;
+            }
+            let mut left = len;
+            while left > 0 {
+                let want = left.min(buf.len() as u64) as usize;
+                let chunk = match file.read(&mut buf[..want]).await {
+                    // 文件在发送过程中被截断
+                    Ok(0) => Err(io::ErrorKind::UnexpectedEof.into()),
+                    Ok(n) => {
+                        left -= n as u64;
+                        Ok(Bytes::copy_from_slice(&buf[..n]))
+                    }
+                    Err(e) => Err(e),
+                };
+                let failed = chunk.is_err();
+                if tx.send(chunk).await.is_err() || failed {
+                    return;
+                }
+            }
+        }
+    });
+    Ok((status, response_headers, Body::from_stream(ReceiverStream::new(rx))).into_response())
+}
+
+// 6. 核心逻辑：Seek + Take (利用标准库的高性能实现)
+async fn read_range(
+    mut file: File,
+    start: u64,
+    len: u64,
+) -> Result<ReaderStream<Take<File>>, AppError> {
+    if start > 0 {
+        file.seek(SeekFrom::Start(start)).await.map_err(|e| AppError::io("seek", e))?;
+    }
+    // 关键点：file.take(len) 会限制读取长度，并且所有权被移交给 ReaderStream
+    Ok(ReaderStream::new(file.take(len)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    // 区间的三种写法：`a-b`、`a-`、`-n`
+    #[derive(Debug, Clone)]
+    enum Spec {
+        Bounded(u64, u64),
+        From(u64),
+        Suffix(u64),
+    }
+
+    impl Spec {
+        fn header(&self) -> String {
+            match self {
+                Spec::Bounded(start, end) => format!("{}-{}", start, end),
+                Spec::From(start) => format!("{}-", start),
+                Spec::Suffix(n) => format!("-{}", n),
+            }
+        }
+
+        // 按 RFC 9110 直接算出在大小为 size 的文件里覆盖的字节
+        fn covers(&self, size: u64) -> std::ops::Range<u64> {
+            match *self {
+                Spec::Bounded(start, end) => start.min(size)..end.saturating_add(1).min(size),
+                Spec::From(start) => start.min(size)..size,
+                Spec::Suffix(n) => size.saturating_sub(n)..size,
+            }
+        }
+    }
+
+    fn spec() -> impl Strategy<Value = Spec> {
+        prop_oneof![
+            (0..300u64, 0..300u64).prop_map(|(start, len)| Spec::Bounded(start, start + len)),
+            (0..300u64).prop_map(Spec::From),
+            (0..300u64).prop_map(Spec::Suffix),
+        ]
+    }
+
+    proptest! {
+        // 区间乱序、重叠、越界时，返回的区间都在文件内、非空、有序且互不相邻，并且正好覆盖请求的字节
+        #[test]
+        fn ranges_stay_within_file(size in 0..200u64, specs in prop::collection::vec(spec(), 1..8)) {
+            let header = format!("bytes={}", specs.iter().map(Spec::header).collect::<Vec<_>>().join(","));
+            let mut expected = vec![false; size as usize];
+            for spec in &specs {
+                for i in spec.covers(size) {
+                    expected[i as usize] = true;
+                }
+            }
+            match parse_ranges(&header, size) {
+                Ranges::Partial(ranges) => {
+                    let mut covered = vec![false; size as usize];
+                    for (i, &(start, end)) in ranges.iter().enumerate() {
+                        prop_assert!(start <= end && end < size, "{:?} of {} for {}", (start, end), size, &header);
+                        if i > 0 {
+                            prop_assert!(start > ranges[i - 1].1 + 1, "{:?} not merged for {}", &ranges, &header);
+                        }
+                        for i in start..=end {
+                            covered[i as usize] = true;
+                        }
+                    }
+                    prop_assert_eq!(covered, expected);
+                }
+                Ranges::Unsatisfiable => prop_assert!(!expected.contains(&true), "{} is satisfiable for {}", &header, size),
+                Ranges::Full => prop_assert!(false, "{} ignored for {}", &header, size),
+            }
+        }
+
+        // 空文件上任何合法的区间都不可满足
+        #[test]
+        fn empty_file_is_unsatisfiable(specs in prop::collection::vec(spec(), 1..8)) {
+            let header = format!("bytes={}", specs.iter().map(Spec::header).collect::<Vec<_>>().join(","));
+            prop_assert_eq!(parse_ranges(&header, 0), Ranges::Unsatisfiable);
+        }
+    }
+
+    #[test]
+    fn parses_range_headers() {
+        let cases: &[(&str, u64, Ranges)] = &[
+            ("bytes=0-99", 1000, Ranges::Partial(vec![(0, 99)])),
+            ("bytes=-500", 1000, Ranges::Partial(vec![(500, 999)])),
+            ("bytes=-5000", 1000, Ranges::Partial(vec![(0, 999)])),
+            ("bytes=900-2000", 1000, Ranges::Partial(vec![(900, 999)])),
+            ("bytes=500-599,0-99,50-150", 1000, Ranges::Partial(vec![(0, 150), (500, 599)])),
+            ("bytes=0-1,,2-3", 1000, Ranges::Partial(vec![(0, 3)])),
+            ("bytes=1000-", 1000, Ranges::Unsatisfiable),
+            ("bytes=1000-1001,2000-", 1000, Ranges::Unsatisfiable),
+            ("bytes=-0", 1000, Ranges::Unsatisfiable),
+            ("bytes=0-", 0, Ranges::Unsatisfiable),
+            ("bytes=-1", 0, Ranges::Unsatisfiable),
+            // 语法错误和不认识的单位按 RFC 9110 忽略
+            ("bytes=5-1", 1000, Ranges::Full),
+            ("bytes=+1-2", 1000, Ranges::Full),
+            ("bytes=a-b", 1000, Ranges::Full),
+            ("bytes=", 1000, Ranges::Full),
+            ("items=0-1", 1000, Ranges::Full),
+        ];
+        for (header, size, expected) in cases {
+            assert_eq!(&parse_ranges(header, *size), expected, "{} of {}", header, size);
+        }
+        let many = format!("bytes={}", vec!["0-0"; MAX_RANGES + 1].join(","));
+        assert_eq!(parse_ranges(&many, 1000), Ranges::Full);
+    }
+
+    #[test]
+    fn encodes_download_names() {
+        let cases = [
+            ("attachment", "report.pdf", "attachment; filename=\"report.pdf\""),
+            ("inline", "a b.txt", "inline; filename=\"a b.txt\""),
+            ("attachment", "a\"b.txt", "attachment; filename=\"a_b.txt\"; filename*=UTF-8''a%22b.txt"),
+            ("attachment", "back\\slash.txt", "attachment; filename=\"back_slash.txt\"; filename*=UTF-8''back%5Cslash.txt"),
+            ("attachment", "100%.txt", "attachment; filename=\"100_.txt\"; filename*=UTF-8''100%25.txt"),
+            // 换行和其它控制字符不能出现在头里，兜底名替换掉，编码名里按字节编码
+            ("attachment", "a\r\nb.txt", "attachment; filename=\"a__b.txt\"; filename*=UTF-8''a%0D%0Ab.txt"),
+            ("attachment", "报告.pdf", "attachment; filename=\"__.pdf\"; filename*=UTF-8''%E6%8A%A5%E5%91%8A.pdf"),
+            (
+                "inline",
+                "naïve 文件 (1).txt",
+                "inline; filename=\"na_ve __ (1).txt\"; filename*=UTF-8''na%C3%AFve%20%E6%96%87%E4%BB%B6%20%281%29.txt",
+            ),
+        ];
+        for (kind, filename, expected) in cases {
+            assert_eq!(content_disposition(kind, filename), expected, "{:?}", filename);
+        }
+    }
+
+    #[test]
+    fn unsatisfiable_reports_size() {
+        let response = AppError::RangeNotSatisfiable(0).into_response();
+        assert_eq!(response.status(), StatusCode::RANGE_NOT_SATISFIABLE);
+        assert_eq!(response.headers()[header::CONTENT_RANGE], "bytes */0");
+    }
+}
@@ -1,5 +1,192 @@
+use crate::paths::PathError;
+use crate::upload::UploadError;
+use axum::Json;
+use axum::http::{HeaderValue, StatusCode, header};
+use axum::response::{IntoResponse, Response};
+use serde_json::json;
+use std::fmt;
+use std::io;
 
-#[allow(dead_code)]
+/// 接口统一的错误类型，响应体为 `{"code": 状态码, "error": 错误码, "message": 说明, "data": null}`，
+/// `error` 是稳定的机器可读错误码，脚本应该依据它而不是 `message` 判断失败原因
+#[derive(Debug)]
 pub(crate) enum AppError {
+    BadRequest(String),
+    /// 未登录，认证中间件会补上 `WWW-Authenticate`
+    Unauthorized(String),
+    Forbidden(String),
+    NotFound(String),
+    Conflict(String),
+    PreconditionFailed(String),
+    PayloadTooLarge(String),
+    UnsupportedMediaType(String),
+    /// 请求的范围都在文件之外，带上文件大小
+    RangeNotSatisfiable(u64),
+    /// tus checksum 扩展规定校验和不一致时返回 460
+    ChecksumMismatch(String),
+    /// 功能没有开启，按 404 处理
+    Disabled(String),
+    Path(PathError),
+    Upload(UploadError),
+    Io(String, io::Error),
+    Internal(String),
 }
 
+impl AppError {
+    /// 带上出错对象的 io 错误，状态码按 [`io::ErrorKind`] 决定
+    pub(crate) fn io(context: impl Into<String>, e: io::Error) -> AppError {
+        AppError::Io(context.into(), e)
+    }
+
+    pub(crate) fn status(&self) -> StatusCode {
+        match self {
+            AppError::BadRequest(_) => StatusCode::BAD_REQUEST,
+            AppError::Unauthorized(_) => StatusCode::UNAUTHORIZED,
+            AppError::Forbidden(_) => StatusCode::FORBIDDEN,
+            AppError::NotFound(_) | AppError::Disabled(_) => StatusCode::NOT_FOUND,
+            AppError::Conflict(_) => StatusCode::CONFLICT,
+            AppError::PreconditionFailed(_) => StatusCode::PRECONDITION_FAILED,
+            AppError::PayloadTooLarge(_) => StatusCode::PAYLOAD_TOO_LARGE,
+            AppError::UnsupportedMediaType(_) => StatusCode::UNSUPPORTED_MEDIA_TYPE,
+            AppError::RangeNotSatisfiable(_) => StatusCode::RANGE_NOT_SATISFIABLE,
+            AppError::ChecksumMismatch(_) => StatusCode::from_u16(460).unwrap(),
+            AppError::Path(e) => match e {
+                PathError::Traversal(_) | PathError::InvalidName(_) => StatusCode::BAD_REQUEST,
+                PathError::Root | PathError::SymlinkDenied(_) | PathError::OutsideRoot(_) => StatusCode::FORBIDDEN,
+                PathError::NotFound(_) => StatusCode::NOT_FOUND,
+                PathError::Io(_, e) => io_status(e.kind()),
+            },
+            AppError::Upload(e) => e.reason.status(),
+            AppError::Io(_, e) => io_status(e.kind()),
+            AppError::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    /// 机器可读的错误码
+    pub(crate) fn code(&self) -> &'static str {
+        match self {
+            AppError::BadRequest(_) => "bad_request",
+            AppError::Unauthorized(_) => "unauthorized",
+            AppError::Forbidden(_) => "forbidden",
+            AppError::NotFound(_) => "not_found",
+            AppError::Conflict(_) => "conflict",
+            AppError::PreconditionFailed(_) => "precondition_failed",
+            AppError::PayloadTooLarge(_) => "too_large",
+            AppError::UnsupportedMediaType(_) => "unsupported_media_type",
+            AppError::RangeNotSatisfiable(_) => "range_not_satisfiable",
+            AppError::ChecksumMismatch(_) => "checksum_mismatch",
+            AppError::Disabled(_) => "disabled",
+            AppError::Path(e) => match e {
+                PathError::Traversal(_) => "path_traversal",
+                PathError::InvalidName(_) => "invalid_name",
+                PathError::Root => "root_directory",
+                PathError::NotFound(_) => "not_found",
+                PathError::SymlinkDenied(_) => "symlink_denied",
+                PathError::OutsideRoot(_) => "outside_root",
+                PathError::Io(_, e) => io_code(e.kind()),
+            },
+            AppError::Upload(e) => e.reason.code(),
+            AppError::Io(_, e) => io_code(e.kind()),
+            AppError::Internal(_) => "internal",
+        }
+    }
+}
+
+fn io_status(kind: io::ErrorKind) -> StatusCode {
+    match kind {
+        io::ErrorKind::NotFound => StatusCode::NOT_FOUND,
+        io::ErrorKind::PermissionDenied | io::ErrorKind::ReadOnlyFilesystem => StatusCode::FORBIDDEN,
+        io::ErrorKind::AlreadyExists | io::ErrorKind::DirectoryNotEmpty => StatusCode::CONFLICT,
+        io::ErrorKind::StorageFull | io::ErrorKind::QuotaExceeded => StatusCode::INSUFFICIENT_STORAGE,
+        io::ErrorKind::FileTooLarge => StatusCode::PAYLOAD_TOO_LARGE,
+        io::ErrorKind::InvalidInput | io::ErrorKind::InvalidFilename | io::ErrorKind::NotADirectory | io::ErrorKind::IsADirectory => {
+            StatusCode::BAD_REQUEST
+        }
+        _ => StatusCode::INTERNAL_SERVER_ERROR,
+    }
+}
+
+fn io_code(kind: io::ErrorKind) -> &'static str {
+    match kind {
+        io::ErrorKind::NotFound => "not_found",
+        io::ErrorKind::PermissionDenied | io::ErrorKind::ReadOnlyFilesystem => "permission_denied",
+        io::ErrorKind::AlreadyExists => "already_exists",
+        io::ErrorKind::DirectoryNotEmpty => "directory_not_empty",
+        io::ErrorKind::StorageFull | io::ErrorKind::QuotaExceeded => "disk_full",
+        io::ErrorKind::FileTooLarge => "too_large",
+        io::ErrorKind::InvalidInput | io::ErrorKind::InvalidFilename => "invalid_name",
+        io::ErrorKind::NotADirectory => "not_a_directory",
+        io::ErrorKind::IsADirectory => "is_a_directory",
+        _ => "io",
+    }
+}
+
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AppError::BadRequest(m)
+            | AppError::Unauthorized(m)
+            | AppError::Forbidden(m)
+            | AppError::NotFound(m)
+            | AppError::Conflict(m)
+            | AppError::PreconditionFailed(m)
+            | AppError::PayloadTooLarge(m)
+            | AppError::UnsupportedMediaType(m)
+            | AppError::ChecksumMismatch(m)
+            | AppError::Disabled(m)
+            | AppError::Internal(m) => f.write_str(m),
+            AppError::RangeNotSatisfiable(size) => write!(f, "no requested range is within the size of {}", size),
+            AppError::Path(e) => match e {
+                PathError::Traversal(p) => write!(f, "{} escapes the root directory", p),
+                PathError::InvalidName(p) => write!(f, "{} is not a valid name", p),
+                PathError::Root => f.write_str("the root directory can not be changed"),
+                PathError::NotFound(p) => write!(f, "{} not found", p),
+                PathError::SymlinkDenied(p) => write!(f, "{} is a symlink and symlinks are denied", p),
+                PathError::OutsideRoot(p) => write!(f, "{} points outside the root directory", p),
+                PathError::Io(p, e) => write!(f, "{}, err:{}", p, e),
+            },
+            AppError::Upload(e) => f.write_str(&e.message),
+            AppError::Io(context, e) => write!(f, "{}, err:{}", context, e),
+        }
+    }
+}
+
+impl From<PathError> for AppError {
+    fn from(e: PathError) -> Self {
+        AppError::Path(e)
+    }
+}
+
+impl From<UploadError> for AppError {
+    fn from(e: UploadError) -> Self {
+        AppError::Upload(e)
+    }
+}
+
+impl From<tokio::task::JoinError> for AppError {
+    fn from(e: tokio::task::JoinError) -> Self {
+        AppError::Internal(e.to_string())
+    }
+}
+
+impl IntoResponse for AppError {
+    fn into_response(self) -> Response {
+        let status = self.status();
+        if status.is_server_error() {
+            tracing::error!(">>> {} {}: {}", status, self.code(), self);
+        }
+        let body = Json(json!({
+            "code": status.as_u16(),
+            "error": self.code(),
+            "message": self.to_string(),
+            "data": null,
+        }));
+        let mut response = (status, body).into_response();
+        if let AppError::RangeNotSatisfiable(size) = self
+            && let Ok(range) = HeaderValue::from_str(&format!("bytes */{}", size))
+        {
+            response.headers_mut().insert(header::CONTENT_RANGE, range);
+        }
+        response
+    }
+}
@@ -0,0 +1,267 @@
+use crate::access::{self, Op};
+use crate::auth::{CurrentUser, Role};
+use crate::error::AppError;
+use crate::state::AppState;
+use crate::watcher::{Change, ChangeKind};
+use axum::Extension;
+use axum::extract::{Path, State};
+use axum::response::sse::{Event, KeepAlive, KeepAliveStream, Sse};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::path::PathBuf;
+use std::time::Duration;
+use tokio::sync::broadcast::error::RecvError;
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+
+/// 收到变化后等这么久再推送，期间同一个文件的多次变化合并成一条
+const DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// 推送给客户端的变化，路径和 `EntryInfo.epath` 一样相对根目录
+#[derive(Serialize)]
+struct ChangeEvent {
+    kind: ChangeKind,
+    path: String,
+    from: Option<String>,
+}
+
+type EventStream = KeepAliveStream<ReceiverStream<Result<Event, Infallible>>>;
+
+/// 订阅目录 `epath` 下直接子条目的新建、修改、删除和重命名，以 Server-Sent Events 推送：
+/// 每个 `change` 事件的数据是一个 [`ChangeEvent`]；事件积压丢失时推送 `resync`，客户端应重新拉取列表
+pub(crate) async fn events_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<CurrentUser>,
+    epath: Option<Path<String>>,
+) -> Result<Sse<EventStream>, AppError> {
+    user.require(Role::Viewer)?;
+    let watcher = match &state.watcher {
+        Some(watcher) if state.config.watch => watcher.clone(),
+        _ => return Err(AppError::Disabled("change notifications are disabled".to_string())),
+    };
+    let epath = epath.map(|Path(p)| p).unwrap_or_default();
+    let a_dir = state.paths.resolve_async(&epath).await?;
+    let rpath = access::rule_path(&state.config.root_dirpath, &a_dir);
+    access::check(&state.config.access_rules, &user, &rpath, Op::List)?;
+    if !tokio::fs::metadata(&a_dir).await.is_ok_and(|m| m.is_dir()) {
+        return Err(AppError::BadRequest(format!("{} is not a folder", &epath)));
+    }
+
+    let mut changes = watcher.subscribe();
+    let (tx, rx) = mpsc::channel(64);
+    tracing::info!(">>> {:?} subscribed to changes of {:?}", &user.name, &a_dir);
+    tokio::spawn(async move {
+        loop {
+            let mut pending = Pending::default();
+            // 客户端断开后结束订阅
+            let first = tokio::select! {
+                _ = tx.closed() => return,
+                r = changes.recv() => r,
+            };
+            let mut lagged = matches!(first, Err(RecvError::Lagged(_)));
+            match first {
+                Ok(change) => pending.push(&a_dir, change),
+                Err(RecvError::Lagged(_)) => {}
+                Err(RecvError::Closed) => return,
+            }
+            let deadline = tokio::time::sleep(DEBOUNCE);
+            tokio::pin!(deadline);
+            loop {
+                tokio::select! {
+                    _ = &mut deadline => break,
+                    r = changes.recv() => match r {
+                        Ok(change) => pending.push(&a_dir, change),
+                        Err(RecvError::Lagged(_)) => lagged = true,
+                        Err(RecvError::Closed) => break,
+                    },
+                }
+            }
+
+            let events = if lagged {
+                vec![Event::default().event("resync").data("")]
+            } else {
+                let state = state.clone();
+                let user = user.clone();
+                tokio::task::spawn_blocking(move || pending.into_events(&state, &user))
+                    .await
+                    .unwrap_or_default()
+            };
+            for event in events {
+                if tx.send(Ok(event)).await.is_err() {
+                    return;
+                }
+            }
+        }
+    });
+    Ok(Sse::new(ReceiverStream::new(rx)).keep_alive(KeepAlive::default()))
+}
+
+/// 防抖期间收集到的变化，按路径合并，保持首次出现的顺序
+#[derive(Default)]
+struct Pending {
+    changes: Vec<Option<Change>>,
+    by_path: HashMap<PathBuf, usize>,
+}
+
+impl Pending {
+    // 只保留目录下直接子条目的变化；移出目录的重命名按删除处理，移入的按新建处理
+    fn push(&mut self, a_dir: &std::path::Path, change: Change) {
+        // 配对的重命名之前已经按移出、移入记下的删除和新建作废
+        if change.kind == ChangeKind::Rename
+            && let Some(tracker) = change.tracker
+        {
+            for slot in &mut self.changes {
+                if slot.as_ref().is_some_and(|c| c.tracker == Some(tracker)) {
+                    let stale = slot.take().unwrap();
+                    self.by_path.remove(&stale.path);
+                }
+            }
+        }
+        let inside = |p: &std::path::Path| p.parent() == Some(a_dir);
+        let change = match (change.kind, &change.from) {
+            (ChangeKind::Rename, Some(from)) => match (inside(from), inside(&change.path)) {
+                (true, true) => change,
+                (true, false) => Change {
+                    kind: ChangeKind::Remove,
+                    path: from.clone(),
+                    from: None,
+                    tracker: None,
+                },
+                (false, true) => Change {
+                    kind: ChangeKind::Create,
+                    path: change.path,
+                    from: None,
+                    tracker: None,
+                },
+                (false, false) => return,
+            },
+            _ if inside(&change.path) => change,
+            _ => return,
+        };
+
+        let Some(&i) = self.by_path.get(&change.path) else {
+            self.by_path.insert(change.path.clone(), self.changes.len());
+            self.changes.push(Some(change));
+            return;
+        };
+        let prev = self.changes[i].as_ref().map(|c| c.kind);
+        self.changes[i] = match (prev, change.kind) {
+            // 新建后接着写入仍然是新建，新建后又删掉就当没发生过
+            (Some(ChangeKind::Create), ChangeKind::Modify) => return,
+            (Some(ChangeKind::Create), ChangeKind::Remove) => {
+                self.by_path.remove(&change.path);
+                None
+            }
+            _ => Some(change),
+        };
+    }
+
+    // 和列表接口一样，跳过对用户隐藏的条目、状态目录和不满足符号链接策略的条目
+    fn into_events(self, state: &AppState, user: &CurrentUser) -> Vec<Event> {
+        let root = &state.config.root_dirpath;
+        let visible = |p: &std::path::Path| {
+            !access::is_hidden(&state.config.access_rules, user, &access::rule_path(root, p)) && state.paths.permits(p)
+        };
+        let relative = |p: &std::path::Path| p.strip_prefix(root).unwrap_or(p).to_string_lossy().to_string();
+        self.changes
+            .into_iter()
+            .flatten()
+            .filter(|c| visible(&c.path) && c.from.as_deref().is_none_or(visible))
+            .filter_map(|c| {
+                let event = ChangeEvent {
+                    kind: c.kind,
+                    path: relative(&c.path),
+                    from: c.from.as_deref().map(relative),
+                };
+                Event::default().event("change").json_data(&event).ok()
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn change(kind: ChangeKind, path: &str, from: Option<&str>, tracker: Option<usize>) -> Change {
+        Change {
+            kind,
+            path: PathBuf::from(path),
+            from: from.map(PathBuf::from),
+            tracker,
+        }
+    }
+
+    fn merged(changes: Vec<Change>) -> Vec<(ChangeKind, String, Option<String>)> {
+        let mut pending = Pending::default();
+        for change in changes {
+            pending.push(std::path::Path::new("/srv/dir"), change);
+        }
+        let display = |p: &std::path::Path| p.to_string_lossy().to_string();
+        pending
+            .changes
+            .into_iter()
+            .flatten()
+            .map(|c| (c.kind, display(&c.path), c.from.as_deref().map(display)))
+            .collect()
+    }
+
+    #[test]
+    fn merges_changes() {
+        use ChangeKind::*;
+        let cases = [
+            // inotify 对目录内的重命名依次发出 From、To、Both，只推送一条重命名
+            (
+                vec![
+                    change(Remove, "/srv/dir/a", None, Some(1)),
+                    change(Create, "/srv/dir/b", None, Some(1)),
+                    change(Rename, "/srv/dir/b", Some("/srv/dir/a"), Some(1)),
+                ],
+                vec![(Rename, "/srv/dir/b", Some("/srv/dir/a"))],
+            ),
+            // 没有配对的 From、To 是移出、移入了根目录
+            (vec![change(Remove, "/srv/dir/a", None, Some(2))], vec![(Remove, "/srv/dir/a", None)]),
+            (vec![change(Create, "/srv/dir/b", None, Some(3))], vec![(Create, "/srv/dir/b", None)]),
+            // 移出、移入订阅的目录
+            (
+                vec![
+                    change(Remove, "/srv/dir/a", None, Some(4)),
+                    change(Create, "/srv/other/a", None, Some(4)),
+                    change(Rename, "/srv/other/a", Some("/srv/dir/a"), Some(4)),
+                ],
+                vec![(Remove, "/srv/dir/a", None)],
+            ),
+            (
+                vec![
+                    change(Remove, "/srv/other/a", None, Some(5)),
+                    change(Create, "/srv/dir/a", None, Some(5)),
+                    change(Rename, "/srv/dir/a", Some("/srv/other/a"), Some(5)),
+                ],
+                vec![(Create, "/srv/dir/a", None)],
+            ),
+            (
+                vec![
+                    change(Create, "/srv/dir/a", None, None),
+                    change(Modify, "/srv/dir/a", None, None),
+                    change(Modify, "/srv/dir/a", None, None),
+                ],
+                vec![(Create, "/srv/dir/a", None)],
+            ),
+            (vec![change(Create, "/srv/dir/a", None, None), change(Remove, "/srv/dir/a", None, None)], vec![]),
+            (
+                vec![change(Modify, "/srv/dir/a", None, None), change(Remove, "/srv/dir/a", None, None)],
+                vec![(Remove, "/srv/dir/a", None)],
+            ),
+            // 子目录里的变化不推送
+            (vec![change(Create, "/srv/dir/sub/a", None, None), change(Remove, "/srv/a", None, None)], vec![]),
+        ];
+        for (changes, expected) in cases {
+            let expected: Vec<_> = expected
+                .into_iter()
+                .map(|(kind, path, from)| (kind, path.to_string(), from.map(str::to_string)))
+                .collect();
+            assert_eq!(merged(changes.clone()), expected, "{:?}", changes);
+        }
+    }
+}
@@ -1,26 +1,31 @@
+use crate::archive::{self, ArchiveFormat};
+use crate::access::{self, Op};
+use crate::download;
+use crate::error::AppError;
+use crate::auth::{CurrentUser, Role};
+use crate::hash::HashAlgo;
+use crate::paths::{self, PathError};
 use crate::state::AppState;
+use crate::upload::{self, ConflictPolicy, Expected, FailReason, UploadError, UploadQuery, UploadResult, UploadStatus, VerifiedStream};
 use crate::utils::format_bytes;
-use axum::Json;
+use axum::{Extension, Json};
 use axum::body::Body;
-use axum::extract::{Multipart, Path, State};
-use axum::http::{HeaderMap, StatusCode, header};
+use axum::extract::{Multipart, Path, Query, State};
+use axum::http::{HeaderMap, HeaderName, HeaderValue, Method, StatusCode, header};
 use axum::response::{Html, IntoResponse, Response};
 use serde::{Deserialize, Serialize};
 use serde_json::{Value, json};
-use std::io::SeekFrom;
 use std::path::PathBuf;
-use tokio::io::AsyncReadExt;
-use tokio::io::{AsyncSeekExt, AsyncWriteExt};
-use tokio_util::io::ReaderStream;
 use askama::Template;
+use globset::{GlobBuilder, GlobMatcher};
 
 // bring trait in scope
 
 #[derive(Serialize)]
 pub(crate) struct ApiResponse {
-    code: i32,
-    message: String,
-    data: Option<Value>,
+    pub(crate) code: i32,
+    pub(crate) message: String,
+    pub(crate) data: Option<Value>,
 }
 
 #[derive(Serialize)]
@@ -33,6 +38,148 @@ pub(crate) struct EntryInfo {
     emodified: u64,
     eaccessed: u64,
     ecreated: u64,
+    /// 只有请求带 `?hash=` 且允许下载的文件才有
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ehash: Option<String>,
+}
+
+impl EntryInfo {
+    pub(crate) fn new(root: &std::path::Path, a_path: &std::path::Path, meta: &std::fs::Metadata, etype: &str) -> Self {
+        let strip_prefix = format!("{}/", root.display());
+        let relative = |p: &std::path::Path| {
+            p.to_string_lossy()
+                .strip_prefix(&strip_prefix)
+                .map_or_else(|| "".to_string(), |p| p.to_string())
+        };
+        let secs = |t: std::io::Result<std::time::SystemTime>| {
+            t.map(|t| {
+                t.duration_since(std::time::UNIX_EPOCH)
+                    .unwrap_or(std::time::Duration::from_secs(0))
+                    .as_secs()
+            })
+            .unwrap_or(0)
+        };
+        EntryInfo {
+            ename: a_path
+                .file_name()
+                .map(|f| f.to_string_lossy().to_string())
+                .unwrap_or_else(|| "Unknown".to_string()),
+            eppath: a_path.parent().map(relative).unwrap_or_default(),
+            epath: relative(a_path),
+            esize: meta.len(),
+            etype: etype.to_string(),
+            emodified: secs(meta.modified()),
+            eaccessed: secs(meta.accessed()),
+            ecreated: secs(meta.created()),
+            ehash: None,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+pub(crate) struct InfoQuery {
+    hash: Option<HashAlgo>,
+    #[serde(default)]
+    sort: SortKey,
+    #[serde(default)]
+    order: SortOrder,
+    #[serde(default)]
+    offset: usize,
+    limit: Option<usize>,
+    /// 名称通配符，例如 `*.tar.gz`，不区分大小写
+    name: Option<String>,
+    /// 名称包含的子串，不区分大小写
+    q: Option<String>,
+    /// 条目类型 f、d 或 u，多个用 `,` 分隔
+    #[serde(rename = "type")]
+    etype: Option<String>,
+}
+
+#[derive(Deserialize, Default, Clone, Copy)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum SortKey {
+    #[default]
+    Name,
+    Size,
+    Modified,
+    Type,
+}
+
+#[derive(Deserialize, Default, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum SortOrder {
+    #[default]
+    Asc,
+    Desc,
+}
+
+/// 列表总数放在响应头里，`data` 仍然是当前页的条目数组
+const TOTAL_COUNT: HeaderName = HeaderName::from_static("x-total-count");
+/// 列表里带校验和时一页最多的条目数，未命中缓存的文件要逐个读完，不能让一个请求读遍整个目录
+const MAX_HASH_ENTRIES: usize = 100;
+
+/// 列表和搜索共用的名称和类型过滤条件
+pub(crate) struct EntryFilter {
+    glob: Option<GlobMatcher>,
+    substring: Option<String>,
+    types: Option<Vec<String>>,
+}
+
+impl EntryFilter {
+    /// `name` 为通配符，`q` 为子串，`etype` 为逗号分隔的类型，都不区分大小写
+    pub(crate) fn new(name: Option<&str>, q: Option<&str>, etype: Option<&str>) -> Result<Self, String> {
+        let glob = match name.filter(|n| !n.is_empty()) {
+            Some(name) => Some(
+                GlobBuilder::new(name)
+                    .case_insensitive(true)
+                    .literal_separator(true)
+                    .build()
+                    .map_err(|e| format!("invalid name glob {}: {}", name, e))?
+                    .compile_matcher(),
+            ),
+            None => None,
+        };
+        let types = match etype.filter(|t| !t.is_empty()) {
+            Some(t) => {
+                let types: Vec<String> = t.split(',').map(|t| t.trim().to_string()).collect();
+                if let Some(bad) = types.iter().find(|t| !["f", "d", "u"].contains(&t.as_str())) {
+                    return Err(format!("invalid type {}, expected f, d or u", bad));
+                }
+                Some(types)
+            }
+            None => None,
+        };
+        Ok(EntryFilter {
+            glob,
+            substring: q.filter(|q| !q.is_empty()).map(str::to_lowercase),
+            types,
+        })
+    }
+
+    pub(crate) fn matches(&self, ename: &str, etype: &str) -> bool {
+        self.types.as_ref().is_none_or(|t| t.iter().any(|t| t == etype))
+            && self.glob.as_ref().is_none_or(|g| g.is_match(ename))
+            && self.substring.as_ref().is_none_or(|q| ename.to_lowercase().contains(q))
+    }
+}
+
+// 排序键相同的条目再按名称（不区分大小写）排，保证分页时顺序稳定
+fn sort_entries(entries: &mut [EntryInfo], key: SortKey, order: SortOrder) {
+    entries.sort_by(|a, b| {
+        let by_key = match key {
+            SortKey::Name => std::cmp::Ordering::Equal,
+            SortKey::Size => a.esize.cmp(&b.esize),
+            SortKey::Modified => a.emodified.cmp(&b.emodified),
+            SortKey::Type => a.etype.cmp(&b.etype).then_with(|| {
+                let ext = |e: &EntryInfo| std::path::Path::new(&e.ename).extension().map(|x| x.to_ascii_lowercase());
+                ext(a).cmp(&ext(b))
+            }),
+        };
+        let ordering = by_key
+            .then_with(|| a.ename.chars().flat_map(char::to_lowercase).cmp(b.ename.chars().flat_map(char::to_lowercase)))
+            .then_with(|| a.ename.cmp(&b.ename));
+        if order == SortOrder::Desc { ordering.reverse() } else { ordering }
+    });
 }
 
 
@@ -41,8 +188,26 @@ pub(crate) struct EntryInfo {
 #[template(path = "index.html")] // using the template in this path, relative
 struct RootTemplate { // the name of the struct can be anything
 }
-pub(crate) async fn root_handler() -> impl IntoResponse {
-    Html(RootTemplate{}.render().unwrap())
+pub(crate) async fn root_handler() -> Result<Html<String>, AppError> {
+    let page = RootTemplate{}.render().map_err(|e| AppError::Internal(format!("render index.html failed: {}", e)))?;
+    Ok(Html(page))
+}
+
+
+/// 告诉前端当前的服务模式，用来隐藏不可用的按钮
+pub(crate) async fn server_mode_handler(State(state): State<AppState>) -> Result<(StatusCode, Json<ApiResponse>), AppError> {
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse {
+            code: 200,
+            message: "OK".to_string(),
+            data: Some(json!({
+                "read_only": state.config.read_only,
+                "upload_only": state.config.upload_only,
+                "auth": !state.config.users.is_empty(),
+            })),
+        }),
+    ))
 }
 
 
@@ -57,279 +222,191 @@ struct Assets;
 // 静态资源服务：/assets/... => static/...
 pub(crate) async fn static_handler(
     Path(path):Path<String>,
-) -> Result<impl IntoResponse ,(StatusCode, Vec<u8>)>{
+) -> Result<impl IntoResponse, AppError>{
     let path = path.trim_start_matches('/');
     match Assets::get(path) {
         Some(file) => {
             let mut headers = HeaderMap::new();
             let mime = mime_guess::from_path(path).first_or_octet_stream();
-            headers.insert("content-type", mime.to_string().parse().unwrap());
+            let content_type = HeaderValue::from_str(mime.as_ref()).map_err(|e| AppError::Internal(format!("invalid content type {}: {}", mime, e)))?;
+            headers.insert(header::CONTENT_TYPE, content_type);
 
             // 可选：缓存一年（生产环境推荐）
-            headers.insert("cache-control", "public, max-age=31536000, immutable".parse().unwrap());
+            headers.insert(header::CACHE_CONTROL, HeaderValue::from_static("public, max-age=31536000, immutable"));
 
            Ok((headers, file.data.to_vec()))
         }
-        None => Err((StatusCode::NOT_FOUND, Vec::new())),
+        None => Err(AppError::NotFound(format!("{} not found", path))),
     }
 }
 
 pub(crate) async fn list_entry_info_handler(
     State(state): State<AppState>,
+    Extension(user): Extension<CurrentUser>,
     entrypath: Option<Path<String>>,
-) -> impl IntoResponse {
+    Query(query): Query<InfoQuery>,
+) -> Result<Response, AppError> {
+    user.require(Role::Viewer)?;
+    let filter = EntryFilter::new(query.name.as_deref(), query.q.as_deref(), query.etype.as_deref()).map_err(AppError::BadRequest)?;
     let r_entry_path = if let Some(Path(p)) = entrypath {
         PathBuf::from(p)
     } else {
         PathBuf::from("")
     };
 
-    let a_entry_path = match state.config.root_dirpath.join(&r_entry_path).canonicalize() {
-        Ok(p) => p,
-        Err(_) => {
-            return (
-                StatusCode::NOT_FOUND,
-                Json(ApiResponse {
-                    code: 404,
-                    message: format!("{} not found", &r_entry_path.display()),
-                    data: None,
-                }),
-            );
-        }
-    };
+    let a_entry_path = state.paths.resolve_async(&r_entry_path.to_string_lossy()).await?;
 
-    let strip_prefix = format!("{}/", &state.config.root_dirpath.display());
+    let rules = &state.config.access_rules;
+    let rpath = access::rule_path(&state.config.root_dirpath, &a_entry_path);
+    access::check(rules, &user, &rpath, Op::List)?;
 
-    if a_entry_path.is_file() || a_entry_path.is_symlink() {
-        let ename = a_entry_path
-            .file_name()
-            .and_then(|f| Some(f.to_string_lossy().to_string()))
-            .unwrap_or_else(|| "Unknown".to_string());
-        let etype = if a_entry_path.is_file() {
-            "f".to_string()
-        } else if a_entry_path.is_symlink() {
-            "l".to_string()
+    let meta = tokio::fs::metadata(&a_entry_path)
+        .await
+        .map_err(|e| AppError::io(r_entry_path.display().to_string(), e))?;
+
+    if !meta.is_dir() {
+        let etype = if meta.is_file() {
+            "f"
+        } else if tokio::fs::symlink_metadata(&a_entry_path).await.is_ok_and(|m| m.is_symlink()) {
+            "l"
         } else {
-            "u".to_string()
+            "u"
         };
-
-        let eppath = a_entry_path
-            .parent()
-            .and_then(|p| Some(p.to_string_lossy().to_string()))
-            .unwrap_or_else(|| "".to_string())
-            .strip_prefix(&strip_prefix)
-            .map_or_else(|| "".to_string(), |p| p.to_string());
-
-        let epath = a_entry_path
-            .to_string_lossy()
-            .to_string()
-            .strip_prefix(&strip_prefix)
-            .map_or_else(|| "".to_string(), |p| p.to_string());
-        let emodified = a_entry_path
-            .metadata()
-            .and_then(|m| {
-                m.modified().map(|m| {
-                    m.duration_since(std::time::UNIX_EPOCH)
-                        .unwrap_or(std::time::Duration::from_secs(0))
-                        .as_secs()
-                })
-            })
-            .unwrap_or_else(|_| 0);
-        let eaccessed = a_entry_path
-            .metadata()
-            .and_then(|m| {
-                m.accessed().map(|m| {
-                    m.duration_since(std::time::UNIX_EPOCH)
-                        .unwrap_or(std::time::Duration::from_secs(0))
-                        .as_secs()
-                })
-            })
-            .unwrap_or_else(|_| 0);
-        let ecreated = a_entry_path
-            .metadata()
-            .and_then(|m| {
-                m.created().map(|m| {
-                    m.duration_since(std::time::UNIX_EPOCH)
-                        .unwrap_or(std::time::Duration::from_secs(0))
-                        .as_secs()
-                })
-            })
-            .unwrap_or_else(|_| 0);
-        let esize = a_entry_path
-            .metadata()
-            .and_then(|m| Ok(m.len()))
-            .unwrap_or_else(|_| 0);
-        return (
+        let mut info = EntryInfo::new(&state.config.root_dirpath, &a_entry_path, &meta, etype);
+        info.ehash = entry_hash(&state, &user, &a_entry_path, query.hash).await;
+        return Ok((
             StatusCode::OK,
             Json(ApiResponse {
                 code: 200,
                 message: "OK".to_string(),
-                data: Some(json!(vec![EntryInfo {
-                    ename,
-                    eppath,
-                    epath,
-                    esize,
-                    etype,
-                    emodified,
-                    eaccessed,
-                    ecreated,
-                }])),
+                data: Some(json!(vec![info])),
             }),
-        );
-    } else if a_entry_path.is_dir() {
-        let mut entries_info = vec![];
-        match std::fs::read_dir(&a_entry_path) {
-            Ok(entries) => {
-                for entry in entries {
-                    if let Ok(entry) = entry {
-                        let ename = entry.file_name().to_string_lossy().to_string();
-                        let etype = entry
-                            .file_type()
-                            .and_then(|ft| {
-                                if ft.is_file() {
-                                    Ok("f")
-                                } else if ft.is_dir() {
-                                    Ok("d")
-                                } else {
-                                    Ok("u")
-                                }
-                            })
-                            .map_or_else(|_| "u".to_string(), |s| s.to_string());
-                        let eppath = entry
-                            .path()
-                            .parent()
-                            .and_then(|p| Some(p.to_string_lossy().to_string()))
-                            .unwrap_or_else(|| "".to_string())
-                            .strip_prefix(&strip_prefix)
-                            .map_or_else(|| "".to_string(), |p| p.to_string());
-
-                        let epath = entry
-                            .path()
-                            .to_string_lossy()
-                            .to_string()
-                            .strip_prefix(&strip_prefix)
-                            .map_or_else(|| "".to_string(), |p| p.to_string());
-
-                        let emodified = entry
-                            .metadata()
-                            .and_then(|m| {
-                                m.modified().map(|m| {
-                                    m.duration_since(std::time::UNIX_EPOCH)
-                                        .unwrap_or(std::time::Duration::from_secs(0))
-                                        .as_secs()
-                                })
-                            })
-                            .unwrap_or_else(|_| 0);
-                        let eaccessed = entry
-                            .metadata()
-                            .and_then(|m| {
-                                m.accessed().map(|m| {
-                                    m.duration_since(std::time::UNIX_EPOCH)
-                                        .unwrap_or(std::time::Duration::from_secs(0))
-                                        .as_secs()
-                                })
-                            })
-                            .unwrap_or_else(|_| 0);
-
-                        let ecreated = entry
-                            .metadata()
-                            .and_then(|m| {
-                                m.created().map(|m| {
-                                    m.duration_since(std::time::UNIX_EPOCH)
-                                        .unwrap_or(std::time::Duration::from_secs(0))
-                                        .as_secs()
-                                })
-                            })
-                            .unwrap_or_else(|_| 0);
-                        let esize = entry
-                            .metadata()
-                            .and_then(|m| Ok(m.len()))
-                            .unwrap_or_else(|_| 0);
-
-                        entries_info.push(EntryInfo {
-                            ename,
-                            eppath,
-                            epath,
-                            esize,
-                            etype,
-                            emodified,
-                            eaccessed,
-                            ecreated,
-                        })
-                    }
-                }
-                return (
-                    StatusCode::OK,
-                    Json(ApiResponse {
-                        code: 200,
-                        message: "OK".to_string(),
-                        data: Some(json!(entries_info)),
-                    }),
-                );
-            }
-            Err(_) => {}
-        }
+        )
+            .into_response());
     }
 
-    (
-        StatusCode::NOT_FOUND,
+    if query.hash.is_some() && query.limit.is_none_or(|limit| limit > MAX_HASH_ENTRIES) {
+        return Err(AppError::BadRequest(format!("hash requires a limit of at most {}", MAX_HASH_ENTRIES)));
+    }
+    // 大目录的遍历和逐条读取元数据都放到阻塞线程里
+    let entries = {
+        let (state, user, a_dir) = (state.clone(), user.clone(), a_entry_path.clone());
+        tokio::task::spawn_blocking(move || read_entries(&state, &user, &a_dir, &filter)).await?
+    };
+    let mut entries_info = entries.map_err(|e| AppError::io(r_entry_path.display().to_string(), e))?;
+    let total = entries_info.len();
+    sort_entries(&mut entries_info, query.sort, query.order);
+    let mut entries_info: Vec<EntryInfo> = entries_info
+        .into_iter()
+        .skip(query.offset)
+        .take(query.limit.unwrap_or(usize::MAX))
+        .collect();
+    // 校验和只算当前页的文件
+    if query.hash.is_some() {
+        for info in entries_info.iter_mut().filter(|i| i.etype == "f") {
+            info.ehash = entry_hash(&state, &user, &a_entry_path.join(&info.ename), query.hash).await;
+        }
+    }
+    Ok((
+        StatusCode::OK,
+        [(TOTAL_COUNT, HeaderValue::from(total))],
         Json(ApiResponse {
-            code: 404,
-            message: format!("{} not found", &r_entry_path.display()),
-            data: None,
+            code: 200,
+            message: "OK".to_string(),
+            data: Some(json!(entries_info)),
         }),
     )
+        .into_response())
 }
 
-pub(crate) async fn delete_entry_handler(
-    Path(epath): Path<String>,
-    State(state): State<AppState>,
-) -> impl IntoResponse {
-    let a_entry_path = match state.config.root_dirpath.join(&epath).canonicalize() {
-        Ok(p) => p,
-        Err(_) => {
-            return (
-                StatusCode::NOT_FOUND,
-                Json(ApiResponse {
-                    code: 404,
-                    message: format!("{} not found", &epath),
-                    data: None,
-                }),
-            );
+// 读取目录下当前用户可见且满足过滤条件的条目，每个条目只读取一次元数据
+fn read_entries(state: &AppState, user: &CurrentUser, a_dir: &std::path::Path, filter: &EntryFilter) -> std::io::Result<Vec<EntryInfo>> {
+    let rules = &state.config.access_rules;
+    let mut entries_info = vec![];
+    for entry in std::fs::read_dir(a_dir)?.flatten() {
+        let a_path = entry.path();
+        if access::is_hidden(rules, user, &access::rule_path(&state.config.root_dirpath, &a_path)) {
+            continue;
         }
-    };
-    if a_entry_path.is_file() || a_entry_path.is_symlink() {
-        if std::fs::remove_file(&a_entry_path).is_err() {
-            return (
-                StatusCode::NOT_FOUND,
-                Json(ApiResponse {
-                    code: 404,
-                    message: format!("{} not found", &epath),
-                    data: None,
-                }),
-            );
+        let Ok(meta) = entry.metadata() else {
+            continue;
+        };
+        // 不满足符号链接策略的条目和状态目录不展示
+        if state.paths.is_reserved(&a_path) || (meta.is_symlink() && !state.paths.permits(&a_path)) {
+            continue;
         }
-    } else if a_entry_path.is_dir() {
-        if std::fs::remove_dir_all(&a_entry_path).is_err() {
-            return (
-                StatusCode::NOT_FOUND,
-                Json(ApiResponse {
-                    code: 404,
-                    message: format!("{} not found", &epath),
-                    data: None,
-                }),
-            );
+        let etype = if meta.is_file() {
+            "f"
+        } else if meta.is_dir() {
+            "d"
+        } else {
+            "u"
+        };
+        if !filter.matches(&entry.file_name().to_string_lossy(), etype) {
+            continue;
         }
+        entries_info.push(EntryInfo::new(&state.config.root_dirpath, &a_path, &meta, etype));
+    }
+    Ok(entries_info)
+}
+
+// 列表里的校验和，计算失败或不允许下载时省略
+async fn entry_hash(state: &AppState, user: &CurrentUser, a_path: &std::path::Path, algo: Option<HashAlgo>) -> Option<String> {
+    let algo = algo?;
+    let rpath = access::rule_path(&state.config.root_dirpath, a_path);
+    if !access::allows(&state.config.access_rules, user, &rpath, Op::Download) {
+        return None;
     }
+    state.hashes.hash(a_path, algo).await.ok()
+}
 
-    (
+pub(crate) async fn delete_entry_handler(
+    Path(epath): Path<String>,
+    State(state): State<AppState>,
+    Extension(user): Extension<CurrentUser>,
+) -> Result<(StatusCode, Json<ApiResponse>), AppError> {
+    user.require(Role::Admin)?;
+    let a_entry_path = state.paths.resolve_async(&epath).await?;
+    if state.paths.is_root(&a_entry_path) {
+        return Err(PathError::Root.into());
+    }
+    let rpath = access::rule_path(&state.config.root_dirpath, &a_entry_path);
+    access::check(&state.config.access_rules, &user, &rpath, Op::Delete)?;
+    // 状态目录里有回收站、tus 上传和索引，不能连同上级目录一起移走或删掉
+    if state.config.state_dir.starts_with(&a_entry_path) {
+        return Err(AppError::Conflict(format!("{} contains the state directory", &epath)));
+    }
+    // 开启回收站时移到回收站
+    if let Some(trash) = state.trash.clone() {
+        let item = tokio::task::spawn_blocking(move || trash.put(&a_entry_path, &user))
+            .await?
+            .map_err(|e| AppError::io(&epath, e))?;
+        return Ok((
+            StatusCode::OK,
+            Json(ApiResponse {
+                code: 200,
+                message: format!("moved {} to trash", &epath),
+                data: Some(json!(item)),
+            }),
+        ));
+    }
+    // 符号链接只删除链接本身
+    let is_dir = tokio::fs::symlink_metadata(&a_entry_path).await.is_ok_and(|m| m.is_dir());
+    let removed = if is_dir {
+        tokio::fs::remove_dir_all(&a_entry_path).await
+    } else {
+        tokio::fs::remove_file(&a_entry_path).await
+    };
+    removed.map_err(|e| AppError::io(&epath, e))?;
+
+    Ok((
         StatusCode::OK,
         Json(ApiResponse {
             code: 200,
             message: format!("succcess remove {}", &epath),
             data: None,
         }),
-    )
+    ))
 }
 
 #[derive(Deserialize)]
@@ -339,288 +416,383 @@ pub(crate) struct RenameEntryBody {
 pub(crate) async fn rename_entry_handler(
     Path(epath): Path<String>,
     State(state): State<AppState>,
+    Extension(user): Extension<CurrentUser>,
     Json(body): Json<RenameEntryBody>,
-) -> impl IntoResponse {
-    let o_a_entry_path = match state.config.root_dirpath.join(&epath).canonicalize() {
-        Ok(p) => p,
-        Err(_) => {
-            return (
-                StatusCode::NOT_FOUND,
-                Json(ApiResponse {
-                    code: 404,
-                    message: format!("{} not found", &epath),
-                    data: None,
-                }),
-            );
-        }
-    };
+) -> Result<(StatusCode, Json<ApiResponse>), AppError> {
+    user.require(Role::Admin)?;
+    let o_a_entry_path = state.paths.resolve_async(&epath).await?;
+    if state.paths.is_root(&o_a_entry_path) {
+        return Err(PathError::Root.into());
+    }
     let o_a_entry_ppath = o_a_entry_path.parent().unwrap();
+    // 新名字只能是同一目录下的一个文件名，不能带路径
+    let n_a_entry_path = state.paths.join_name_async(o_a_entry_ppath, &body.newname).await?;
 
-    if std::fs::rename(&o_a_entry_path, o_a_entry_ppath.join(&body.newname)).is_err() {
-        return (
-            StatusCode::NOT_FOUND,
-            Json(ApiResponse {
-                code: 404,
-                message: format!("error {} to {} ", &epath, &body.newname),
-                data: None,
-            }),
-        );
+    // 原路径和新路径都需要允许重命名
+    for a_path in [&o_a_entry_path, &n_a_entry_path] {
+        let rpath = access::rule_path(&state.config.root_dirpath, a_path);
+        access::check(&state.config.access_rules, &user, &rpath, Op::Rename)?;
     }
 
-    (
+    // rename 会直接覆盖同名文件，目标已存在时按冲突处理
+    if tokio::fs::symlink_metadata(&n_a_entry_path).await.is_ok() {
+        return Err(AppError::Conflict(format!("{} already exists", &body.newname)));
+    }
+    tokio::fs::rename(&o_a_entry_path, &n_a_entry_path)
+        .await
+        .map_err(|e| AppError::io(format!("rename {} to {}", &epath, &body.newname), e))?;
+
+    Ok((
         StatusCode::OK,
         Json(ApiResponse {
             code: 200,
             message: format!("success {} to {}", &epath, &body.newname),
             data: None,
         }),
-    )
+    ))
 }
 
 pub(crate) async fn create_entry_handler(
     Path(entrypath): Path<String>,
     State(state): State<AppState>,
-) -> impl IntoResponse {
-    let a_entry_path = state.config.root_dirpath.join(&entrypath);
-    if std::fs::create_dir_all(&a_entry_path).is_err() {
-        return (
-            StatusCode::NOT_FOUND,
-            Json(ApiResponse {
-                code: 404,
-                message: format!("error create {} ", &entrypath),
-                data: None,
-            }),
-        );
-    }
-
-    (
+    Extension(user): Extension<CurrentUser>,
+) -> Result<(StatusCode, Json<ApiResponse>), AppError> {
+    user.require(Role::Uploader)?;
+    let a_entry_path = state.paths.resolve_new_async(&entrypath).await?;
+    let rpath = access::rule_path(&state.config.root_dirpath, &a_entry_path);
+    access::check(&state.config.access_rules, &user, &rpath, Op::Create)?;
+    tokio::fs::create_dir_all(&a_entry_path)
+        .await
+        .map_err(|e| AppError::io(format!("create {}", &entrypath), e))?;
+
+    Ok((
         StatusCode::OK,
         Json(ApiResponse {
             code: 200,
             message: format!("success create {}", &entrypath),
             data: None,
         }),
-    )
+    ))
 }
 
 pub(crate) async fn upload_entry_handler(
     entrypath: Option<Path<String>>,
     State(state): State<AppState>,
+    Extension(user): Extension<CurrentUser>,
+    Query(query): Query<UploadQuery>,
     mut multipart: Multipart,
-) -> impl IntoResponse {
+) -> Result<(StatusCode, Json<ApiResponse>), AppError> {
+    user.require(Role::Uploader)?;
     let r_entry_path = if let Some(Path(p)) = entrypath {
         PathBuf::from(p)
     } else {
         PathBuf::from("")
     };
 
-    let a_entry_path = match state.config.root_dirpath.join(&r_entry_path).canonicalize() {
-        Ok(p) => p,
-        Err(_) => {
-            tracing::warn!(">>> {} not found", &r_entry_path.display());
-            return (
-                StatusCode::NOT_FOUND,
-                Json(ApiResponse {
-                    code: 404,
-                    message: format!("{} not found", &r_entry_path.display()),
-                    data: None,
-                }),
-            );
-        }
-    };
-
-    let mut total_bytes = 0;
-
-    while let Ok(Some(mut field)) = multipart.next_field().await {
+    let a_entry_path = state.paths.resolve_async(&r_entry_path.to_string_lossy()).await.inspect_err(|e| {
+        tracing::warn!(">>> upload to {} rejected: {:?}", &r_entry_path.display(), e);
+    })?;
+
+    let rules = &state.config.access_rules;
+    let rpath = access::rule_path(&state.config.root_dirpath, &a_entry_path);
+    access::check(rules, &user, &rpath, Op::Upload)?;
+
+    let mut results = vec![];
+    let mut folders = vec![];
+    let display = |p: &std::path::Path| access::rule_path(&state.config.root_dirpath, p);
+    loop {
+        let field = match multipart.next_field().await {
+            Ok(Some(field)) => field,
+            Ok(None) => break,
+            // 请求体在两个文件之间中断或超出大小限制，之前的文件已经保存
+            Err(e) => {
+                let e = UploadError::from(e);
+                tracing::warn!(">>> read multipart error: {:?}", &e);
+                results.push(UploadResult::failed("", 0, e));
+                break;
+            }
+        };
         if let Some(file_name) = field.file_name() {
-            let file_name = PathBuf::from(&file_name)
-                .file_name()
-                .map_or_else(|| "unknow".to_string(), |m| m.to_string_lossy().to_string());
-            let save_path = a_entry_path.join(&file_name);
-            tracing::info!(">>> start save {} to {:?}", &file_name, &save_path);
-
-            match tokio::fs::File::create(&save_path).await {
-                Ok(file) => {
-                    let mut stream_writer = tokio::io::BufWriter::new(file);
-                    let mut total_chunk_bytes = 0;
-                    while let Ok(Some(chunk)) = field.chunk().await {
-                        total_chunk_bytes += chunk.len();
-                        // 关键优化 3: 流式读取 (Chunked)
-                        // 只要网络还在传数据，这个循环就会继续。内存中永远只保留当前的一个 chunk。
-                        let _ = stream_writer.write_all(&chunk).await;
-
-                        // 必须 flush 确保缓冲区的数据全部落盘
-                        let _ = stream_writer.flush().await;
-                    }
-                    total_bytes += total_chunk_bytes;
-                    tracing::info!(
-                        "success save file: {:?}, size: {}",
-                        &save_path,
-                        format_bytes(total_chunk_bytes as u64)
-                    );
-                }
+            let file_name = file_name.to_string();
+            let save_path = match upload_target(&state, &user, &a_entry_path, &file_name, &mut folders).await {
+                Ok(p) => p,
                 Err(e) => {
-                    tracing::error!(">>> create {:?} error: {}", &save_path, e);
+                    tracing::warn!(">>> upload {} rejected: {:?}", &file_name, e);
+                    results.push(UploadResult::failed(&file_name, 0, e));
                     continue;
                 }
+            };
+            tracing::info!(">>> start save {} to {:?}", &file_name, &save_path);
+            let result = upload::save(&file_name, &save_path, display, query.policy, field).await;
+            // 请求体已经读不下去了，后面不会再有文件
+            let body_broken = matches!(result.reason, Some(FailReason::TooLarge | FailReason::ClientAborted));
+            results.push(result);
+            if body_broken {
+                break;
             }
         } else {
             tracing::warn!(">>> no name or file_name and skip");
         }
     }
 
-    (
-        StatusCode::OK,
+    let status = upload::overall_status(&results);
+    let saved_bytes: u64 = results.iter().filter(|r| r.path.is_some()).map(|r| r.bytes).sum();
+    let failed = results.iter().filter(|r| r.reason.is_some()).count();
+    let message = if results.is_empty() {
+        "no file in the request".to_string()
+    } else {
+        format!(
+            "upload to {}, {} of {} files saved and total {}",
+            &r_entry_path.display(),
+            results.len() - failed,
+            results.len(),
+            format_bytes(saved_bytes)
+        )
+    };
+    // 每个文件的结果都在 data.files 里，即使全部失败也按结果报告返回，而不是 AppError
+    Ok((
+        status,
         Json(ApiResponse {
-            code: 200,
-            message: format!(
-                "success upload to {} and total {}",
-                &r_entry_path.display(),
-                format_bytes(total_bytes as u64)
-            ),
-            data: None,
+            code: status.as_u16() as i32,
+            message,
+            data: Some(json!({
+                "files": results,
+                "folders": folders.iter().map(|p| display(p)).collect::<Vec<_>>(),
+            })),
         }),
-    )
+    ))
 }
 
-pub(crate) async fn download_entry_handler(
-    Path(entrypath): Path<String>,
+/// 把请求体原样保存到 `epath`，方便脚本用 `curl -T` 上传；缺少的目录会自动创建，
+/// `If-None-Match: *` 表示不覆盖已有文件
+pub(crate) async fn put_upload_handler(
+    Path(epath): Path<String>,
     State(state): State<AppState>,
+    Extension(user): Extension<CurrentUser>,
+    Query(query): Query<UploadQuery>,
     headers: HeaderMap,
-) -> Result<Response, (StatusCode, Json<ApiResponse>)> {
-    let a_entry_path = match state.config.root_dirpath.join(&entrypath).canonicalize() {
-        Ok(p) => p,
-        Err(_) => {
-            return Err((
-                StatusCode::NOT_FOUND,
-                Json(ApiResponse {
-                    code: 404,
-                    message: format!("{} not found", &entrypath),
-                    data: None,
-                }),
-            ));
+    body: Body,
+) -> Result<(StatusCode, Json<ApiResponse>), AppError> {
+    user.require(Role::Uploader)?;
+    let display = |p: &std::path::Path| access::rule_path(&state.config.root_dirpath, p);
+    let failed = |e: UploadError| {
+        tracing::warn!(">>> put upload {} rejected: {:?}", &epath, &e);
+        AppError::Upload(e)
+    };
+    let expected = Expected::from_headers(&headers).map_err(failed)?;
+    let limit = state.config.max_upload_size;
+    if let Some(length) = headers.get(header::CONTENT_LENGTH).and_then(|v| v.to_str().ok()).and_then(|v| v.parse::<u64>().ok())
+        && length > limit
+    {
+        return Err(failed(UploadError::new(
+            FailReason::TooLarge,
+            format!("upload of {} exceeds the limit of {}", format_bytes(length), format_bytes(limit)),
+        )));
+    }
+    let no_overwrite = headers.get(header::IF_NONE_MATCH).is_some_and(|v| v.as_bytes() == b"*");
+
+    let mut folders = vec![];
+    let save_path = upload_target(&state, &user, &state.config.root_dirpath, &epath, &mut folders)
+        .await
+        .map_err(failed)?;
+    if no_overwrite && tokio::fs::symlink_metadata(&save_path).await.is_ok() {
+        return Err(AppError::PreconditionFailed(format!("{} already exists", display(&save_path))));
+    }
+    let policy = if no_overwrite { ConflictPolicy::Fail } else { query.policy };
+    let stream = VerifiedStream::new(body.into_data_stream(), expected, limit);
+    tracing::info!(">>> start save request body to {:?}", &save_path);
+    let result = upload::save(&epath, &save_path, display, policy, stream).await;
+
+    if let Some(reason) = result.reason {
+        if no_overwrite && reason == FailReason::AlreadyExists {
+            return Err(AppError::PreconditionFailed(format!("{} already exists", display(&save_path))));
         }
+        return Err(AppError::Upload(UploadError::new(reason, result.error.unwrap_or_default())));
+    }
+
+    // 新建返回 201，覆盖返回 200，和 HTTP PUT 的语义一致
+    let status = match result.status {
+        UploadStatus::Created | UploadStatus::Renamed => StatusCode::CREATED,
+        _ => StatusCode::OK,
+    };
+    let message = match &result.path {
+        Some(path) => format!("success upload {} and total {}", path, format_bytes(result.bytes)),
+        None => format!("skip upload {}", &epath),
+    };
+    Ok((
+        status,
+        Json(ApiResponse {
+            code: status.as_u16() as i32,
+            message,
+            data: Some(json!({
+                "files": [result],
+                "folders": folders.iter().map(|p| display(p)).collect::<Vec<_>>(),
+            })),
+        }),
+    ))
+}
+
+/// 上传文件夹时浏览器会把 `dir/sub/name` 这样的相对路径放在文件名里，逐级校验并创建中间目录，
+/// 返回文件的保存路径，新建的目录记录到 `folders`
+async fn upload_target(
+    state: &AppState,
+    user: &CurrentUser,
+    a_dir: &std::path::Path,
+    file_name: &str,
+    folders: &mut Vec<PathBuf>,
+) -> Result<PathBuf, UploadError> {
+    let rules = &state.config.access_rules;
+    let segments: Vec<&str> = file_name.split('/').filter(|s| !s.is_empty()).collect();
+    let Some((name, dirs)) = segments.split_last() else {
+        return Err(UploadError::new(FailReason::InvalidName, format!("{} is not a valid name", file_name)));
     };
+    // 先校验所有片段，避免 `new/../x` 这样的名字在被拒绝前已经建出了目录
+    for segment in &segments {
+        paths::validate_name(segment)?;
+    }
+    let mut a_path = a_dir.to_path_buf();
+    for dir in dirs {
+        a_path = state.paths.join_name_async(&a_path, dir).await?;
+        let rpath = access::rule_path(&state.config.root_dirpath, &a_path);
+        match tokio::fs::metadata(&a_path).await {
+            Ok(meta) if meta.is_dir() => continue,
+            Ok(_) => return Err(UploadError::new(FailReason::AlreadyExists, format!("{} is not a folder", &rpath))),
+            Err(_) => {}
+        }
+        if !access::allows(rules, user, &rpath, Op::Create) {
+            return Err(UploadError::new(FailReason::NotAllowed, format!("create is not allowed on {}", &rpath)));
+        }
+        match tokio::fs::create_dir(&a_path).await {
+            Ok(()) => folders.push(a_path.clone()),
+            // 同一请求里的其它文件或并发的请求刚刚创建了它
+            Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists && tokio::fs::metadata(&a_path).await.is_ok_and(|m| m.is_dir()) => {}
+            Err(e) => return Err(e.into()),
+        }
+    }
+    let save_path = state.paths.join_name_async(&a_path, name).await?;
+    let rpath = access::rule_path(&state.config.root_dirpath, &save_path);
+    if !access::allows(rules, user, &rpath, Op::Upload) {
+        return Err(UploadError::new(FailReason::NotAllowed, format!("upload is not allowed on {}", &rpath)));
+    }
+    Ok(save_path)
+}
 
-    if a_entry_path.is_file() {
-        let mut file = match tokio::fs::File::open(&a_entry_path).await {
-            Ok(f) => f,
-            Err(e) => {
-                return Err((
-                    StatusCode::NOT_FOUND,
-                    Json(ApiResponse {
-                        code: 404,
-                        message: format!("{},err:{}", &entrypath, e),
-                        data: None,
-                    }),
-                ));
-            }
-        };
+#[derive(Deserialize)]
+pub(crate) struct DownloadQuery {
+    format: Option<String>,
+}
 
-        let emeta = match a_entry_path.metadata() {
-            Ok(m) => m,
-            Err(_) => {
-                return Err((
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    Json(ApiResponse {
-                        code: 500,
-                        message: format!("{} not get meta", &entrypath),
-                        data: None,
-                    }),
-                ));
-            }
+pub(crate) async fn download_entry_handler(
+    Path(entrypath): Path<String>,
+    State(state): State<AppState>,
+    Extension(user): Extension<CurrentUser>,
+    Query(query): Query<DownloadQuery>,
+    method: Method,
+    headers: HeaderMap,
+) -> Result<Response, AppError> {
+    user.require(Role::Viewer)?;
+    let a_entry_path = state.paths.resolve_async(&entrypath).await?;
+    let rpath = access::rule_path(&state.config.root_dirpath, &a_entry_path);
+    access::check(&state.config.access_rules, &user, &rpath, Op::Download)?;
+    let emeta = tokio::fs::metadata(&a_entry_path).await.map_err(|e| AppError::io(&entrypath, e))?;
+
+    if emeta.is_file() {
+        download::serve_file(&a_entry_path, &entrypath, &emeta, &method, &headers, false).await
+    } else if emeta.is_dir() {
+        let Some(format) = ArchiveFormat::from_query(query.format.as_deref()) else {
+            return Err(AppError::BadRequest(format!(
+                "unsupported format {}, expect zip, tar, tar.gz or tar.zst",
+                query.format.unwrap_or_default()
+            )));
         };
         let ename = a_entry_path
             .file_name()
-            .map_or_else(|| "unknow".to_string(), |m| m.to_string_lossy().to_string());
-        let esize = emeta.len();
-        let mime_type = mime_guess::from_path(&a_entry_path).first_or_octet_stream();
-        // 解析 Range
-        let mut start = 0;
-        let mut end = esize - 1;
-        let mut is_partial = false;
-
-        if let Some(range_header) = headers.get(header::RANGE) {
-            if let Ok(range_str) = range_header.to_str() {
-                if let Some((s, e)) = parse_range(range_str, esize) {
-                    start = s;
-                    end = e;
-                    is_partial = true;
-                } else {
-                    return Err((
-                        StatusCode::RANGE_NOT_SATISFIABLE,
-                        Json(ApiResponse {
-                            code: 416,
-                            message: format!("bytes */{}", esize),
-                            data: None,
-                        }),
-                    ));
-                }
-            }
-        }
-        let content_length = end - start + 1;
-        // 6. 核心逻辑：Seek + Take (利用标准库的高性能实现)
-        if start > 0 {
-            if file.seek(SeekFrom::Start(start)).await.is_err() {
-                return Err((
-                    StatusCode::INTERNAL_SERVER_ERROR,
-                    Json(ApiResponse {
-                        code: 500,
-                        message: format!("{} seek error", &entrypath),
-                        data: None,
-                    }),
-                ));
-            }
-        }
-        // 关键点：file.take(len) 会限制读取长度，并且所有权被移交给 ReaderStream
-        let stream = ReaderStream::new(file.take(content_length));
-        let body = Body::from_stream(stream);
-        // 构建响应头
+            .map_or_else(|| "archive".to_string(), |m| m.to_string_lossy().to_string());
         let mut response_headers = HeaderMap::new();
-        response_headers.insert(header::CONTENT_TYPE, mime_type.as_ref().parse().unwrap());
+        response_headers.insert(header::CONTENT_TYPE, HeaderValue::from_static(format.content_type()));
         response_headers.insert(
             header::CONTENT_DISPOSITION,
-            format!("attachment; filename=\"{}\"", ename)
-                .parse()
-                .unwrap(),
+            download::content_disposition("attachment", &format!("{}.{}", ename, format.extension())),
         );
-        response_headers.insert(header::ACCEPT_RANGES, "bytes".parse().unwrap());
-        response_headers.insert(
-            header::CONTENT_LENGTH,
-            content_length.to_string().parse().unwrap(),
-        );
-
-        if is_partial {
-            response_headers.insert(
-                header::CONTENT_RANGE,
-                format!("bytes {}-{}/{}", start, end, esize)
-                    .parse()
-                    .unwrap(),
-            );
-            Ok((StatusCode::PARTIAL_CONTENT, response_headers, body).into_response())
-        } else {
-            Ok((StatusCode::OK, response_headers, body).into_response())
+        // 打包是边读边压缩的，大小事先不知道，HEAD 不需要真的打包
+        if method == Method::HEAD {
+            let body = Body::from_stream(tokio_stream::empty::<Result<bytes::Bytes, std::io::Error>>());
+            return Ok((StatusCode::OK, response_headers, body).into_response());
         }
+        // 打包时跳过规则不允许下载或对当前用户隐藏的条目
+        let config = state.config.clone();
+        let paths = state.paths.clone();
+        let filter: archive::EntryFilter = Box::new(move |p| {
+            let rpath = access::rule_path(&config.root_dirpath, p);
+            access::allows(&config.access_rules, &user, &rpath, Op::Download) && paths.permits(p)
+        });
+        let body = archive::stream_dir(a_entry_path, format, filter);
+        Ok((StatusCode::OK, response_headers, body).into_response())
     } else {
-        todo!()
-    }
-} // 解析 Range 的辅助函数 (保持简单有效)
-fn parse_range(range: &str, size: u64) -> Option<(u64, u64)> {
-    let range = range.strip_prefix("bytes=")?;
-    let parts: Vec<&str> = range.split('-').collect();
-    if parts.len() != 2 {
-        return None;
+        Err(AppError::NotFound(format!("{} is not a file or directory", &entrypath)))
     }
+}
 
-    let start = parts[0].parse::<u64>().ok()?;
-    let end = if parts[1].is_empty() {
-        size - 1
-    } else {
-        parts[1].parse::<u64>().ok()?.min(size - 1)
-    };
+/// 在浏览器里预览文件：图片、音视频、PDF 内联显示，文本（包括 HTML、SVG）按纯文本显示，其它类型仍按附件下载
+pub(crate) async fn view_entry_handler(
+    Path(entrypath): Path<String>,
+    State(state): State<AppState>,
+    Extension(user): Extension<CurrentUser>,
+    method: Method,
+    headers: HeaderMap,
+) -> Result<Response, AppError> {
+    user.require(Role::Viewer)?;
+    let a_entry_path = state.paths.resolve_async(&entrypath).await?;
+    let rpath = access::rule_path(&state.config.root_dirpath, &a_entry_path);
+    access::check(&state.config.access_rules, &user, &rpath, Op::Download)?;
+    match tokio::fs::metadata(&a_entry_path).await {
+        Ok(emeta) if emeta.is_file() => download::serve_file(&a_entry_path, &entrypath, &emeta, &method, &headers, true).await,
+        Ok(_) => Err(AppError::BadRequest(format!("{} is not a file", &entrypath))),
+        Err(e) => Err(AppError::io(&entrypath, e)),
+    }
+}
 
-    if start > end {
-        return None;
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::AppConfig;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn rejects_upload_traversal() {
+        let tmp = tempfile::tempdir().unwrap();
+        let root = tmp.path().canonicalize().unwrap().join("root");
+        std::fs::create_dir_all(root.join("dir")).unwrap();
+        std::fs::create_dir_all(tmp.path().join("outside")).unwrap();
+        std::fs::write(root.join("file"), b"x").unwrap();
+        #[cfg(unix)]
+        std::os::unix::fs::symlink(tmp.path().join("outside"), root.join("out_link")).unwrap();
+        let state = AppState::new(Arc::new(AppConfig::for_root(&root)));
+        let user = CurrentUser { name: None, role: None };
+
+        let cases = [
+            ("a.txt", Ok("a.txt")),
+            ("/dir/a.txt", Ok("dir/a.txt")),
+            ("new/sub//a.txt", Ok("new/sub/a.txt")),
+            ("../a.txt", Err(FailReason::InvalidName)),
+            ("dir/../../a.txt", Err(FailReason::InvalidName)),
+            ("made/../a.txt", Err(FailReason::InvalidName)),
+            ("./a.txt", Err(FailReason::InvalidName)),
+            ("..", Err(FailReason::InvalidName)),
+            ("/", Err(FailReason::InvalidName)),
+            ("", Err(FailReason::InvalidName)),
+            ("a\0b", Err(FailReason::InvalidName)),
+            ("file/a.txt", Err(FailReason::AlreadyExists)),
+            #[cfg(unix)]
+            ("out_link/a.txt", Err(FailReason::NotAllowed)),
+        ];
+        for (name, expected) in cases {
+            let mut folders = vec![];
+            let target = upload_target(&state, &user, &root, name, &mut folders).await;
+            assert_eq!(target.map_err(|e| e.reason), expected.map(|p| root.join(p)), "{}", name);
+        }
+        // 被拒绝的名字不会先建出目录
+        assert!(!root.join("made").exists());
+        assert!(!tmp.path().join("a.txt").exists() && !tmp.path().join("outside/a.txt").exists());
     }
-    Some((start, end))
 }
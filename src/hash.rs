@@ -0,0 +1,216 @@
+use crate::access::{self, Op};
+use crate::auth::{CurrentUser, Role};
+use crate::error::AppError;
+use crate::handlers::ApiResponse;
+use crate::state::AppState;
+use axum::{Extension, Json};
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value, json};
+use sha2::digest::DynDigest;
+use std::collections::HashMap;
+use std::io::{self, Read};
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// 缓存最多保留的条目数，超出后随便淘汰一条
+const CACHE_CAPACITY: usize = 10_000;
+
+/// 支持的校验和算法
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum HashAlgo {
+    Md5,
+    Sha1,
+    Sha256,
+    Blake3,
+}
+
+impl HashAlgo {
+    pub(crate) fn name(self) -> &'static str {
+        match self {
+            HashAlgo::Md5 => "md5",
+            HashAlgo::Sha1 => "sha1",
+            HashAlgo::Sha256 => "sha256",
+            HashAlgo::Blake3 => "blake3",
+        }
+    }
+
+    fn hasher(self) -> Hasher {
+        match self {
+            HashAlgo::Md5 => Hasher::Digest(Box::new(md5::Md5::default())),
+            HashAlgo::Sha1 => Hasher::Digest(Box::new(sha1::Sha1::default())),
+            HashAlgo::Sha256 => Hasher::Digest(Box::new(sha2::Sha256::default())),
+            HashAlgo::Blake3 => Hasher::Blake3(Box::default()),
+        }
+    }
+}
+
+// blake3 不实现 RustCrypto 的 digest trait，单独处理
+enum Hasher {
+    Digest(Box<dyn DynDigest + Send>),
+    Blake3(Box<blake3::Hasher>),
+}
+
+impl Hasher {
+    fn update(&mut self, data: &[u8]) {
+        match self {
+            Hasher::Digest(d) => d.update(data),
+            Hasher::Blake3(h) => {
+                h.update(data);
+            }
+        }
+    }
+
+    fn finalize(self) -> Vec<u8> {
+        match self {
+            Hasher::Digest(d) => d.finalize().to_vec(),
+            Hasher::Blake3(h) => h.finalize().as_bytes().to_vec(),
+        }
+    }
+}
+
+impl FromStr for HashAlgo {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "md5" => Ok(HashAlgo::Md5),
+            "sha1" => Ok(HashAlgo::Sha1),
+            "sha256" => Ok(HashAlgo::Sha256),
+            "blake3" => Ok(HashAlgo::Blake3),
+            other => Err(format!("unsupported algo {}, expected md5, sha1, sha256 or blake3", other)),
+        }
+    }
+}
+
+struct CachedHash {
+    size: u64,
+    modified: SystemTime,
+    hash: String,
+}
+
+/// 按 (路径, 算法) 缓存计算结果，文件大小或修改时间变化后缓存自动失效
+#[derive(Default)]
+pub(crate) struct HashCache {
+    entries: Mutex<HashMap<(PathBuf, HashAlgo), CachedHash>>,
+}
+
+impl HashCache {
+    /// 返回文件的十六进制校验和，未命中缓存时在阻塞线程中流式计算
+    pub(crate) async fn hash(&self, a_path: &std::path::Path, algo: HashAlgo) -> io::Result<String> {
+        let meta = tokio::fs::metadata(a_path).await?;
+        if !meta.is_file() {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "not a file"));
+        }
+        let (size, modified) = (meta.len(), meta.modified()?);
+        let key = (a_path.to_path_buf(), algo);
+        if let Some(cached) = self.entries.lock().unwrap().get(&key)
+            && cached.size == size
+            && cached.modified == modified
+        {
+            return Ok(cached.hash.clone());
+        }
+
+        let path = key.0.clone();
+        let (hash, unchanged) = tokio::task::spawn_blocking(move || -> io::Result<(String, bool)> {
+            let hash = hash_file(&path, algo)?;
+            // 计算期间文件被改写时结果照常返回，但不进缓存
+            let meta = std::fs::metadata(&path)?;
+            Ok((hash, meta.len() == size && meta.modified()? == modified))
+        })
+        .await
+        .map_err(io::Error::other)??;
+        tracing::info!(">>> {} of {:?} computed", algo.name(), &key.0);
+
+        if unchanged {
+            let mut entries = self.entries.lock().unwrap();
+            if entries.len() >= CACHE_CAPACITY && !entries.contains_key(&key) {
+                let evicted = entries.keys().next().cloned();
+                if let Some(evicted) = evicted {
+                    entries.remove(&evicted);
+                }
+            }
+            entries.insert(key, CachedHash { size, modified, hash: hash.clone() });
+        }
+        Ok(hash)
+    }
+}
+
+fn hash_file(a_path: &std::path::Path, algo: HashAlgo) -> io::Result<String> {
+    let mut file = std::fs::File::open(a_path)?;
+    let mut hasher = algo.hasher();
+    let mut buf = vec![0u8; 64 * 1024];
+    loop {
+        let n = file.read(&mut buf)?;
+        if n == 0 {
+            break;
+        }
+        hasher.update(&buf[..n]);
+    }
+    Ok(hasher.finalize().iter().map(|b| format!("{:02x}", b)).collect())
+}
+
+#[derive(Deserialize)]
+pub(crate) struct HashQuery {
+    /// 逗号分隔的算法列表，默认 sha256
+    algo: Option<String>,
+}
+
+/// 计算文件校验和，例如 `GET /hash/releases/app.tar.gz?algo=sha256,blake3`
+pub(crate) async fn hash_entry_handler(
+    Path(epath): Path<String>,
+    State(state): State<AppState>,
+    Extension(user): Extension<CurrentUser>,
+    Query(query): Query<HashQuery>,
+) -> Result<(StatusCode, Json<ApiResponse>), AppError> {
+    user.require(Role::Viewer)?;
+    let mut algos = vec![];
+    for name in query.algo.as_deref().unwrap_or("sha256").split(',') {
+        let algo: HashAlgo = name.parse().map_err(AppError::BadRequest)?;
+        if !algos.contains(&algo) {
+            algos.push(algo);
+        }
+    }
+
+    let a_entry_path = state.paths.resolve_async(&epath).await?;
+    let rpath = access::rule_path(&state.config.root_dirpath, &a_entry_path);
+    access::check(&state.config.access_rules, &user, &rpath, Op::Download)?;
+    let meta = tokio::fs::metadata(&a_entry_path)
+        .await
+        .map_err(|e| AppError::io(&epath, e))?;
+    if !meta.is_file() {
+        return Err(AppError::BadRequest(format!("{} is not a file", &epath)));
+    }
+
+    let mut hashes = Map::new();
+    for algo in algos {
+        let hash = state
+            .hashes
+            .hash(&a_entry_path, algo)
+            .await
+            .map_err(|e| AppError::io(format!("{} of {}", algo.name(), &epath), e))?;
+        hashes.insert(algo.name().to_string(), Value::String(hash));
+    }
+    let modified = meta
+        .modified()
+        .ok()
+        .and_then(|m| m.duration_since(UNIX_EPOCH).ok())
+        .map_or(0, |d| d.as_secs());
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse {
+            code: 200,
+            message: "OK".to_string(),
+            data: Some(json!({
+                "path": epath,
+                "size": meta.len(),
+                "modified": modified,
+                "hashes": hashes,
+            })),
+        }),
+    ))
+}
@@ -0,0 +1,331 @@
+use crate::config::AppConfig;
+use crate::paths::PathResolver;
+use crate::watcher::{Change, ChangeKind};
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::fs::Metadata;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, UNIX_EPOCH};
+use tantivy::collector::{DocSetCollector, TopDocs};
+use tantivy::query::{BooleanQuery, Occur, Query, QueryParser, TermQuery};
+use tantivy::schema::{Field, INDEXED, IndexRecordOption, STORED, STRING, Schema, TEXT, Value};
+use tantivy::snippet::SnippetGenerator;
+use tantivy::{Index, IndexReader, IndexWriter, ReloadPolicy, TantivyDocument, Term, doc};
+use tokio::sync::broadcast;
+use tokio::sync::broadcast::error::RecvError;
+use walkdir::WalkDir;
+
+/// 超过这个大小的文件不索引内容
+const MAX_FILE_SIZE: u64 = 10 * 1024 * 1024;
+/// 开头这么多字节里出现 NUL 就当作二进制文件
+const SNIFF_LEN: usize = 8 * 1024;
+/// 过长的行（压缩过的 js、单行 json）只索引开头部分
+const MAX_LINE_LEN: usize = 1000;
+const SNIPPET_LEN: usize = 200;
+/// 收到变化后再等一会儿，把一批变化合并到一次提交里
+const BATCH_DELAY: Duration = Duration::from_secs(2);
+const WRITER_MEMORY: usize = 50 * 1024 * 1024;
+
+#[derive(Clone, Copy)]
+struct Fields {
+    /// 相对根目录的路径，和 `EntryInfo.epath` 一致
+    path: Field,
+    /// 所有上级目录，用来按目录过滤和删除整个目录
+    dirs: Field,
+    /// 行号从 1 开始；0 是每个文件一条的标记文档，记录索引时的大小和修改时间
+    line: Field,
+    content: Field,
+    modified: Field,
+    size: Field,
+}
+
+/// 一条搜索结果，`highlights` 是 `snippet` 中匹配部分的字节区间
+#[derive(Serialize)]
+pub(crate) struct ContentHit {
+    pub(crate) path: String,
+    pub(crate) line: u64,
+    pub(crate) snippet: String,
+    pub(crate) highlights: Vec<(usize, usize)>,
+}
+
+/// 文本文件的全文索引，按行建文档，保存在状态目录下的 `index` 里，重启后只重新索引有变化的文件
+pub(crate) struct ContentIndex {
+    root: PathBuf,
+    paths: Arc<PathResolver>,
+    index: Index,
+    reader: IndexReader,
+    writer: Mutex<IndexWriter>,
+    fields: Fields,
+}
+
+impl ContentIndex {
+    pub(crate) fn open(config: &AppConfig, paths: Arc<PathResolver>) -> tantivy::Result<Self> {
+        let mut builder = Schema::builder();
+        let fields = Fields {
+            path: builder.add_text_field("path", STRING | STORED),
+            dirs: builder.add_text_field("dirs", STRING),
+            line: builder.add_u64_field("line", INDEXED | STORED),
+            content: builder.add_text_field("content", TEXT | STORED),
+            modified: builder.add_u64_field("modified", STORED),
+            size: builder.add_u64_field("size", STORED),
+        };
+        let schema = builder.build();
+
+        let dir = config.state_dir.join("index");
+        std::fs::create_dir_all(&dir)?;
+        let index = match Index::open_or_create(tantivy::directory::MmapDirectory::open(&dir)?, schema.clone()) {
+            Ok(index) => index,
+            // 旧版本留下的索引结构不同，直接重建
+            Err(e) => {
+                tracing::warn!(">>> rebuild content index {:?}: {}", &dir, e);
+                std::fs::remove_dir_all(&dir)?;
+                std::fs::create_dir_all(&dir)?;
+                Index::create_in_dir(&dir, schema)?
+            }
+        };
+        let reader = index.reader_builder().reload_policy(ReloadPolicy::OnCommitWithDelay).try_into()?;
+        let writer = index.writer(WRITER_MEMORY)?;
+        Ok(ContentIndex {
+            root: config.root_dirpath.clone(),
+            paths,
+            index,
+            reader,
+            writer: Mutex::new(writer),
+            fields,
+        })
+    }
+
+    /// 在后台先和磁盘做一次全量比对，之后按文件变化增量更新；事件积压丢失时再做一次全量比对。
+    /// 没有 `changes` 时只在启动时比对
+    pub(crate) fn spawn(self: &Arc<Self>, mut changes: Option<broadcast::Receiver<Change>>) {
+        let index = self.clone();
+        tokio::spawn(async move {
+            let mut resync = true;
+            loop {
+                if resync {
+                    resync = false;
+                    let i = index.clone();
+                    match tokio::task::spawn_blocking(move || i.sync()).await {
+                        Ok(Ok(())) => {}
+                        Ok(Err(e)) => tracing::error!(">>> content index sync failed: {}", e),
+                        Err(e) => tracing::error!(">>> content index sync failed: {}", e),
+                    }
+                }
+                let Some(rx) = changes.as_mut() else {
+                    return;
+                };
+                let mut pending = HashSet::new();
+                match rx.recv().await {
+                    Ok(change) => collect(&mut pending, change),
+                    Err(RecvError::Lagged(_)) => resync = true,
+                    Err(RecvError::Closed) => return,
+                }
+                let deadline = tokio::time::sleep(BATCH_DELAY);
+                tokio::pin!(deadline);
+                loop {
+                    tokio::select! {
+                        _ = &mut deadline => break,
+                        r = rx.recv() => match r {
+                            Ok(change) => collect(&mut pending, change),
+                            Err(RecvError::Lagged(_)) => resync = true,
+                            Err(RecvError::Closed) => break,
+                        },
+                    }
+                }
+                if resync || pending.is_empty() {
+                    continue;
+                }
+                let i = index.clone();
+                match tokio::task::spawn_blocking(move || i.update(pending)).await {
+                    Ok(Ok(())) => {}
+                    Ok(Err(e)) => tracing::error!(">>> content index update failed: {}", e),
+                    Err(e) => tracing::error!(">>> content index update failed: {}", e),
+                }
+            }
+        });
+    }
+
+    fn rel(&self, a_path: &Path) -> Option<String> {
+        if self.paths.is_reserved(a_path) {
+            return None;
+        }
+        let rel = a_path.strip_prefix(&self.root).ok()?.to_string_lossy().to_string();
+        (!rel.is_empty()).then_some(rel)
+    }
+
+    // 比对索引里记录的大小、修改时间和磁盘上的文件，只重新索引有变化的文件
+    fn sync(&self) -> tantivy::Result<()> {
+        let searcher = self.reader.searcher();
+        let markers = searcher.search(&TermQuery::new(Term::from_field_u64(self.fields.line, 0), IndexRecordOption::Basic), &DocSetCollector)?;
+        let mut indexed = HashMap::new();
+        for addr in markers {
+            let doc: TantivyDocument = searcher.doc(addr)?;
+            let path = doc.get_first(self.fields.path).and_then(|v| v.as_str()).unwrap_or_default().to_string();
+            let modified = doc.get_first(self.fields.modified).and_then(|v| v.as_u64()).unwrap_or_default();
+            let size = doc.get_first(self.fields.size).and_then(|v| v.as_u64()).unwrap_or_default();
+            indexed.insert(path, (modified, size));
+        }
+
+        let mut writer = self.writer.lock().unwrap();
+        let (mut updated, mut removed) = (0, 0);
+        let walker = WalkDir::new(&self.root).into_iter().filter_entry(|e| !self.paths.is_reserved(e.path()));
+        for entry in walker.flatten() {
+            if !entry.file_type().is_file() {
+                continue;
+            }
+            let (Some(rel), Ok(meta)) = (self.rel(entry.path()), entry.metadata()) else {
+                continue;
+            };
+            if meta.len() > MAX_FILE_SIZE {
+                continue;
+            }
+            if indexed.remove(&rel) == Some((modified(&meta), meta.len())) {
+                continue;
+            }
+            writer.delete_term(Term::from_field_text(self.fields.path, &rel));
+            self.add_file(&writer, entry.path(), &rel, &meta)?;
+            updated += 1;
+        }
+        for rel in indexed.keys() {
+            writer.delete_term(Term::from_field_text(self.fields.path, rel));
+            removed += 1;
+        }
+        writer.commit()?;
+        tracing::info!(">>> content index synced, {} files updated, {} removed", updated, removed);
+        Ok(())
+    }
+
+    fn update(&self, changed: HashSet<PathBuf>) -> tantivy::Result<()> {
+        let mut writer = self.writer.lock().unwrap();
+        for a_path in &changed {
+            let Some(rel) = self.rel(a_path) else {
+                continue;
+            };
+            // 不管是什么变化都先删掉旧的，再按磁盘上的现状重新索引
+            writer.delete_term(Term::from_field_text(self.fields.path, &rel));
+            let Ok(meta) = std::fs::symlink_metadata(a_path) else {
+                writer.delete_term(Term::from_field_text(self.fields.dirs, &rel));
+                continue;
+            };
+            if meta.is_file() {
+                if meta.len() <= MAX_FILE_SIZE {
+                    self.add_file(&writer, a_path, &rel, &meta)?;
+                }
+            } else if meta.is_dir() {
+                // 移入或重命名过来的目录，里面的文件不会各自产生事件
+                writer.delete_term(Term::from_field_text(self.fields.dirs, &rel));
+                let walker = WalkDir::new(a_path).into_iter().filter_entry(|e| !self.paths.is_reserved(e.path()));
+                for entry in walker.flatten() {
+                    if let (true, Some(rel), Ok(meta)) = (entry.file_type().is_file(), self.rel(entry.path()), entry.metadata())
+                        && meta.len() <= MAX_FILE_SIZE
+                    {
+                        self.add_file(&writer, entry.path(), &rel, &meta)?;
+                    }
+                }
+            }
+        }
+        writer.commit()?;
+        tracing::info!(">>> content index updated {} paths", changed.len());
+        Ok(())
+    }
+
+    // 读不了的文件和二进制文件只写标记文档，下次比对时不用再读
+    fn add_file(&self, writer: &IndexWriter, a_path: &Path, rel: &str, meta: &Metadata) -> tantivy::Result<()> {
+        let f = self.fields;
+        let dirs: Vec<String> = Path::new(rel)
+            .ancestors()
+            .skip(1)
+            .map(|p| p.to_string_lossy().to_string())
+            .filter(|p| !p.is_empty())
+            .collect();
+        let mut marker = doc!(f.path => rel, f.line => 0u64, f.modified => modified(meta), f.size => meta.len());
+        for dir in &dirs {
+            marker.add_text(f.dirs, dir);
+        }
+        writer.add_document(marker)?;
+
+        let mut content = vec![];
+        let read = std::fs::File::open(a_path).and_then(|file| file.take(MAX_FILE_SIZE).read_to_end(&mut content));
+        if read.is_err() || content[..content.len().min(SNIFF_LEN)].contains(&0) {
+            return Ok(());
+        }
+        for (i, line) in String::from_utf8_lossy(&content).lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let mut end = line.len().min(MAX_LINE_LEN);
+            while !line.is_char_boundary(end) {
+                end -= 1;
+            }
+            let mut doc = doc!(f.path => rel, f.line => i as u64 + 1, f.content => &line[..end]);
+            for dir in &dirs {
+                doc.add_text(f.dirs, dir);
+            }
+            writer.add_document(doc)?;
+        }
+        Ok(())
+    }
+
+    /// 按 tantivy 的查询语法搜索，`dir` 限定在某个目录下，`keep` 过滤掉当前用户无权查看的文件
+    pub(crate) fn search(&self, q: &str, dir: Option<&str>, limit: usize, keep: impl Fn(&str) -> bool) -> tantivy::Result<Vec<ContentHit>> {
+        let f = self.fields;
+        let searcher = self.reader.searcher();
+        let (query, _) = QueryParser::for_index(&self.index, vec![f.content]).parse_query_lenient(q);
+        let query: Box<dyn Query> = match dir.map(|d| d.trim_matches('/')).filter(|d| !d.is_empty()) {
+            Some(dir) => Box::new(BooleanQuery::new(vec![
+                (Occur::Must, query),
+                (Occur::Must, Box::new(TermQuery::new(Term::from_field_text(f.dirs, dir), IndexRecordOption::Basic))),
+            ])),
+            None => query,
+        };
+        let mut generator = SnippetGenerator::create(&searcher, &*query, f.content)?;
+        generator.set_max_num_chars(SNIPPET_LEN);
+
+        // 多取一些，去掉无权查看的之后仍尽量凑够 limit 条
+        let mut hits = vec![];
+        for (_, addr) in searcher.search(&query, &TopDocs::with_limit(limit.saturating_mul(4)))? {
+            let doc: TantivyDocument = searcher.doc(addr)?;
+            let path = doc.get_first(f.path).and_then(|v| v.as_str()).unwrap_or_default();
+            let line = doc.get_first(f.line).and_then(|v| v.as_u64()).unwrap_or_default();
+            // 标记文档没有内容，只有 `*` 这样的查询会匹配到
+            if line == 0 || !keep(path) {
+                continue;
+            }
+            let snippet = generator.snippet_from_doc(&doc);
+            let (snippet, highlights) = if snippet.fragment().trim().is_empty() {
+                let line = doc.get_first(f.content).and_then(|v| v.as_str()).unwrap_or_default();
+                (line.chars().take(SNIPPET_LEN).collect(), vec![])
+            } else {
+                (snippet.fragment().to_string(), snippet.highlighted().iter().map(|r| (r.start, r.end)).collect())
+            };
+            hits.push(ContentHit {
+                path: path.to_string(),
+                line,
+                snippet,
+                highlights,
+            });
+            if hits.len() >= limit {
+                break;
+            }
+        }
+        Ok(hits)
+    }
+}
+
+fn collect(pending: &mut HashSet<PathBuf>, change: Change) {
+    if change.kind == ChangeKind::Rename
+        && let Some(from) = change.from
+    {
+        pending.insert(from);
+    }
+    pending.insert(change.path);
+}
+
+fn modified(meta: &Metadata) -> u64 {
+    meta.modified()
+        .ok()
+        .and_then(|m| m.duration_since(UNIX_EPOCH).ok())
+        .map_or(0, |d| d.as_secs())
+}
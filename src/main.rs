@@ -10,7 +10,22 @@ mod handlers;
 mod config;
 mod state;
 mod error;
+mod download;
+mod events;
 mod utils;
+mod archive;
+mod auth;
+mod hash;
+mod index;
+mod access;
+mod paths;
+mod search;
+mod tls;
+mod trash;
+mod tus;
+mod upload;
+mod watcher;
+mod webdav;
 
 
 
@@ -34,14 +49,29 @@ async fn main() {
 
     let app_config = config::AppConfig::new();
     tracing::info!(">>> {:?}", app_config);
-    let listener = tokio::net::TcpListener::bind(format!("{}:{}", &app_config.host, &app_config.port))
-        .await
-        .expect("Failed to bind to port");
+    let addr: std::net::SocketAddr = format!("{}:{}", &app_config.host, &app_config.port)
+        .parse()
+        .expect("Failed to parse listen address");
+    let rustls_config = tls::load_rustls_config(&app_config).await;
+    if app_config.tls_enabled() && let Some(redirect_port) = app_config.tls_redirect_port {
+        tokio::spawn(tls::serve_https_redirect(app_config.host.clone(), redirect_port, app_config.port));
+    }
 
     let app_state = state::AppState::new(Arc::new(app_config));
     let app_router = create_global_router(app_state);
     let app_service = app_router.into_make_service_with_connect_info::<std::net::SocketAddr>();
 
-    tracing::info!(">>> listening on {}", listener.local_addr().expect("Failed to get local address"));
-    axum::serve(listener, app_service).await.expect("Failed to start server");
+    if let Some(rustls_config) = rustls_config {
+        tracing::info!(">>> listening on https://{}", addr);
+        axum_server::bind_rustls(addr, rustls_config)
+            .serve(app_service)
+            .await
+            .expect("Failed to start server");
+    } else {
+        let listener = tokio::net::TcpListener::bind(addr)
+            .await
+            .expect("Failed to bind to port");
+        tracing::info!(">>> listening on {}", listener.local_addr().expect("Failed to get local address"));
+        axum::serve(listener, app_service).await.expect("Failed to start server");
+    }
 }
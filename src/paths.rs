@@ -0,0 +1,293 @@
+use std::io;
+use std::path::{Component, Path, PathBuf};
+use std::sync::Arc;
+
+/// 路径中遇到符号链接时的处理方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub(crate) enum SymlinkPolicy {
+    /// 跟随所有符号链接，包括指向根目录之外的
+    Follow,
+    /// 只跟随目标仍在根目录内的符号链接
+    FollowWithinRoot,
+    /// 路径中出现任何符号链接都拒绝访问
+    Deny,
+}
+
+#[derive(Debug)]
+pub(crate) enum PathError {
+    /// 含有 `..` 等试图跳出根目录的片段
+    Traversal(String),
+    /// 文件名为空、含有路径分隔符或 NUL 等非法字符
+    InvalidName(String),
+    /// 对根目录本身做删除、重命名等操作
+    Root,
+    NotFound(String),
+    SymlinkDenied(String),
+    OutsideRoot(String),
+    Io(String, io::Error),
+}
+
+/// 上传中的临时文件名前缀，这些文件和状态目录一样对客户端按不存在处理
+pub(crate) const TEMP_PREFIX: &str = ".rshttpserver-upload-";
+
+/// 所有处理器共用的路径解析：把请求中的相对路径解析到根目录下，拒绝跳出根目录的路径，
+/// 并按 [`SymlinkPolicy`] 检查路径上的符号链接。
+///
+/// 返回的都是根目录下按字面拼接的路径（不做 canonicalize），方便计算相对路径和匹配访问规则。
+#[derive(Debug)]
+pub(crate) struct PathResolver {
+    root: PathBuf,
+    policy: SymlinkPolicy,
+    /// 根目录内保留给服务自己使用的目录（状态目录），和上传中的临时文件一样对客户端按不存在处理
+    reserved: Option<PathBuf>,
+}
+
+impl PathResolver {
+    /// `root` 和 `state_dir` 需要是 canonicalize 之后的路径
+    pub(crate) fn new(root: PathBuf, policy: SymlinkPolicy, state_dir: &Path) -> Self {
+        let reserved = state_dir.starts_with(&root).then(|| state_dir.to_path_buf());
+        PathResolver { root, policy, reserved }
+    }
+
+    pub(crate) fn is_reserved(&self, a_path: &Path) -> bool {
+        self.reserved.as_ref().is_some_and(|r| a_path.starts_with(r))
+            || a_path.file_name().is_some_and(|n| n.to_string_lossy().starts_with(TEMP_PREFIX))
+    }
+
+    pub(crate) fn is_root(&self, a_path: &Path) -> bool {
+        a_path == self.root
+    }
+
+    /// 解析一个已存在的路径
+    pub(crate) fn resolve(&self, epath: &str) -> Result<PathBuf, PathError> {
+        let a_path = self.resolve_new(epath)?;
+        match std::fs::metadata(&a_path) {
+            Ok(_) => Ok(a_path),
+            // 悬空的符号链接也按不存在处理
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Err(PathError::NotFound(epath.to_string())),
+            Err(e) => Err(PathError::Io(epath.to_string(), e)),
+        }
+    }
+
+    /// 解析一个可能还不存在的路径（新建目录、上传的文件、重命名的目标），已存在的部分同样要满足符号链接策略
+    pub(crate) fn resolve_new(&self, epath: &str) -> Result<PathBuf, PathError> {
+        let rel = normalize(epath)?;
+        let a_path = self.root.join(&rel);
+        if self.is_reserved(&a_path) {
+            return Err(PathError::NotFound(epath.to_string()));
+        }
+        self.check_components(&rel, epath)?;
+        Ok(a_path)
+    }
+
+    /// 在目录 `a_dir` 下拼接一个文件名，文件名只能是单独的一段
+    pub(crate) fn join_name(&self, a_dir: &Path, name: &str) -> Result<PathBuf, PathError> {
+        validate_name(name)?;
+        let rel = a_dir.strip_prefix(&self.root).map_err(|_| PathError::OutsideRoot(name.to_string()))?;
+        let rel = rel.join(name);
+        if self.is_reserved(&self.root.join(&rel)) {
+            return Err(PathError::NotFound(name.to_string()));
+        }
+        self.check_components(&rel, name)?;
+        Ok(self.root.join(rel))
+    }
+
+    /// 遍历目录时得到的路径是否满足符号链接策略且不在保留目录内，用于列表和打包时过滤条目
+    pub(crate) fn permits(&self, a_path: &Path) -> bool {
+        if self.is_reserved(a_path) {
+            return false;
+        }
+        match a_path.strip_prefix(&self.root) {
+            Ok(rel) => self.check_components(rel, &rel.to_string_lossy()).is_ok(),
+            Err(_) => false,
+        }
+    }
+
+    // 逐级检查已存在的路径片段，遇到不存在的片段就停止
+    fn check_components(&self, rel: &Path, epath: &str) -> Result<(), PathError> {
+        if self.policy == SymlinkPolicy::Follow {
+            return Ok(());
+        }
+        let mut a_path = self.root.clone();
+        for c in rel.components() {
+            a_path.push(c);
+            let meta = match a_path.symlink_metadata() {
+                Ok(m) => m,
+                Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(()),
+                Err(e) => return Err(PathError::Io(epath.to_string(), e)),
+            };
+            if !meta.is_symlink() {
+                continue;
+            }
+            match self.policy {
+                SymlinkPolicy::Deny => return Err(PathError::SymlinkDenied(epath.to_string())),
+                SymlinkPolicy::FollowWithinRoot => match a_path.canonicalize() {
+                    Ok(target) if target.starts_with(&self.root) => {}
+                    Ok(_) => return Err(PathError::OutsideRoot(epath.to_string())),
+                    Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                        return Err(PathError::NotFound(epath.to_string()));
+                    }
+                    Err(e) => return Err(PathError::Io(epath.to_string(), e)),
+                },
+                SymlinkPolicy::Follow => {}
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 上面几个方法的异步版本：检查路径时要逐级读取元数据，放到阻塞线程里执行，
+/// 避免慢速的网络挂载卡住异步运行时
+impl PathResolver {
+    pub(crate) async fn resolve_async(self: &Arc<Self>, epath: &str) -> Result<PathBuf, PathError> {
+        let (this, e) = (self.clone(), epath.to_string());
+        blocking(epath, move || this.resolve(&e)).await
+    }
+
+    pub(crate) async fn resolve_new_async(self: &Arc<Self>, epath: &str) -> Result<PathBuf, PathError> {
+        let (this, e) = (self.clone(), epath.to_string());
+        blocking(epath, move || this.resolve_new(&e)).await
+    }
+
+    pub(crate) async fn join_name_async(self: &Arc<Self>, a_dir: &Path, name: &str) -> Result<PathBuf, PathError> {
+        let (this, a_dir, n) = (self.clone(), a_dir.to_path_buf(), name.to_string());
+        blocking(name, move || this.join_name(&a_dir, &n)).await
+    }
+}
+
+async fn blocking<T: Send + 'static>(
+    epath: &str,
+    f: impl FnOnce() -> Result<T, PathError> + Send + 'static,
+) -> Result<T, PathError> {
+    tokio::task::spawn_blocking(f)
+        .await
+        .map_err(|e| PathError::Io(epath.to_string(), io::Error::other(e)))?
+}
+
+/// 把请求中的路径规范化为不含 `.`、`..` 的相对路径，开头的 `/` 视为根目录
+fn normalize(epath: &str) -> Result<PathBuf, PathError> {
+    // 反斜杠在 Windows 上是分隔符，直接拒绝以免 `..\` 绕过检查
+    if epath.contains('\0') || (cfg!(windows) && epath.contains('\\')) {
+        return Err(PathError::InvalidName(epath.to_string()));
+    }
+    let mut rel = PathBuf::new();
+    for c in Path::new(epath).components() {
+        match c {
+            Component::Normal(p) => rel.push(p),
+            Component::CurDir | Component::RootDir => {}
+            Component::ParentDir | Component::Prefix(_) => return Err(PathError::Traversal(epath.to_string())),
+        }
+    }
+    Ok(rel)
+}
+
+/// 单独一段的文件名：非空，不是 `.` 或 `..`，不含分隔符和 NUL
+pub(crate) fn validate_name(name: &str) -> Result<(), PathError> {
+    if name.is_empty() || name == "." || name == ".." || name.contains(['/', '\0']) || (cfg!(windows) && name.contains('\\')) {
+        return Err(PathError::InvalidName(name.to_string()));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 根目录下：inner/file、指向 inner 的 in_link、指向根目录外的 out_link，以及状态目录 .rshttpserver
+    fn fixture(policy: SymlinkPolicy) -> (tempfile::TempDir, PathBuf, PathResolver) {
+        let tmp = tempfile::tempdir().unwrap();
+        let base = tmp.path().canonicalize().unwrap();
+        let root = base.join("root");
+        std::fs::create_dir_all(root.join("inner")).unwrap();
+        std::fs::create_dir_all(root.join(".rshttpserver/tus")).unwrap();
+        std::fs::create_dir_all(base.join("outside")).unwrap();
+        std::fs::write(root.join("inner/file"), b"in").unwrap();
+        std::fs::write(base.join("outside/secret"), b"out").unwrap();
+        #[cfg(unix)]
+        {
+            std::os::unix::fs::symlink(root.join("inner"), root.join("in_link")).unwrap();
+            std::os::unix::fs::symlink(base.join("outside"), root.join("out_link")).unwrap();
+        }
+        let resolver = PathResolver::new(root.clone(), policy, &root.join(".rshttpserver"));
+        (tmp, root, resolver)
+    }
+
+    fn kind<T>(result: &Result<T, PathError>) -> &'static str {
+        match result {
+            Ok(_) => "ok",
+            Err(PathError::Traversal(_)) => "traversal",
+            Err(PathError::InvalidName(_)) => "invalid_name",
+            Err(PathError::Root) => "root",
+            Err(PathError::NotFound(_)) => "not_found",
+            Err(PathError::SymlinkDenied(_)) => "symlink_denied",
+            Err(PathError::OutsideRoot(_)) => "outside_root",
+            Err(PathError::Io(..)) => "io",
+        }
+    }
+
+    #[test]
+    fn rejects_traversal_shapes() {
+        let (_tmp, root, resolver) = fixture(SymlinkPolicy::FollowWithinRoot);
+        let cases = [
+            ("../outside/secret", "traversal"),
+            ("inner/../../outside/secret", "traversal"),
+            ("/../outside", "traversal"),
+            ("inner/./../..", "traversal"),
+            ("a\0b", "invalid_name"),
+            (".rshttpserver/tus", "not_found"),
+            // 请求路径在路由层已经解码过，这里再出现的 %2e 只是普通字符
+            ("%2e%2e%2f%2e%2e%2foutside", "ok"),
+            // 开头的 `/` 视为根目录，不是文件系统的绝对路径
+            ("/outside/secret", "ok"),
+            ("//inner//file", "ok"),
+        ];
+        for (epath, expected) in cases {
+            let result = resolver.resolve_new(epath);
+            assert_eq!(kind(&result), expected, "resolve_new({:?})", epath);
+            if let Ok(a_path) = result {
+                assert!(a_path.starts_with(&root), "{:?} escaped to {:?}", epath, a_path);
+            }
+        }
+        // 反斜杠在 Windows 上直接拒绝，其它系统上只是文件名里的普通字符
+        if cfg!(windows) {
+            assert_eq!(kind(&resolver.resolve_new("..\\outside")), "invalid_name");
+        } else {
+            assert_eq!(resolver.resolve_new("..\\..\\outside").unwrap(), root.join("..\\..\\outside"));
+        }
+        assert_eq!(resolver.resolve_new("/outside/secret").unwrap(), root.join("outside/secret"));
+        assert_eq!(kind(&resolver.resolve("/outside/secret")), "not_found");
+        assert_eq!(resolver.resolve("inner/file").unwrap(), root.join("inner/file"));
+    }
+
+    #[test]
+    fn rejects_invalid_names() {
+        let (_tmp, root, resolver) = fixture(SymlinkPolicy::FollowWithinRoot);
+        for name in ["", ".", "..", "a/b", "../x", "a\0b"] {
+            assert_eq!(kind(&resolver.join_name(&root, name)), "invalid_name", "join_name({:?})", name);
+        }
+        assert_eq!(kind(&resolver.join_name(&root, ".rshttpserver")), "not_found");
+        assert_eq!(kind(&resolver.join_name(&root, &format!("{}x", TEMP_PREFIX))), "not_found");
+        assert_eq!(resolver.join_name(&root.join("inner"), "new").unwrap(), root.join("inner/new"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn applies_symlink_policy() {
+        let cases = [
+            (SymlinkPolicy::Follow, "in_link/file", "ok"),
+            (SymlinkPolicy::Follow, "out_link/secret", "ok"),
+            (SymlinkPolicy::FollowWithinRoot, "in_link/file", "ok"),
+            (SymlinkPolicy::FollowWithinRoot, "out_link/secret", "outside_root"),
+            (SymlinkPolicy::FollowWithinRoot, "out_link/new", "outside_root"),
+            (SymlinkPolicy::Deny, "in_link/file", "symlink_denied"),
+            (SymlinkPolicy::Deny, "out_link/secret", "symlink_denied"),
+            (SymlinkPolicy::Deny, "inner/file", "ok"),
+        ];
+        for (policy, epath, expected) in cases {
+            let (_tmp, root, resolver) = fixture(policy);
+            let result = if epath.ends_with("new") { resolver.resolve_new(epath) } else { resolver.resolve(epath) };
+            assert_eq!(kind(&result), expected, "{:?} {:?}", policy, epath);
+            assert_eq!(resolver.permits(&root.join(epath)), expected == "ok", "permits {:?} {:?}", policy, epath);
+        }
+    }
+}
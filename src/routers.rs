@@ -1,30 +1,102 @@
-use crate::handlers::{create_entry_handler, delete_entry_handler, download_entry_handler, list_entry_info_handler, rename_entry_handler, root_handler, static_handler, upload_entry_handler};
+use crate::handlers::{create_entry_handler, delete_entry_handler, download_entry_handler, list_entry_info_handler, put_upload_handler, rename_entry_handler, root_handler, server_mode_handler, static_handler, upload_entry_handler, view_entry_handler};
+use crate::events::events_handler;
+use crate::hash::hash_entry_handler;
+use crate::auth::{auth_middleware, login_handler, logout_handler, whoami_handler};
+use crate::search::{content_search_handler, search_handler};
 use crate::state::AppState;
+use crate::trash::{empty_trash_handler, list_trash_handler, purge_trash_handler, restore_trash_handler};
+use crate::tus::{tus_create_handler, tus_delete_handler, tus_head_handler, tus_options_handler, tus_patch_handler};
+use crate::webdav::dav_handler;
 use axum::Router;
+use axum::http::{Extensions, HeaderMap, StatusCode, Version, header};
 use axum::extract::DefaultBodyLimit;
-use axum::routing::{delete, get, post, put};
+use axum::middleware;
+use axum::routing::{any, delete, get, head, options, post, put};
 use tower::ServiceBuilder;
 use tower_http::compression::CompressionLayer;
+use tower_http::compression::predicate::{DefaultPredicate, NotForContentType, Predicate};
 use tower_http::decompression::RequestDecompressionLayer;
 
 pub(crate) fn create_global_router(app_state: AppState) -> Router {
-    Router::new()
+    let mut router = Router::new()
         .route("/", get(root_handler))
-        .route("/info/", get(list_entry_info_handler))
-        .route("/info/{*epath}", get(list_entry_info_handler))
-        .route("/delete/{*epath}", delete(delete_entry_handler))
-        .route("/rename/{*oepath}", put(rename_entry_handler))
-        .route("/create/{*epath}", get(create_entry_handler))
-        .route("/upload/", post(upload_entry_handler))
-        .route("/upload/{*epath}", post(upload_entry_handler))
-        .route("/download/{*epath}", get(download_entry_handler))
         .route("/~/static/{*dpath}", get(static_handler))
+        .route("/mode", get(server_mode_handler))
+        .route("/login", post(login_handler))
+        .route("/logout", post(logout_handler))
+        .route("/whoami", get(whoami_handler));
+
+    // 只读模式不注册任何修改类的路由，投递箱模式不注册浏览和下载的路由
+    if !app_state.config.upload_only {
+        router = router
+            .route("/info/", get(list_entry_info_handler))
+            .route("/info/{*epath}", get(list_entry_info_handler))
+            .route("/download/{*epath}", get(download_entry_handler))
+            .route("/view/{*epath}", get(view_entry_handler))
+            .route("/hash/{*epath}", get(hash_entry_handler))
+            .route("/search", get(search_handler))
+            .route("/search/content", get(content_search_handler))
+            .route("/events/", get(events_handler))
+            .route("/events/{*epath}", get(events_handler));
+    }
+    if !app_state.config.read_only {
+        router = router
+            .route("/upload/", post(upload_entry_handler))
+            .route("/upload/{*epath}", post(upload_entry_handler).put(put_upload_handler))
+            .route("/tus/", options(tus_options_handler).post(tus_create_handler))
+            .route(
+                "/tus/{id}",
+                head(tus_head_handler).patch(tus_patch_handler).delete(tus_delete_handler),
+            );
+    }
+    if !app_state.config.read_only && !app_state.config.upload_only {
+        router = router
+            .route("/delete/{*epath}", delete(delete_entry_handler))
+            .route("/rename/{*oepath}", put(rename_entry_handler))
+            .route("/create/{*epath}", post(create_entry_handler))
+            .route("/trash/", get(list_trash_handler).delete(empty_trash_handler))
+            .route("/trash/{id}", delete(purge_trash_handler))
+            .route("/trash/{id}/restore", post(restore_trash_handler));
+        // 会话 cookie 是 SameSite=Lax，跨站的顶层 GET 跳转仍会带上，开启登录后新建目录只接受 POST
+        if app_state.config.users.is_empty() {
+            router = router.route("/create/{*epath}", get(create_entry_handler));
+        }
+    }
+
+    // WebDAV 自己按方法区分读写，只读和投递箱模式在 webdav 模块里限制可用的方法
+    if let Some(prefix) = &app_state.config.dav_prefix {
+        router = router
+            .route(prefix, any(dav_handler))
+            .route(&format!("{}/", prefix), any(dav_handler))
+            .route(&format!("{}/{{*dpath}}", prefix), any(dav_handler));
+    }
+
+    let body_limit = usize::try_from(app_state.config.max_upload_size).unwrap_or(usize::MAX);
+    router
+        .layer(middleware::from_fn_with_state(app_state.clone(), auth_middleware))
         .layer(
             ServiceBuilder::new()
                 .layer(tower_http::trace::TraceLayer::new_for_http())
                 .layer(RequestDecompressionLayer::new())
-                .layer(CompressionLayer::new())
-                .layer(DefaultBodyLimit::max(1024usize * 1024 * 1024 * 1024)),
+                .layer(CompressionLayer::new().compress_when(compress_predicate()))
+                .layer(DefaultBodyLimit::max(body_limit)),
         )
         .with_state(app_state)
 }
+
+fn compress_predicate() -> impl Predicate {
+    DefaultPredicate::new()
+        .and(not_ranged)
+        // 打包下载的 zip、tar.gz、tar.zst 本身已经压缩过，再压一次只是浪费 CPU
+        .and(NotForContentType::const_new("application/zip"))
+        .and(NotForContentType::const_new("application/gzip"))
+        .and(NotForContentType::const_new("application/zstd"))
+        // 流式输出逐行刷新，压缩器会攒满缓冲区才吐出数据，客户端要等很久才看到第一条结果
+        .and(NotForContentType::const_new("application/x-ndjson"))
+        .and(NotForContentType::const_new("text/event-stream"))
+}
+
+// 支持 Range 的文件下载不压缩：压缩后的内容和 ETag、Range 偏移对应的都不是同一份字节，续传会拼错
+fn not_ranged(_: StatusCode, _: Version, headers: &HeaderMap, _: &Extensions) -> bool {
+    !headers.contains_key(header::ACCEPT_RANGES)
+}
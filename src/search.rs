@@ -0,0 +1,291 @@
+use crate::access::{self, Op};
+use crate::auth::{CurrentUser, Role};
+use crate::error::AppError;
+use crate::handlers::{ApiResponse, EntryFilter, EntryInfo};
+use crate::state::AppState;
+use axum::{Extension, Json};
+use axum::body::Body;
+use axum::extract::{Query, State};
+use axum::http::{HeaderValue, StatusCode, header};
+use axum::response::{IntoResponse, Response};
+use bytes::Bytes;
+use serde::Deserialize;
+use serde_json::json;
+use std::collections::VecDeque;
+use std::fs::Metadata;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, UNIX_EPOCH};
+use tokio::sync::mpsc;
+use tokio::task::JoinSet;
+use tokio_stream::wrappers::ReceiverStream;
+
+/// 同时在阻塞线程中读取的目录数
+const WORKERS: usize = 8;
+/// 默认和最大的搜索深度，起始目录的子条目深度为 1
+const DEFAULT_DEPTH: usize = 16;
+const MAX_DEPTH: usize = 64;
+/// 默认和最多返回的结果数
+const DEFAULT_LIMIT: usize = 1000;
+const MAX_LIMIT: usize = 100_000;
+/// 单次搜索的时间预算，超时后停止遍历
+const TIME_BUDGET: Duration = Duration::from_secs(30);
+
+#[derive(Deserialize)]
+pub(crate) struct SearchQuery {
+    /// 名称包含的子串，不区分大小写
+    q: Option<String>,
+    /// 名称通配符，例如 `*.iso`
+    name: Option<String>,
+    /// 起始目录，默认根目录
+    #[serde(default)]
+    path: String,
+    /// 条目类型 f、d 或 u，多个用 `,` 分隔
+    #[serde(rename = "type")]
+    etype: Option<String>,
+    min_size: Option<u64>,
+    max_size: Option<u64>,
+    /// unix 时间戳（秒）
+    modified_after: Option<u64>,
+    modified_before: Option<u64>,
+    depth: Option<usize>,
+    limit: Option<usize>,
+}
+
+struct SearchFilter {
+    entry: EntryFilter,
+    min_size: Option<u64>,
+    max_size: Option<u64>,
+    modified_after: Option<u64>,
+    modified_before: Option<u64>,
+}
+
+impl SearchFilter {
+    fn matches(&self, ename: &str, etype: &str, meta: &Metadata) -> bool {
+        let modified = meta
+            .modified()
+            .ok()
+            .and_then(|m| m.duration_since(UNIX_EPOCH).ok())
+            .map_or(0, |d| d.as_secs());
+        self.entry.matches(ename, etype)
+            && self.min_size.is_none_or(|s| meta.len() >= s)
+            && self.max_size.is_none_or(|s| meta.len() <= s)
+            && self.modified_after.is_none_or(|t| modified >= t)
+            && self.modified_before.is_none_or(|t| modified <= t)
+    }
+}
+
+/// 一个目录的扫描结果：匹配的条目和需要继续深入的子目录
+struct Scanned {
+    matched: Vec<EntryInfo>,
+    dirs: Vec<PathBuf>,
+    scanned: usize,
+}
+
+/// 递归搜索，结果按 NDJSON 逐行返回，每行一个 [`EntryInfo`]，最后一行是 `{"done": true, ...}` 汇总，
+/// 其中 `truncated` 说明是否因为数量或时间预算提前结束
+pub(crate) async fn search_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<CurrentUser>,
+    Query(query): Query<SearchQuery>,
+) -> Result<Response, AppError> {
+    user.require(Role::Viewer)?;
+    let entry = EntryFilter::new(query.name.as_deref(), query.q.as_deref(), query.etype.as_deref()).map_err(AppError::BadRequest)?;
+    let filter = SearchFilter {
+        entry,
+        min_size: query.min_size,
+        max_size: query.max_size,
+        modified_after: query.modified_after,
+        modified_before: query.modified_before,
+    };
+    let max_depth = query.depth.unwrap_or(DEFAULT_DEPTH).clamp(1, MAX_DEPTH);
+    let limit = query.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT);
+
+    let a_dir = state.paths.resolve_async(&query.path).await?;
+    let rpath = access::rule_path(&state.config.root_dirpath, &a_dir);
+    access::check(&state.config.access_rules, &user, &rpath, Op::List)?;
+    if !tokio::fs::metadata(&a_dir).await.is_ok_and(|m| m.is_dir()) {
+        return Err(AppError::BadRequest(format!("{} is not a folder", &query.path)));
+    }
+
+    let (tx, rx) = mpsc::channel::<Result<Bytes, std::io::Error>>(64);
+    tokio::spawn(walk(state, user, a_dir, filter, max_depth, limit, tx));
+    let mut response = Body::from_stream(ReceiverStream::new(rx)).into_response();
+    response
+        .headers_mut()
+        .insert(header::CONTENT_TYPE, HeaderValue::from_static("application/x-ndjson"));
+    Ok(response)
+}
+
+// 最多 WORKERS 个目录同时在阻塞线程中读取，先进先出，浅层的结果先返回
+async fn walk(
+    state: AppState,
+    user: CurrentUser,
+    a_dir: PathBuf,
+    filter: SearchFilter,
+    max_depth: usize,
+    limit: usize,
+    tx: mpsc::Sender<Result<Bytes, std::io::Error>>,
+) {
+    let started = Instant::now();
+    let filter = std::sync::Arc::new(filter);
+    let mut pending = VecDeque::from([(a_dir.clone(), 1)]);
+    let mut tasks = JoinSet::new();
+    let (mut matched, mut scanned) = (0, 0);
+    let mut truncated = None;
+
+    'walk: loop {
+        while tasks.len() < WORKERS
+            && let Some((dir, depth)) = pending.pop_front()
+        {
+            let (state, user, filter) = (state.clone(), user.clone(), filter.clone());
+            tasks.spawn_blocking(move || (scan(&state, &user, &dir, &filter), depth));
+        }
+        let next = tokio::time::timeout(TIME_BUDGET.saturating_sub(started.elapsed()), tasks.join_next()).await;
+        let (result, depth) = match next {
+            Err(_) => {
+                truncated = Some("timeout");
+                break;
+            }
+            Ok(None) => break,
+            Ok(Some(Ok(done))) => done,
+            Ok(Some(Err(e))) => {
+                tracing::error!(">>> search worker failed: {}", e);
+                continue;
+            }
+        };
+        let Some(result) = result else {
+            continue;
+        };
+        scanned += result.scanned;
+        for info in result.matched {
+            let mut line = serde_json::to_vec(&info).unwrap_or_default();
+            line.push(b'\n');
+            // 客户端断开后不再继续遍历
+            if tx.send(Ok(Bytes::from(line))).await.is_err() {
+                tasks.abort_all();
+                return;
+            }
+            matched += 1;
+            if matched >= limit {
+                truncated = Some("limit");
+                break 'walk;
+            }
+        }
+        if depth < max_depth {
+            pending.extend(result.dirs.into_iter().map(|d| (d, depth + 1)));
+        }
+    }
+    tasks.abort_all();
+
+    tracing::info!(
+        ">>> search {:?} matched {} of {} entries in {:?}",
+        &a_dir,
+        matched,
+        scanned,
+        started.elapsed()
+    );
+    let summary = json!({
+        "done": true,
+        "matched": matched,
+        "scanned": scanned,
+        "truncated": truncated,
+    });
+    let mut line = serde_json::to_vec(&summary).unwrap_or_default();
+    line.push(b'\n');
+    let _ = tx.send(Ok(Bytes::from(line))).await;
+}
+
+// 和列表接口一样跳过隐藏的条目、状态目录和不满足符号链接策略的条目；
+// 符号链接指向的目录不会深入，避免循环，规则禁止列出的目录本身可以匹配但不会深入
+fn scan(state: &AppState, user: &CurrentUser, a_dir: &Path, filter: &SearchFilter) -> Option<Scanned> {
+    let rules = &state.config.access_rules;
+    let entries = match std::fs::read_dir(a_dir) {
+        Ok(entries) => entries,
+        Err(e) => {
+            tracing::warn!(">>> search skip {:?}: {}", a_dir, e);
+            return None;
+        }
+    };
+    let mut result = Scanned {
+        matched: vec![],
+        dirs: vec![],
+        scanned: 0,
+    };
+    for entry in entries.flatten() {
+        let a_path = entry.path();
+        let rpath = access::rule_path(&state.config.root_dirpath, &a_path);
+        if access::is_hidden(rules, user, &rpath) {
+            continue;
+        }
+        let Ok(meta) = entry.metadata() else {
+            continue;
+        };
+        if state.paths.is_reserved(&a_path) || (meta.is_symlink() && !state.paths.permits(&a_path)) {
+            continue;
+        }
+        result.scanned += 1;
+        let etype = if meta.is_file() {
+            "f"
+        } else if meta.is_dir() {
+            "d"
+        } else {
+            "u"
+        };
+        if filter.matches(&entry.file_name().to_string_lossy(), etype, &meta) {
+            result.matched.push(EntryInfo::new(&state.config.root_dirpath, &a_path, &meta, etype));
+        }
+        if meta.is_dir() && access::allows(rules, user, &rpath, Op::List) {
+            result.dirs.push(a_path);
+        }
+    }
+    Some(result)
+}
+
+#[derive(Deserialize)]
+pub(crate) struct ContentQuery {
+    q: String,
+    /// 只搜索这个目录下的文件，默认整个根目录
+    path: Option<String>,
+    limit: Option<usize>,
+}
+
+/// 在内容索引里搜索文本文件，返回文件、行号和片段；需要开启 `--content-index`
+pub(crate) async fn content_search_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<CurrentUser>,
+    Query(query): Query<ContentQuery>,
+) -> Result<(StatusCode, Json<ApiResponse>), AppError> {
+    user.require(Role::Viewer)?;
+    let Some(index) = state.index.clone() else {
+        return Err(AppError::Disabled("content index is not enabled".to_string()));
+    };
+    if query.q.trim().is_empty() {
+        return Err(AppError::BadRequest("q is required".to_string()));
+    }
+    if let Some(path) = &query.path {
+        let a_dir = state.paths.resolve_async(path).await?;
+        let rpath = access::rule_path(&state.config.root_dirpath, &a_dir);
+        access::check(&state.config.access_rules, &user, &rpath, Op::List)?;
+    }
+    let limit = query.limit.unwrap_or(100).clamp(1, DEFAULT_LIMIT);
+
+    // 结果会透露文件内容，按下载权限过滤；索引不跟随符号链接，不需要再检查链接策略
+    let (config, q, dir) = (state.config.clone(), query.q.clone(), query.path.clone());
+    let hits = tokio::task::spawn_blocking(move || {
+        index.search(&q, dir.as_deref(), limit, |epath| {
+            let rpath = access::rule_path(&config.root_dirpath, &config.root_dirpath.join(epath));
+            access::allows(&config.access_rules, &user, &rpath, Op::Download)
+        })
+    })
+    .await?
+    .map_err(|e| AppError::Internal(format!("content search error: {}", e)))?;
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse {
+            code: 200,
+            message: format!("{} matches", hits.len()),
+            data: Some(json!(hits)),
+        }),
+    ))
+}
@@ -1,15 +1,84 @@
 use std::sync::Arc;
+use std::time::Duration;
+use crate::auth::SessionStore;
 use crate::config::AppConfig;
+use crate::hash::HashCache;
+use crate::index::ContentIndex;
+use crate::paths::PathResolver;
+use crate::trash::Trash;
+use crate::tus::TusStore;
+use crate::watcher::FsWatcher;
+use crate::webdav;
+use dav_server::DavHandler;
+use crate::auth::CurrentUser;
 
 #[derive(Clone)]
 pub(crate) struct AppState{
     pub(crate) config: Arc<AppConfig>,
+    pub(crate) sessions: Arc<SessionStore>,
+    pub(crate) paths: Arc<PathResolver>,
+    /// 未配置 `dav_prefix` 时为 None
+    pub(crate) dav: Option<DavHandler<CurrentUser>>,
+    /// 只读模式或状态目录不可写时为 None，tus 接口返回 404
+    pub(crate) tus: Option<Arc<TusStore>>,
+    /// 关闭 `trash` 时为 None，删除即永久删除
+    pub(crate) trash: Option<Arc<Trash>>,
+    pub(crate) hashes: Arc<HashCache>,
+    /// 关闭 `watch` 且未开启 `content_index` 时为 None
+    pub(crate) watcher: Option<Arc<FsWatcher>>,
+    /// 未开启 `content_index` 时为 None
+    pub(crate) index: Option<Arc<ContentIndex>>,
 }
 
 impl AppState {
     pub(crate) fn new(config: Arc<AppConfig>) -> Self {
+        let paths = Arc::new(PathResolver::new(config.root_dirpath.clone(), config.symlink_policy, &config.state_dir));
+        // 只读模式不会删除任何东西，也不在根目录里建状态目录
+        let trash = (config.trash && !config.read_only).then(|| {
+            Arc::new(Trash::new(
+                &config.state_dir,
+                config.root_dirpath.clone(),
+                Duration::from_secs(config.trash_max_age),
+                config.trash_max_size,
+            ))
+        });
+        if let Some(trash) = &trash {
+            trash.spawn_retention();
+        }
+        let dav = config.dav_prefix.as_ref().map(|prefix| webdav::create_dav_handler(&config, &paths, &trash, prefix));
+        let tus = (!config.read_only).then(|| TusStore::new(&config.state_dir, Duration::from_secs(config.tus_expiry))).and_then(|t| {
+            t.map(Arc::new)
+                .inspect_err(|e| tracing::error!(">>> create tus upload directory in {:?} failed: {}", &config.state_dir, e))
+                .ok()
+        });
+        if let Some(tus) = &tus {
+            tus.spawn_expiry();
+        }
+        // 监听失败（例如 inotify 数量超限）时 `/events` 不可用，内容索引仍可用，只是只在启动时更新
+        let watch = config.content_index || (config.watch && !config.upload_only);
+        let watcher = watch.then(|| FsWatcher::new(&config.root_dirpath, paths.clone())).and_then(|w| {
+            w.map(Arc::new)
+                .inspect_err(|e| tracing::warn!(">>> watch {:?} failed: {}", &config.root_dirpath, e))
+                .ok()
+        });
+        let index = config.content_index.then(|| ContentIndex::open(&config, paths.clone())).and_then(|i| {
+            i.map(Arc::new)
+                .inspect_err(|e| tracing::error!(">>> open content index failed: {}", e))
+                .ok()
+        });
+        if let Some(index) = &index {
+            index.spawn(watcher.as_ref().map(|w| w.subscribe()));
+        }
         AppState {
             config,
+            sessions: Arc::new(SessionStore::default()),
+            paths,
+            dav,
+            tus,
+            trash,
+            hashes: Arc::new(HashCache::default()),
+            watcher,
+            index,
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,102 @@
+use crate::config::AppConfig;
+use crate::error::AppError;
+use axum::Router;
+use axum::extract::Request;
+use axum::http::{Uri, header};
+use axum::response::Redirect;
+use axum_server::tls_rustls::RustlsConfig;
+use sha2::{Digest, Sha256};
+
+/// 根据配置加载证书，未启用 TLS 时返回 None
+pub(crate) async fn load_rustls_config(config: &AppConfig) -> Option<RustlsConfig> {
+    // 只启用 ring 作为加密实现，这里把它注册为进程默认
+    let _ = rustls::crypto::ring::default_provider().install_default();
+
+    if let (Some(cert), Some(key)) = (&config.tls_cert, &config.tls_key) {
+        let rustls_config = RustlsConfig::from_pem_file(cert, key)
+            .await
+            .expect("Failed to load TLS certificate or key");
+        tracing::info!(">>> TLS certificate loaded from {:?}", cert);
+        #[cfg(unix)]
+        spawn_reload_on_sighup(rustls_config.clone(), cert.clone(), key.clone());
+        return Some(rustls_config);
+    }
+
+    if config.tls_self_signed {
+        let mut names = vec!["localhost".to_string(), "127.0.0.1".to_string(), "::1".to_string()];
+        if config.host != "0.0.0.0" && config.host != "::" {
+            names.push(config.host.clone());
+        }
+        let certified = rcgen::generate_simple_self_signed(names).expect("Failed to generate self-signed certificate");
+        // 打印证书指纹，客户端可以据此确认连上的是这台服务
+        let fingerprint = Sha256::digest(certified.cert.der())
+            .iter()
+            .map(|b| format!("{:02X}", b))
+            .collect::<Vec<_>>()
+            .join(":");
+        tracing::info!(">>> self-signed certificate generated, SHA-256 fingerprint {}", fingerprint);
+        let rustls_config = RustlsConfig::from_pem(
+            certified.cert.pem().into_bytes(),
+            certified.signing_key.serialize_pem().into_bytes(),
+        )
+        .await
+        .expect("Failed to load self-signed certificate");
+        return Some(rustls_config);
+    }
+
+    None
+}
+
+/// 收到 SIGHUP 时重新读取证书文件，已建立的连接不受影响
+#[cfg(unix)]
+fn spawn_reload_on_sighup(rustls_config: RustlsConfig, cert: std::path::PathBuf, key: std::path::PathBuf) {
+    tokio::spawn(async move {
+        let mut hangup = match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::hangup()) {
+            Ok(s) => s,
+            Err(e) => {
+                tracing::error!(">>> listen SIGHUP error: {}", e);
+                return;
+            }
+        };
+        while hangup.recv().await.is_some() {
+            match rustls_config.reload_from_pem_file(&cert, &key).await {
+                Ok(()) => tracing::info!(">>> TLS certificate reloaded from {:?}", &cert),
+                Err(e) => tracing::error!(">>> reload TLS certificate error, keep the old one: {}", e),
+            }
+        }
+    });
+}
+
+/// 明文 HTTP 监听，所有请求都重定向到 HTTPS 端口
+pub(crate) async fn serve_https_redirect(host: String, port: u16, https_port: u16) {
+    let app = Router::new().fallback(move |request: Request| async move { https_redirect(request, https_port) });
+    let listener = tokio::net::TcpListener::bind(format!("{}:{}", &host, port))
+        .await
+        .expect("Failed to bind redirect port");
+    tracing::info!(">>> redirect http on {} to https", listener.local_addr().expect("Failed to get local address"));
+    axum::serve(listener, app).await.expect("Failed to start redirect server");
+}
+
+fn https_redirect(request: Request, https_port: u16) -> Result<Redirect, AppError> {
+    let Some(host) = request
+        .headers()
+        .get(header::HOST)
+        .and_then(|h| h.to_str().ok())
+        .and_then(|h| h.parse::<axum::http::uri::Authority>().ok())
+    else {
+        return Err(AppError::BadRequest("missing host header".to_string()));
+    };
+    let authority = if https_port == 443 {
+        host.host().to_string()
+    } else {
+        format!("{}:{}", host.host(), https_port)
+    };
+    let path_and_query = request.uri().path_and_query().map_or("/", |p| p.as_str());
+    let uri = Uri::builder()
+        .scheme("https")
+        .authority(authority)
+        .path_and_query(path_and_query)
+        .build()
+        .map_err(|_| AppError::BadRequest("invalid host header".to_string()))?;
+    Ok(Redirect::permanent(&uri.to_string()))
+}
@@ -0,0 +1,448 @@
+use crate::access::{self, Op};
+use crate::auth::{CurrentUser, Role};
+use crate::error::AppError;
+use crate::handlers::ApiResponse;
+use crate::paths::TEMP_PREFIX;
+use crate::state::AppState;
+use crate::upload;
+use crate::utils::{format_bytes, random_hex};
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::{Extension, Json};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::io;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// 回收站里的一个条目，数据在回收站目录下的 `<id>`，信息在 `<id>.json`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct TrashItem {
+    id: String,
+    /// 原路径，相对根目录
+    path: String,
+    /// 删除者；未配置用户时为空
+    deleted_by: Option<String>,
+    /// unix 时间戳（秒）
+    deleted_at: u64,
+    /// 目录是其中所有文件大小之和
+    size: u64,
+    is_dir: bool,
+}
+
+/// 删除的条目先移动到状态目录下的 `trash`，可以恢复到原路径；
+/// 超过 `trash_max_age` 的条目和超出 `trash_max_size` 时最早删除的条目会被自动清理
+pub(crate) struct Trash {
+    dir: PathBuf,
+    root: PathBuf,
+    max_age: Duration,
+    max_size: u64,
+}
+
+impl Trash {
+    /// 目录在第一次删除时才创建，状态目录不可写时删除会失败，而不是直接永久删除
+    pub(crate) fn new(state_dir: &std::path::Path, root: PathBuf, max_age: Duration, max_size: u64) -> Trash {
+        let dir = state_dir.join("trash");
+        Trash {
+            dir,
+            root,
+            max_age,
+            max_size,
+        }
+    }
+
+    fn data_path(&self, id: &str) -> PathBuf {
+        self.dir.join(id)
+    }
+
+    fn info_path(&self, id: &str) -> PathBuf {
+        self.dir.join(format!("{}.json", id))
+    }
+
+    /// 把 `a_path` 移到回收站，符号链接只移动链接本身；会阻塞，需要在阻塞线程中调用
+    pub(crate) fn put(&self, a_path: &std::path::Path, user: &CurrentUser) -> io::Result<TrashItem> {
+        let meta = std::fs::symlink_metadata(a_path)?;
+        // 回收站目录的问题是服务端配置错误，不能按被删除条目的错误返回 404 或 403
+        std::fs::create_dir_all(&self.dir).map_err(|e| io::Error::other(format!("create trash directory {:?} failed: {}", &self.dir, e)))?;
+        let size = if meta.is_dir() {
+            walkdir::WalkDir::new(a_path).follow_root_links(false)
+                .into_iter()
+                .flatten()
+                .filter_map(|e| e.metadata().ok())
+                .filter(|m| m.is_file())
+                .map(|m| m.len())
+                .sum()
+        } else {
+            meta.len()
+        };
+        let id = random_hex(16);
+        let item = TrashItem {
+            id: id.clone(),
+            path: a_path.strip_prefix(&self.root).unwrap_or(a_path).to_string_lossy().to_string(),
+            deleted_by: user.name.clone(),
+            deleted_at: SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs()),
+            size,
+            is_dir: meta.is_dir(),
+        };
+        // 先写信息再移动数据，移动失败时信息文件也删掉，不会留下没有信息的数据
+        std::fs::write(self.info_path(&id), serde_json::to_vec(&item)?)?;
+        if let Err(e) = move_entry(a_path, &self.data_path(&id)) {
+            let _ = std::fs::remove_file(self.info_path(&id));
+            return Err(e);
+        }
+        tracing::info!(">>> {:?} moved {:?} to trash as {}", &item.deleted_by, a_path, &id);
+        Ok(item)
+    }
+
+    fn load(&self, id: &str) -> io::Result<TrashItem> {
+        if id.len() != 32 || !id.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(io::ErrorKind::NotFound.into());
+        }
+        let content = std::fs::read(self.info_path(id))?;
+        serde_json::from_slice(&content).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    /// 所有条目，最近删除的在前
+    pub(crate) fn list(&self) -> Vec<TrashItem> {
+        let Ok(entries) = std::fs::read_dir(&self.dir) else {
+            return vec![];
+        };
+        let mut items: Vec<TrashItem> = entries
+            .flatten()
+            .filter_map(|e| {
+                let path = e.path();
+                let id = path.file_stem()?.to_str()?;
+                path.extension().is_some_and(|e| e == "json").then(|| self.load(id).ok())?
+            })
+            .collect();
+        items.sort_by(|a, b| b.deleted_at.cmp(&a.deleted_at).then_with(|| a.path.cmp(&b.path)));
+        items
+    }
+
+    /// 移回原路径，原路径已存在时返回 `AlreadyExists`，缺少的上级目录会重新创建
+    fn restore(&self, item: &TrashItem, a_path: &std::path::Path) -> io::Result<()> {
+        if let Some(parent) = a_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        move_new(&self.data_path(&item.id), a_path)?;
+        std::fs::remove_file(self.info_path(&item.id))?;
+        tracing::info!(">>> restore {} to {:?}", &item.id, a_path);
+        Ok(())
+    }
+
+    /// 永久删除一个条目，数据先删，信息文件最后删
+    fn purge(&self, id: &str) -> io::Result<()> {
+        let data = self.data_path(id);
+        let result = match std::fs::symlink_metadata(&data) {
+            Ok(meta) if meta.is_dir() => std::fs::remove_dir_all(&data),
+            Ok(_) => std::fs::remove_file(&data),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(e),
+        };
+        result?;
+        std::fs::remove_file(self.info_path(id))?;
+        tracing::info!(">>> purge {} from trash", id);
+        Ok(())
+    }
+
+    /// 定期按保留时间和总大小清理
+    pub(crate) fn spawn_retention(self: &Arc<Self>) {
+        let trash = self.clone();
+        tokio::spawn(async move {
+            let period = match trash.max_age {
+                age if age.is_zero() => Duration::from_secs(10 * 60),
+                age => age.clamp(Duration::from_secs(1), Duration::from_secs(10 * 60)),
+            };
+            let mut interval = tokio::time::interval(period);
+            loop {
+                interval.tick().await;
+                let trash = trash.clone();
+                let _ = tokio::task::spawn_blocking(move || trash.retain()).await;
+            }
+        });
+    }
+
+    /// 保留时间和总大小为 0 时不限制
+    pub(crate) fn retain(&self) {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+        let mut items = self.list();
+        if !self.max_age.is_zero() {
+            let expired = now.saturating_sub(self.max_age.as_secs());
+            for item in items.iter().filter(|i| i.deleted_at < expired) {
+                if let Err(e) = self.purge(&item.id) {
+                    tracing::error!(">>> purge {} error: {}", &item.id, e);
+                }
+            }
+            items.retain(|i| i.deleted_at >= expired);
+        }
+        if self.max_size == 0 {
+            return;
+        }
+        // 从最早删除的开始清理，直到总大小不超过上限
+        let mut total: u64 = items.iter().map(|i| i.size).sum();
+        while total > self.max_size
+            && let Some(item) = items.pop()
+        {
+            tracing::info!(">>> trash size {} exceeds {}", format_bytes(total), format_bytes(self.max_size));
+            match self.purge(&item.id) {
+                Ok(()) => total -= item.size,
+                Err(e) => tracing::error!(">>> purge {} error: {}", &item.id, e),
+            }
+        }
+    }
+}
+
+// 同一文件系统内直接重命名；状态目录和条目不在同一个文件系统时先复制再删除
+fn move_entry(from: &std::path::Path, to: &std::path::Path) -> io::Result<()> {
+    match std::fs::rename(from, to) {
+        Err(e) if e.kind() == io::ErrorKind::CrossesDevices => {
+            if let Err(e) = copy_entry(from, to) {
+                let _ = std::fs::remove_dir_all(to).or_else(|_| std::fs::remove_file(to));
+                return Err(e);
+            }
+            remove_entry(from)
+        }
+        result => result,
+    }
+}
+
+// 和 move_entry 一样，但 `to` 已存在时返回 `AlreadyExists`，不会覆盖检查之后别的请求建出的同名条目
+fn move_new(from: &std::path::Path, to: &std::path::Path) -> io::Result<()> {
+    match place_new(from, to) {
+        // 跨文件系统时先完整复制到目标目录下的临时条目，再同样不覆盖地落到目标名
+        Err(e) if e.kind() == io::ErrorKind::CrossesDevices => {
+            let temp = to.with_file_name(format!("{}{}", TEMP_PREFIX, random_hex(8)));
+            if let Err(e) = copy_entry(from, &temp).and_then(|()| place_new(&temp, to)) {
+                let _ = remove_entry(&temp);
+                return Err(e);
+            }
+            remove_entry(from)
+        }
+        result => result,
+    }
+}
+
+// 同一文件系统内不覆盖地重命名：文件用硬链接占住目标名；目录先建一个空目录占住，再重命名替换这个空目录，
+// 期间别的请求往里面放了东西时重命名失败
+fn place_new(from: &std::path::Path, to: &std::path::Path) -> io::Result<()> {
+    if !std::fs::symlink_metadata(from)?.is_dir() {
+        return upload::rename_new(from, to);
+    }
+    #[cfg(unix)]
+    {
+        std::fs::create_dir(to)?;
+        std::fs::rename(from, to).map_err(|e| {
+            let _ = std::fs::remove_dir(to);
+            match e.kind() {
+                io::ErrorKind::DirectoryNotEmpty => io::ErrorKind::AlreadyExists.into(),
+                _ => e,
+            }
+        })
+    }
+    // Windows 上的重命名不会替换已有的目录
+    #[cfg(not(unix))]
+    std::fs::rename(from, to)
+}
+
+fn remove_entry(path: &std::path::Path) -> io::Result<()> {
+    if std::fs::symlink_metadata(path)?.is_dir() {
+        std::fs::remove_dir_all(path)
+    } else {
+        std::fs::remove_file(path)
+    }
+}
+
+fn copy_entry(from: &std::path::Path, to: &std::path::Path) -> io::Result<()> {
+    for entry in walkdir::WalkDir::new(from).follow_root_links(false) {
+        let entry = entry?;
+        let rel = entry.path().strip_prefix(from).unwrap_or(entry.path());
+        // 单个文件时 rel 为空，直接复制到 to
+        let target = if rel.as_os_str().is_empty() { to.to_path_buf() } else { to.join(rel) };
+        let file_type = entry.file_type();
+        if file_type.is_dir() {
+            std::fs::create_dir_all(&target)?;
+        } else if file_type.is_symlink() {
+            #[cfg(unix)]
+            std::os::unix::fs::symlink(std::fs::read_link(entry.path())?, &target)?;
+            #[cfg(not(unix))]
+            std::fs::copy(entry.path(), &target)?;
+        } else {
+            std::fs::copy(entry.path(), &target)?;
+        }
+    }
+    Ok(())
+}
+
+fn trash(state: &AppState) -> Result<Arc<Trash>, AppError> {
+    state.trash.clone().ok_or_else(|| AppError::Disabled("trash is not enabled".to_string()))
+}
+
+// `what` 是错误信息里的条目，回收站条目 id 或原路径
+async fn blocking<T: Send + 'static>(
+    what: &str,
+    f: impl FnOnce() -> io::Result<T> + Send + 'static,
+) -> Result<T, AppError> {
+    tokio::task::spawn_blocking(f).await?.map_err(|e| match e.kind() {
+        io::ErrorKind::NotFound => AppError::NotFound(format!("{} not found in trash", what)),
+        io::ErrorKind::AlreadyExists => AppError::Conflict(format!("{} already exists", what)),
+        _ => AppError::io(format!("trash {}", what), e),
+    })
+}
+
+/// 列出回收站，对用户隐藏的原路径不列出
+pub(crate) async fn list_trash_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<CurrentUser>,
+) -> Result<(StatusCode, Json<ApiResponse>), AppError> {
+    user.require(Role::Admin)?;
+    let trash = trash(&state)?;
+    let mut items = blocking("", move || Ok(trash.list())).await?;
+    let root = &state.config.root_dirpath;
+    items.retain(|i| !access::is_hidden(&state.config.access_rules, &user, &access::rule_path(root, &root.join(&i.path))));
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse {
+            code: 200,
+            message: format!("{} items, {}", items.len(), format_bytes(items.iter().map(|i| i.size).sum())),
+            data: Some(json!(items)),
+        }),
+    ))
+}
+
+/// 恢复到原路径，需要原路径允许新建（目录）或上传（文件）
+pub(crate) async fn restore_trash_handler(
+    Path(id): Path<String>,
+    State(state): State<AppState>,
+    Extension(user): Extension<CurrentUser>,
+) -> Result<(StatusCode, Json<ApiResponse>), AppError> {
+    user.require(Role::Admin)?;
+    let trash = trash(&state)?;
+    let item = blocking(&id.clone(), {
+        let trash = trash.clone();
+        move || trash.load(&id)
+    })
+    .await?;
+    let a_path = state.paths.resolve_new_async(&item.path).await?;
+    let rpath = access::rule_path(&state.config.root_dirpath, &a_path);
+    access::check(&state.config.access_rules, &user, &rpath, if item.is_dir { Op::Create } else { Op::Upload })?;
+    let restored = item.clone();
+    blocking(&item.path, move || trash.restore(&restored, &a_path)).await?;
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse {
+            code: 200,
+            message: format!("restored {}", &item.path),
+            data: Some(json!(item)),
+        }),
+    ))
+}
+
+/// 永久删除一个条目
+pub(crate) async fn purge_trash_handler(
+    Path(id): Path<String>,
+    State(state): State<AppState>,
+    Extension(user): Extension<CurrentUser>,
+) -> Result<(StatusCode, Json<ApiResponse>), AppError> {
+    user.require(Role::Admin)?;
+    let trash = trash(&state)?;
+    let item = blocking(&id.clone(), move || {
+        let item = trash.load(&id)?;
+        trash.purge(&id)?;
+        Ok(item)
+    })
+    .await?;
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse {
+            code: 200,
+            message: format!("purged {}", &item.path),
+            data: None,
+        }),
+    ))
+}
+
+/// 清空回收站
+pub(crate) async fn empty_trash_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<CurrentUser>,
+) -> Result<(StatusCode, Json<ApiResponse>), AppError> {
+    user.require(Role::Admin)?;
+    let trash = trash(&state)?;
+    let purged = blocking("", move || {
+        let items = trash.list();
+        for item in &items {
+            trash.purge(&item.id)?;
+        }
+        Ok(items.len())
+    })
+    .await?;
+    tracing::info!(">>> {:?} emptied trash", &user.name);
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse {
+            code: 200,
+            message: format!("purged {} items", purged),
+            data: None,
+        }),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture() -> (tempfile::TempDir, PathBuf, Trash) {
+        let tmp = tempfile::tempdir().unwrap();
+        let root = tmp.path().canonicalize().unwrap().join("root");
+        std::fs::create_dir_all(root.join("dir/sub")).unwrap();
+        std::fs::write(root.join("dir/sub/a.txt"), b"a").unwrap();
+        std::fs::write(root.join("file.txt"), b"f").unwrap();
+        let trash = Trash::new(&root.join(".rshttpserver"), root.clone(), Duration::ZERO, 0);
+        (tmp, root, trash)
+    }
+
+    #[test]
+    fn restores_entries() {
+        let (_tmp, root, trash) = fixture();
+        let user = CurrentUser { name: None, role: None };
+        let dir = trash.put(&root.join("dir"), &user).unwrap();
+        let file = trash.put(&root.join("file.txt"), &user).unwrap();
+        assert_eq!((dir.size, dir.is_dir, file.size, file.is_dir), (1, true, 1, false));
+        assert!(!root.join("dir").exists() && !root.join("file.txt").exists());
+        assert_eq!(trash.list().len(), 2);
+
+        trash.restore(&dir, &root.join("dir")).unwrap();
+        trash.restore(&file, &root.join("file.txt")).unwrap();
+        assert_eq!(std::fs::read(root.join("dir/sub/a.txt")).unwrap(), b"a");
+        assert_eq!(std::fs::read(root.join("file.txt")).unwrap(), b"f");
+        assert!(trash.list().is_empty());
+        assert_eq!(std::fs::read_dir(&trash.dir).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn restore_never_replaces() {
+        let (_tmp, root, trash) = fixture();
+        let user = CurrentUser { name: None, role: None };
+        let dir = trash.put(&root.join("dir"), &user).unwrap();
+        let file = trash.put(&root.join("file.txt"), &user).unwrap();
+        // 删除之后原路径又被占用：空目录、同名文件、同名目录都不能被替换
+        let cases = [(&dir, true, false), (&dir, false, false), (&file, false, true), (&file, true, true)];
+        for (item, as_dir, file_item) in cases {
+            let target = root.join(if file_item { "file.txt" } else { "dir" });
+            if as_dir {
+                std::fs::create_dir(&target).unwrap();
+            } else {
+                std::fs::write(&target, b"new").unwrap();
+            }
+            let restored = trash.restore(item, &target);
+            assert_eq!(restored.unwrap_err().kind(), io::ErrorKind::AlreadyExists, "{:?} over dir={}", &item.path, as_dir);
+            assert_eq!(target.is_dir(), as_dir);
+            if !as_dir {
+                assert_eq!(std::fs::read(&target).unwrap(), b"new");
+            }
+            assert!(trash.data_path(&item.id).exists() && trash.info_path(&item.id).exists());
+            remove_entry(&target).unwrap();
+        }
+    }
+}
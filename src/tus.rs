@@ -0,0 +1,591 @@
+use crate::access::{self, Op};
+use crate::auth::{CurrentUser, Role};
+use crate::error::AppError;
+use crate::state::AppState;
+use crate::upload::{self, ConflictPolicy, FailReason, UploadError, UploadQuery};
+use crate::utils::{format_bytes, random_hex};
+use axum::body::Body;
+use axum::extract::{Path, Query, State};
+use axum::http::{HeaderMap, HeaderName, HeaderValue, StatusCode, header};
+use axum::response::{IntoResponse, Response};
+use axum::Extension;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use sha2::digest::DynDigest;
+use std::collections::{HashMap, HashSet};
+use std::io;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
+use tokio::io::{AsyncWriteExt, BufWriter};
+use tokio_stream::StreamExt;
+
+const TUS_VERSION: &str = "1.0.0";
+const TUS_EXTENSIONS: &str = "creation,expiration,termination,checksum";
+const TUS_CHECKSUM_ALGORITHMS: &str = "sha1,sha256";
+const TUS_RESUMABLE: HeaderName = HeaderName::from_static("tus-resumable");
+const UPLOAD_OFFSET: HeaderName = HeaderName::from_static("upload-offset");
+const UPLOAD_LENGTH: HeaderName = HeaderName::from_static("upload-length");
+const UPLOAD_METADATA: HeaderName = HeaderName::from_static("upload-metadata");
+const UPLOAD_EXPIRES: HeaderName = HeaderName::from_static("upload-expires");
+const UPLOAD_CHECKSUM: HeaderName = HeaderName::from_static("upload-checksum");
+
+/// 一次未完成的上传，和数据文件一起放在状态目录里，服务重启后仍然可以继续
+#[derive(Debug, Serialize, Deserialize)]
+struct TusUpload {
+    /// 目标目录，相对根目录
+    dir: String,
+    filename: String,
+    length: u64,
+    /// 创建者，只有创建者能继续或取消这个上传；未配置用户时为空
+    owner: Option<String>,
+    /// 客户端的 Upload-Metadata 原文，HEAD 时原样返回
+    metadata: String,
+    /// 创建时 `policy` 参数指定的冲突处理方式，收齐后按它保存
+    #[serde(default)]
+    policy: ConflictPolicy,
+}
+
+/// tus 上传的存储，每个上传对应状态目录下的 `<id>.part`（已收到的数据）和 `<id>.json`（上传信息）
+pub(crate) struct TusStore {
+    dir: PathBuf,
+    expiry: Duration,
+    // 正在写入的上传，同一个上传不允许并发 PATCH
+    busy: Mutex<HashSet<String>>,
+}
+
+struct BusyGuard {
+    store: Arc<TusStore>,
+    id: String,
+}
+
+impl Drop for BusyGuard {
+    fn drop(&mut self) {
+        self.store.busy.lock().unwrap().remove(&self.id);
+    }
+}
+
+impl TusStore {
+    pub(crate) fn new(state_dir: &std::path::Path, expiry: Duration) -> io::Result<TusStore> {
+        let dir = state_dir.join("tus");
+        std::fs::create_dir_all(&dir)?;
+        Ok(TusStore {
+            dir,
+            expiry,
+            busy: Mutex::new(HashSet::new()),
+        })
+    }
+
+    fn data_path(&self, id: &str) -> PathBuf {
+        self.dir.join(format!("{}.part", id))
+    }
+
+    fn info_path(&self, id: &str) -> PathBuf {
+        self.dir.join(format!("{}.json", id))
+    }
+
+    // 其它用户的上传也按不存在处理，避免通过 id 探测
+    async fn load(&self, id: &str, user: &CurrentUser) -> Result<TusUpload, AppError> {
+        let not_found = || AppError::NotFound(format!("upload {} not found", id));
+        if id.len() != 32 || !id.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(not_found());
+        }
+        let content = tokio::fs::read(self.info_path(id)).await.map_err(|_| not_found())?;
+        let upload: TusUpload = serde_json::from_slice(&content).map_err(|_| not_found())?;
+        if upload.owner != user.name {
+            return Err(not_found());
+        }
+        Ok(upload)
+    }
+
+    async fn offset(&self, id: &str) -> Result<(u64, SystemTime), AppError> {
+        match tokio::fs::metadata(self.data_path(id)).await {
+            Ok(meta) => Ok((meta.len(), meta.modified().unwrap_or_else(|_| SystemTime::now()))),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Err(AppError::NotFound(format!("upload {} not found", id))),
+            Err(e) => Err(AppError::io(format!("read upload {}", id), e)),
+        }
+    }
+
+    /// PATCH 的 Upload-Offset 必须等于已收到的字节数，否则客户端应先 HEAD 再继续
+    async fn check_offset(&self, id: &str, offset: u64) -> Result<(), AppError> {
+        let (current, _) = self.offset(id).await?;
+        if current != offset {
+            return Err(AppError::Conflict(format!("Upload-Offset {} does not match the current offset {}", offset, current)));
+        }
+        Ok(())
+    }
+
+    fn lock(self: &Arc<Self>, id: &str) -> Result<BusyGuard, AppError> {
+        if !self.busy.lock().unwrap().insert(id.to_string()) {
+            return Err(AppError::Conflict(format!("upload {} is being written by another request", id)));
+        }
+        Ok(BusyGuard {
+            store: self.clone(),
+            id: id.to_string(),
+        })
+    }
+
+    async fn remove(&self, id: &str) {
+        for path in [self.data_path(id), self.info_path(id)] {
+            if let Err(e) = tokio::fs::remove_file(&path).await
+                && e.kind() != io::ErrorKind::NotFound
+            {
+                tracing::error!(">>> remove {:?} error: {}", &path, e);
+            }
+        }
+    }
+
+    fn expires(&self, modified: SystemTime) -> String {
+        httpdate::fmt_http_date(modified + self.expiry)
+    }
+
+    /// 定期清理超过 `tus_expiry` 没有写入的上传
+    pub(crate) fn spawn_expiry(self: &Arc<Self>) {
+        let store = self.clone();
+        tokio::spawn(async move {
+            let period = store.expiry.clamp(Duration::from_secs(1), Duration::from_secs(10 * 60));
+            let mut interval = tokio::time::interval(period);
+            loop {
+                interval.tick().await;
+                store.expire().await;
+            }
+        });
+    }
+
+    async fn expire(&self) {
+        let Ok(mut entries) = tokio::fs::read_dir(&self.dir).await else {
+            return;
+        };
+        let now = SystemTime::now();
+        while let Ok(Some(entry)) = entries.next_entry().await {
+            let path = entry.path();
+            // 只看 json，数据文件跟着一起删；数据文件的修改时间就是最后一次写入的时间
+            let Some(id) = path.file_stem().and_then(|s| s.to_str()).filter(|_| path.extension().is_some_and(|e| e == "json")) else {
+                continue;
+            };
+            let modified = match tokio::fs::metadata(self.data_path(id)).await {
+                Ok(meta) => meta.modified(),
+                Err(_) => entry.metadata().await.and_then(|m| m.modified()),
+            };
+            let stale = modified.is_ok_and(|m| now.duration_since(m).is_ok_and(|d| d > self.expiry));
+            if stale && !self.busy.lock().unwrap().contains(id) {
+                tracing::info!(">>> expire unfinished upload {}", id);
+                self.remove(id).await;
+            }
+        }
+    }
+}
+
+// 所有 tus 响应都要带上 Tus-Resumable，版本不匹配的 412 还需要 Tus-Version，这里一并加上
+fn tus(result: Result<Response, AppError>) -> Response {
+    let mut response = result.unwrap_or_else(IntoResponse::into_response);
+    response.headers_mut().insert(TUS_RESUMABLE, HeaderValue::from_static(TUS_VERSION));
+    response.headers_mut().insert("tus-version", HeaderValue::from_static(TUS_VERSION));
+    response
+}
+
+// 只读模式或状态目录不可写时没有 tus 存储
+fn store(state: &AppState) -> Result<&Arc<TusStore>, AppError> {
+    state
+        .tus
+        .as_ref()
+        .ok_or_else(|| AppError::Disabled("resumable uploads are disabled".to_string()))
+}
+
+fn header_str<'a>(headers: &'a HeaderMap, name: &HeaderName) -> Option<&'a str> {
+    headers.get(name).and_then(|v| v.to_str().ok())
+}
+
+fn require_version(headers: &HeaderMap) -> Result<(), AppError> {
+    if header_str(headers, &TUS_RESUMABLE) == Some(TUS_VERSION) {
+        return Ok(());
+    }
+    Err(AppError::PreconditionFailed(format!("Tus-Resumable {} is required", TUS_VERSION)))
+}
+
+/// 解析 `key base64,key base64` 形式的 Upload-Metadata，值可以省略
+fn parse_metadata(raw: &str) -> Result<HashMap<String, String>, AppError> {
+    let mut metadata = HashMap::new();
+    for pair in raw.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+        let (key, value) = pair.split_once(' ').unwrap_or((pair, ""));
+        let value = base64::engine::general_purpose::STANDARD
+            .decode(value.trim())
+            .ok()
+            .and_then(|v| String::from_utf8(v).ok())
+            .ok_or_else(|| AppError::BadRequest(format!("invalid Upload-Metadata value of {}", key)))?;
+        metadata.insert(key.to_string(), value);
+    }
+    Ok(metadata)
+}
+
+// 计算中的摘要和客户端给出的期望值
+type Checksum = (Box<dyn DynDigest + Send>, Vec<u8>);
+
+/// 解析 `sha1 base64` 形式的 Upload-Checksum
+fn parse_checksum(raw: &str) -> Result<Checksum, AppError> {
+    let (algo, digest) = raw.split_once(' ').unwrap_or((raw, ""));
+    let hasher: Box<dyn DynDigest + Send> = match algo {
+        "sha1" => Box::new(sha1::Sha1::default()),
+        "sha256" => Box::new(sha2::Sha256::default()),
+        _ => return Err(AppError::BadRequest(format!("checksum algorithm {} is not supported", algo))),
+    };
+    let digest = base64::engine::general_purpose::STANDARD
+        .decode(digest.trim())
+        .ok()
+        .filter(|d| d.len() == hasher.output_size())
+        .ok_or_else(|| AppError::BadRequest("invalid Upload-Checksum".to_string()))?;
+    Ok((hasher, digest))
+}
+
+pub(crate) async fn tus_options_handler(State(state): State<AppState>) -> Response {
+    let headers = [
+        ("tus-extension", TUS_EXTENSIONS.to_string()),
+        ("tus-checksum-algorithm", TUS_CHECKSUM_ALGORITHMS.to_string()),
+        ("tus-max-size", state.config.max_upload_size.to_string()),
+    ];
+    tus(Ok((StatusCode::NO_CONTENT, headers).into_response()))
+}
+
+/// creation 扩展：登记一个新上传，`filename` 和目标目录 `path` 通过 Upload-Metadata 传入
+pub(crate) async fn tus_create_handler(
+    State(state): State<AppState>,
+    Extension(user): Extension<CurrentUser>,
+    Query(query): Query<UploadQuery>,
+    headers: HeaderMap,
+) -> Response {
+    tus(create_upload(state, user, query.policy, headers).await)
+}
+
+async fn create_upload(state: AppState, user: CurrentUser, policy: ConflictPolicy, headers: HeaderMap) -> Result<Response, AppError> {
+    user.require(Role::Uploader)?;
+    require_version(&headers)?;
+    let store = store(&state)?;
+    let Some(length) = header_str(&headers, &UPLOAD_LENGTH) else {
+        let message = if headers.contains_key("upload-defer-length") {
+            "Upload-Defer-Length is not supported"
+        } else {
+            "Upload-Length is required"
+        };
+        return Err(AppError::BadRequest(message.to_string()));
+    };
+    let length: u64 = length.parse().map_err(|_| AppError::BadRequest(format!("invalid Upload-Length {}", length)))?;
+    if length > state.config.max_upload_size {
+        return Err(AppError::PayloadTooLarge(format!(
+            "upload of {} exceeds the limit of {}",
+            format_bytes(length),
+            format_bytes(state.config.max_upload_size)
+        )));
+    }
+
+    let raw_metadata = header_str(&headers, &UPLOAD_METADATA).unwrap_or_default().to_string();
+    let metadata = parse_metadata(&raw_metadata)?;
+    let Some(filename) = metadata.get("filename").or(metadata.get("name")) else {
+        return Err(AppError::BadRequest("filename is required in Upload-Metadata".to_string()));
+    };
+    // 和表单上传一样只取文件名部分
+    let filename = std::path::Path::new(filename)
+        .file_name()
+        .map_or_else(|| filename.clone(), |n| n.to_string_lossy().to_string());
+    let dir = metadata.get("path").cloned().unwrap_or_default();
+    let save_path = check_target(&state, &user, &dir, &filename).await?;
+    // 和表单上传一样，跳过和失败的策略在接收数据之前先检查一次
+    if matches!(policy, ConflictPolicy::Skip | ConflictPolicy::Fail) && tokio::fs::symlink_metadata(&save_path).await.is_ok() {
+        let rpath = access::rule_path(&state.config.root_dirpath, &save_path);
+        return Err(UploadError::new(FailReason::AlreadyExists, format!("{} already exists", rpath)).into());
+    }
+
+    let id = random_hex(16);
+    let upload = TusUpload {
+        dir,
+        filename,
+        length,
+        owner: user.name.clone(),
+        metadata: raw_metadata,
+        policy,
+    };
+    let internal = |e: io::Error| AppError::io("create upload", e);
+    tokio::fs::File::create(store.data_path(&id)).await.map_err(internal)?;
+    tokio::fs::write(store.info_path(&id), serde_json::to_vec(&upload).expect("Failed to serialize upload"))
+        .await
+        .map_err(internal)?;
+    tracing::info!(">>> create upload {} of {} for {}/{}", &id, format_bytes(length), &upload.dir, &upload.filename);
+
+    let mut headers = HeaderMap::new();
+    if let Ok(location) = HeaderValue::from_str(&format!("/tus/{}", id)) {
+        headers.insert(header::LOCATION, location);
+    }
+    // 空文件不会再有 PATCH，创建时就已经收齐
+    if length == 0 {
+        finish(&state, store, &id, &upload, &save_path).await?;
+    } else if let Ok(expires) = HeaderValue::from_str(&store.expires(SystemTime::now())) {
+        headers.insert(UPLOAD_EXPIRES, expires);
+    }
+    Ok((StatusCode::CREATED, headers).into_response())
+}
+
+/// 收齐后按创建时的冲突策略保存到目标位置
+async fn finish(state: &AppState, store: &TusStore, id: &str, upload: &TusUpload, save_path: &std::path::Path) -> Result<(), AppError> {
+    let display = |p: &std::path::Path| access::rule_path(&state.config.root_dirpath, p);
+    let result = upload::save_file(&upload.filename, &store.data_path(id), save_path, display, upload.policy).await;
+    // 数据已经移出状态目录，无论成败这个上传都结束了
+    store.remove(id).await;
+    if let Some(reason) = result.reason {
+        return Err(UploadError::new(reason, result.error.unwrap_or_default()).into());
+    }
+    Ok(())
+}
+
+// 创建和完成时都要检查目标：目录存在，文件名合法，且规则允许上传
+async fn check_target(state: &AppState, user: &CurrentUser, dir: &str, filename: &str) -> Result<PathBuf, AppError> {
+    let a_dir = state.paths.resolve_async(dir).await?;
+    if !tokio::fs::metadata(&a_dir).await.is_ok_and(|m| m.is_dir()) {
+        return Err(AppError::NotFound(format!("{} is not a folder", dir)));
+    }
+    let save_path = state.paths.join_name_async(&a_dir, filename).await?;
+    for a_path in [&a_dir, &save_path] {
+        let rpath = access::rule_path(&state.config.root_dirpath, a_path);
+        access::check(&state.config.access_rules, user, &rpath, Op::Upload)?;
+    }
+    Ok(save_path)
+}
+
+/// 查询已收到的字节数，客户端据此从断点继续
+pub(crate) async fn tus_head_handler(
+    Path(id): Path<String>,
+    State(state): State<AppState>,
+    Extension(user): Extension<CurrentUser>,
+    headers: HeaderMap,
+) -> Response {
+    tus(head_upload(state, user, id, headers).await)
+}
+
+async fn head_upload(state: AppState, user: CurrentUser, id: String, headers: HeaderMap) -> Result<Response, AppError> {
+    user.require(Role::Uploader)?;
+    require_version(&headers)?;
+    let store = store(&state)?;
+    let upload = store.load(&id, &user).await?;
+    let (offset, modified) = store.offset(&id).await?;
+    let mut headers = HeaderMap::new();
+    headers.insert(UPLOAD_OFFSET, HeaderValue::from(offset));
+    headers.insert(UPLOAD_LENGTH, HeaderValue::from(upload.length));
+    headers.insert(header::CACHE_CONTROL, HeaderValue::from_static("no-store"));
+    if let Ok(expires) = HeaderValue::from_str(&store.expires(modified)) {
+        headers.insert(UPLOAD_EXPIRES, expires);
+    }
+    if let Ok(metadata) = HeaderValue::from_str(&upload.metadata)
+        && !upload.metadata.is_empty()
+    {
+        headers.insert(UPLOAD_METADATA, metadata);
+    }
+    Ok((StatusCode::OK, headers).into_response())
+}
+
+/// 从 Upload-Offset 开始追加数据，连接中断时已写入的部分保留，收齐后移动到目标位置
+pub(crate) async fn tus_patch_handler(
+    Path(id): Path<String>,
+    State(state): State<AppState>,
+    Extension(user): Extension<CurrentUser>,
+    headers: HeaderMap,
+    body: Body,
+) -> Response {
+    tus(patch_upload(state, user, id, headers, body).await)
+}
+
+async fn patch_upload(state: AppState, user: CurrentUser, id: String, headers: HeaderMap, body: Body) -> Result<Response, AppError> {
+    user.require(Role::Uploader)?;
+    require_version(&headers)?;
+    let store = store(&state)?;
+    if header_str(&headers, &header::CONTENT_TYPE) != Some("application/offset+octet-stream") {
+        return Err(AppError::UnsupportedMediaType(
+            "Content-Type must be application/offset+octet-stream".to_string(),
+        ));
+    }
+    let offset: u64 = header_str(&headers, &UPLOAD_OFFSET)
+        .and_then(|o| o.parse().ok())
+        .ok_or_else(|| AppError::BadRequest("Upload-Offset is required".to_string()))?;
+    let mut checksum = header_str(&headers, &UPLOAD_CHECKSUM).map(parse_checksum).transpose()?;
+
+    let upload = store.load(&id, &user).await?;
+    let _guard = store.lock(&id)?;
+    store.check_offset(&id, offset).await?;
+
+    let data_path = store.data_path(&id);
+    let internal = |e: io::Error| AppError::io(format!("write upload {}", &id), e);
+    let file = tokio::fs::OpenOptions::new().append(true).open(&data_path).await.map_err(internal)?;
+    let mut writer = BufWriter::new(file);
+    let mut written = 0u64;
+    let mut failure = None;
+    let mut stream = body.into_data_stream();
+    while let Some(chunk) = stream.next().await {
+        let chunk = match chunk {
+            Ok(c) => c,
+            Err(e) => {
+                failure = Some(AppError::BadRequest(format!("upload {} interrupted: {}", &id, e)));
+                break;
+            }
+        };
+        if offset + written + chunk.len() as u64 > upload.length {
+            failure = Some(AppError::PayloadTooLarge(format!("upload {} exceeds Upload-Length {}", &id, upload.length)));
+            break;
+        }
+        if let Err(e) = writer.write_all(&chunk).await {
+            failure = Some(internal(e));
+            break;
+        }
+        if let Some((hasher, _)) = checksum.as_mut() {
+            hasher.update(&chunk);
+        }
+        written += chunk.len() as u64;
+    }
+    // 确认的偏移量必须已经落盘，断点续传才可靠
+    let mut flushed = writer.flush().await;
+    if flushed.is_ok() {
+        flushed = writer.get_ref().sync_data().await;
+    }
+    if let Err(e) = flushed {
+        failure.get_or_insert(internal(e));
+    }
+    if failure.is_none()
+        && let Some((hasher, expected)) = checksum
+        && *hasher.finalize() != *expected
+    {
+        failure = Some(AppError::ChecksumMismatch(format!("checksum mismatch for upload {}", &id)));
+    }
+    if let Some(failure) = failure {
+        // 没有校验和时中断前收到的数据可以保留，其它情况回退到这次请求之前的偏移量
+        let keep = matches!(failure, AppError::BadRequest(_)) && !headers.contains_key(UPLOAD_CHECKSUM);
+        if !keep {
+            let _ = writer.into_inner().set_len(offset).await;
+        }
+        tracing::warn!(">>> patch upload {} at offset {} failed with {}", &id, offset, failure.status());
+        return Err(failure);
+    }
+    drop(writer);
+
+    let new_offset = offset + written;
+    let mut headers = HeaderMap::new();
+    headers.insert(UPLOAD_OFFSET, HeaderValue::from(new_offset));
+    if new_offset < upload.length {
+        if let Ok(expires) = HeaderValue::from_str(&store.expires(SystemTime::now())) {
+            headers.insert(UPLOAD_EXPIRES, expires);
+        }
+        return Ok((StatusCode::NO_CONTENT, headers).into_response());
+    }
+
+    // 收齐后再检查一次目标，期间目录可能被删除或规则有变化
+    let save_path = check_target(&state, &user, &upload.dir, &upload.filename).await?;
+    finish(&state, store, &id, &upload, &save_path).await?;
+    Ok((StatusCode::NO_CONTENT, headers).into_response())
+}
+
+/// termination 扩展：取消上传并删除已收到的数据
+pub(crate) async fn tus_delete_handler(
+    Path(id): Path<String>,
+    State(state): State<AppState>,
+    Extension(user): Extension<CurrentUser>,
+    headers: HeaderMap,
+) -> Response {
+    tus(delete_upload(state, user, id, headers).await)
+}
+
+async fn delete_upload(state: AppState, user: CurrentUser, id: String, headers: HeaderMap) -> Result<Response, AppError> {
+    user.require(Role::Uploader)?;
+    require_version(&headers)?;
+    let store = store(&state)?;
+    store.load(&id, &user).await?;
+    let _guard = store.lock(&id)?;
+    store.remove(&id).await;
+    tracing::info!(">>> terminate upload {}", &id);
+    Ok(StatusCode::NO_CONTENT.into_response())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn encode(s: &str) -> String {
+        base64::engine::general_purpose::STANDARD.encode(s)
+    }
+
+    fn store(expiry: Duration) -> (tempfile::TempDir, Arc<TusStore>) {
+        let tmp = tempfile::tempdir().unwrap();
+        let store = Arc::new(TusStore::new(tmp.path(), expiry).unwrap());
+        (tmp, store)
+    }
+
+    // 写入一个上传的数据文件和信息文件，数据文件的修改时间往前拨 `age`
+    fn add_upload(store: &TusStore, id: &str, data: &[u8], age: Duration) {
+        std::fs::write(store.data_path(id), data).unwrap();
+        std::fs::write(store.info_path(id), b"{}").unwrap();
+        let file = std::fs::File::options().write(true).open(store.data_path(id)).unwrap();
+        file.set_modified(SystemTime::now() - age).unwrap();
+    }
+
+    #[test]
+    fn parses_metadata() {
+        let raw = format!("filename {},path {}, is_confidential,empty ", encode("报告 (1).pdf"), encode("docs/2024"));
+        let metadata = parse_metadata(&raw).unwrap();
+        assert_eq!(metadata.len(), 4);
+        assert_eq!(metadata["filename"], "报告 (1).pdf");
+        assert_eq!(metadata["path"], "docs/2024");
+        assert_eq!(metadata["is_confidential"], "");
+        assert_eq!(metadata["empty"], "");
+        assert!(parse_metadata("").unwrap().is_empty());
+        for invalid in ["filename !!!", "filename /w==="] {
+            assert_eq!(parse_metadata(invalid).unwrap_err().status(), StatusCode::BAD_REQUEST, "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn parses_checksum() {
+        let sha1 = base64::engine::general_purpose::STANDARD.encode(<sha1::Sha1 as sha2::Digest>::digest(b"hello"));
+        let (mut hasher, expected) = parse_checksum(&format!("sha1 {}", sha1)).unwrap();
+        hasher.update(b"hello");
+        assert_eq!(*hasher.finalize(), *expected);
+
+        let sha256 = base64::engine::general_purpose::STANDARD.encode(<sha2::Sha256 as sha2::Digest>::digest(b"hello"));
+        let (mut hasher, expected) = parse_checksum(&format!("sha256 {}", sha256)).unwrap();
+        hasher.update(b"hello");
+        assert_eq!(*hasher.finalize(), *expected);
+
+        // 摘要长度必须和算法一致
+        let sha1_as_sha256 = format!("sha256 {}", sha1);
+        for invalid in ["md5 XUFAKrxLKna5cZ2REBfFkg==", "sha1 !!!", "sha1", "sha1 ", &sha1_as_sha256] {
+            assert_eq!(parse_checksum(invalid).err().map(|e| e.status()), Some(StatusCode::BAD_REQUEST), "{}", invalid);
+        }
+    }
+
+    #[tokio::test]
+    async fn rejects_offset_mismatch() {
+        let (_tmp, store) = store(Duration::from_secs(60));
+        let id = "0123456789abcdef0123456789abcdef";
+        add_upload(&store, id, b"hello", Duration::ZERO);
+        assert!(store.check_offset(id, 5).await.is_ok());
+        for offset in [0, 4, 6] {
+            assert_eq!(store.check_offset(id, offset).await.unwrap_err().status(), StatusCode::CONFLICT, "{}", offset);
+        }
+        assert_eq!(store.check_offset("ffffffffffffffffffffffffffffffff", 0).await.unwrap_err().status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn expires_stale_uploads() {
+        let (_tmp, store) = store(Duration::from_secs(60));
+        let (stale, fresh, busy) = ("a".repeat(32), "b".repeat(32), "c".repeat(32));
+        add_upload(&store, &stale, b"x", Duration::from_secs(120));
+        add_upload(&store, &fresh, b"x", Duration::from_secs(30));
+        add_upload(&store, &busy, b"x", Duration::from_secs(120));
+        let guard = store.lock(&busy).unwrap();
+        // 正在写入的上传不能再被锁住
+        assert_eq!(store.lock(&busy).err().map(|e| e.status()), Some(StatusCode::CONFLICT));
+
+        store.expire().await;
+        assert!(!store.data_path(&stale).exists() && !store.info_path(&stale).exists());
+        assert!(store.data_path(&fresh).exists() && store.info_path(&fresh).exists());
+        assert!(store.data_path(&busy).exists());
+
+        drop(guard);
+        store.expire().await;
+        assert!(!store.data_path(&busy).exists() && !store.info_path(&busy).exists());
+        assert!(store.data_path(&fresh).exists());
+    }
+}
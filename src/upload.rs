@@ -0,0 +1,707 @@
+use crate::paths::{PathError, TEMP_PREFIX};
+use crate::utils::{format_bytes, random_hex};
+use axum::extract::multipart::MultipartError;
+use axum::http::{HeaderMap, StatusCode, header};
+use base64::Engine;
+use bytes::Bytes;
+use sha2::digest::DynDigest;
+use serde::{Deserialize, Serialize};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::task::{Context, Poll, ready};
+use tokio::io::{AsyncWriteExt, BufWriter};
+use tokio_stream::{Stream, StreamExt};
+
+/// 目标文件已存在时的处理方式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum ConflictPolicy {
+    /// 覆盖已有文件
+    #[default]
+    Overwrite,
+    /// 保留已有文件，不保存这次上传
+    Skip,
+    /// 另存为 `name (1).ext`
+    Rename,
+    /// 保留已有文件并报告失败
+    Fail,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct UploadQuery {
+    #[serde(default)]
+    pub(crate) policy: ConflictPolicy,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum UploadStatus {
+    Created,
+    Overwritten,
+    Renamed,
+    Skipped,
+    Failed,
+}
+
+/// 失败原因，脚本可以据此决定是否重试：磁盘满和权限问题重试无用，客户端中断可以重传
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum FailReason {
+    InvalidName,
+    NotAllowed,
+    AlreadyExists,
+    TooLarge,
+    ChecksumMismatch,
+    DiskFull,
+    PermissionDenied,
+    ClientAborted,
+    Io,
+}
+
+impl FailReason {
+    pub(crate) fn status(self) -> StatusCode {
+        match self {
+            FailReason::InvalidName | FailReason::ClientAborted | FailReason::ChecksumMismatch => StatusCode::BAD_REQUEST,
+            FailReason::NotAllowed | FailReason::PermissionDenied => StatusCode::FORBIDDEN,
+            FailReason::AlreadyExists => StatusCode::CONFLICT,
+            FailReason::TooLarge => StatusCode::PAYLOAD_TOO_LARGE,
+            FailReason::DiskFull => StatusCode::INSUFFICIENT_STORAGE,
+            FailReason::Io => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    /// 和序列化后的名字一致，整个请求失败时作为错误码返回
+    pub(crate) fn code(self) -> &'static str {
+        match self {
+            FailReason::InvalidName => "invalid_name",
+            FailReason::NotAllowed => "not_allowed",
+            FailReason::AlreadyExists => "already_exists",
+            FailReason::TooLarge => "too_large",
+            FailReason::ChecksumMismatch => "checksum_mismatch",
+            FailReason::DiskFull => "disk_full",
+            FailReason::PermissionDenied => "permission_denied",
+            FailReason::ClientAborted => "client_aborted",
+            FailReason::Io => "io",
+        }
+    }
+}
+
+/// 一个文件上传失败的原因和说明
+#[derive(Debug)]
+pub(crate) struct UploadError {
+    pub(crate) reason: FailReason,
+    pub(crate) message: String,
+}
+
+impl UploadError {
+    pub(crate) fn new(reason: FailReason, message: String) -> UploadError {
+        UploadError { reason, message }
+    }
+}
+
+impl From<io::Error> for UploadError {
+    fn from(e: io::Error) -> Self {
+        let reason = match e.kind() {
+            io::ErrorKind::StorageFull | io::ErrorKind::QuotaExceeded | io::ErrorKind::FileTooLarge => FailReason::DiskFull,
+            io::ErrorKind::PermissionDenied | io::ErrorKind::ReadOnlyFilesystem => FailReason::PermissionDenied,
+            io::ErrorKind::AlreadyExists => FailReason::AlreadyExists,
+            _ => FailReason::Io,
+        };
+        UploadError::new(reason, e.to_string())
+    }
+}
+
+impl From<PathError> for UploadError {
+    fn from(e: PathError) -> Self {
+        match e {
+            PathError::Io(_, e) => e.into(),
+            PathError::Traversal(p) | PathError::InvalidName(p) | PathError::NotFound(p) => {
+                UploadError::new(FailReason::InvalidName, format!("{} is not a valid name", p))
+            }
+            PathError::Root => UploadError::new(FailReason::NotAllowed, "the root directory can not be changed".to_string()),
+            PathError::SymlinkDenied(p) | PathError::OutsideRoot(p) => {
+                UploadError::new(FailReason::NotAllowed, format!("{} is behind a symlink that is not allowed", p))
+            }
+        }
+    }
+}
+
+// 原始请求体中断，超出大小限制的情况由 VerifiedStream 自己判断
+impl From<axum::Error> for UploadError {
+    fn from(e: axum::Error) -> Self {
+        UploadError::new(FailReason::ClientAborted, e.to_string())
+    }
+}
+
+// 读取请求体出错：超过大小限制，或者客户端断开、发送的数据不完整
+impl From<MultipartError> for UploadError {
+    fn from(e: MultipartError) -> Self {
+        let reason = if e.status() == StatusCode::PAYLOAD_TOO_LARGE {
+            FailReason::TooLarge
+        } else {
+            FailReason::ClientAborted
+        };
+        UploadError::new(reason, e.body_text())
+    }
+}
+
+/// 单个文件的上传结果，放在 `ApiResponse.data` 里返回
+#[derive(Debug, Serialize)]
+pub(crate) struct UploadResult {
+    /// 客户端给出的文件名
+    pub(crate) name: String,
+    /// 实际保存的位置，跳过或失败时为空
+    pub(crate) path: Option<String>,
+    /// 收到的字节数，失败时是出错前收到的部分
+    pub(crate) bytes: u64,
+    pub(crate) status: UploadStatus,
+    pub(crate) reason: Option<FailReason>,
+    pub(crate) error: Option<String>,
+}
+
+impl UploadResult {
+    pub(crate) fn failed(name: &str, bytes: u64, e: UploadError) -> UploadResult {
+        UploadResult {
+            name: name.to_string(),
+            path: None,
+            bytes,
+            status: UploadStatus::Failed,
+            reason: Some(e.reason),
+            error: Some(e.message),
+        }
+    }
+}
+
+/// 整个请求的状态：全部成功 200，部分失败 207，全部失败时取各失败原因中最严重的状态码
+pub(crate) fn overall_status(results: &[UploadResult]) -> StatusCode {
+    let failed = results.iter().filter_map(|r| r.reason).map(FailReason::status);
+    match (failed.clone().count(), results.len()) {
+        (_, 0) => StatusCode::BAD_REQUEST,
+        (0, _) => StatusCode::OK,
+        (n, total) if n < total => StatusCode::MULTI_STATUS,
+        _ => failed.max().unwrap_or(StatusCode::INTERNAL_SERVER_ERROR),
+    }
+}
+
+/// 请求头中声明的完整性信息：Content-Length、Content-MD5 和 Digest（RFC 3230，支持 md5、sha、sha-256）
+pub(crate) struct Expected {
+    length: Option<u64>,
+    digests: Vec<(&'static str, Box<dyn DynDigest + Send>, Vec<u8>)>,
+}
+
+impl Expected {
+    pub(crate) fn from_headers(headers: &HeaderMap) -> Result<Expected, UploadError> {
+        let invalid = |name: &str| UploadError::new(FailReason::ChecksumMismatch, format!("invalid {} header", name));
+        let decode = |name: &str, value: &str| base64::engine::general_purpose::STANDARD.decode(value.trim()).map_err(|_| invalid(name));
+        let length = match headers.get(header::CONTENT_LENGTH) {
+            Some(v) => Some(v.to_str().ok().and_then(|v| v.parse().ok()).ok_or_else(|| invalid("Content-Length"))?),
+            None => None,
+        };
+        let mut digests: Vec<(&'static str, Box<dyn DynDigest + Send>, Vec<u8>)> = vec![];
+        if let Some(v) = headers.get("content-md5") {
+            let v = v.to_str().map_err(|_| invalid("Content-MD5"))?;
+            digests.push(("Content-MD5", Box::new(md5::Md5::default()), decode("Content-MD5", v)?));
+        }
+        for v in headers.get_all("digest") {
+            let v = v.to_str().map_err(|_| invalid("Digest"))?;
+            for item in v.split(',') {
+                let Some((algo, value)) = item.trim().split_once('=') else {
+                    return Err(invalid("Digest"));
+                };
+                // 不认识的算法按 RFC 3230 忽略
+                let hasher: Box<dyn DynDigest + Send> = match algo.to_ascii_lowercase().as_str() {
+                    "md5" => Box::new(md5::Md5::default()),
+                    "sha" => Box::new(sha1::Sha1::default()),
+                    "sha-256" => Box::new(sha2::Sha256::default()),
+                    _ => continue,
+                };
+                digests.push(("Digest", hasher, decode("Digest", value)?));
+            }
+        }
+        Ok(Expected { length, digests })
+    }
+}
+
+/// 边接收边统计长度和计算摘要，数据流结束时和请求头里声明的值比较，
+/// 不一致时产生一个错误，[`save`] 会因此放弃临时文件
+pub(crate) struct VerifiedStream<S> {
+    inner: S,
+    expected: Expected,
+    limit: u64,
+    received: u64,
+    done: bool,
+}
+
+impl<S> VerifiedStream<S> {
+    pub(crate) fn new(inner: S, expected: Expected, limit: u64) -> Self {
+        VerifiedStream {
+            inner,
+            expected,
+            limit,
+            received: 0,
+            done: false,
+        }
+    }
+
+    // 连接中途断开时底层流会先报错，按客户端中断处理；走到这里说明请求体正常结束但和声明的长度不符
+    fn verify(&mut self) -> Result<(), UploadError> {
+        if let Some(length) = self.expected.length
+            && length != self.received
+        {
+            return Err(UploadError::new(
+                FailReason::ChecksumMismatch,
+                format!("received {} of {} bytes", self.received, length),
+            ));
+        }
+        for (name, hasher, expected) in self.expected.digests.iter_mut() {
+            if *hasher.finalize_reset() != **expected {
+                return Err(UploadError::new(FailReason::ChecksumMismatch, format!("{} does not match the received data", name)));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<S, E> Stream for VerifiedStream<S>
+where
+    S: Stream<Item = Result<Bytes, E>> + Unpin,
+    E: Into<UploadError>,
+{
+    type Item = Result<Bytes, UploadError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        if this.done {
+            return Poll::Ready(None);
+        }
+        let item = match ready!(Pin::new(&mut this.inner).poll_next(cx)) {
+            Some(Ok(chunk)) => {
+                this.received += chunk.len() as u64;
+                if this.received > this.limit {
+                    this.done = true;
+                    let message = format!("upload exceeds the limit of {}", format_bytes(this.limit));
+                    return Poll::Ready(Some(Err(UploadError::new(FailReason::TooLarge, message))));
+                }
+                for (_, hasher, _) in this.expected.digests.iter_mut() {
+                    hasher.update(&chunk);
+                }
+                Some(Ok(chunk))
+            }
+            Some(Err(e)) => {
+                this.done = true;
+                Some(Err(e.into()))
+            }
+            None => {
+                this.done = true;
+                this.verify().err().map(Err)
+            }
+        };
+        Poll::Ready(item)
+    }
+}
+
+/// 上传过程中的临时文件，没有保存到目标位置就在 drop 时删除，客户端断开导致请求被取消时也一样
+#[derive(Debug)]
+pub(crate) struct TempFile {
+    pub(crate) path: PathBuf,
+    persisted: bool,
+}
+
+impl TempFile {
+    /// 在目标目录 `dir` 下新建隐藏的临时文件，和目标在同一个文件系统里，保存时才能原子地 rename
+    pub(crate) async fn create(dir: &Path) -> io::Result<(TempFile, tokio::fs::File)> {
+        let temp = TempFile {
+            path: dir.join(format!("{}{}", TEMP_PREFIX, random_hex(8))),
+            persisted: false,
+        };
+        let file = tokio::fs::OpenOptions::new().write(true).create_new(true).open(&temp.path).await?;
+        Ok((temp, file))
+    }
+
+    pub(crate) fn is_persisted(&self) -> bool {
+        self.persisted
+    }
+
+    /// 写完后按 `policy` 移动到 `target`，返回最终路径；成功后临时文件不再删除
+    pub(crate) async fn persist(&mut self, target: &Path, policy: ConflictPolicy) -> io::Result<(PathBuf, UploadStatus)> {
+        let (temp_path, target_path) = (self.path.clone(), target.to_path_buf());
+        let persisted = tokio::task::spawn_blocking(move || persist(&temp_path, &target_path, policy))
+            .await
+            .unwrap_or_else(|e| Err(io::Error::other(e)))?;
+        self.persisted = true;
+        Ok(persisted)
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        if !self.persisted
+            && let Err(e) = std::fs::remove_file(&self.path)
+            && e.kind() != io::ErrorKind::NotFound
+        {
+            tracing::error!(">>> remove temp file {:?} error: {}", &self.path, e);
+        }
+    }
+}
+
+/// 把数据流写入目标目录下的隐藏临时文件，写完后按 `policy` 落到 `target`，
+/// 中途出错或连接断开时不会留下不完整的文件，也不会破坏已有文件
+pub(crate) async fn save<S, E>(name: &str, target: &Path, display: impl Fn(&Path) -> String, policy: ConflictPolicy, mut stream: S) -> UploadResult
+where
+    S: Stream<Item = Result<Bytes, E>> + Unpin,
+    E: Into<UploadError>,
+{
+    let failed = |bytes: u64, e: UploadError| UploadResult::failed(name, bytes, e);
+    // 跳过和失败的策略先检查一次，避免白白接收数据，保存时还会再检查
+    if matches!(policy, ConflictPolicy::Skip | ConflictPolicy::Fail) && tokio::fs::symlink_metadata(target).await.is_ok() {
+        return conflict(name, target, &display, policy);
+    }
+    let Some(dir) = target.parent() else {
+        return failed(0, UploadError::new(FailReason::InvalidName, format!("{} is not a valid name", name)));
+    };
+    let (mut temp, file) = match TempFile::create(dir).await {
+        Ok(t) => t,
+        Err(e) => {
+            tracing::error!(">>> create temp file in {:?} error: {}", dir, e);
+            return failed(0, e.into());
+        }
+    };
+
+    let mut writer = BufWriter::new(file);
+    let mut bytes = 0u64;
+    while let Some(chunk) = stream.next().await {
+        let chunk = match chunk {
+            Ok(c) => c,
+            Err(e) => {
+                let e = e.into();
+                tracing::warn!(">>> upload {} interrupted after {}: {:?}", name, format_bytes(bytes), &e);
+                return failed(bytes, e);
+            }
+        };
+        if let Err(e) = writer.write_all(&chunk).await {
+            tracing::error!(">>> write {:?} error: {}", &temp.path, e);
+            return failed(bytes, e.into());
+        }
+        bytes += chunk.len() as u64;
+    }
+    if let Err(e) = writer.flush().await {
+        tracing::error!(">>> write {:?} error: {}", &temp.path, e);
+        return failed(bytes, e.into());
+    }
+    drop(writer);
+
+    match temp.persist(target, policy).await {
+        Ok((path, status)) => {
+            tracing::info!("success save file: {:?}, size: {}", &path, format_bytes(bytes));
+            UploadResult {
+                name: name.to_string(),
+                path: Some(display(&path)),
+                bytes,
+                status,
+                reason: None,
+                error: None,
+            }
+        }
+        Err(e) if e.kind() == io::ErrorKind::AlreadyExists => conflict(name, target, &display, policy),
+        Err(e) => {
+            tracing::error!(">>> save {:?} error: {}", target, e);
+            failed(bytes, e.into())
+        }
+    }
+}
+
+/// 把已经完整写好的文件 `source`（例如 tus 状态目录里收齐的数据）按 `policy` 落到 `target`，
+/// 和 [`save`] 一样先放到目标目录下的临时文件，不会让读者看到复制了一半的文件
+pub(crate) async fn save_file(name: &str, source: &Path, target: &Path, display: impl Fn(&Path) -> String, policy: ConflictPolicy) -> UploadResult {
+    let failed = |bytes: u64, e: UploadError| UploadResult::failed(name, bytes, e);
+    if matches!(policy, ConflictPolicy::Skip | ConflictPolicy::Fail) && tokio::fs::symlink_metadata(target).await.is_ok() {
+        return conflict(name, target, &display, policy);
+    }
+    let Some(dir) = target.parent() else {
+        return failed(0, UploadError::new(FailReason::InvalidName, format!("{} is not a valid name", name)));
+    };
+    let mut temp = match TempFile::create(dir).await {
+        Ok((temp, _)) => temp,
+        Err(e) => {
+            tracing::error!(">>> create temp file in {:?} error: {}", dir, e);
+            return failed(0, e.into());
+        }
+    };
+    // 同一个文件系统内直接移动，否则只能复制
+    if tokio::fs::rename(source, &temp.path).await.is_err()
+        && let Err(e) = tokio::fs::copy(source, &temp.path).await
+    {
+        tracing::error!(">>> copy {:?} to {:?} error: {}", source, &temp.path, e);
+        return failed(0, e.into());
+    }
+    let bytes = tokio::fs::metadata(&temp.path).await.map(|m| m.len()).unwrap_or_default();
+
+    match temp.persist(target, policy).await {
+        Ok((path, status)) => {
+            tracing::info!("success save file: {:?}, size: {}", &path, format_bytes(bytes));
+            UploadResult {
+                name: name.to_string(),
+                path: Some(display(&path)),
+                bytes,
+                status,
+                reason: None,
+                error: None,
+            }
+        }
+        Err(e) if e.kind() == io::ErrorKind::AlreadyExists => conflict(name, target, &display, policy),
+        Err(e) => {
+            tracing::error!(">>> save {:?} error: {}", target, e);
+            failed(bytes, e.into())
+        }
+    }
+}
+
+fn conflict(name: &str, target: &Path, display: &impl Fn(&Path) -> String, policy: ConflictPolicy) -> UploadResult {
+    let message = format!("{} already exists", display(target));
+    if policy == ConflictPolicy::Skip {
+        // 跳过是客户端要求的结果，不算失败
+        return UploadResult {
+            name: name.to_string(),
+            path: None,
+            bytes: 0,
+            status: UploadStatus::Skipped,
+            reason: None,
+            error: Some(message),
+        };
+    }
+    UploadResult::failed(name, 0, UploadError::new(FailReason::AlreadyExists, message))
+}
+
+// 把写完的临时文件移动到目标位置，返回最终路径
+fn persist(temp: &Path, target: &Path, policy: ConflictPolicy) -> io::Result<(PathBuf, UploadStatus)> {
+    match policy {
+        ConflictPolicy::Overwrite => {
+            let status = match target.symlink_metadata() {
+                Ok(meta) if meta.is_dir() => return Err(io::Error::new(io::ErrorKind::AlreadyExists, "a folder with the same name exists")),
+                Ok(_) => UploadStatus::Overwritten,
+                Err(_) => UploadStatus::Created,
+            };
+            // 同一目录内的 rename 是原子的，读者要么看到旧文件要么看到完整的新文件
+            std::fs::rename(temp, target)?;
+            Ok((target.to_path_buf(), status))
+        }
+        ConflictPolicy::Skip | ConflictPolicy::Fail => {
+            rename_new(temp, target)?;
+            Ok((target.to_path_buf(), UploadStatus::Created))
+        }
+        ConflictPolicy::Rename => {
+            for n in 0..10000 {
+                let candidate = if n == 0 { target.to_path_buf() } else { numbered(target, n) };
+                match rename_new(temp, &candidate) {
+                    Ok(()) if n == 0 => return Ok((candidate, UploadStatus::Created)),
+                    Ok(()) => return Ok((candidate, UploadStatus::Renamed)),
+                    Err(e) if e.kind() == io::ErrorKind::AlreadyExists => continue,
+                    Err(e) => return Err(e),
+                }
+            }
+            Err(io::Error::new(io::ErrorKind::AlreadyExists, "no free name left"))
+        }
+    }
+}
+
+/// 只在目标不存在时移动；硬链接在目标已存在时会失败，可以避免检查和移动之间被别的请求抢先
+pub(crate) fn rename_new(temp: &Path, target: &Path) -> io::Result<()> {
+    match std::fs::hard_link(temp, target) {
+        Ok(()) => std::fs::remove_file(temp),
+        Err(e) if e.kind() == io::ErrorKind::AlreadyExists => Err(e),
+        // 不支持硬链接的文件系统退回到先检查再移动
+        Err(_) if target.symlink_metadata().is_ok() => Err(io::Error::from(io::ErrorKind::AlreadyExists)),
+        Err(_) => std::fs::rename(temp, target),
+    }
+}
+
+/// `name.ext` 的第 n 个候选名 `name (n).ext`；`.tar.gz` 这类压缩包的双扩展名作为一个整体，
+/// 其它名字只把最后一段当作扩展名，`report.v2.pdf` 得到 `report.v2 (1).pdf`
+fn numbered(target: &Path, n: u32) -> PathBuf {
+    let name = target.file_name().map(|s| s.to_string_lossy().to_string()).unwrap_or_default();
+    // 以点开头的隐藏文件，开头的点不算扩展名
+    let split = name
+        .char_indices()
+        .filter(|&(i, c)| c == '.' && i > 0)
+        .map(|(i, _)| i)
+        .collect::<Vec<_>>();
+    let ext_start = match split.as_slice() {
+        [.., tar, last] if name[*tar..*last].eq_ignore_ascii_case(".tar") => Some(*tar),
+        [.., last] => Some(*last),
+        [] => None,
+    };
+    let name = match ext_start {
+        Some(i) => format!("{} ({}){}", &name[..i], n, &name[i..]),
+        None => format!("{} ({})", name, n),
+    };
+    target.with_file_name(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(reason: Option<FailReason>) -> UploadResult {
+        UploadResult {
+            name: "f".to_string(),
+            path: None,
+            bytes: 0,
+            status: if reason.is_some() { UploadStatus::Failed } else { UploadStatus::Created },
+            reason,
+            error: None,
+        }
+    }
+
+    // 把 `chunks` 作为带 `headers` 的请求体保存到临时目录下的 a.txt，返回结果和目录里剩下的文件
+    async fn save_body(headers: &[(&str, &str)], chunks: &[&'static [u8]]) -> (UploadResult, Vec<String>) {
+        let tmp = tempfile::tempdir().unwrap();
+        let mut map = HeaderMap::new();
+        for (name, value) in headers {
+            map.insert(header::HeaderName::from_bytes(name.as_bytes()).unwrap(), value.parse().unwrap());
+        }
+        let expected = Expected::from_headers(&map).unwrap();
+        let body = tokio_stream::iter(chunks.iter().map(|c| Ok::<_, axum::Error>(Bytes::from_static(c))));
+        let stream = VerifiedStream::new(body, expected, 16);
+        let result = save("a.txt", &tmp.path().join("a.txt"), |p| p.display().to_string(), ConflictPolicy::Overwrite, stream).await;
+        let mut files: Vec<_> = std::fs::read_dir(tmp.path())
+            .unwrap()
+            .map(|e| e.unwrap().file_name().to_string_lossy().to_string())
+            .collect();
+        files.sort();
+        (result, files)
+    }
+
+    #[tokio::test]
+    async fn verifies_bodies() {
+        let b64 = |digest: &[u8]| base64::engine::general_purpose::STANDARD.encode(digest);
+        let md5 = b64(&<md5::Md5 as md5::Digest>::digest(b"hello"));
+        let sha256 = format!("sha-256={}", b64(&<sha2::Sha256 as sha2::Digest>::digest(b"hello")));
+        let wrong_md5 = b64(&<md5::Md5 as md5::Digest>::digest(b"hellO"));
+        let wrong_sha256 = format!("sha-256={}", b64(&<sha2::Sha256 as sha2::Digest>::digest(b"hellO")));
+        let cases = [
+            (vec![], None),
+            (vec![("content-length", "5"), ("content-md5", &md5)], None),
+            (vec![("digest", &sha256)], None),
+            // 不认识的算法忽略
+            (vec![("digest", "unknown=abc, md5=XUFAKrxLKna5cZ2REBfFkg==")], None),
+            (vec![("content-md5", &wrong_md5)], Some(FailReason::ChecksumMismatch)),
+            (vec![("digest", &wrong_sha256)], Some(FailReason::ChecksumMismatch)),
+            (vec![("content-md5", &md5), ("digest", &wrong_sha256)], Some(FailReason::ChecksumMismatch)),
+            (vec![("content-length", "6")], Some(FailReason::ChecksumMismatch)),
+            (vec![("content-length", "4")], Some(FailReason::ChecksumMismatch)),
+        ];
+        for (headers, reason) in cases {
+            let (result, files) = save_body(&headers, &[b"he", b"llo"]).await;
+            assert_eq!(result.reason, reason, "{:?}", headers);
+            // 校验失败时不落盘，也不留下临时文件
+            let expected_files = if reason.is_some() { vec![] } else { vec!["a.txt".to_string()] };
+            assert_eq!(files, expected_files, "{:?}", headers);
+        }
+        assert_eq!(FailReason::ChecksumMismatch.code(), "checksum_mismatch");
+    }
+
+    #[tokio::test]
+    async fn rejects_oversized_and_broken_bodies() {
+        let (result, files) = save_body(&[], &[b"0123456789", b"0123456789"]).await;
+        assert_eq!((result.reason, result.bytes), (Some(FailReason::TooLarge), 10));
+        assert!(files.is_empty());
+
+        let tmp = tempfile::tempdir().unwrap();
+        let body = tokio_stream::iter([Ok(Bytes::from_static(b"he")), Err(axum::Error::new(io::Error::other("reset")))]);
+        let stream = VerifiedStream::new(body, Expected::from_headers(&HeaderMap::new()).unwrap(), 16);
+        let result = save("a.txt", &tmp.path().join("a.txt"), |p| p.display().to_string(), ConflictPolicy::Overwrite, stream).await;
+        assert_eq!((result.reason, result.bytes), (Some(FailReason::ClientAborted), 2));
+        assert_eq!(std::fs::read_dir(tmp.path()).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn numbers_names() {
+        let cases = [
+            ("a.txt", 1, "a (1).txt"),
+            ("a", 2, "a (2)"),
+            ("a.tar.gz", 1, "a (1).tar.gz"),
+            ("a.TAR.zst", 3, "a (3).TAR.zst"),
+            ("report.v2.pdf", 1, "report.v2 (1).pdf"),
+            (".bashrc", 1, ".bashrc (1)"),
+            (".config.toml", 1, ".config (1).toml"),
+            ("a.", 1, "a (1)."),
+            ("报告.docx", 1, "报告 (1).docx"),
+        ];
+        for (name, n, expected) in cases {
+            assert_eq!(numbered(&Path::new("/srv/dir").join(name), n), Path::new("/srv/dir").join(expected), "{}", name);
+        }
+    }
+
+    #[test]
+    fn persists_by_policy() {
+        use ConflictPolicy::*;
+        // (策略, 目标是否已存在, 期望的状态, 期望的最终文件名)
+        let cases = [
+            (Overwrite, false, Ok(UploadStatus::Created), "a.txt"),
+            (Overwrite, true, Ok(UploadStatus::Overwritten), "a.txt"),
+            (Skip, false, Ok(UploadStatus::Created), "a.txt"),
+            (Skip, true, Err(io::ErrorKind::AlreadyExists), "a.txt"),
+            (Fail, false, Ok(UploadStatus::Created), "a.txt"),
+            (Fail, true, Err(io::ErrorKind::AlreadyExists), "a.txt"),
+            (Rename, false, Ok(UploadStatus::Created), "a.txt"),
+            (Rename, true, Ok(UploadStatus::Renamed), "a (2).txt"),
+        ];
+        for (policy, exists, expected, saved) in cases {
+            let tmp = tempfile::tempdir().unwrap();
+            let (temp, target) = (tmp.path().join(".upload"), tmp.path().join("a.txt"));
+            std::fs::write(&temp, b"new").unwrap();
+            if exists {
+                std::fs::write(&target, b"old").unwrap();
+                std::fs::write(tmp.path().join("a (1).txt"), b"old").unwrap();
+            }
+            let persisted = persist(&temp, &target, policy);
+            let case = format!("{:?} exists={}", policy, exists);
+            assert_eq!(persisted.as_ref().map(|(_, s)| *s).map_err(|e| e.kind()), expected, "{}", case);
+            match persisted {
+                Ok((path, _)) => {
+                    assert_eq!(path, tmp.path().join(saved), "{}", case);
+                    assert_eq!(std::fs::read(&path).unwrap(), b"new", "{}", case);
+                    assert!(!temp.exists(), "{}", case);
+                }
+                // 冲突时已有文件保持原样，临时文件留给调用者清理
+                Err(_) => {
+                    assert_eq!(std::fs::read(&target).unwrap(), b"old", "{}", case);
+                    assert!(temp.exists(), "{}", case);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn overwrite_keeps_folders() {
+        let tmp = tempfile::tempdir().unwrap();
+        let (temp, target) = (tmp.path().join(".upload"), tmp.path().join("dir"));
+        std::fs::write(&temp, b"new").unwrap();
+        std::fs::create_dir(&target).unwrap();
+        assert_eq!(persist(&temp, &target, ConflictPolicy::Overwrite).unwrap_err().kind(), io::ErrorKind::AlreadyExists);
+        assert!(target.is_dir());
+    }
+
+    #[test]
+    fn combines_statuses() {
+        use FailReason::*;
+        let cases = [
+            (vec![], StatusCode::BAD_REQUEST),
+            (vec![None], StatusCode::OK),
+            (vec![None, None], StatusCode::OK),
+            (vec![None, Some(DiskFull)], StatusCode::MULTI_STATUS),
+            (vec![Some(ClientAborted), None], StatusCode::MULTI_STATUS),
+            (vec![Some(AlreadyExists)], StatusCode::CONFLICT),
+            // 全部失败时取最严重的，5xx 优先于 4xx
+            (vec![Some(AlreadyExists), Some(DiskFull)], StatusCode::INSUFFICIENT_STORAGE),
+            (vec![Some(Io), Some(DiskFull), Some(InvalidName)], StatusCode::INSUFFICIENT_STORAGE),
+            (vec![Some(InvalidName), Some(TooLarge)], StatusCode::PAYLOAD_TOO_LARGE),
+            (vec![Some(NotAllowed), Some(Io)], StatusCode::INTERNAL_SERVER_ERROR),
+        ];
+        for (reasons, expected) in cases {
+            let results: Vec<_> = reasons.iter().copied().map(result).collect();
+            assert_eq!(overall_status(&results), expected, "{:?}", reasons);
+        }
+    }
+}
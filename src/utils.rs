@@ -1,3 +1,12 @@
+use argon2::password_hash::rand_core::{OsRng, RngCore};
+
+/// `n` 个随机字节的十六进制字符串，用于会话令牌、上传 id、临时文件名等
+pub(crate) fn random_hex(n: usize) -> String {
+    let mut bytes = vec![0u8; n];
+    OsRng.fill_bytes(&mut bytes);
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
 pub(crate) fn format_bytes(b: u64) -> String {
     // 预定义单位常量 (Base 1024)
     const KIB: u64 = 1024;
@@ -0,0 +1,161 @@
+use crate::paths::PathResolver;
+use notify::event::{ModifyKind, RenameMode};
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tokio::sync::broadcast;
+
+/// 订阅者处理不过来时最多积压的事件数，超出后订阅者会收到 `Lagged`
+const CHANNEL_CAPACITY: usize = 4096;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum ChangeKind {
+    Create,
+    Modify,
+    #[serde(rename = "delete")]
+    Remove,
+    Rename,
+}
+
+/// 根目录下的一次变化，路径都是绝对路径；重命名时 `from` 是原路径
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Change {
+    pub(crate) kind: ChangeKind,
+    pub(crate) path: PathBuf,
+    pub(crate) from: Option<PathBuf>,
+    /// 来自重命名通知时的配对标识。inotify 对根目录内的重命名会先后发出 From、To 和配对好的 Both，
+    /// 单独的 From、To 只有在没有配对时才表示移出、移入，收到同一标识的 [`ChangeKind::Rename`] 后应当丢弃
+    pub(crate) tracker: Option<usize>,
+}
+
+/// 递归监听根目录，把文件系统通知整理成 [`Change`] 广播给订阅者（内容索引和 `/events`）；
+/// 状态目录和上传中的临时文件不产生事件
+pub(crate) struct FsWatcher {
+    // 持有 watcher，drop 之后监听就停止了
+    _watcher: RecommendedWatcher,
+    tx: broadcast::Sender<Change>,
+}
+
+impl FsWatcher {
+    pub(crate) fn new(root: &Path, paths: Arc<PathResolver>) -> notify::Result<Self> {
+        let (tx, _) = broadcast::channel(CHANNEL_CAPACITY);
+        let sender = tx.clone();
+        let mut watcher = notify::recommended_watcher(move |res: notify::Result<Event>| match res {
+            Ok(event) => {
+                for change in changes(event, &paths) {
+                    // 没有订阅者时发送失败，忽略即可
+                    let _ = sender.send(change);
+                }
+            }
+            Err(e) => tracing::warn!(">>> watch error: {}", e),
+        })?;
+        watcher.watch(root, RecursiveMode::Recursive)?;
+        tracing::info!(">>> watching {:?} for changes", root);
+        Ok(FsWatcher { _watcher: watcher, tx })
+    }
+
+    pub(crate) fn subscribe(&self) -> broadcast::Receiver<Change> {
+        self.tx.subscribe()
+    }
+}
+
+fn changes(event: Event, paths: &PathResolver) -> Vec<Change> {
+    let tracker = event.tracker();
+    let change = |kind, path: &PathBuf| Change {
+        kind,
+        path: path.clone(),
+        from: None,
+        tracker,
+    };
+    let kind = match event.kind {
+        EventKind::Create(_) => ChangeKind::Create,
+        EventKind::Remove(_) => ChangeKind::Remove,
+        EventKind::Modify(ModifyKind::Name(RenameMode::Both)) if event.paths.len() == 2 => {
+            let (from, to) = (&event.paths[0], &event.paths[1]);
+            // 上传完成时临时文件被重命名为目标文件，对外表现为新建
+            return match (paths.is_reserved(from), paths.is_reserved(to)) {
+                (true, true) => vec![],
+                (true, false) => vec![change(ChangeKind::Create, to)],
+                (false, true) => vec![change(ChangeKind::Remove, from)],
+                (false, false) => vec![Change {
+                    kind: ChangeKind::Rename,
+                    path: to.clone(),
+                    from: Some(from.clone()),
+                    tracker,
+                }],
+            };
+        }
+        // 没有配对的重命名是移入或移出了根目录；配对的随后还有一个 Both，由订阅者按 tracker 去重
+        EventKind::Modify(ModifyKind::Name(RenameMode::From)) => ChangeKind::Remove,
+        EventKind::Modify(ModifyKind::Name(RenameMode::To)) => ChangeKind::Create,
+        EventKind::Modify(_) | EventKind::Any => ChangeKind::Modify,
+        EventKind::Access(_) | EventKind::Other => return vec![],
+    };
+    event
+        .paths
+        .iter()
+        .filter(|p| !paths.is_reserved(p))
+        .map(|p| change(kind, p))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::paths::{SymlinkPolicy, TEMP_PREFIX};
+    use notify::event::{CreateKind, DataChange, RemoveKind};
+
+    fn resolver() -> PathResolver {
+        PathResolver::new(PathBuf::from("/srv"), SymlinkPolicy::FollowWithinRoot, Path::new("/srv/.rshttpserver"))
+    }
+
+    fn change(kind: ChangeKind, path: &str, from: Option<&str>, tracker: Option<usize>) -> Change {
+        Change {
+            kind,
+            path: PathBuf::from(path),
+            from: from.map(PathBuf::from),
+            tracker,
+        }
+    }
+
+    #[test]
+    fn translates_events() {
+        let temp = format!("/srv/{}0123", TEMP_PREFIX);
+        let rename = |mode| EventKind::Modify(ModifyKind::Name(mode));
+        let cases = [
+            (Event::new(EventKind::Create(CreateKind::File)).add_path("/srv/a".into()), vec![change(ChangeKind::Create, "/srv/a", None, None)]),
+            (Event::new(EventKind::Remove(RemoveKind::File)).add_path("/srv/a".into()), vec![change(ChangeKind::Remove, "/srv/a", None, None)]),
+            (
+                Event::new(EventKind::Modify(ModifyKind::Data(DataChange::Any))).add_path("/srv/a".into()),
+                vec![change(ChangeKind::Modify, "/srv/a", None, None)],
+            ),
+            (
+                Event::new(rename(RenameMode::From)).add_path("/srv/a".into()).set_tracker(7),
+                vec![change(ChangeKind::Remove, "/srv/a", None, Some(7))],
+            ),
+            (
+                Event::new(rename(RenameMode::To)).add_path("/srv/b".into()).set_tracker(7),
+                vec![change(ChangeKind::Create, "/srv/b", None, Some(7))],
+            ),
+            (
+                Event::new(rename(RenameMode::Both)).add_path("/srv/a".into()).add_path("/srv/b".into()).set_tracker(7),
+                vec![change(ChangeKind::Rename, "/srv/b", Some("/srv/a"), Some(7))],
+            ),
+            // 上传完成时临时文件重命名为目标文件
+            (
+                Event::new(rename(RenameMode::Both)).add_path(temp.clone().into()).add_path("/srv/b".into()).set_tracker(8),
+                vec![change(ChangeKind::Create, "/srv/b", None, Some(8))],
+            ),
+            (Event::new(EventKind::Create(CreateKind::File)).add_path(temp.into()), vec![]),
+            (Event::new(EventKind::Create(CreateKind::File)).add_path("/srv/.rshttpserver/tus/x.part".into()), vec![]),
+            (Event::new(EventKind::Access(notify::event::AccessKind::Any)).add_path("/srv/a".into()), vec![]),
+        ];
+        let paths = resolver();
+        for (event, expected) in cases {
+            let kind = event.kind;
+            assert_eq!(changes(event, &paths), expected, "{:?}", kind);
+        }
+    }
+}
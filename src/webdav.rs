@@ -0,0 +1,450 @@
+use crate::access::{self, Op};
+use crate::auth::{CurrentUser, Role};
+use crate::config::AppConfig;
+use crate::error::AppError;
+use crate::paths::{PathError, PathResolver};
+use crate::state::AppState;
+use crate::trash::Trash;
+use crate::upload::{ConflictPolicy, TempFile};
+use axum::Extension;
+use axum::body::Body;
+use axum::extract::{Request, State};
+use axum::http::Uri;
+use axum::response::{IntoResponse, Response};
+use bytes::{Buf, Bytes};
+use dav_server::davpath::DavPath;
+use dav_server::fs::{DavDirEntry, DavFile, DavMetaData, FsError, FsFuture, FsStream, GuardedFileSystem, OpenOptions, ReadDirMeta};
+use dav_server::localfs::LocalFs;
+use dav_server::memls::MemLs;
+use dav_server::{DavHandler, DavMethod, DavMethodSet};
+use std::io::{self, SeekFrom};
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::SystemTime;
+use tokio::io::{AsyncWriteExt, BufWriter};
+use tokio_stream::StreamExt;
+
+/// 以 WebDAV 暴露根目录，读写都经过 [`RootFs`]，和 JSON API 使用同一套路径解析和访问规则
+pub(crate) fn create_dav_handler(config: &Arc<AppConfig>, paths: &Arc<PathResolver>, trash: &Option<Arc<Trash>>, prefix: &str) -> DavHandler<CurrentUser> {
+    // 和 JSON API 一样，只读模式不提供修改类的方法，投递箱模式不提供浏览和下载
+    let methods = if config.read_only {
+        DavMethodSet::WEBDAV_RO
+    } else if config.upload_only {
+        let mut methods = DavMethodSet::none();
+        for m in [DavMethod::Options, DavMethod::Put, DavMethod::MkCol, DavMethod::Lock, DavMethod::Unlock] {
+            methods.add(m);
+        }
+        methods
+    } else {
+        DavMethodSet::WEBDAV_RW
+    };
+    let fs = RootFs {
+        inner: LocalFs::new(&config.root_dirpath, true, false, false),
+        config: config.clone(),
+        paths: paths.clone(),
+        trash: trash.clone(),
+    };
+    DavHandler::builder()
+        .strip_prefix(prefix)
+        .filesystem(Box::new(fs))
+        .locksystem(MemLs::new())
+        .methods(methods)
+        .autoindex(false)
+        .build_handler()
+}
+
+/// WebDAV 请求入口，先按方法检查角色，未登录时返回 401 让客户端弹出登录框
+pub(crate) async fn dav_handler(State(state): State<AppState>, Extension(user): Extension<CurrentUser>, request: Request) -> Response {
+    let role = match request.method().as_str() {
+        "GET" | "HEAD" | "OPTIONS" | "PROPFIND" => Role::Viewer,
+        "MOVE" | "DELETE" => Role::Admin,
+        _ => Role::Uploader,
+    };
+    if let Err(e) = user.require(role) {
+        return e.into_response();
+    }
+    let (Some(dav), Some(prefix)) = (&state.dav, &state.config.dav_prefix) else {
+        return AppError::Disabled("WebDAV is disabled".to_string()).into_response();
+    };
+    // 递归删除会先删掉子条目再在根目录上失败，所以根目录要在交给 DavHandler 之前拒绝
+    if role == Role::Admin && is_root(&state.paths, request.uri(), prefix).await {
+        return AppError::from(PathError::Root).into_response();
+    }
+    // DavHandler 只在 Depth: 0 时对目录直接调用 remove_dir，否则逐个删除其中的文件；
+    // 开启回收站时改成 Depth: 0，由 remove_dir 把整个目录作为一个条目移到回收站，和 `/delete` 一致
+    let mut request = request;
+    if request.method() == axum::http::Method::DELETE && state.trash.is_some() {
+        request.headers_mut().insert("depth", axum::http::HeaderValue::from_static("0"));
+    }
+    dav.handle_guarded(request, user).await.map(Body::new)
+}
+
+async fn is_root(paths: &Arc<PathResolver>, uri: &Uri, prefix: &str) -> bool {
+    let Ok(mut path) = DavPath::from_uri(uri) else {
+        return false;
+    };
+    if path.set_prefix(prefix).is_err() {
+        return false;
+    }
+    paths
+        .resolve_new_async(&path.as_rel_ospath().to_string_lossy())
+        .await
+        .is_ok_and(|p| paths.is_root(&p))
+}
+
+impl From<PathError> for FsError {
+    fn from(e: PathError) -> Self {
+        match e {
+            PathError::NotFound(_) => FsError::NotFound,
+            PathError::Io(_, e) if e.kind() == io::ErrorKind::NotFound => FsError::NotFound,
+            PathError::Io(_, e) if e.kind() != io::ErrorKind::PermissionDenied => FsError::GeneralFailure,
+            _ => FsError::Forbidden,
+        }
+    }
+}
+
+/// 套在 [`LocalFs`] 外面的文件系统，每个操作都先检查角色、路径和访问规则，再交给 LocalFs 执行
+#[derive(Clone)]
+struct RootFs {
+    inner: Box<LocalFs>,
+    config: Arc<AppConfig>,
+    paths: Arc<PathResolver>,
+    trash: Option<Arc<Trash>>,
+}
+
+impl RootFs {
+    /// 返回解析后的绝对路径；`op` 为空时只检查路径是否可见
+    async fn guard(&self, path: &DavPath, user: &CurrentUser, role: Role, op: Option<Op>, exists: bool) -> Result<PathBuf, FsError> {
+        user.require(role).map_err(|_| FsError::Forbidden)?;
+        let epath = path.as_rel_ospath().to_string_lossy();
+        let a_path = if exists {
+            self.paths.resolve_async(&epath).await?
+        } else {
+            self.paths.resolve_new_async(&epath).await?
+        };
+        let rpath = access::rule_path(&self.config.root_dirpath, &a_path);
+        if access::is_hidden(&self.config.access_rules, user, &rpath) {
+            return Err(FsError::NotFound);
+        }
+        if let Some(op) = op
+            && !access::allows(&self.config.access_rules, user, &rpath, op)
+        {
+            return Err(FsError::Forbidden);
+        }
+        Ok(a_path)
+    }
+
+    // 删除、移动前拒绝根目录本身，以及包含状态目录的目录
+    async fn guard_not_root(&self, path: &DavPath, user: &CurrentUser, role: Role, op: Op) -> Result<PathBuf, FsError> {
+        let a_path = self.guard(path, user, role, Some(op), true).await?;
+        if self.paths.is_root(&a_path) {
+            return Err(PathError::Root.into());
+        }
+        if self.config.state_dir.starts_with(&a_path) {
+            return Err(FsError::Forbidden);
+        }
+        Ok(a_path)
+    }
+
+    async fn move_to_trash(&self, trash: Arc<Trash>, a_path: PathBuf, user: &CurrentUser) -> Result<(), FsError> {
+        let user = user.clone();
+        match tokio::task::spawn_blocking(move || trash.put(&a_path, &user)).await {
+            Ok(Ok(_)) => Ok(()),
+            Ok(Err(e)) if e.kind() == io::ErrorKind::NotFound => Err(FsError::NotFound),
+            Ok(Err(e)) => {
+                tracing::error!(">>> move to trash error: {}", e);
+                Err(FsError::GeneralFailure)
+            }
+            Err(_) => Err(FsError::GeneralFailure),
+        }
+    }
+}
+
+impl GuardedFileSystem<CurrentUser> for RootFs {
+    fn open<'a>(&'a self, path: &'a DavPath, options: OpenOptions, user: &'a CurrentUser) -> FsFuture<'a, Box<dyn DavFile>> {
+        Box::pin(async move {
+            if options.write || options.append || options.truncate || options.create || options.create_new {
+                let a_path = self.guard(path, user, Role::Uploader, Some(Op::Upload), false).await?;
+                // DavHandler 直接读取请求体，不经过 DefaultBodyLimit，大小限制要在这里检查
+                let limit = self.config.max_upload_size;
+                if options.size.is_some_and(|size| size > limit) {
+                    return Err(FsError::TooLarge);
+                }
+                // 整个文件的 PUT 写到临时文件，写完再移动到目标位置
+                if options.truncate && !options.read {
+                    return Ok(Box::new(TempDavFile::create(a_path, &options, limit).await?) as Box<dyn DavFile>);
+                }
+                // 带 Content-Range 的局部写入只能在原文件上进行，按写入位置限制文件大小
+                let append = options.append;
+                let mut file = self.inner.open(path, options, &()).await?;
+                let pos = if append { file.metadata().await?.len() } else { 0 };
+                return Ok(Box::new(LimitedDavFile { inner: file, pos, limit }) as Box<dyn DavFile>);
+            } else {
+                if self.config.upload_only {
+                    return Err(FsError::Forbidden);
+                }
+                self.guard(path, user, Role::Viewer, Some(Op::Download), true).await?;
+            }
+            self.inner.open(path, options, &()).await
+        })
+    }
+
+    fn read_dir<'a>(&'a self, path: &'a DavPath, meta: ReadDirMeta, user: &'a CurrentUser) -> FsFuture<'a, FsStream<Box<dyn DavDirEntry>>> {
+        Box::pin(async move {
+            if self.config.upload_only {
+                return Err(FsError::Forbidden);
+            }
+            let a_path = self.guard(path, user, Role::Viewer, Some(Op::List), true).await?;
+            let mut stream = self.inner.read_dir(path, meta, &()).await?;
+            // 和列表接口一样，跳过对当前用户隐藏的条目和不满足符号链接策略的条目
+            let mut entries = vec![];
+            while let Some(entry) = stream.next().await {
+                let entry = entry?;
+                let a_entry_path = a_path.join(String::from_utf8_lossy(&entry.name()).as_ref());
+                entries.push((a_entry_path, entry));
+            }
+            // 符号链接策略要逐级读取元数据，整批放到阻塞线程里检查
+            let (fs, user) = (self.clone(), user.clone());
+            let entries = tokio::task::spawn_blocking(move || {
+                entries
+                    .into_iter()
+                    .filter(|(a_entry_path, _)| {
+                        let rpath = access::rule_path(&fs.config.root_dirpath, a_entry_path);
+                        !access::is_hidden(&fs.config.access_rules, &user, &rpath) && fs.paths.permits(a_entry_path)
+                    })
+                    .map(|(_, entry)| Ok(entry))
+                    .collect::<Vec<_>>()
+            })
+            .await
+            .map_err(|_| FsError::GeneralFailure)?;
+            Ok(Box::pin(tokio_stream::iter(entries)) as FsStream<Box<dyn DavDirEntry>>)
+        })
+    }
+
+    fn metadata<'a>(&'a self, path: &'a DavPath, user: &'a CurrentUser) -> FsFuture<'a, Box<dyn DavMetaData>> {
+        Box::pin(async move {
+            self.guard(path, user, Role::Viewer, None, true).await?;
+            self.inner.metadata(path, &()).await
+        })
+    }
+
+    fn symlink_metadata<'a>(&'a self, path: &'a DavPath, user: &'a CurrentUser) -> FsFuture<'a, Box<dyn DavMetaData>> {
+        Box::pin(async move {
+            self.guard(path, user, Role::Viewer, None, true).await?;
+            self.inner.symlink_metadata(path, &()).await
+        })
+    }
+
+    fn create_dir<'a>(&'a self, path: &'a DavPath, user: &'a CurrentUser) -> FsFuture<'a, ()> {
+        Box::pin(async move {
+            self.guard(path, user, Role::Uploader, Some(Op::Create), false).await?;
+            self.inner.create_dir(path, &()).await
+        })
+    }
+
+    // 开启回收站时 dav_handler 让 DavHandler 直接删除目录本身，整个目录作为一个条目进入回收站；
+    // 否则 DavHandler 先逐个删除其中的文件，这里只删除已经清空的目录
+    fn remove_dir<'a>(&'a self, path: &'a DavPath, user: &'a CurrentUser) -> FsFuture<'a, ()> {
+        Box::pin(async move {
+            let a_path = self.guard_not_root(path, user, Role::Admin, Op::Delete).await?;
+            match self.trash.clone() {
+                Some(trash) => self.move_to_trash(trash, a_path, user).await,
+                None => self.inner.remove_dir(path, &()).await,
+            }
+        })
+    }
+
+    fn remove_file<'a>(&'a self, path: &'a DavPath, user: &'a CurrentUser) -> FsFuture<'a, ()> {
+        Box::pin(async move {
+            let a_path = self.guard_not_root(path, user, Role::Admin, Op::Delete).await?;
+            match self.trash.clone() {
+                Some(trash) => self.move_to_trash(trash, a_path, user).await,
+                None => self.inner.remove_file(path, &()).await,
+            }
+        })
+    }
+
+    fn rename<'a>(&'a self, from: &'a DavPath, to: &'a DavPath, user: &'a CurrentUser) -> FsFuture<'a, ()> {
+        Box::pin(async move {
+            // 原路径和新路径都需要允许重命名
+            self.guard_not_root(from, user, Role::Admin, Op::Rename).await?;
+            self.guard(to, user, Role::Admin, Some(Op::Rename), false).await?;
+            self.inner.rename(from, to, &()).await
+        })
+    }
+
+    fn copy<'a>(&'a self, from: &'a DavPath, to: &'a DavPath, user: &'a CurrentUser) -> FsFuture<'a, ()> {
+        Box::pin(async move {
+            self.guard(from, user, Role::Uploader, Some(Op::Download), true).await?;
+            self.guard(to, user, Role::Uploader, Some(Op::Upload), false).await?;
+            self.inner.copy(from, to, &()).await
+        })
+    }
+}
+
+/// PUT 整个文件时的写入目标：先写到目标目录下的临时文件，flush 时再移动到目标位置，
+/// 写入失败或客户端断开时临时文件随 drop 删除，其他客户端不会读到写了一半的文件
+#[derive(Debug)]
+struct TempDavFile {
+    writer: BufWriter<tokio::fs::File>,
+    temp: TempFile,
+    target: PathBuf,
+    policy: ConflictPolicy,
+    expected: Option<u64>,
+    written: u64,
+    limit: u64,
+}
+
+impl TempDavFile {
+    async fn create(target: PathBuf, options: &OpenOptions, limit: u64) -> Result<TempDavFile, FsError> {
+        match tokio::fs::symlink_metadata(&target).await {
+            Ok(meta) if meta.is_dir() => return Err(FsError::Forbidden),
+            Ok(_) if options.create_new => return Err(FsError::Exists),
+            Err(e) if e.kind() == io::ErrorKind::NotFound && !options.create && !options.create_new => return Err(FsError::NotFound),
+            _ => {}
+        }
+        let dir = target.parent().ok_or(FsError::Forbidden)?;
+        let (temp, file) = TempFile::create(dir).await?;
+        Ok(TempDavFile {
+            writer: BufWriter::new(file),
+            temp,
+            target,
+            // If-None-Match: * 要求目标不存在，移动时目标已被别的请求创建也要失败
+            policy: if options.create_new { ConflictPolicy::Fail } else { ConflictPolicy::Overwrite },
+            expected: options.size,
+            written: 0,
+            limit,
+        })
+    }
+
+    async fn write(&mut self, bytes: Bytes) -> Result<(), FsError> {
+        if self.written + bytes.len() as u64 > self.limit {
+            return Err(FsError::TooLarge);
+        }
+        self.writer.write_all(&bytes).await?;
+        self.written += bytes.len() as u64;
+        Ok(())
+    }
+}
+
+impl DavFile for TempDavFile {
+    fn metadata(&mut self) -> FsFuture<'_, Box<dyn DavMetaData>> {
+        Box::pin(async move {
+            let path = if self.temp.is_persisted() { &self.target } else { &self.temp.path };
+            let meta = tokio::fs::metadata(path).await?;
+            Ok(Box::new(FileMeta(meta)) as Box<dyn DavMetaData>)
+        })
+    }
+
+    fn write_buf(&mut self, mut buf: Box<dyn Buf + Send>) -> FsFuture<'_, ()> {
+        Box::pin(async move { self.write(buf.copy_to_bytes(buf.remaining())).await })
+    }
+
+    fn write_bytes(&mut self, buf: Bytes) -> FsFuture<'_, ()> {
+        Box::pin(async move { self.write(buf).await })
+    }
+
+    fn read_bytes(&mut self, _count: usize) -> FsFuture<'_, Bytes> {
+        Box::pin(async move { Err(FsError::NotImplemented) })
+    }
+
+    fn seek(&mut self, _pos: SeekFrom) -> FsFuture<'_, u64> {
+        Box::pin(async move { Err(FsError::NotImplemented) })
+    }
+
+    fn flush(&mut self) -> FsFuture<'_, ()> {
+        Box::pin(async move {
+            self.writer.flush().await?;
+            // 请求体比 Content-Length 短时不保存，DavHandler 随后返回 400，临时文件随 drop 删除
+            if self.temp.is_persisted() || self.expected.is_some_and(|n| n != self.written) {
+                return Ok(());
+            }
+            match self.temp.persist(&self.target, self.policy).await {
+                Ok(_) => Ok(()),
+                Err(e) => {
+                    tracing::error!(">>> save {:?} error: {}", &self.target, e);
+                    Err(e.into())
+                }
+            }
+        })
+    }
+}
+
+/// 局部写入时套在 LocalFs 文件外面，写入位置超过上传大小限制时拒绝
+#[derive(Debug)]
+struct LimitedDavFile {
+    inner: Box<dyn DavFile>,
+    pos: u64,
+    limit: u64,
+}
+
+impl LimitedDavFile {
+    fn check(&self, len: usize) -> Result<(), FsError> {
+        if self.pos + len as u64 > self.limit {
+            return Err(FsError::TooLarge);
+        }
+        Ok(())
+    }
+}
+
+impl DavFile for LimitedDavFile {
+    fn metadata(&mut self) -> FsFuture<'_, Box<dyn DavMetaData>> {
+        self.inner.metadata()
+    }
+
+    fn write_buf(&mut self, buf: Box<dyn Buf + Send>) -> FsFuture<'_, ()> {
+        Box::pin(async move {
+            let len = buf.remaining();
+            self.check(len)?;
+            self.inner.write_buf(buf).await?;
+            self.pos += len as u64;
+            Ok(())
+        })
+    }
+
+    fn write_bytes(&mut self, buf: Bytes) -> FsFuture<'_, ()> {
+        Box::pin(async move {
+            let len = buf.len();
+            self.check(len)?;
+            self.inner.write_bytes(buf).await?;
+            self.pos += len as u64;
+            Ok(())
+        })
+    }
+
+    fn read_bytes(&mut self, count: usize) -> FsFuture<'_, Bytes> {
+        Box::pin(async move {
+            let bytes = self.inner.read_bytes(count).await?;
+            self.pos += bytes.len() as u64;
+            Ok(bytes)
+        })
+    }
+
+    fn seek(&mut self, pos: SeekFrom) -> FsFuture<'_, u64> {
+        Box::pin(async move {
+            self.pos = self.inner.seek(pos).await?;
+            Ok(self.pos)
+        })
+    }
+
+    fn flush(&mut self) -> FsFuture<'_, ()> {
+        self.inner.flush()
+    }
+}
+
+#[derive(Debug, Clone)]
+struct FileMeta(std::fs::Metadata);
+
+impl DavMetaData for FileMeta {
+    fn len(&self) -> u64 {
+        self.0.len()
+    }
+
+    fn modified(&self) -> Result<SystemTime, FsError> {
+        Ok(self.0.modified()?)
+    }
+
+    fn is_dir(&self) -> bool {
+        self.0.is_dir()
+    }
+}
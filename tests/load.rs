@@ -0,0 +1,121 @@
+//! 大目录删除的负载测试，耗时较长，默认不运行：
+//!
+//! ```sh
+//! cargo test --release --test load -- --ignored --nocapture
+//! ```
+//!
+//! `RSHTTP_LOAD_FILES` 指定被删除目录里的文件数（默认 100000）。
+
+use std::net::TcpListener;
+use std::path::PathBuf;
+use std::process::{Child, Command, Stdio};
+use std::time::{Duration, Instant};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+
+/// 在临时根目录上启动的服务进程，drop 时结束
+struct Server {
+    child: Child,
+    port: u16,
+    root: PathBuf,
+    _dir: tempfile::TempDir,
+}
+
+impl Server {
+    async fn start(args: &[&str]) -> Server {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().join("root");
+        std::fs::create_dir_all(&root).unwrap();
+        let port = TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap().port();
+        let child = Command::new(env!("CARGO_BIN_EXE_rshttpserver"))
+            .args(["--host", "127.0.0.1", "--port", &port.to_string()])
+            .arg("--root")
+            .arg(&root)
+            .args(args)
+            .env("RUST_LOG", "warn")
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()
+            .expect("Failed to start server");
+        let server = Server {
+            child,
+            port,
+            root,
+            _dir: dir,
+        };
+        for _ in 0..100 {
+            if TcpStream::connect(("127.0.0.1", port)).await.is_ok() {
+                return server;
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+        panic!("server did not start on port {}", port);
+    }
+}
+
+impl Drop for Server {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+async fn read_status(mut stream: TcpStream) -> u16 {
+    let mut response = vec![];
+    stream.read_to_end(&mut response).await.unwrap();
+    let response = String::from_utf8_lossy(&response);
+    response
+        .split(' ')
+        .nth(1)
+        .and_then(|s| s.parse().ok())
+        .unwrap_or_else(|| panic!("invalid response: {:?}", response))
+}
+
+/// 发送一个没有请求体的请求，返回状态码
+async fn request(port: u16, method: &str, path: &str) -> u16 {
+    let mut stream = TcpStream::connect(("127.0.0.1", port)).await.unwrap();
+    let head = format!("{} {} HTTP/1.1\r\nHost: localhost\r\nContent-Length: 0\r\nConnection: close\r\n\r\n", method, path);
+    stream.write_all(head.as_bytes()).await.unwrap();
+    read_status(stream).await
+}
+
+fn env_or(name: &str, default: u64) -> u64 {
+    std::env::var(name).ok().and_then(|v| v.parse().ok()).unwrap_or(default)
+}
+
+// 删除大目录时其它请求的延迟不受影响
+#[tokio::test(flavor = "multi_thread")]
+#[ignore]
+async fn listing_during_large_delete() {
+    let files = env_or("RSHTTP_LOAD_FILES", 100_000);
+    // 关闭回收站，删除就是耗时的 remove_dir_all 而不是一次 rename
+    let server = Server::start(&["--trash", "false"]).await;
+    std::fs::create_dir_all(server.root.join("small")).unwrap();
+    let big = server.root.join("big");
+    for i in 0..files {
+        let dir = big.join((i / 1000).to_string());
+        if i % 1000 == 0 {
+            std::fs::create_dir_all(&dir).unwrap();
+        }
+        std::fs::write(dir.join(format!("{}.txt", i)), b"x").unwrap();
+    }
+
+    let mut idle = Duration::ZERO;
+    for _ in 0..20 {
+        let started = Instant::now();
+        assert_eq!(request(server.port, "GET", "/info/small").await, 200);
+        idle = idle.max(started.elapsed());
+    }
+    let delete = tokio::spawn(request(server.port, "DELETE", "/delete/big"));
+    let (mut busy, mut count) = (Duration::ZERO, 0);
+    while !delete.is_finished() {
+        let started = Instant::now();
+        assert_eq!(request(server.port, "GET", "/info/small").await, 200);
+        busy = busy.max(started.elapsed());
+        count += 1;
+    }
+    assert_eq!(delete.await.unwrap(), 200);
+    assert!(!big.exists());
+    println!("{} files deleted, {} listings meanwhile, slowest {:?}, {:?} when idle", files, count, busy, idle);
+    assert!(busy < idle.max(Duration::from_millis(50)) * 10, "slowest listing took {:?}", busy);
+}